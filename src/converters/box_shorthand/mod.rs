@@ -0,0 +1,126 @@
+/// Expands a CSS box shorthand value into its top, right, bottom and left values.
+///
+/// The box shorthands, such as `margin`, `padding` and `border-width`, accept from one to four
+/// whitespace separated values, which are distributed clockwise following the CSS rules:
+///
+/// - `10px`: All four sides receive `10px`.
+/// - `10px 20px`: Top and bottom receive `10px`, right and left receive `20px`.
+/// - `10px 20px 30px`: Top receives `10px`, right and left receive `20px`, bottom receives `30px`.
+/// - `10px 20px 30px 40px`: Top, right, bottom and left respectively.
+///
+/// Functions such as `calc(1rem + 2px)` or `var(--gutter)` are kept together as a single value.
+///
+/// # Parameters
+/// - `value`: The value assigned to a box shorthand property.
+///
+/// # Returns
+/// - `Some([top, right, bottom, left])` when the value holds from one to four values.
+/// - `None` when the value is empty, holds more than four values or has unbalanced parentheses.
+pub fn expand_box_shorthand(value: &str) -> Option<[String; 4]> {
+    let values = split_box_values(value)?;
+
+    match values.as_slice() {
+        [all] => Some([all, all, all, all].map(|side| side.to_string())),
+        [vertical, horizontal] => {
+            Some([vertical, horizontal, vertical, horizontal].map(|side| side.to_string()))
+        }
+        [top, horizontal, bottom] => {
+            Some([top, horizontal, bottom, horizontal].map(|side| side.to_string()))
+        }
+        [top, right, bottom, left] => Some([top, right, bottom, left].map(|side| side.to_string())),
+        _ => None,
+    }
+}
+
+/// Splits a box shorthand value on the whitespaces outside of parentheses.
+fn split_box_values(value: &str) -> Option<Vec<&str>> {
+    let mut values = Vec::new();
+    let mut value_start: Option<usize> = None;
+    let mut depth = 0;
+
+    for (idx, char) in value.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' if depth == 0 => return None,
+            ')' => depth -= 1,
+            _ if char.is_whitespace() && depth == 0 => {
+                if let Some(start) = value_start.take() {
+                    values.push(&value[start..idx]);
+                }
+
+                continue;
+            }
+            _ => {}
+        }
+
+        value_start.get_or_insert(idx);
+    }
+
+    if depth != 0 {
+        return None;
+    }
+
+    if let Some(start) = value_start {
+        values.push(&value[start..]);
+    }
+
+    Some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand_box_shorthand;
+
+    fn sides(top: &str, right: &str, bottom: &str, left: &str) -> Option<[String; 4]> {
+        Some([top, right, bottom, left].map(|side| side.to_string()))
+    }
+
+    #[test]
+    fn one_value_box_shorthand_is_expanded() {
+        assert_eq!(
+            expand_box_shorthand("10px"),
+            sides("10px", "10px", "10px", "10px")
+        );
+        assert_eq!(
+            expand_box_shorthand("  calc(1rem + 2px) "),
+            sides(
+                "calc(1rem + 2px)",
+                "calc(1rem + 2px)",
+                "calc(1rem + 2px)",
+                "calc(1rem + 2px)"
+            )
+        );
+    }
+
+    #[test]
+    fn two_values_box_shorthand_is_expanded() {
+        assert_eq!(
+            expand_box_shorthand("10px 20px"),
+            sides("10px", "20px", "10px", "20px")
+        );
+    }
+
+    #[test]
+    fn three_values_box_shorthand_is_expanded() {
+        assert_eq!(
+            expand_box_shorthand("10px auto 30px"),
+            sides("10px", "auto", "30px", "auto")
+        );
+    }
+
+    #[test]
+    fn four_values_box_shorthand_is_expanded() {
+        assert_eq!(
+            expand_box_shorthand("10px 20px 30px var(--gutter)"),
+            sides("10px", "20px", "30px", "var(--gutter)")
+        );
+    }
+
+    #[test]
+    fn invalid_box_shorthand_is_not_expanded() {
+        assert_eq!(expand_box_shorthand("1px 2px 3px 4px 5px"), None);
+        assert_eq!(expand_box_shorthand("   "), None);
+        assert_eq!(expand_box_shorthand("calc(1px + 2px"), None);
+        assert_eq!(expand_box_shorthand("1px) 2px"), None);
+    }
+}
@@ -0,0 +1,788 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+use crate::{
+    types::{
+        aliases::NenyrAliases,
+        animations::{NenyrAnimation, NenyrKeyframe},
+        ast::NenyrAst,
+        breakpoints::NenyrBreakpoints,
+        class::NenyrStyleClass,
+        variables::NenyrVariables,
+    },
+    validators::style_syntax::is_css_wide_keyword,
+};
+
+lazy_static! {
+    static ref VARIABLE_REFERENCE: Regex = Regex::new(r"\$\{([a-zA-Z0-9_-]+)\}").unwrap();
+}
+
+/// The generic font families, which must not be quoted since quoting turns them
+/// into family names.
+const GENERIC_FONT_FAMILIES: [&str; 13] = [
+    "serif",
+    "sans-serif",
+    "monospace",
+    "cursive",
+    "fantasy",
+    "system-ui",
+    "ui-serif",
+    "ui-sans-serif",
+    "ui-monospace",
+    "ui-rounded",
+    "math",
+    "emoji",
+    "fangsong",
+];
+
+/// Options controlling how a Nenyr AST is emitted as CSS.
+///
+/// # Fields
+/// - `minify`: Whether the indentation, the line breaks, the optional spaces and the last
+///   semicolon of each rule must be removed, writing every rule on a single line.
+/// - `sort_properties`: Whether the declarations of each rule must be sorted by property
+///   name instead of following their declaration order.
+/// - `custom_properties`: Whether the variables of the context must be emitted as CSS
+///   custom properties of a `:root` rule, compiling the `${variable}` references to
+///   `var(--variable)` instead of substituting their value inline.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct EmitOptions {
+    pub minify: bool,
+    pub sort_properties: bool,
+    pub custom_properties: bool,
+}
+
+/// Emits the CSS produced by a Nenyr AST.
+///
+/// The rule blocks yielded by `emit_css_iter` are concatenated, separated by a blank line.
+///
+/// # Parameters
+/// - `ast`: The Nenyr AST to be emitted.
+///
+/// # Returns
+/// A `String` containing the whole stylesheet.
+pub fn emit_css(ast: &NenyrAst) -> String {
+    emit_css_with(ast, EmitOptions::default())
+}
+
+/// Emits the CSS produced by a Nenyr AST, following the given options.
+///
+/// Without minification the rule blocks are separated by a blank line, exactly like
+/// `emit_css`, while minified blocks are concatenated one after the other.
+///
+/// # Parameters
+/// - `ast`: The Nenyr AST to be emitted.
+/// - `options`: The `EmitOptions` controlling the output.
+///
+/// # Returns
+/// A `String` containing the whole stylesheet.
+pub fn emit_css_with(ast: &NenyrAst, options: EmitOptions) -> String {
+    let separator = if options.minify { "" } else { "\n" };
+
+    emit_blocks(ast, None, options)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Emits the CSS produced by a Nenyr AST, resolving its responsive patterns through the
+/// given breakpoints.
+///
+/// Only the central context declares breakpoints, so the responsive patterns of a layout
+/// or module context are skipped by `emit_css_with`. This function takes the breakpoints
+/// of the central context instead, which replace the ones of the given AST, if any.
+///
+/// # Parameters
+/// - `ast`: The Nenyr AST to be emitted.
+/// - `breakpoints`: The breakpoints used to build the `@media` rules, usually declared by
+///   the central context.
+/// - `options`: The `EmitOptions` controlling the output.
+///
+/// # Returns
+/// A `String` containing the whole stylesheet.
+pub fn emit_css_with_breakpoints(
+    ast: &NenyrAst,
+    breakpoints: &NenyrBreakpoints,
+    options: EmitOptions,
+) -> String {
+    let separator = if options.minify { "" } else { "\n" };
+
+    emit_blocks(ast, Some(breakpoints), options)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Lazily emits the CSS produced by a Nenyr AST, one rule block at a time.
+///
+/// The animations are emitted first as `@keyframes` rules, followed by the classes, each
+/// style pattern producing its own rule, e.g. `.button:hover { ... }`. The `Supports` and
+/// `Container` patterns are wrapped by their `@supports` and `@container` rules, and the
+/// responsive patterns by a `@media` rule built from the breakpoints of the context. Since
+/// only the central context declares breakpoints, the responsive patterns whose breakpoint
+/// is not declared in the given AST are skipped, so the responsive patterns of a layout or
+/// module context require `emit_css_with_breakpoints`. The patterns guarded by a media type are
+/// wrapped by a `@media` rule of that type, e.g. `@media print { ... }`.
+///
+/// Aliases are replaced by the property they stand for and the `${variable}` references
+/// by the value of the variable, whenever they are declared in the given AST. When the
+/// `custom_properties` option is set, the variables are instead declared by a leading
+/// `:root` rule, e.g. `:root { --accent: #FF0000; }`, and referenced as `var(--accent)`. The
+/// `font-family` families containing whitespaces are quoted, e.g. `"Times New Roman"`,
+/// while the generic families are kept unquoted. Each class is
+/// only converted once the iterator reaches it, so very large stylesheets can be written
+/// incrementally without building the whole output in memory.
+///
+/// # Parameters
+/// - `ast`: The Nenyr AST to be emitted.
+///
+/// # Returns
+/// An iterator yielding each CSS rule block, ending with a line break.
+pub fn emit_css_iter(ast: &NenyrAst) -> impl Iterator<Item = String> + '_ {
+    emit_blocks(ast, None, EmitOptions::default())
+}
+
+/// Lazily emits the rule blocks of a Nenyr AST, following the given options. The given
+/// breakpoints, if any, replace the ones declared in the AST.
+fn emit_blocks<'a>(
+    ast: &'a NenyrAst,
+    breakpoints: Option<&'a NenyrBreakpoints>,
+    options: EmitOptions,
+) -> impl Iterator<Item = String> + 'a {
+    let mut emitter = CssEmitter::new(ast, options);
+
+    if breakpoints.is_some() {
+        emitter.breakpoints = breakpoints;
+    }
+
+    let (animations, classes) = match ast {
+        NenyrAst::CentralContext(context) => (&context.animations, &context.classes),
+        NenyrAst::LayoutContext(context) => (&context.animations, &context.classes),
+        NenyrAst::ModuleContext(context) => (&context.animations, &context.classes),
+    };
+
+    let root = emitter.emit_root();
+    let keyframes = animations
+        .iter()
+        .flat_map(|animations| animations.values())
+        .map(move |animation| emitter.emit_keyframes(animation));
+    let rules = classes
+        .iter()
+        .flat_map(|classes| classes.values())
+        .flat_map(move |style_class| emitter.emit_class(style_class));
+
+    root.into_iter().chain(keyframes).chain(rules)
+}
+
+/// Holds the declarations of the context needed to resolve the emitted values.
+#[derive(Clone, Copy)]
+struct CssEmitter<'a> {
+    aliases: Option<&'a NenyrAliases>,
+    variables: Option<&'a NenyrVariables>,
+    breakpoints: Option<&'a NenyrBreakpoints>,
+    options: EmitOptions,
+}
+
+impl<'a> CssEmitter<'a> {
+    fn new(ast: &'a NenyrAst, options: EmitOptions) -> Self {
+        match ast {
+            NenyrAst::CentralContext(context) => Self {
+                aliases: context.aliases.as_ref(),
+                variables: context.variables.as_ref(),
+                breakpoints: context.breakpoints.as_ref(),
+                options,
+            },
+            NenyrAst::LayoutContext(context) => Self {
+                aliases: context.aliases.as_ref(),
+                variables: context.variables.as_ref(),
+                breakpoints: None,
+                options,
+            },
+            NenyrAst::ModuleContext(context) => Self {
+                aliases: context.aliases.as_ref(),
+                variables: context.variables.as_ref(),
+                breakpoints: None,
+                options,
+            },
+        }
+    }
+
+    /// Retrieves the indentation of the given level, which is empty when minifying.
+    fn indent(&self, level: usize) -> String {
+        match self.options.minify {
+            true => String::new(),
+            false => "    ".repeat(level),
+        }
+    }
+
+    /// Wraps the given blocks in a block opened by the given prelude, such as
+    /// `@media screen and (min-width: 360px)`.
+    fn wrap_block(&self, level: usize, prelude: &str, blocks: &str) -> String {
+        let indent = self.indent(level);
+
+        match self.options.minify {
+            true => format!("{}{{{}}}", prelude, blocks),
+            false => format!("{}{} {{\n{}{}}}\n", indent, prelude, blocks, indent),
+        }
+    }
+
+    /// Emits the variables of the context as the custom properties of a `:root` rule,
+    /// when the `custom_properties` option is set and the context declares variables.
+    fn emit_root(&self) -> Option<String> {
+        let variables = self.variables.filter(|_| self.options.custom_properties)?;
+
+        if variables.values.is_empty() {
+            return None;
+        }
+
+        let properties: IndexMap<String, Arc<str>> = variables
+            .values
+            .iter()
+            .map(|(identifier, value)| (format!("--{}", identifier), Arc::from(value.to_string())))
+            .collect();
+
+        Some(self.emit_rule(0, ":root", &properties, false))
+    }
+
+    fn emit_keyframes(&self, animation: &NenyrAnimation) -> String {
+        let progressive_count = animation.progressive_count.unwrap_or(1);
+        let mut progressive_idx = 0;
+        let mut blocks = String::new();
+
+        for keyframe in &animation.keyframe {
+            let selector = match keyframe {
+                NenyrKeyframe::Fraction { stops, .. } => stops
+                    .iter()
+                    .map(|stop| to_percentage(*stop))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                NenyrKeyframe::Progressive(_) => {
+                    let stop = match progressive_count {
+                        1 => 100.0,
+                        _ => progressive_idx as f64 * 100.0 / (progressive_count - 1) as f64,
+                    };
+
+                    progressive_idx += 1;
+
+                    to_percentage(stop)
+                }
+                NenyrKeyframe::From(_) => "from".to_string(),
+                NenyrKeyframe::Halfway(_) => "50%".to_string(),
+                NenyrKeyframe::To(_) => "to".to_string(),
+            };
+
+            blocks.push_str(&self.emit_rule(1, &selector, keyframe.properties(), false));
+        }
+
+        self.wrap_block(
+            0,
+            &format!("@keyframes {}", animation.animation_name),
+            &blocks,
+        )
+    }
+
+    fn emit_class(&self, style_class: &NenyrStyleClass) -> Vec<String> {
+        let class_selector = format!(".{}", style_class.class_name);
+        let is_important = style_class.is_important.unwrap_or(false);
+        let to_selector = |pattern_name: &str| match pattern_name {
+            "_stylesheet" => class_selector.clone(),
+            pseudo_selector => format!("{}{}", class_selector, pseudo_selector),
+        };
+        let mut rules = vec![];
+
+        if let Some(style_patterns) = &style_class.style_patterns {
+            for (pattern_name, properties) in style_patterns {
+                rules.push(self.emit_rule(0, &to_selector(pattern_name), properties, is_important));
+            }
+        }
+
+        let at_rules = [
+            ("@supports", &style_class.supports_patterns),
+            ("@container", &style_class.container_patterns),
+        ];
+
+        for (at_rule, patterns) in at_rules {
+            for (prelude, properties) in patterns.iter().flatten() {
+                let rule = self.emit_rule(1, &class_selector, properties, is_important);
+
+                rules.push(self.wrap_block(0, &format!("{} {}", at_rule, prelude), &rule));
+            }
+        }
+
+        let media_queries = style_class
+            .responsive_patterns
+            .iter()
+            .flatten()
+            .filter_map(|(breakpoint_name, panoramic_patterns)| {
+                Some((self.media_query(breakpoint_name)?, panoramic_patterns))
+            })
+            .chain(
+                style_class
+                    .media_patterns
+                    .iter()
+                    .flatten()
+                    .map(|(media_type, patterns)| (media_type.to_string(), patterns)),
+            );
+
+        for (media_query, panoramic_patterns) in media_queries {
+            let blocks: String = panoramic_patterns
+                .iter()
+                .map(|(pattern_name, properties)| {
+                    self.emit_rule(1, &to_selector(pattern_name), properties, is_important)
+                })
+                .collect();
+
+            rules.push(self.wrap_block(0, &format!("@media {}", media_query), &blocks));
+        }
+
+        rules
+    }
+
+    fn emit_rule(
+        &self,
+        level: usize,
+        selector: &str,
+        properties: &IndexMap<String, Arc<str>>,
+        is_important: bool,
+    ) -> String {
+        let importance = match (is_important, self.options.minify) {
+            (false, _) => "",
+            (true, false) => " !important",
+            (true, true) => "!important",
+        };
+        let mut declarations: Vec<(&str, String)> = properties
+            .iter()
+            .map(|(property, value)| {
+                let property = self.resolve_property(property);
+                let mut value = self.resolve_value(value);
+
+                if property == "font-family" {
+                    value = quote_font_families(&value);
+                }
+
+                (property, value)
+            })
+            .collect();
+
+        if self.options.sort_properties {
+            declarations.sort_by_key(|(property, _)| *property);
+        }
+
+        // The minified declarations are joined by semicolons, dropping the last one.
+        let declarations: String = match self.options.minify {
+            true => declarations
+                .iter()
+                .map(|(property, value)| format!("{}:{}{}", property, value, importance))
+                .collect::<Vec<_>>()
+                .join(";"),
+            false => declarations
+                .iter()
+                .map(|(property, value)| {
+                    format!(
+                        "{}{}: {}{};\n",
+                        self.indent(level + 1),
+                        property,
+                        value,
+                        importance
+                    )
+                })
+                .collect(),
+        };
+
+        self.wrap_block(level, selector, &declarations)
+    }
+
+    /// Replaces an alias by the property it stands for, keeping the nickname of the
+    /// aliases declared by other contexts.
+    fn resolve_property<'p>(&'p self, property: &'p str) -> &'p str {
+        match property.strip_prefix("nickname;") {
+            Some(nickname) => self
+                .aliases
+                .and_then(|aliases| aliases.values.get(nickname))
+                .map_or(nickname, |property| property.as_str()),
+            None => property,
+        }
+    }
+
+    /// Replaces the `${variable}` references by the value of the variables declared
+    /// in the context, keeping the references to the variables of other contexts. The
+    /// computed values referencing other variables are resolved as well, and with the
+    /// `custom_properties` option every reference becomes `var(--variable)`.
+    fn resolve_value(&self, value: &str) -> String {
+        VARIABLE_REFERENCE
+            .replace_all(value, |captures: &Captures| {
+                if self.options.custom_properties {
+                    return format!("var(--{})", &captures[1]);
+                }
+
+                self.variables
+                    .and_then(|variables| variables.values.get(&captures[1]))
+                    .map_or(captures[0].to_string(), |value| {
+                        self.resolve_value(&value.to_string())
+                    })
+            })
+            .to_string()
+    }
+
+    /// Builds the media query of a breakpoint, `MobileFirst` breakpoints producing a
+    /// `min-width` query and `DesktopFirst` breakpoints a `max-width` query.
+    fn media_query(&self, breakpoint_name: &str) -> Option<String> {
+        let breakpoints = self.breakpoints?;
+
+        if let Some(value) = breakpoints
+            .mobile_first
+            .as_ref()
+            .and_then(|mobile_first| mobile_first.get(breakpoint_name))
+        {
+            return Some(format!("screen and (min-width: {})", value));
+        }
+
+        breakpoints
+            .desktop_first
+            .as_ref()
+            .and_then(|desktop_first| desktop_first.get(breakpoint_name))
+            .map(|value| format!("screen and (max-width: {})", value))
+    }
+}
+
+/// Quotes the families of a `font-family` value containing whitespaces, such as
+/// `Times New Roman`, keeping the generic families, the CSS-wide keywords, the
+/// already quoted families and the single-word families untouched.
+fn quote_font_families(value: &str) -> String {
+    if is_css_wide_keyword(value) {
+        return value.trim().to_string();
+    }
+
+    split_font_families(value)
+        .iter()
+        .map(|family| {
+            let is_generic = GENERIC_FONT_FAMILIES
+                .iter()
+                .any(|generic| generic.eq_ignore_ascii_case(family));
+            let is_quoted = family.starts_with('"') || family.starts_with('\'');
+            let is_function = family.contains('(');
+
+            if is_generic || is_quoted || is_function || !family.contains(char::is_whitespace) {
+                return family.to_string();
+            }
+
+            let family = family.split_whitespace().collect::<Vec<_>>().join(" ");
+
+            format!("\"{}\"", family.replace('"', "\\\""))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Splits a `font-family` value on the commas outside of quotes and parentheses.
+fn split_font_families(value: &str) -> Vec<&str> {
+    let mut families = vec![];
+    let mut quote: Option<char> = None;
+    let mut depth = 0;
+    let mut family_start = 0;
+
+    for (idx, char) in value.char_indices() {
+        match (char, quote) {
+            ('"' | '\'', None) => quote = Some(char),
+            (_, Some(opening)) if char == opening => quote = None,
+            ('(', None) => depth += 1,
+            (')', None) => depth -= 1,
+            (',', None) if depth == 0 => {
+                families.push(value[family_start..idx].trim());
+                family_start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+
+    families.push(value[family_start..].trim());
+    families.retain(|family| !family.is_empty());
+    families
+}
+
+/// Writes a keyframe stop as a percentage, dropping the unnecessary decimals.
+fn to_percentage(stop: f64) -> String {
+    let stop = (stop * 100.0).round() / 100.0;
+
+    format!("{}%", stop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        emit_css, emit_css_iter, emit_css_with, emit_css_with_breakpoints, quote_font_families,
+        EmitOptions,
+    };
+    use crate::types::ast::NenyrAst;
+    use crate::NenyrParser;
+
+    #[test]
+    fn each_class_must_be_yielded_as_a_rule_block() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Class('title') {
+        Stylesheet({ color: 'red', fontSize: '16px' })
+    },
+    Declare Class('body') {
+        Stylesheet({ padding: '10px' })
+    }
+}";
+        let ast = NenyrParser::new()
+            .parse(raw_nenyr.to_string(), "".to_string())
+            .unwrap();
+        let blocks: Vec<String> = emit_css_iter(&ast).collect();
+
+        assert_eq!(
+            blocks,
+            vec![
+                ".title {\n    color: red;\n    font-size: 16px;\n}\n".to_string(),
+                ".body {\n    padding: 10px;\n}\n".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn minified_output_must_keep_the_rules_on_a_single_line() {
+        let raw_nenyr = "Construct Central {
+    Declare Breakpoints({ MobileFirst({ onMobile: '360px' }) }),
+    Declare Class('button') {
+        Important(true),
+        Stylesheet({ color: 'red', padding: '10px 20px' }),
+        PanoramicViewer({ onMobile({ Stylesheet({ color: 'blue' }) }) })
+    }
+}";
+        let ast = NenyrParser::new()
+            .parse(raw_nenyr.to_string(), "".to_string())
+            .unwrap();
+        let minified_css = emit_css_with(
+            &ast,
+            EmitOptions {
+                minify: true,
+                sort_properties: false,
+                custom_properties: false,
+            },
+        );
+
+        assert_eq!(
+            emit_css(&ast),
+            ".button {
+    color: red !important;
+    padding: 10px 20px !important;
+}
+
+@media screen and (min-width: 360px) {
+    .button {
+        color: blue !important;
+    }
+}
+"
+        );
+        assert_eq!(
+            minified_css,
+            ".button{color:red!important;padding:10px 20px!important}@media screen and (min-width: 360px){.button{color:blue!important}}"
+        );
+        assert!(!minified_css.contains('\n'));
+    }
+
+    #[test]
+    fn media_type_patterns_must_be_wrapped_by_their_media_rule() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    Declare Class('header') {
+        Stylesheet({ color: 'red' }),
+        PanoramicViewer({ 'print'({ Stylesheet({ display: 'none' }) }) })
+    }
+}";
+        let ast = NenyrParser::new()
+            .parse(raw_nenyr.to_string(), "".to_string())
+            .unwrap();
+
+        assert_eq!(
+            emit_css(&ast),
+            ".header {
+    color: red;
+}
+
+@media print {
+    .header {
+        display: none;
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn layout_responsive_patterns_must_use_the_given_breakpoints() {
+        let central_nenyr = "Construct Central {
+    Declare Breakpoints({ MobileFirst({ onMobile: '360px' }) })
+}";
+        let layout_nenyr = "Construct Layout('mainLayout') {
+    Declare Class('header') {
+        Stylesheet({ color: 'red' }),
+        PanoramicViewer({ onMobile({ Stylesheet({ color: 'blue' }) }) })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let breakpoints = match parser.parse(central_nenyr.to_string(), "".to_string()) {
+            Ok(NenyrAst::CentralContext(context)) => context.breakpoints.unwrap(),
+            other => panic!("{:?}", other),
+        };
+        let ast = parser
+            .parse(layout_nenyr.to_string(), "".to_string())
+            .unwrap();
+
+        assert_eq!(emit_css(&ast), ".header {\n    color: red;\n}\n");
+        assert_eq!(
+            emit_css_with_breakpoints(&ast, &breakpoints, EmitOptions::default()),
+            ".header {
+    color: red;
+}
+
+@media screen and (min-width: 360px) {
+    .header {
+        color: blue;
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn central_context_must_be_emitted() {
+        let raw_nenyr = "Construct Central {
+    Declare Aliases({ bgd: backgroundColor }),
+    Declare Variables({ accent: '#FF0000' }),
+    Declare Breakpoints({ MobileFirst({ onMobile: '360px' }) }),
+    Declare Animation('fade') {
+        Progressive({ opacity: '0' }),
+        Progressive({ opacity: '0.5' }),
+        Progressive({ opacity: '1' })
+    },
+    Declare Class('button') {
+        Important(true),
+        Stylesheet({ bgd: '${accent}' }),
+        Hover({ color: 'red' }),
+        Supports('(display: grid)', { display: 'grid' }),
+        PanoramicViewer({
+            onMobile({ Stylesheet({ color: 'blue' }) }),
+            onDesktop({ Stylesheet({ color: 'green' }) })
+        })
+    }
+}";
+        let ast = NenyrParser::new()
+            .parse(raw_nenyr.to_string(), "".to_string())
+            .unwrap();
+
+        assert_eq!(
+            emit_css(&ast),
+            "@keyframes fade {
+    0% {
+        opacity: 0;
+    }
+    50% {
+        opacity: 0.5;
+    }
+    100% {
+        opacity: 1;
+    }
+}
+
+.button {
+    background-color: #FF0000 !important;
+}
+
+.button:hover {
+    color: red !important;
+}
+
+@supports (display: grid) {
+    .button {
+        display: grid !important;
+    }
+}
+
+@media screen and (min-width: 360px) {
+    .button {
+        color: blue !important;
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn variables_must_be_emitted_as_custom_properties() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    Declare Variables({ accent: '#FF0000', spacing: 8px, doubleSpacing: calc('${spacing}' * 2) }),
+    Declare Class('header') {
+        Stylesheet({ color: '${accent}', margin: '${doubleSpacing} ${spacing}' })
+    }
+}";
+        let ast = NenyrParser::new()
+            .parse(raw_nenyr.to_string(), "".to_string())
+            .unwrap();
+        let options = EmitOptions {
+            custom_properties: true,
+            ..EmitOptions::default()
+        };
+
+        assert_eq!(
+            emit_css_with(&ast, options),
+            ":root {
+    --accent: #FF0000;
+    --spacing: 8px;
+    --doubleSpacing: calc(var(--spacing) * 2);
+}
+
+.header {
+    color: var(--accent);
+    margin: var(--doubleSpacing) var(--spacing);
+}
+"
+        );
+        assert_eq!(
+            emit_css(&ast),
+            ".header {\n    color: #FF0000;\n    margin: calc(8px * 2) 8px;\n}\n"
+        );
+    }
+
+    #[test]
+    fn multi_word_font_family_must_be_quoted() {
+        assert_eq!(
+            quote_font_families("Times New Roman"),
+            "\"Times New Roman\""
+        );
+        assert_eq!(
+            quote_font_families("'Times New Roman'"),
+            "'Times New Roman'"
+        );
+    }
+
+    #[test]
+    fn generic_font_family_must_not_be_quoted() {
+        assert_eq!(quote_font_families("serif"), "serif");
+        assert_eq!(quote_font_families(" sans-serif "), "sans-serif");
+        assert_eq!(quote_font_families("inherit"), "inherit");
+    }
+
+    #[test]
+    fn font_family_stack_must_quote_only_multi_word_families() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Class('title') {
+        Stylesheet({ fontFamily: 'Times New Roman, Georgia,serif' })
+    }
+}";
+        let ast = NenyrParser::new()
+            .parse(raw_nenyr.to_string(), "".to_string())
+            .unwrap();
+
+        assert_eq!(
+            emit_css(&ast),
+            ".title {\n    font-family: \"Times New Roman\", Georgia, serif;\n}\n"
+        );
+        assert_eq!(
+            quote_font_families("Helvetica Neue,Arial, var(--font, Open Sans), monospace"),
+            "\"Helvetica Neue\", Arial, var(--font, Open Sans), monospace"
+        );
+    }
+}
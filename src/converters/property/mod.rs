@@ -304,6 +304,55 @@ pub trait NenyrPropertyConverter {
             NenyrTokens::ScrollbarColor => Some("scrollbar-color".to_string()),
             NenyrTokens::ScrollbarWidth => Some("scrollbar-width".to_string()),
             NenyrTokens::ScrollbarGutter => Some("scrollbar-gutter".to_string()),
+            NenyrTokens::MarginInline => Some("margin-inline".to_string()),
+            NenyrTokens::MarginInlineStart => Some("margin-inline-start".to_string()),
+            NenyrTokens::MarginInlineEnd => Some("margin-inline-end".to_string()),
+            NenyrTokens::MarginBlock => Some("margin-block".to_string()),
+            NenyrTokens::MarginBlockStart => Some("margin-block-start".to_string()),
+            NenyrTokens::MarginBlockEnd => Some("margin-block-end".to_string()),
+            NenyrTokens::PaddingInline => Some("padding-inline".to_string()),
+            NenyrTokens::PaddingInlineStart => Some("padding-inline-start".to_string()),
+            NenyrTokens::PaddingInlineEnd => Some("padding-inline-end".to_string()),
+            NenyrTokens::PaddingBlock => Some("padding-block".to_string()),
+            NenyrTokens::PaddingBlockStart => Some("padding-block-start".to_string()),
+            NenyrTokens::PaddingBlockEnd => Some("padding-block-end".to_string()),
+            NenyrTokens::Inset => Some("inset".to_string()),
+            NenyrTokens::InsetInline => Some("inset-inline".to_string()),
+            NenyrTokens::InsetInlineStart => Some("inset-inline-start".to_string()),
+            NenyrTokens::InsetInlineEnd => Some("inset-inline-end".to_string()),
+            NenyrTokens::InsetBlock => Some("inset-block".to_string()),
+            NenyrTokens::InsetBlockStart => Some("inset-block-start".to_string()),
+            NenyrTokens::InsetBlockEnd => Some("inset-block-end".to_string()),
+            NenyrTokens::BorderInline => Some("border-inline".to_string()),
+            NenyrTokens::BorderInlineStart => Some("border-inline-start".to_string()),
+            NenyrTokens::BorderInlineEnd => Some("border-inline-end".to_string()),
+            NenyrTokens::BorderBlock => Some("border-block".to_string()),
+            NenyrTokens::BorderBlockStart => Some("border-block-start".to_string()),
+            NenyrTokens::BorderBlockEnd => Some("border-block-end".to_string()),
+            NenyrTokens::InlineSize => Some("inline-size".to_string()),
+            NenyrTokens::BlockSize => Some("block-size".to_string()),
+            NenyrTokens::MinInlineSize => Some("min-inline-size".to_string()),
+            NenyrTokens::MaxInlineSize => Some("max-inline-size".to_string()),
+            NenyrTokens::MinBlockSize => Some("min-block-size".to_string()),
+            NenyrTokens::MaxBlockSize => Some("max-block-size".to_string()),
+            NenyrTokens::WebkitBackdropFilter => Some("-webkit-backdrop-filter".to_string()),
+            NenyrTokens::WebkitAppearance => Some("-webkit-appearance".to_string()),
+            NenyrTokens::MozAppearance => Some("-moz-appearance".to_string()),
+            NenyrTokens::WebkitUserSelect => Some("-webkit-user-select".to_string()),
+            NenyrTokens::MozUserSelect => Some("-moz-user-select".to_string()),
+            NenyrTokens::WebkitBackgroundClip => Some("-webkit-background-clip".to_string()),
+            NenyrTokens::WebkitTextFillColor => Some("-webkit-text-fill-color".to_string()),
+            NenyrTokens::WebkitTextStroke => Some("-webkit-text-stroke".to_string()),
+            NenyrTokens::WebkitTextStrokeColor => Some("-webkit-text-stroke-color".to_string()),
+            NenyrTokens::WebkitTextStrokeWidth => Some("-webkit-text-stroke-width".to_string()),
+            NenyrTokens::WebkitLineClamp => Some("-webkit-line-clamp".to_string()),
+            NenyrTokens::WebkitBoxOrient => Some("-webkit-box-orient".to_string()),
+            NenyrTokens::WebkitTapHighlightColor => Some("-webkit-tap-highlight-color".to_string()),
+            NenyrTokens::WebkitFontSmoothing => Some("-webkit-font-smoothing".to_string()),
+            NenyrTokens::MozOsxFontSmoothing => Some("-moz-osx-font-smoothing".to_string()),
+            NenyrTokens::WebkitMaskImage => Some("-webkit-mask-image".to_string()),
+            NenyrTokens::WebkitOverflowScrolling => Some("-webkit-overflow-scrolling".to_string()),
+            NenyrTokens::MozTabSize => Some("-moz-tab-size".to_string()),
             _ => None,
         }
     }
@@ -2368,4 +2417,60 @@ mod tests {
             nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::ScrollbarGutter)
         );
     }
+
+    #[test]
+    fn logical_properties_are_valid() {
+        let nenyr_token = NenyrToken::new();
+
+        assert_eq!(
+            Some("margin-inline-start".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::MarginInlineStart)
+        );
+        assert_eq!(
+            Some("margin-block-end".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::MarginBlockEnd)
+        );
+        assert_eq!(
+            Some("padding-inline".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::PaddingInline)
+        );
+        assert_eq!(
+            Some("inset-inline-end".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::InsetInlineEnd)
+        );
+        assert_eq!(
+            Some("border-block-end".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::BorderBlockEnd)
+        );
+        assert_eq!(
+            Some("max-inline-size".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::MaxInlineSize)
+        );
+        assert_eq!(
+            Some("inset".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::Inset)
+        );
+    }
+
+    #[test]
+    fn vendor_prefixed_properties_are_valid() {
+        let nenyr_token = NenyrToken::new();
+
+        assert_eq!(
+            Some("-webkit-backdrop-filter".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::WebkitBackdropFilter)
+        );
+        assert_eq!(
+            Some("-moz-appearance".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::MozAppearance)
+        );
+        assert_eq!(
+            Some("-webkit-line-clamp".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::WebkitLineClamp)
+        );
+        assert_eq!(
+            Some("-moz-osx-font-smoothing".to_string()),
+            nenyr_token.convert_nenyr_property_to_css_property(&NenyrTokens::MozOsxFontSmoothing)
+        );
+    }
 }
@@ -0,0 +1,233 @@
+/// The pseudo-elements that may still be written with a single colon, such as `:before`.
+const LEGACY_PSEUDO_ELEMENTS: [&str; 4] = ["before", "after", "first-line", "first-letter"];
+
+/// Computes the specificity of a CSS selector.
+///
+/// The specificity is made of three counters, compared in order:
+///
+/// - The ID selectors, such as `#main`.
+/// - The class selectors, the attribute selectors and the pseudo-classes, such as `.button`,
+///   `[data-state="open"]` and `:hover`.
+/// - The type selectors and the pseudo-elements, such as `div` and `::before`.
+///
+/// The `:is()`, `:not()` and `:has()` pseudo-classes count as their most specific argument,
+/// `:where()` counts as nothing and `:nth-child(An+B of S)` counts as a pseudo-class plus its
+/// most specific selector. The legacy single-colon pseudo-elements, such as `:before`, count as
+/// pseudo-elements, while the universal selector and the combinators are not counted. For a
+/// selector list, the specificity of its most specific selector is returned.
+///
+/// # Parameters
+/// - `selector`: The selector, such as `.button:hover` or `.card::before`.
+///
+/// # Returns
+/// - A `(ids, classes, elements)` tuple, which can be compared directly.
+pub fn selector_specificity(selector: &str) -> (u32, u32, u32) {
+    split_selector_list(selector)
+        .into_iter()
+        .map(compound_specificity)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Computes the specificity of a selector that is not a list.
+fn compound_specificity(selector: &str) -> (u32, u32, u32) {
+    let chars: Vec<char> = selector.chars().collect();
+    let (mut ids, mut classes, mut elements) = (0, 0, 0);
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        match chars[idx] {
+            '#' => {
+                ids += 1;
+                idx = skip_identifier(&chars, idx + 1);
+            }
+            '.' => {
+                classes += 1;
+                idx = skip_identifier(&chars, idx + 1);
+            }
+            '[' => {
+                classes += 1;
+                idx = skip_enclosed(&chars, idx, '[', ']');
+            }
+            ':' if chars.get(idx + 1) == Some(&':') => {
+                elements += 1;
+                idx = skip_identifier(&chars, idx + 2);
+
+                if chars.get(idx) == Some(&'(') {
+                    idx = skip_enclosed(&chars, idx, '(', ')');
+                }
+            }
+            ':' => {
+                let name_end = skip_identifier(&chars, idx + 1);
+                let name = chars[idx + 1..name_end]
+                    .iter()
+                    .collect::<String>()
+                    .to_ascii_lowercase();
+                let mut argument = None;
+
+                idx = name_end;
+
+                if chars.get(idx) == Some(&'(') {
+                    let argument_end = skip_enclosed(&chars, idx, '(', ')');
+                    let content_end = match chars[argument_end - 1] {
+                        ')' if argument_end > idx + 1 => argument_end - 1,
+                        _ => argument_end,
+                    };
+
+                    argument = Some(chars[idx + 1..content_end].iter().collect::<String>());
+                    idx = argument_end;
+                }
+
+                let (a, b, c) = pseudo_class_specificity(&name, argument.as_deref());
+
+                ids += a;
+                classes += b;
+                elements += c;
+            }
+            char if is_identifier_start(char) => {
+                elements += 1;
+                idx = skip_identifier(&chars, idx);
+            }
+            _ => idx += 1,
+        }
+    }
+
+    (ids, classes, elements)
+}
+
+/// Computes the specificity added by a single-colon pseudo-class, along with its argument.
+fn pseudo_class_specificity(name: &str, argument: Option<&str>) -> (u32, u32, u32) {
+    match (name, argument) {
+        _ if LEGACY_PSEUDO_ELEMENTS.contains(&name) => (0, 0, 1),
+        ("where", _) => (0, 0, 0),
+        ("is" | "not" | "has" | "matches", Some(argument)) => selector_specificity(argument),
+        ("nth-child" | "nth-last-child", Some(argument)) => {
+            let (ids, classes, elements) = argument
+                .split_once(" of ")
+                .map_or((0, 0, 0), |(_, selector)| selector_specificity(selector));
+
+            (ids, classes + 1, elements)
+        }
+        _ => (0, 1, 0),
+    }
+}
+
+/// Splits a selector list on the commas found outside of parentheses, brackets and quotes.
+fn split_selector_list(selector: &str) -> Vec<&str> {
+    let mut selectors = vec![];
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut selector_start = 0;
+
+    for (idx, char) in selector.char_indices() {
+        match (char, quote) {
+            (_, Some(quote_char)) if char == quote_char => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) => quote = Some(char),
+            ('(' | '[', None) => depth += 1,
+            (')' | ']', None) => depth -= 1,
+            (',', None) if depth == 0 => {
+                selectors.push(selector[selector_start..idx].trim());
+                selector_start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+
+    selectors.push(selector[selector_start..].trim());
+    selectors.retain(|selector| !selector.is_empty());
+
+    selectors
+}
+
+/// Checks whether a character starts a type selector or an identifier.
+fn is_identifier_start(char: char) -> bool {
+    char.is_alphabetic() || char == '_' || char == '-' || char == '\\' || !char.is_ascii()
+}
+
+/// Retrieves the index right after the identifier starting at the given index, skipping the
+/// escaped characters.
+fn skip_identifier(chars: &[char], mut idx: usize) -> usize {
+    while let Some(&char) = chars.get(idx) {
+        match char {
+            '\\' => idx += 2,
+            _ if char.is_alphanumeric() || char == '_' || char == '-' || !char.is_ascii() => {
+                idx += 1
+            }
+            _ => break,
+        }
+    }
+
+    idx.min(chars.len())
+}
+
+/// Retrieves the index right after the delimiter closing the one found at the given index,
+/// skipping the nested delimiters and the quoted strings.
+fn skip_enclosed(chars: &[char], mut idx: usize, opening: char, closing: char) -> usize {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+
+    while let Some(&char) = chars.get(idx) {
+        idx += 1;
+
+        match (char, quote) {
+            (_, Some(quote_char)) if char == quote_char => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) => quote = Some(char),
+            _ if char == opening => depth += 1,
+            _ if char == closing => {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::selector_specificity;
+
+    #[test]
+    fn class_selectors_specificity_is_computed() {
+        assert_eq!(selector_specificity(".foo"), (0, 1, 0));
+        assert_eq!(selector_specificity(".foo.bar"), (0, 2, 0));
+        assert_eq!(selector_specificity("#main .foo"), (1, 1, 0));
+        assert_eq!(selector_specificity("ul > li.foo"), (0, 1, 2));
+        assert_eq!(selector_specificity("*"), (0, 0, 0));
+    }
+
+    #[test]
+    fn pseudo_classes_and_pseudo_elements_specificity_is_computed() {
+        assert_eq!(selector_specificity(".foo:hover"), (0, 2, 0));
+        assert_eq!(selector_specificity(".foo::before"), (0, 1, 1));
+        assert_eq!(selector_specificity(".foo:before"), (0, 1, 1));
+        assert_eq!(selector_specificity(".foo::first-letter"), (0, 1, 1));
+        assert_eq!(selector_specificity(".foo:first-child"), (0, 2, 0));
+        assert_eq!(
+            selector_specificity(".foo[data-state=\"open, closed\"]"),
+            (0, 2, 0)
+        );
+    }
+
+    #[test]
+    fn functional_pseudo_classes_specificity_is_computed() {
+        assert_eq!(selector_specificity(".foo:not(.disabled)"), (0, 2, 0));
+        assert_eq!(selector_specificity(".foo:not(#main, p)"), (1, 1, 0));
+        assert_eq!(selector_specificity(".foo:is(p, .bar .baz)"), (0, 3, 0));
+        assert_eq!(selector_specificity(".foo:where(#main)"), (0, 1, 0));
+        assert_eq!(selector_specificity(".foo:nth-child(2n + 1)"), (0, 2, 0));
+        assert_eq!(selector_specificity("li:nth-child(2n of .item)"), (0, 2, 1));
+    }
+
+    #[test]
+    fn selector_list_specificity_is_the_highest_one() {
+        assert_eq!(selector_specificity(".foo, #main, p"), (1, 0, 0));
+        assert_eq!(selector_specificity(""), (0, 0, 0));
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// `NenyrErrorKind` is an enumeration that categorizes errors that can occur
 /// within the Nenyr framework. This enum provides a structured way to identify
 /// and handle different types of errors that may arise during parsing,
@@ -136,6 +138,10 @@ impl NenyrErrorTracing {
 ///
 /// # Fields
 ///
+/// - `error_code`: A stable code identifying the error, such as `N0012`, which tools can
+///   rely on to filter or suppress specific errors. Each place raising an error has its
+///   own code, and codes are never reused for a different error.
+///
 /// - `suggestion`: An optional field that provides personalized suggestions
 ///   on how to fix the error. This can help users quickly resolve issues
 ///   by offering actionable advice.
@@ -181,6 +187,7 @@ impl NenyrErrorTracing {
 ///   detail about the error's location.
 #[derive(Debug, PartialEq, Clone)]
 pub struct NenyrError {
+    pub error_code: &'static str,
     pub suggestion: Option<String>,
     pub context_name: Option<String>,
     pub context_path: String,
@@ -190,6 +197,9 @@ pub struct NenyrError {
 }
 
 impl NenyrError {
+    /// Creates an error carrying the `N0000` placeholder code, which is replaced by the
+    /// stable code of the error through `NenyrError::with_code`.
+    #[must_use = "creating a `NenyrError` does nothing unless it is returned"]
     pub(crate) fn new(
         suggestion: Option<String>,
         context_name: Option<String>,
@@ -199,6 +209,7 @@ impl NenyrError {
         error_tracing: NenyrErrorTracing,
     ) -> Self {
         Self {
+            error_code: "N0000",
             suggestion,
             context_name,
             context_path,
@@ -208,6 +219,16 @@ impl NenyrError {
         }
     }
 
+    /// Sets the stable code identifying the error, such as `N0012`.
+    #[must_use = "creating a `NenyrError` does nothing unless it is returned"]
+    pub(crate) fn with_code(self, error_code: &'static str) -> Self {
+        Self { error_code, ..self }
+    }
+
+    pub fn get_error_code(&self) -> &'static str {
+        self.error_code
+    }
+
     pub fn get_suggestion(&self) -> Option<String> {
         self.suggestion.clone()
     }
@@ -251,11 +272,178 @@ impl NenyrError {
     pub fn get_column(&self) -> usize {
         self.error_tracing.get_column()
     }
+
+    pub fn get_severity(&self) -> Severity {
+        Severity::Error
+    }
+}
+
+impl fmt::Display for NenyrError {
+    /// Formats the error as `error[N0012]: message`, followed by its location and, when
+    /// available, its suggestion.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "error[{}]: {}", self.error_code, self.error_message)?;
+        write!(
+            f,
+            "  --> {}:{}:{}",
+            self.context_path, self.error_tracing.error_on_line, self.error_tracing.error_on_col
+        )?;
+
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "\n  = help: {}", suggestion)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// `Severity` describes how serious a `NenyrDiagnostic` is.
+///
+/// # Variants
+///
+/// - `Error`: A fatal issue that stops the parsing process. Every `NenyrError` has this severity.
+/// - `Warning`: A non-fatal issue flagging code which is valid but likely unintended.
+/// - `Info`: A purely informational note about the parsed code.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// `NenyrDiagnostic` describes any issue found while parsing Nenyr code, regardless of
+/// its severity.
+///
+/// Fatal errors are still returned as `NenyrError` by the parsing methods, but every issue
+/// found during the last parsing, including the fatal error converted through
+/// `NenyrDiagnostic::from`, is collected by the parser and available through
+/// `NenyrParser::get_diagnostics` once the parsing finishes.
+///
+/// # Fields
+///
+/// - `severity`: The `Severity` of the diagnostic.
+/// - `code`: A stable code identifying the diagnostic, such as `W0003` for a warning. The
+///   fatal errors keep the `error_code` of their `NenyrError`.
+/// - `suggestion`: An optional suggestion on how to address the diagnostic.
+/// - `context_name`: The name of the context in which the diagnostic was raised, if known.
+/// - `context_path`: The path of the `.nyr` file in which the diagnostic was raised.
+/// - `message`: A message explaining the reason for the diagnostic.
+/// - `kind`: The `NenyrErrorKind` categorizing the diagnostic.
+/// - `tracing`: The position in the Nenyr code where the diagnostic was raised.
+#[derive(Debug, PartialEq, Clone)]
+pub struct NenyrDiagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub suggestion: Option<String>,
+    pub context_name: Option<String>,
+    pub context_path: String,
+    pub message: String,
+    pub kind: NenyrErrorKind,
+    pub tracing: NenyrErrorTracing,
+}
+
+impl NenyrDiagnostic {
+    /// Creates a diagnostic carrying the `N0000` placeholder code, which is replaced by the
+    /// stable code of the diagnostic through `NenyrDiagnostic::with_code`.
+    #[must_use = "creating a `NenyrDiagnostic` does nothing unless it is collected"]
+    pub(crate) fn new(
+        severity: Severity,
+        suggestion: Option<String>,
+        context_name: Option<String>,
+        context_path: String,
+        message: String,
+        kind: NenyrErrorKind,
+        tracing: NenyrErrorTracing,
+    ) -> Self {
+        Self {
+            severity,
+            code: "N0000",
+            suggestion,
+            context_name,
+            context_path,
+            message,
+            kind,
+            tracing,
+        }
+    }
+
+    /// Sets the stable code identifying the diagnostic, such as `W0003`.
+    #[must_use = "creating a `NenyrDiagnostic` does nothing unless it is collected"]
+    pub(crate) fn with_code(self, code: &'static str) -> Self {
+        Self { code, ..self }
+    }
+
+    pub fn get_severity(&self) -> Severity {
+        self.severity.clone()
+    }
+
+    pub fn get_code(&self) -> &'static str {
+        self.code
+    }
+
+    pub fn get_suggestion(&self) -> Option<String> {
+        self.suggestion.clone()
+    }
+
+    pub fn get_context_name(&self) -> Option<String> {
+        self.context_name.clone()
+    }
+
+    pub fn get_context_path(&self) -> String {
+        self.context_path.clone()
+    }
+
+    pub fn get_message(&self) -> String {
+        self.message.clone()
+    }
+
+    pub fn get_kind(&self) -> NenyrErrorKind {
+        self.kind.clone()
+    }
+
+    pub fn get_line_before(&self) -> Option<String> {
+        self.tracing.get_line_before_error()
+    }
+
+    pub fn get_line_after(&self) -> Option<String> {
+        self.tracing.get_line_after_error()
+    }
+
+    pub fn get_diagnostic_line(&self) -> Option<String> {
+        self.tracing.get_error_line()
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.tracing.get_line()
+    }
+
+    pub fn get_position(&self) -> usize {
+        self.tracing.get_position()
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.tracing.get_column()
+    }
+}
+
+impl From<NenyrError> for NenyrDiagnostic {
+    fn from(error: NenyrError) -> Self {
+        Self::new(
+            Severity::Error,
+            error.suggestion,
+            error.context_name,
+            error.context_path,
+            error.error_message,
+            error.error_kind,
+            error.error_tracing,
+        )
+        .with_code(error.error_code)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::error::{NenyrError, NenyrErrorKind};
+    use crate::error::{NenyrDiagnostic, NenyrError, NenyrErrorKind, Severity};
 
     use super::NenyrErrorTracing;
 
@@ -275,6 +463,7 @@ mod tests {
                 20,
             ),
         )
+        .with_code("N0000")
     }
 
     fn create_none_fields_error() -> NenyrError {
@@ -286,6 +475,7 @@ mod tests {
             NenyrErrorKind::SyntaxError,
             NenyrErrorTracing::new(None, None, None, 10, 5, 20),
         )
+        .with_code("N0000")
     }
 
     #[test]
@@ -367,9 +557,63 @@ mod tests {
 
     #[test]
     fn test_nenyr_error_debug() {
-        let printed_error = r#"NenyrError { suggestion: Some("suggestion"), context_name: Some("context name"), context_path: "context path", error_message: "error message", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some("line before"), line_after: Some("line after"), error_line: Some("error line"), error_on_line: 10, error_on_col: 5, error_on_pos: 20 } }"#;
+        let printed_error = r#"NenyrError { error_code: "N0000", suggestion: Some("suggestion"), context_name: Some("context name"), context_path: "context path", error_message: "error message", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some("line before"), line_after: Some("line after"), error_line: Some("error line"), error_on_line: 10, error_on_col: 5, error_on_pos: 20 } }"#;
         let all_fields_error = create_all_fields_error();
 
         assert_eq!(printed_error.to_string(), format!("{:?}", all_fields_error));
     }
+
+    #[test]
+    fn creating_nenyr_diagnostic() {
+        let diagnostic = NenyrDiagnostic::new(
+            Severity::Warning,
+            Some("suggestion".to_string()),
+            Some("context name".to_string()),
+            "context path".to_string(),
+            "warning message".to_string(),
+            NenyrErrorKind::ValidationError,
+            NenyrErrorTracing::new(None, None, None, 10, 5, 20),
+        )
+        .with_code("W0000");
+
+        assert_eq!(diagnostic.get_severity(), Severity::Warning);
+        assert_eq!(diagnostic.get_code(), "W0000");
+        assert_eq!(diagnostic.get_suggestion(), Some("suggestion".to_string()));
+        assert_eq!(
+            diagnostic.get_context_name(),
+            Some("context name".to_string())
+        );
+        assert_eq!(diagnostic.get_context_path(), "context path".to_string());
+        assert_eq!(diagnostic.get_message(), "warning message".to_string());
+        assert_eq!(diagnostic.get_kind(), NenyrErrorKind::ValidationError);
+        assert_eq!(diagnostic.get_line(), 10);
+        assert_eq!(diagnostic.get_column(), 5);
+        assert_eq!(diagnostic.get_position(), 20);
+    }
+
+    #[test]
+    fn nenyr_error_converts_into_error_diagnostic() {
+        let error = create_all_fields_error();
+        let diagnostic = NenyrDiagnostic::from(error.clone());
+
+        assert_eq!(error.get_severity(), Severity::Error);
+        assert_eq!(diagnostic.get_severity(), Severity::Error);
+        assert_eq!(diagnostic.get_message(), error.get_error_message());
+        assert_eq!(diagnostic.get_kind(), error.get_error_kind());
+        assert_eq!(diagnostic.get_code(), error.get_error_code());
+        assert_eq!(diagnostic.get_diagnostic_line(), error.get_error_line());
+        assert_eq!(diagnostic.get_line(), error.get_line());
+    }
+
+    #[test]
+    fn nenyr_error_display_must_include_the_error_code() {
+        assert_eq!(
+            create_all_fields_error().to_string(),
+            "error[N0000]: error message\n  --> context path:10:5\n  = help: suggestion"
+        );
+        assert_eq!(
+            create_none_fields_error().to_string(),
+            "error[N0000]: error message\n  --> context path:10:5"
+        );
+    }
 }
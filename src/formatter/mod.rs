@@ -0,0 +1,1146 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+
+use crate::types::{
+    aliases::NenyrAliases,
+    animations::{NenyrAnimation, NenyrKeyframe},
+    ast::NenyrAst,
+    breakpoints::NenyrBreakpoints,
+    central::CentralContext,
+    class::NenyrStyleClass,
+    comments::NenyrComments,
+    layout::LayoutContext,
+    mixins::NenyrMixins,
+    module::ModuleContext,
+    themes::NenyrThemes,
+    variables::{NenyrVariableValue, NenyrVariables},
+};
+
+/// Options controlling how a Nenyr AST is formatted back into source code.
+///
+/// # Fields
+/// - `indent_width`: The number of spaces used for each indentation level.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormatOptions {
+    pub indent_width: usize,
+}
+
+impl FormatOptions {
+    /// Creates a new `FormatOptions` with the given indentation width.
+    ///
+    /// # Parameters
+    /// - `indent_width`: The number of spaces used for each indentation level.
+    pub fn new(indent_width: usize) -> Self {
+        Self { indent_width }
+    }
+}
+
+impl Default for FormatOptions {
+    /// The default options indent each level with four spaces, matching the
+    /// style used throughout the Nenyr documentation.
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+/// Formats a Nenyr AST into canonical Nenyr source code.
+///
+/// The formatter regenerates the `Construct ... { Declare ... }` structure of the
+/// given context, emitting one declaration per block with consistent indentation.
+/// Declarations are written in a fixed order (imports, typefaces, breakpoints, aliases,
+/// variables, themes, animations and classes), while the entries inside each declaration
+/// follow the insertion order of the underlying `IndexMap`s, so the original declaration
+/// order is preserved.
+///
+/// Comments are not part of the AST, so none is written. Use `format_with_comments` to
+/// write back the comments collected by the parser.
+///
+/// # Parameters
+/// - `ast`: The Nenyr AST to be formatted.
+/// - `opts`: The `FormatOptions` controlling the output layout.
+///
+/// # Returns
+/// A `String` containing the formatted Nenyr source, which parses back into an AST
+/// equal to the given one.
+pub fn format(ast: &NenyrAst, opts: FormatOptions) -> String {
+    format_with_comments(ast, &NenyrComments::new(), opts)
+}
+
+/// Formats a Nenyr AST into canonical Nenyr source code, writing back its comments.
+///
+/// This function behaves like `format`, writing the comments collected through
+/// `NenyrParser::set_comments_collected` along with the declarations. The comments found
+/// before or inside a declaration are written on their own lines before it, so they follow
+/// the declaration when the declarations are reordered. The comments found before the
+/// `Construct` keyword are written before it, and the ones found after the last declaration
+/// are written before the closing bracket of the context.
+///
+/// # Parameters
+/// - `ast`: The Nenyr AST to be formatted.
+/// - `comments`: The comments retrieved through `NenyrParser::get_comments`.
+/// - `opts`: The `FormatOptions` controlling the output layout.
+///
+/// # Returns
+/// A `String` containing the formatted Nenyr source, which parses back into an AST
+/// equal to the given one.
+pub fn format_with_comments(
+    ast: &NenyrAst,
+    comments: &NenyrComments,
+    opts: FormatOptions,
+) -> String {
+    NenyrFormatter::new(opts, comments).format_ast(ast)
+}
+
+/// Holds the formatting options and the comments while the AST is being written.
+struct NenyrFormatter<'a> {
+    opts: FormatOptions,
+    comments: &'a NenyrComments,
+}
+
+impl<'a> NenyrFormatter<'a> {
+    fn new(opts: FormatOptions, comments: &'a NenyrComments) -> Self {
+        Self { opts, comments }
+    }
+
+    fn format_ast(&self, ast: &NenyrAst) -> String {
+        match ast {
+            NenyrAst::CentralContext(central_context) => self.format_context(
+                "Construct Central".to_string(),
+                self.format_central_declarations(central_context),
+            ),
+            NenyrAst::LayoutContext(layout_context) => self.format_context(
+                format!("Construct Layout({})", quote(&layout_context.layout_name)),
+                self.format_layout_declarations(layout_context),
+            ),
+            NenyrAst::ModuleContext(module_context) => {
+                let mut header =
+                    format!("Construct Module({})", quote(&module_context.module_name));
+
+                if let Some(extending_from) = &module_context.extending_from {
+                    header.push_str(&format!(" Extending({})", quote(extending_from)));
+                }
+
+                self.format_context(header, self.format_module_declarations(module_context))
+            }
+        }
+    }
+
+    fn format_context(&self, header: String, declarations: Vec<String>) -> String {
+        let mut formatted = String::new();
+
+        for comment in &self.comments.leading {
+            formatted.push_str(&format!("{}\n", comment));
+        }
+
+        formatted.push_str(&format!("{} {{\n", header));
+
+        if !declarations.is_empty() {
+            formatted.push_str(&declarations.join(",\n"));
+            formatted.push('\n');
+        }
+
+        for comment in &self.comments.trailing {
+            formatted.push_str(&format!("{}{}\n", self.indent(1), comment));
+        }
+
+        formatted.push_str("}\n");
+        formatted
+    }
+
+    /// Writes the comments attached to a declaration on their own lines before it.
+    fn with_comments(&self, method: &str, name: Option<&str>, declaration: String) -> String {
+        let mut formatted = String::new();
+
+        for comment in self.comments.get_declaration_comments(method, name) {
+            formatted.push_str(&format!("{}{}\n", self.indent(1), comment));
+        }
+
+        formatted.push_str(&declaration);
+        formatted
+    }
+
+    fn format_central_declarations(&self, central_context: &CentralContext) -> Vec<String> {
+        let mut declarations = vec![];
+
+        if let Some(imports) = &central_context.imports {
+            let children = imports
+                .values
+                .iter()
+                .map(|(import, layer)| match layer {
+                    Some(layer) => format!(
+                        "{}Import({}, Layer({}))",
+                        self.indent(2),
+                        quote(import),
+                        quote(layer)
+                    ),
+                    None => format!("{}Import({})", self.indent(2), quote(import)),
+                })
+                .collect();
+
+            declarations.push(self.with_comments(
+                "Imports",
+                None,
+                self.format_block(1, "Declare Imports([", children, "])"),
+            ));
+        }
+
+        if let Some(typefaces) = &central_context.typefaces {
+            declarations.push(self.with_comments(
+                "Typefaces",
+                None,
+                self.format_block(
+                    1,
+                    "Declare Typefaces({",
+                    self.format_string_values(2, &typefaces.values),
+                    "})",
+                ),
+            ));
+        }
+
+        if let Some(breakpoints) = &central_context.breakpoints {
+            declarations.push(self.with_comments(
+                "Breakpoints",
+                None,
+                self.format_breakpoints(breakpoints),
+            ));
+        }
+
+        if let Some(consts) = &central_context.consts {
+            declarations.push(self.with_comments(
+                "Const",
+                None,
+                self.format_block(
+                    1,
+                    "Declare Const({",
+                    self.format_string_values(2, &consts.values),
+                    "})",
+                ),
+            ));
+        }
+
+        self.push_shared_declarations(
+            &mut declarations,
+            &central_context.aliases,
+            &central_context.variables,
+            &central_context.themes,
+        );
+        self.push_styling_declarations(
+            &mut declarations,
+            &central_context.mixins,
+            &central_context.animations,
+            &central_context.classes,
+        );
+
+        declarations
+    }
+
+    fn format_layout_declarations(&self, layout_context: &LayoutContext) -> Vec<String> {
+        let mut declarations = vec![];
+
+        self.push_shared_declarations(
+            &mut declarations,
+            &layout_context.aliases,
+            &layout_context.variables,
+            &layout_context.themes,
+        );
+        self.push_styling_declarations(
+            &mut declarations,
+            &layout_context.mixins,
+            &layout_context.animations,
+            &layout_context.classes,
+        );
+
+        declarations
+    }
+
+    fn format_module_declarations(&self, module_context: &ModuleContext) -> Vec<String> {
+        let mut declarations = vec![];
+
+        self.push_shared_declarations(
+            &mut declarations,
+            &module_context.aliases,
+            &module_context.variables,
+            &None,
+        );
+        self.push_styling_declarations(
+            &mut declarations,
+            &module_context.mixins,
+            &module_context.animations,
+            &module_context.classes,
+        );
+
+        declarations
+    }
+
+    /// Formats the aliases, variables and themes declarations shared across contexts.
+    fn push_shared_declarations(
+        &self,
+        declarations: &mut Vec<String>,
+        aliases: &Option<NenyrAliases>,
+        variables: &Option<NenyrVariables>,
+        themes: &Option<NenyrThemes>,
+    ) {
+        if let Some(aliases) = aliases {
+            declarations.push(self.with_comments(
+                "Aliases",
+                None,
+                self.format_block(
+                    1,
+                    "Declare Aliases({",
+                    self.format_aliases(2, aliases),
+                    "})",
+                ),
+            ));
+        }
+
+        if let Some(variables) = variables {
+            declarations.push(self.with_comments(
+                "Variables",
+                None,
+                self.format_block(
+                    1,
+                    "Declare Variables({",
+                    self.format_variables(2, variables),
+                    "})",
+                ),
+            ));
+        }
+
+        if let Some(themes) = themes {
+            declarations.push(self.with_comments("Themes", None, self.format_themes(themes)));
+        }
+    }
+
+    /// Formats the mixins, animations and classes declarations shared across contexts.
+    fn push_styling_declarations(
+        &self,
+        declarations: &mut Vec<String>,
+        mixins: &Option<NenyrMixins>,
+        animations: &Option<IndexMap<String, NenyrAnimation>>,
+        classes: &Option<IndexMap<String, NenyrStyleClass>>,
+    ) {
+        if let Some(mixins) = mixins {
+            for (mixin_name, properties) in &mixins.values {
+                let children = properties
+                    .iter()
+                    .map(|(property, value)| {
+                        format!(
+                            "{}{}: {}",
+                            self.indent(2),
+                            to_nenyr_property(property),
+                            quote(value)
+                        )
+                    })
+                    .collect();
+
+                declarations.push(self.with_comments(
+                    "Mixin",
+                    Some(mixin_name),
+                    self.format_block(
+                        1,
+                        &format!("Declare Mixin({}) {{", quote(mixin_name)),
+                        children,
+                        "}",
+                    ),
+                ));
+            }
+        }
+
+        if let Some(animations) = animations {
+            for (animation_name, animation) in animations {
+                declarations.push(self.with_comments(
+                    "Animation",
+                    Some(animation_name),
+                    self.format_animation(animation),
+                ));
+            }
+        }
+
+        if let Some(classes) = classes {
+            for (class_name, style_class) in classes {
+                declarations.push(self.with_comments(
+                    "Class",
+                    Some(class_name),
+                    self.format_class(style_class),
+                ));
+            }
+        }
+    }
+
+    fn format_breakpoints(&self, breakpoints: &NenyrBreakpoints) -> String {
+        let mut children = vec![];
+
+        if let Some(mobile_first) = &breakpoints.mobile_first {
+            children.push(self.format_block(
+                2,
+                "MobileFirst({",
+                self.format_string_values(3, mobile_first),
+                "})",
+            ));
+        }
+
+        if let Some(desktop_first) = &breakpoints.desktop_first {
+            children.push(self.format_block(
+                2,
+                "DesktopFirst({",
+                self.format_string_values(3, desktop_first),
+                "})",
+            ));
+        }
+
+        self.format_block(1, "Declare Breakpoints({", children, "})")
+    }
+
+    fn format_themes(&self, themes: &NenyrThemes) -> String {
+        let mut children = vec![];
+
+        for (schema_name, schema, aliases, typefaces) in [
+            (
+                "Light",
+                &themes.light_schema,
+                &themes.light_aliases,
+                &themes.light_typefaces,
+            ),
+            (
+                "Dark",
+                &themes.dark_schema,
+                &themes.dark_aliases,
+                &themes.dark_typefaces,
+            ),
+        ] {
+            let mut schema_children = vec![];
+
+            if let Some(schema) = schema {
+                schema_children.push(self.format_block(
+                    3,
+                    "Variables({",
+                    self.format_variables(4, schema),
+                    "})",
+                ));
+            }
+
+            if let Some(aliases) = aliases {
+                schema_children.push(self.format_block(
+                    3,
+                    "Aliases({",
+                    self.format_aliases(4, aliases),
+                    "})",
+                ));
+            }
+
+            if let Some(typefaces) = typefaces {
+                schema_children.push(self.format_block(
+                    3,
+                    "Typefaces({",
+                    self.format_string_values(4, &typefaces.values),
+                    "})",
+                ));
+            }
+
+            if !schema_children.is_empty() {
+                children.push(self.format_block(
+                    2,
+                    &format!("{}({{", schema_name),
+                    schema_children,
+                    "})",
+                ));
+            }
+        }
+
+        self.format_block(1, "Declare Themes({", children, "})")
+    }
+
+    fn format_animation(&self, animation: &NenyrAnimation) -> String {
+        let children = animation
+            .keyframe
+            .iter()
+            .map(|keyframe| match keyframe {
+                NenyrKeyframe::Fraction {
+                    stops,
+                    is_percentage,
+                    properties,
+                } => {
+                    let stops = stops
+                        .iter()
+                        .enumerate()
+                        .map(|(index, stop)| match is_percentage.get(index) {
+                            Some(true) => format!("{}%", stop),
+                            _ => stop.to_string(),
+                        })
+                        .collect::<Vec<_>>();
+                    let stops = match stops.as_slice() {
+                        [stop] => stop.to_string(),
+                        _ => format!("[{}]", stops.join(", ")),
+                    };
+
+                    self.format_properties(2, &format!("Fraction({}, {{", stops), properties)
+                }
+                NenyrKeyframe::Progressive(properties) => {
+                    self.format_properties(2, "Progressive({", properties)
+                }
+                NenyrKeyframe::From(properties) => self.format_properties(2, "From({", properties),
+                NenyrKeyframe::Halfway(properties) => {
+                    self.format_properties(2, "Halfway({", properties)
+                }
+                NenyrKeyframe::To(properties) => self.format_properties(2, "To({", properties),
+            })
+            .collect();
+
+        let method = if animation.is_keyframes_declaration {
+            "Keyframes"
+        } else {
+            "Animation"
+        };
+
+        self.format_block(
+            1,
+            &format!(
+                "Declare {}({}) {{",
+                method,
+                quote(&animation.animation_name)
+            ),
+            children,
+            "}",
+        )
+    }
+
+    fn format_class(&self, style_class: &NenyrStyleClass) -> String {
+        let mut header = format!("Declare Class({})", quote(&style_class.class_name));
+        let mut children = vec![];
+
+        if let Some(deriving_from) = &style_class.deriving_from {
+            header.push_str(&format!(" Deriving({})", quote(deriving_from)));
+        }
+
+        if let Some(is_important) = style_class.is_important {
+            children.push(format!("{}Important({})", self.indent(2), is_important));
+        }
+
+        if let Some(style_patterns) = &style_class.style_patterns {
+            children.extend(self.format_style_patterns(2, style_patterns));
+        }
+
+        if let Some(supports_patterns) = &style_class.supports_patterns {
+            for (feature_query, properties) in supports_patterns {
+                children.push(self.format_properties(
+                    2,
+                    &format!("Supports({}, {{", quote(feature_query)),
+                    properties,
+                ));
+            }
+        }
+
+        if let Some(container_patterns) = &style_class.container_patterns {
+            for (container_query, properties) in container_patterns {
+                let arguments = match split_container_query(container_query) {
+                    (Some(container_name), condition) => {
+                        format!("{}, {}", quote(container_name), quote(condition))
+                    }
+                    (None, condition) => quote(condition),
+                };
+
+                children.push(self.format_properties(
+                    2,
+                    &format!("Container({}, {{", arguments),
+                    properties,
+                ));
+            }
+        }
+
+        // The media types are written quoted, setting them apart from the breakpoints.
+        let panoramic_entries: Vec<String> = style_class
+            .responsive_patterns
+            .iter()
+            .flatten()
+            .map(|(breakpoint_name, style_patterns)| (breakpoint_name.to_string(), style_patterns))
+            .chain(
+                style_class
+                    .media_patterns
+                    .iter()
+                    .flatten()
+                    .map(|(media_type, style_patterns)| (quote(media_type), style_patterns)),
+            )
+            .map(|(entry_name, style_patterns)| {
+                self.format_block(
+                    3,
+                    &format!("{}({{", entry_name),
+                    self.format_style_patterns(4, style_patterns),
+                    "})",
+                )
+            })
+            .collect();
+
+        if !panoramic_entries.is_empty() {
+            children.push(self.format_block(2, "PanoramicViewer({", panoramic_entries, "})"));
+        }
+
+        self.format_block(1, &format!("{} {{", header), children, "}")
+    }
+
+    fn format_style_patterns(
+        &self,
+        level: usize,
+        style_patterns: &IndexMap<String, IndexMap<String, Arc<str>>>,
+    ) -> Vec<String> {
+        style_patterns
+            .iter()
+            .map(|(pattern_name, properties)| {
+                let opening = match split_functional_pattern(pattern_name) {
+                    _ if pattern_name.starts_with('[') => {
+                        format!("Attribute({}, {{", quote(pattern_name))
+                    }
+                    Some((pseudo_class, argument)) => {
+                        format!("{}({}, {{", to_nenyr_pattern(pseudo_class), quote(argument))
+                    }
+                    None => format!("{}({{", to_nenyr_pattern(pattern_name)),
+                };
+
+                self.format_properties(level, &opening, properties)
+            })
+            .collect()
+    }
+
+    /// Formats a block of style properties, writing aliases by their nickname and
+    /// CSS properties by their Nenyr name.
+    fn format_properties(
+        &self,
+        level: usize,
+        opening: &str,
+        properties: &IndexMap<String, Arc<str>>,
+    ) -> String {
+        let children = properties
+            .iter()
+            .map(|(property, value)| {
+                format!(
+                    "{}{}: {}",
+                    self.indent(level + 1),
+                    to_nenyr_property(property),
+                    quote(value)
+                )
+            })
+            .collect();
+
+        self.format_block(level, opening, children, "})")
+    }
+
+    /// Formats the variables, writing the ones declared as `number+unit` values unquoted and
+    /// the ones declared as array literals as arrays.
+    fn format_variables(&self, level: usize, variables: &NenyrVariables) -> Vec<String> {
+        variables
+            .values
+            .iter()
+            .map(|(name, value)| {
+                let is_dimension = variables
+                    .dimensions
+                    .as_ref()
+                    .is_some_and(|dimensions| dimensions.contains_key(name));
+                let expression = variables
+                    .expressions
+                    .as_ref()
+                    .and_then(|expressions| expressions.get(name));
+
+                match (value, expression) {
+                    (_, Some(expression)) => {
+                        format!("{}{}: {}", self.indent(level), name, expression.to_nenyr())
+                    }
+                    (NenyrVariableValue::List(elements), None) => {
+                        let elements: Vec<String> =
+                            elements.iter().map(|element| quote(element)).collect();
+
+                        format!("{}{}: [{}]", self.indent(level), name, elements.join(", "))
+                    }
+                    (NenyrVariableValue::Text(value), None) if is_dimension => {
+                        format!("{}{}: {}", self.indent(level), name, value)
+                    }
+                    (NenyrVariableValue::Text(value), None) => {
+                        format!("{}{}: {}", self.indent(level), name, quote(value))
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn format_aliases(&self, level: usize, aliases: &NenyrAliases) -> Vec<String> {
+        aliases
+            .values
+            .iter()
+            .map(|(alias, property)| {
+                format!(
+                    "{}{}: {}",
+                    self.indent(level),
+                    alias,
+                    to_nenyr_property(property)
+                )
+            })
+            .collect()
+    }
+
+    fn format_string_values(&self, level: usize, values: &IndexMap<String, String>) -> Vec<String> {
+        values
+            .iter()
+            .map(|(name, value)| format!("{}{}: {}", self.indent(level), name, quote(value)))
+            .collect()
+    }
+
+    /// Writes the opening line, the comma-separated children (already indented) and the
+    /// closing line of a block at the given indentation level.
+    fn format_block(
+        &self,
+        level: usize,
+        opening: &str,
+        children: Vec<String>,
+        closing: &str,
+    ) -> String {
+        let mut formatted = format!("{}{}\n", self.indent(level), opening);
+
+        if !children.is_empty() {
+            formatted.push_str(&children.join(",\n"));
+            formatted.push('\n');
+        }
+
+        formatted.push_str(&self.indent(level));
+        formatted.push_str(closing);
+        formatted
+    }
+
+    fn indent(&self, level: usize) -> String {
+        " ".repeat(level * self.opts.indent_width)
+    }
+}
+
+/// Wraps a value in double quotes, falling back to single quotes when the value
+/// itself contains a double quote.
+///
+/// A value containing both kinds of quotes, or a `\\u{` that would be decoded as an escape
+/// when parsed back, is written as a backtick raw string, whose contents are taken verbatim.
+fn quote(value: &str) -> String {
+    if value.contains("\\u{") || (value.contains('"') && value.contains('\'')) {
+        format!("`{}`", value)
+    } else if value.contains('"') {
+        format!("'{}'", value)
+    } else {
+        format!("\"{}\"", value)
+    }
+}
+
+/// Splits a container query prelude stored in the AST into the optional container name
+/// and the container query condition, e.g. `sidebar (min-width: 400px)`.
+fn split_container_query(container_query: &str) -> (Option<&str>, &str) {
+    let is_anonymous = container_query.starts_with('(')
+        || container_query
+            .strip_prefix("not")
+            .is_some_and(|rest| rest.starts_with(char::is_whitespace) || rest.starts_with('('));
+
+    match container_query.split_once(' ') {
+        Some((container_name, condition)) if !is_anonymous => (Some(container_name), condition),
+        _ => (None, container_query),
+    }
+}
+
+/// Splits a functional pseudo-class stored in the AST into the pseudo-class and its
+/// argument, e.g. `:nth-child(odd)` into `:nth-child` and `odd`.
+fn split_functional_pattern(pattern_name: &str) -> Option<(&str, &str)> {
+    let (pseudo_class, argument) = pattern_name.split_once('(')?;
+
+    Some((pseudo_class, argument.strip_suffix(')')?))
+}
+
+/// Converts a property stored in the AST back into its Nenyr form. Aliases are stored
+/// with the `nickname;` prefix, while CSS properties are converted from kebab-case
+/// into the camelCase names recognized by the lexer. Vendor-prefixed properties lose
+/// their leading dash, e.g. `-webkit-line-clamp` into `webkitLineClamp`.
+fn to_nenyr_property(property: &str) -> String {
+    if let Some(nickname) = property.strip_prefix("nickname;") {
+        return nickname.to_string();
+    }
+
+    let mut segments = property.trim_start_matches('-').split('-');
+    let mut nenyr_property = segments.next().unwrap_or_default().to_string();
+
+    for segment in segments {
+        nenyr_property.push_str(&capitalize(segment));
+    }
+
+    nenyr_property
+}
+
+/// Converts a pseudo-selector stored in the AST back into the Nenyr pattern name,
+/// e.g. `:first-child` into `FirstChild`.
+fn to_nenyr_pattern(pattern_name: &str) -> String {
+    if pattern_name == "_stylesheet" {
+        return "Stylesheet".to_string();
+    }
+
+    pattern_name
+        .trim_start_matches(':')
+        .split('-')
+        .map(capitalize)
+        .collect()
+}
+
+fn capitalize(segment: &str) -> String {
+    let mut chars = segment.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NenyrParser;
+
+    use crate::types::comments::NenyrComments;
+
+    use super::{format, format_with_comments, FormatOptions};
+
+    /// Parses `src`, formats the resulting AST, re-parses the formatted output
+    /// and asserts that both ASTs are structurally equal.
+    fn assert_reparse_equals(src: &str) {
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(src.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn central_fixture_must_reparse_to_equal_ast() {
+        assert_reparse_equals(include_str!("../../mocks/nenyr/central.nyr"));
+    }
+
+    #[test]
+    fn layout_fixture_must_reparse_to_equal_ast() {
+        assert_reparse_equals(include_str!("../../mocks/nenyr/layout.nyr"));
+    }
+
+    #[test]
+    fn module_fixture_must_reparse_to_equal_ast() {
+        assert_reparse_equals(include_str!("../../mocks/nenyr/module.nyr"));
+    }
+
+    #[test]
+    fn formatted_layout_must_reparse_to_equal_ast() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    Declare Variables({ primaryColor: '#FF6677', secondaryColor: 'white', shadows: ['0 1px 2px #000', '0 2px 4px #333'] }),
+    Declare Aliases({ bgd: backgroundColor, pdg: padding }),
+    Declare Animation('fadeIn') {
+        Fraction([0, 50.5], { opacity: '0' }),
+        Fraction(100, { opacity: '1', bgd: '${primaryColor}' })
+    },
+    Declare Class('mainCard') Deriving('baseCard') {
+        Important(true),
+        Stylesheet({ bgd: '${primaryColor}', display: 'flex', content: '\"\"' }),
+        Hover({ pdg: '10px' }),
+        PanoramicViewer({
+            onMobTablet({ FirstChild({ borderRadius: '5px' }) })
+        })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert_eq!(
+            formatted,
+            "Construct Layout(\"mainLayout\") {
+    Declare Aliases({
+        bgd: backgroundColor,
+        pdg: padding
+    }),
+    Declare Variables({
+        primaryColor: \"#FF6677\",
+        secondaryColor: \"white\",
+        shadows: [\"0 1px 2px #000\", \"0 2px 4px #333\"]
+    }),
+    Declare Animation(\"fadeIn\") {
+        Fraction([0, 50.5], {
+            opacity: \"0\"
+        }),
+        Fraction(100, {
+            opacity: \"1\",
+            bgd: \"${primaryColor}\"
+        })
+    },
+    Declare Class(\"mainCard\") Deriving(\"baseCard\") {
+        Important(true),
+        Stylesheet({
+            bgd: \"${primaryColor}\",
+            display: \"flex\",
+            content: '\"\"'
+        }),
+        Hover({
+            pdg: \"10px\"
+        }),
+        PanoramicViewer({
+            onMobTablet({
+                FirstChild({
+                    borderRadius: \"5px\"
+                })
+            })
+        })
+    }
+}
+"
+        );
+        assert_eq!(
+            parser.parse(formatted, "".to_string()).unwrap(),
+            ast.clone()
+        );
+    }
+
+    #[test]
+    fn indent_width_must_be_honored() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Class('box') { Stylesheet({ width: '10px' }) }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::new(2));
+
+        assert_eq!(
+            formatted,
+            "Construct Module(\"card\") Extending(\"mainLayout\") {
+  Declare Class(\"box\") {
+    Stylesheet({
+      width: \"10px\"
+    })
+  }
+}
+"
+        );
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn comments_must_be_written_back_when_collected() {
+        let raw_nenyr = "// The card module.
+Construct Module('card') Extending('mainLayout') {
+    /* The card container. */
+    Declare Class('box') {
+        // Fixed width.
+        Stylesheet({ width: '10px' })
+    },
+    // The card spacing.
+    Declare Variables({ spacing: '4px' })
+    // End of the card.
+}
+/* End of the file. */";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+
+        assert_eq!(parser.get_comments(), NenyrComments::new());
+        assert_eq!(
+            format(&ast, FormatOptions::default()),
+            format_with_comments(&ast, &parser.get_comments(), FormatOptions::default())
+        );
+
+        parser.set_comments_collected(true);
+
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let comments = parser.get_comments();
+        let formatted = format_with_comments(&ast, &comments, FormatOptions::default());
+
+        assert_eq!(
+            formatted,
+            "// The card module.
+Construct Module(\"card\") Extending(\"mainLayout\") {
+    // The card spacing.
+    Declare Variables({
+        spacing: \"4px\"
+    }),
+    /* The card container. */
+    // Fixed width.
+    Declare Class(\"box\") {
+        Stylesheet({
+            width: \"10px\"
+        })
+    }
+    // End of the card.
+    /* End of the file. */
+}
+"
+        );
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+        assert_eq!(parser.get_comments(), comments);
+    }
+
+    #[test]
+    fn vendor_prefixed_properties_must_round_trip() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Class('glass') { Stylesheet({ webkitBackdropFilter: 'blur(4px)', mozAppearance: 'none' }) }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(format!("{:?}", ast).contains(
+            "{\"-webkit-backdrop-filter\": \"blur(4px)\", \"-moz-appearance\": \"none\"}"
+        ));
+        assert!(formatted.contains("webkitBackdropFilter: \"blur(4px)\""));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn functional_pseudo_patterns_must_round_trip() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Class('row') {
+        Not('[type=\"hidden\"]', { display: 'block' }),
+        NthChild('2n + 1', { backgroundColor: 'gray' }),
+        Attribute('[data-state=\"open\"]', { display: 'flex' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("Not('[type=\"hidden\"]', {"));
+        assert!(formatted.contains("NthChild(\"2n + 1\", {"));
+        assert!(formatted.contains("Attribute('[data-state=\"open\"]', {"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn panoramic_media_types_must_round_trip() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Class('row') {
+        PanoramicViewer({
+            onMobile({ Stylesheet({ display: 'block' }) }),
+            'print'({ Stylesheet({ display: 'none' }) })
+        })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("onMobile({"));
+        assert!(formatted.contains("\"print\"({"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn percentage_fraction_stops_must_round_trip() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Animation('slideIn') {
+        Fraction(0, { opacity: '0' }),
+        Fraction([50%, 75%], { opacity: '0.5' }),
+        Fraction([60%, 80], { opacity: '0.8' }),
+        Fraction(100%, { opacity: '1' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("Fraction(0, {"));
+        assert!(formatted.contains("Fraction([50%, 75%], {"));
+        assert!(formatted.contains("Fraction([60%, 80], {"));
+        assert!(formatted.contains("Fraction(100%, {"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn keyframes_declarations_must_round_trip() {
+        let raw_nenyr = "Construct Layout('motion') {
+    Declare Keyframes('spin') {
+        From({ transform: 'rotate(0deg)' }),
+        To({ transform: 'rotate(360deg)' })
+    },
+    Declare Animation('fade') {
+        From({ opacity: '0' }),
+        To({ opacity: '1' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("Declare Keyframes(\"spin\") {"));
+        assert!(formatted.contains("Declare Animation(\"fade\") {"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn values_with_both_quotes_or_escapes_must_round_trip() {
+        let raw_nenyr = "Construct Module('card') {
+    Declare Class('box') {
+        Stylesheet({ content: `\"a\" 'b'` }),
+        Hover({ content: `'\\u{2022}'` })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("content: `\"a\" 'b'`"));
+        assert!(formatted.contains("content: `'\\u{2022}'`"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn central_consts_must_round_trip() {
+        let raw_nenyr = "Construct Central {
+    Declare Const({ siteWidth: '1200px' }),
+    Declare Variables({ primaryColor: 'red' })
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("Declare Const({\n        siteWidth: \"1200px\"\n    })"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn mixins_must_round_trip() {
+        let raw_nenyr = "Construct Layout('cards') {
+    Declare Mixin('flexCenter') { display: 'flex', justifyContent: 'center' },
+    Declare Class('myCard') { Include('flexCenter') }
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("Declare Mixin(\"flexCenter\") {\n        display: \"flex\",\n        justifyContent: \"center\"\n    }"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn computed_variables_must_round_trip() {
+        let raw_nenyr = "Construct Module('cards') {
+    Declare Variables({
+        spacing: 8px,
+        halfGap: calc(('${spacing}' + 2px) / 2 - 1px)
+    })
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("halfGap: calc((\"${spacing}\" + 2px) / 2 - 1px)"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+
+    #[test]
+    fn theme_aliases_and_typefaces_must_round_trip() {
+        let raw_nenyr = "Construct Layout('cards') {
+    Declare Themes({
+        Light({
+            Variables({ primaryColor: '#FFFFFF' }),
+            Aliases({ bgd: backgroundColor }),
+            Typefaces({ mainFont: '../fonts/light.woff2' })
+        }),
+        Dark({ Variables({ primaryColor: '#000000' }) })
+    })
+}";
+        let mut parser = NenyrParser::new();
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let formatted = format(&ast, FormatOptions::default());
+
+        assert!(formatted.contains("        Light({\n            Variables({\n                primaryColor: \"#FFFFFF\"\n            }),\n            Aliases({\n                bgd: backgroundColor\n            }),"));
+        assert_eq!(parser.parse(formatted, "".to_string()).unwrap(), ast);
+    }
+}
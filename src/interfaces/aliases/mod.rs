@@ -22,11 +22,21 @@ impl NenyrParser {
     /// - The properties block does not start with an opening curly bracket `{`.
     /// - The properties block does not end with a closing curly bracket `}`.
     /// - There are any syntax issues within the properties block, such as duplicated commas.
+    /// - An alias refers to a different property than the alias of the same name declared on
+    ///   the other side of `Themes`.
+    ///
+    /// # Parameters
+    ///
+    /// - `is_from_themes`: A boolean indicating whether the `Aliases` block is declared within
+    ///   a `Themes` block.
     ///
     /// # Returns
     ///
     /// Returns a `NenyrAliases` instance containing the parsed alias definitions.
-    pub(crate) fn process_aliases_method(&mut self) -> NenyrResult<NenyrAliases> {
+    pub(crate) fn process_aliases_method(
+        &mut self,
+        is_from_themes: bool,
+    ) -> NenyrResult<NenyrAliases> {
         self.process_next_token()?;
 
         self.parse_parenthesized_delimiter(
@@ -40,9 +50,17 @@ impl NenyrParser {
                     "The `Aliases` declaration block was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis.",
                     Some("Ensure that the properties block within the `Aliases` declaration is properly closed with a closing curly bracket `}`. The correct syntax should look like: `Declare Aliases({ key: 'value', ... })`.".to_string()),
                     "The `Aliases` declaration block is missing a closing curly bracket `}` to properly close the properties block.",
-                    Self::process_aliases_children,
+                    |parser| parser.process_aliases_children(is_from_themes),
                 )?;
 
+                for (alias_name, property) in &aliases.values {
+                    parser.processing_state.register_alias_declaration(
+                        alias_name,
+                        property,
+                        is_from_themes,
+                    );
+                }
+
                 parser.process_next_token()?;
 
                 Ok(aliases)
@@ -63,10 +81,14 @@ impl NenyrParser {
     /// - A new property statement is expected but not found.
     /// - Commas are missing between properties.
     ///
+    /// # Parameters
+    ///
+    /// - `is_from_themes`: A boolean indicating whether the aliases are declared within `Themes`.
+    ///
     /// # Returns
     ///
     /// Returns a `NenyrAliases` instance containing all parsed aliases from the properties block.
-    fn process_aliases_children(&mut self) -> NenyrResult<NenyrAliases> {
+    fn process_aliases_children(&mut self, is_from_themes: bool) -> NenyrResult<NenyrAliases> {
         let mut aliases = NenyrAliases::new();
 
         loop_while_not!(
@@ -78,7 +100,7 @@ impl NenyrParser {
             || self.processing_state.is_block_active(),
             |is_active| self.processing_state.set_block_active(is_active),
             {
-                self.process_alias_identifier(&mut aliases)?;
+                self.process_alias_identifier(is_from_themes, &mut aliases)?;
             }
         );
 
@@ -95,17 +117,22 @@ impl NenyrParser {
     ///
     /// # Parameters
     ///
+    /// - `is_from_themes`: A boolean indicating whether the alias is declared within `Themes`.
     /// - `aliases`: A mutable reference to the `NenyrAliases` instance to which the alias will be added.
     ///
     /// # Errors
     ///
     /// Returns a `NenyrError` if:
     /// - The identifier is not a valid identifier (must be alphanumeric and start with a letter).
-    fn process_alias_identifier(&mut self, aliases: &mut NenyrAliases) -> NenyrResult<()> {
+    fn process_alias_identifier(
+        &mut self,
+        is_from_themes: bool,
+        aliases: &mut NenyrAliases,
+    ) -> NenyrResult<()> {
         self.processing_state.set_block_active(true);
 
         if let NenyrTokens::Identifier(identifier) = self.current_token.clone() {
-            return self.process_alias_value(identifier, aliases);
+            return self.process_alias_value(is_from_themes, identifier, aliases);
         }
 
         Err(NenyrError::new(
@@ -115,7 +142,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error("The `Aliases` declaration contains an invalid identifier for the alias name. Please ensure the identifier follows the required format."),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0001"))
     }
 
     /// Processes the value associated with an alias identifier.
@@ -125,6 +152,7 @@ impl NenyrParser {
     ///
     /// # Parameters
     ///
+    /// - `is_from_themes`: A boolean indicating whether the alias is declared within `Themes`.
     /// - `identifier`: A string representing the alias identifier.
     /// - `aliases`: A mutable reference to the `NenyrAliases` instance to which the alias will be added.
     ///
@@ -132,8 +160,11 @@ impl NenyrParser {
     ///
     /// Returns a `NenyrError` if:
     /// - The property value is not a valid Nenyr property.
+    /// - The alias refers to a different property than the alias of the same name declared on
+    ///   the other side of `Themes`.
     fn process_alias_value(
         &mut self,
+        is_from_themes: bool,
         identifier: String,
         aliases: &mut NenyrAliases,
     ) -> NenyrResult<()> {
@@ -145,6 +176,7 @@ impl NenyrParser {
         )?;
 
         if let Some(property) = self.convert_nenyr_property_to_css_property(&self.current_token) {
+            self.check_alias_themes_collision(is_from_themes, &identifier, &property)?;
             aliases.add_alias(identifier, property);
 
             return Ok(());
@@ -157,7 +189,49 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&format!("The `{}` alias contains an invalid property, which is not a valid Nenyr property as a value.", identifier)),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0002"))
+    }
+
+    /// Ensures that an alias declared within `Themes` refers to the same property as the alias
+    /// of the same name declared by the context, and the other way around, since the aliases
+    /// of a class are resolved through the context aliases regardless of the active theme.
+    ///
+    /// # Parameters
+    /// - `is_from_themes`: A boolean indicating whether the alias is declared within `Themes`.
+    /// - `identifier`: The name of the alias being declared.
+    /// - `property`: The CSS property the alias refers to.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` of kind `ValidationError` if the alias of the same name refers to
+    /// another property.
+    fn check_alias_themes_collision(
+        &self,
+        is_from_themes: bool,
+        identifier: &str,
+        property: &str,
+    ) -> NenyrResult<()> {
+        let Some(declared_property) = self.processing_state.get_conflicting_alias_property(
+            identifier,
+            property,
+            is_from_themes,
+        ) else {
+            return Ok(());
+        };
+
+        let error_message = if is_from_themes {
+            format!("In the `Themes` block, the `{}` alias refers to the `{}` property, but the `Aliases` declaration of the context already maps it to the `{}` property.", identifier, property, declared_property)
+        } else {
+            format!("The `{}` alias refers to the `{}` property, but the `Themes` block already maps it to the `{}` property.", identifier, property, declared_property)
+        };
+
+        Err(NenyrError::new(
+            Some(format!("Either map the `{}` alias to the same property in the context and in `Themes`, or rename one of them.", identifier)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            error_message,
+            NenyrErrorKind::ValidationError,
+            self.get_tracing(),
+        ).with_code("N0105"))
     }
 }
 
@@ -181,7 +255,7 @@ mod tests {
 
         let _ = parser.process_next_token();
         assert_eq!(
-            format!("{:?}", parser.process_aliases_method()),
+            format!("{:?}", parser.process_aliases_method(false)),
             "Ok(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} })".to_string()
         );
     }
@@ -202,8 +276,8 @@ mod tests {
 
         let _ = parser.process_next_token();
         assert_eq!(
-            format!("{:?}", parser.process_aliases_method()),
-            "Err(NenyrError { suggestion: Some(\"After the opening parenthesis, an opening curly bracket `{` is required to properly define the properties block in the `Aliases` declaration. Ensure the pattern follows correct Nenyr syntax, like `Declare Aliases({ key: 'value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `Aliases` declaration block was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis. However, found `bgd` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Aliases(\"), line_after: Some(\"        bgd: backgroundColor,\"), error_line: Some(\"        bgd: background,\"), error_on_line: 2, error_on_col: 12, error_on_pos: 20 } })".to_string()
+            format!("{:?}", parser.process_aliases_method(false)),
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"After the opening parenthesis, an opening curly bracket `{` is required to properly define the properties block in the `Aliases` declaration. Ensure the pattern follows correct Nenyr syntax, like `Declare Aliases({ key: 'value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `Aliases` declaration block was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis. However, found `bgd` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Aliases(\"), line_after: Some(\"        bgd: backgroundColor,\"), error_line: Some(\"        bgd: background,\"), error_on_line: 2, error_on_col: 12, error_on_pos: 20 } })".to_string()
         );
     }
 
@@ -216,7 +290,7 @@ mod tests {
 
         let _ = parser.process_next_token();
         assert_eq!(
-            format!("{:?}", parser.process_aliases_method()),
+            format!("{:?}", parser.process_aliases_method(false)),
             "Ok(NenyrAliases { values: {} })".to_string()
         );
     }
@@ -1,12 +1,14 @@
 use indexmap::IndexMap;
+use std::sync::Arc;
 
 use crate::{
     converters::property::NenyrPropertyConverter,
-    error::{NenyrError, NenyrErrorKind},
+    error::{NenyrError, NenyrErrorKind, NenyrErrorTracing},
     loop_while_not,
+    options::IncompleteTransitivePolicy,
     tokens::NenyrTokens,
-    types::animations::{NenyrAnimation, NenyrAnimationKind, NenyrSubAnimationKind},
-    validators::{identifier::NenyrIdentifierValidator, style_syntax::NenyrStyleSyntaxValidator},
+    types::animations::{NenyrAnimation, NenyrAnimationKind, NenyrKeyframe, NenyrSubAnimationKind},
+    validators::identifier::NenyrIdentifierValidator,
     NenyrParser, NenyrResult,
 };
 
@@ -23,6 +25,9 @@ impl NenyrParser {
     /// }
     /// ```
     ///
+    /// `Keyframes` is accepted as a synonym of `Animation`, e.g. `Declare Keyframes('spin') { ... }`,
+    /// and is recorded in the parsed `NenyrAnimation` so it can be written back the same way.
+    ///
     /// # Returns
     /// Returns a tuple containing the animation name and the parsed `NenyrAnimation`.
     ///
@@ -30,20 +35,32 @@ impl NenyrParser {
     /// Will return a `NenyrError` if:
     /// - The animation name is improperly formatted.
     /// - Curly brackets are missing to delimit the animation block.
+    /// - An animation with the same name was already declared within the current context.
     pub(crate) fn process_animation_method(&mut self) -> NenyrResult<(String, NenyrAnimation)> {
+        let declaration_tracing = self.get_tracing();
+        let is_keyframes_declaration = self.current_token == NenyrTokens::Keyframes;
+
         self.process_next_token()?;
 
         let animation_name = self.retrieve_animation_name()?;
 
+        self.validate_animation_name_uniqueness(&animation_name, declaration_tracing)?;
         self.process_next_token()?;
 
-        self.parse_curly_bracketed_delimiter(
+        let (animation_name, mut animation) = self.parse_curly_bracketed_delimiter(
             Some(format!("Ensure that the `{}` animation name declaration is followed by an opening curly bracket `{{` to properly define the animation block. The correct syntax is: `Declare Animation('{}') {{ ... }}`.", &animation_name, &animation_name)),
             &format!("An opening curly bracket `{{` was expected after the `{}` animation name declaration to start the animation block, but it was not found.", &animation_name),
             Some(format!("Ensure that each animation definition block is properly closed with a corresponding closing curly bracket `}}`. Example: `Declare Animation('{}') {{ ... }}`.", &animation_name)),
             &format!("A closing curly bracket `}}` was expected to terminate the `{}` animation definition block, but it was not found.", &animation_name),
             |parser| parser.process_animation_block(&animation_name),
-        )
+        )?;
+
+        self.processing_state
+            .register_animation_span(&animation_name, self.lexer.token_span().1);
+
+        animation.is_keyframes_declaration = is_keyframes_declaration;
+
+        Ok((animation_name, animation))
     }
 
     /// Retrieves the name of the animation by parsing the text between the parentheses
@@ -64,12 +81,12 @@ impl NenyrParser {
     ///   - Parentheses are missing or improperly placed.
     ///   - The animation name is empty or contains invalid characters.
     fn retrieve_animation_name(&mut self) -> NenyrResult<String> {
-        let animation_name = self.parse_parenthesized_delimiter(
+        let (animation_name, literal_start) = self.parse_parenthesized_delimiter(
             Some("Ensure that an opening parenthesis `(` is placed after the keyword `Animation` to properly define the animation name. The correct syntax is: `Animation('animationName') { ... }`.".to_string()),
             "The declaration block of `Animation` was expecting an open parenthesis `(` after the keyword `Animation`, but none was found.",
             Some("Ensure that the animation name in the `Animation` declaration is properly closed with a parenthesis `)`. The correct syntax is: `Animation('animationName') { ... }`.".to_string()),
             "The `Animation` declaration is missing a closing parenthesis `)` after the animation name.",
-            |parser| parser.parse_string_literal(
+            |parser| parser.parse_spanned_string_literal(
                 Some("All `Animation` declarations must have a non-empty string as a name. The name should contain only alphanumeric characters, with the first character being a letter. The correct syntax is: `Animation('animationName') { ... }`.".to_string()),
                 "The `Animation` declaration must receive a name that is a non-empty string, but no animation name was found.",
                 true
@@ -83,13 +100,44 @@ impl NenyrParser {
                 self.context_path.to_string(),
                 self.add_nenyr_token_to_error("The validation of the animation name failed. The provided name does not meet the required format."),
                 NenyrErrorKind::SyntaxError,
-                self.get_tracing(),
-            ));
+                self.get_identifier_tracing(literal_start, &animation_name),
+            ).with_code("N0003"));
         }
 
         return Ok(animation_name);
     }
 
+    /// Ensures that the animation name has not been declared before within the current context.
+    ///
+    /// Animations are stored by name, so declaring the same name twice would make the second
+    /// declaration silently replace the first one. The error points to the repeated declaration
+    /// and its message references where the original animation was declared.
+    ///
+    /// # Errors
+    /// - A `NenyrError` of kind `ValidationError` will be returned if the animation name
+    ///   was already declared.
+    fn validate_animation_name_uniqueness(
+        &mut self,
+        animation_name: &str,
+        declaration_tracing: NenyrErrorTracing,
+    ) -> NenyrResult<()> {
+        if let Some(previous_tracing) = self
+            .processing_state
+            .register_animation_declaration(animation_name, declaration_tracing)
+        {
+            return Err(NenyrError::new(
+                Some(format!("Ensure that each animation declared within the context has a unique name. Either rename one of the `{}` animations or merge their patterns into a single declaration.", animation_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("The `{}` animation has already been declared on line {}, column {}, and cannot be declared again within the same context.", animation_name, previous_tracing.get_line(), previous_tracing.get_column())),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0004"));
+        }
+
+        Ok(())
+    }
+
     /// Processes the block of the animation which contains different animation patterns.
     ///
     /// # Syntax
@@ -132,10 +180,132 @@ impl NenyrParser {
         );
 
         self.processing_state.set_block_active(false);
+        self.check_transitive_endpoints(animation_name, &animation)?;
+
+        if self.options.warn_on_inconsistent_progressive_steps {
+            self.check_progressive_steps_consistency(animation_name, &animation)?;
+        }
 
         Ok((animation_name.to_string(), animation))
     }
 
+    /// Checks whether a `Transitive` animation declares at least one of its endpoints.
+    ///
+    /// A `Transitive` animation declaring neither a `From` nor a `To` pattern is handled
+    /// according to the `IncompleteTransitivePolicy` set in the parser options: it is either
+    /// rejected, kept with a warning, or kept silently.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_name` - The name of the animation being checked.
+    /// * `animation` - The parsed animation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` if the animation is incomplete and the policy is `Error`.
+    fn check_transitive_endpoints(
+        &mut self,
+        animation_name: &str,
+        animation: &NenyrAnimation,
+    ) -> NenyrResult<()> {
+        if animation.kind != Some(NenyrAnimationKind::Transitive)
+            || self.options.incomplete_transitive_policy == IncompleteTransitivePolicy::Allow
+        {
+            return Ok(());
+        }
+
+        let has_endpoint = animation
+            .keyframe
+            .iter()
+            .any(|keyframe| matches!(keyframe, NenyrKeyframe::From(_) | NenyrKeyframe::To(_)));
+
+        if has_endpoint {
+            return Ok(());
+        }
+
+        let suggestion = format!("Add a `From` or a `To` pattern to the `{}` animation to define where it starts or ends. Example: `Declare Animation('{}') {{ From({{ ... }}), Halfway({{ ... }}), To({{ ... }}) }}`.", animation_name, animation_name);
+        let message = format!("The `{}` animation is a transitive animation declaring neither a `From` nor a `To` pattern, so it has no start or end keyframe.", animation_name);
+
+        match self.options.incomplete_transitive_policy {
+            IncompleteTransitivePolicy::Error => Err(NenyrError::new(
+                Some(suggestion),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                message,
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            )
+            .with_code("N0005")),
+            IncompleteTransitivePolicy::Warn => {
+                self.add_warning("W0001", Some(suggestion), message)
+            }
+            IncompleteTransitivePolicy::Allow => Ok(()),
+        }
+    }
+
+    /// Raises a warning when the steps of a `Progressive` animation declare different sets
+    /// of properties, listing every property missing from at least one step.
+    ///
+    /// This check only runs when the `warn_on_inconsistent_progressive_steps` option is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_name` - The name of the animation being checked.
+    /// * `animation` - The parsed animation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` only if the diagnostics limit is exceeded with the `Abort` policy.
+    fn check_progressive_steps_consistency(
+        &mut self,
+        animation_name: &str,
+        animation: &NenyrAnimation,
+    ) -> NenyrResult<()> {
+        let steps: Vec<&IndexMap<String, Arc<str>>> = animation
+            .keyframe
+            .iter()
+            .filter_map(|keyframe| match keyframe {
+                NenyrKeyframe::Progressive(properties) => Some(properties),
+                _ => None,
+            })
+            .collect();
+
+        let mut inconsistent_properties: Vec<&str> = vec![];
+
+        for property in steps.iter().flat_map(|properties| properties.keys()) {
+            let property = property.trim_start_matches("nickname;");
+
+            if inconsistent_properties.contains(&property) {
+                continue;
+            }
+
+            let is_inconsistent = steps.iter().any(|properties| {
+                !properties.contains_key(property)
+                    && !properties.contains_key(&format!("nickname;{}", property))
+            });
+
+            if is_inconsistent {
+                inconsistent_properties.push(property);
+            }
+        }
+
+        if inconsistent_properties.is_empty() {
+            return Ok(());
+        }
+
+        let properties = inconsistent_properties
+            .iter()
+            .map(|property| format!("`{}`", property))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        self.add_warning(
+            "W0002",
+            Some(format!("Declare the {} properties in every step of the `{}` animation so that they animate smoothly across all the steps.", properties, animation_name)),
+            format!("The steps of the `{}` progressive animation declare inconsistent properties. The {} properties are missing from at least one step.", animation_name, properties),
+        )
+    }
+
     /// Processes the individual patterns within an animation block.
     /// Animation patterns include:
     /// - `Fraction`
@@ -258,7 +428,7 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error(&format!("The `{}` animation contains an invalid pattern statement. Please ensure that all methods within the animation are correctly defined and formatted.", animation_name)),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0006"));
             }
         }
     }
@@ -270,6 +440,9 @@ impl NenyrParser {
     /// - `current_token`: The token representing the animation pattern (e.g., `Fraction`, `Progressive`).
     /// - `animation_kind`: The current kind of the animation (e.g., `Fraction`, `Transitive`).
     ///
+    /// When `Fraction` and `Progressive` patterns are mixed, a specialized error naming both
+    /// patterns is returned, since their keyframe stops are computed in incompatible ways.
+    ///
     /// # Returns
     /// Returns a `NenyrError` indicating a mismatch in animation types.
     fn throw_animation_type_error(
@@ -278,6 +451,23 @@ impl NenyrParser {
         current_token: NenyrTokens,
         animation_kind: NenyrAnimationKind,
     ) -> NenyrResult<()> {
+        let is_fraction_progressive_mix = matches!(
+            (&animation_kind, &current_token),
+            (NenyrAnimationKind::Fraction, NenyrTokens::Progressive)
+                | (NenyrAnimationKind::Progressive, NenyrTokens::Fraction)
+        );
+
+        if is_fraction_progressive_mix {
+            return Err(NenyrError::new(
+                Some(format!("Use either `Fraction` patterns or `Progressive` patterns inside the `{}` animation, but not both. To define the keyframe stops explicitly, replace the `Progressive` patterns with `Fraction` patterns, e.g. `Fraction(50, {{ ... }})`; otherwise, replace the `Fraction` patterns with `Progressive` patterns, e.g. `Progressive({{ ... }})`.", animation_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The `{}` animation started with a `{:?}` pattern and cannot receive a `{:?}` pattern. Fraction animations use explicit stops; Progressive animations auto-distribute — they cannot be combined.", animation_name, animation_kind, current_token),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0007"));
+        }
+
         Err(NenyrError::new(
             Some(format!("The type of an animation is determined by the first pattern statement it receives. For instance, if an animation begins with a `Fraction` pattern, it can only accept other `Fraction` patterns within its block. Similarly, an animation that starts with a `Progressive` pattern will only accept `Progressive` patterns as children. The patterns `From`, `Halfway`, and `To` represent the same animation type and can be used together inside an animation block. To resolve this issue, ensure that the `{}` animation only contains patterns matching its initial type or modify the first pattern to align with the intended type.", animation_name)),
             self.context_name.clone(),
@@ -285,7 +475,7 @@ impl NenyrParser {
             format!("The `{}` animation is of type `{:?}`, and it cannot receive a different statement type. Assigning a `{:?}` pattern to the `{}` animation is invalid because its type was already defined by a different pattern statement.", animation_name, animation_kind, current_token, animation_name),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0008"))
     }
 
     /// Processes a section of an animation defined by both parenthesis
@@ -328,6 +518,7 @@ impl NenyrParser {
                         parser.process_animation_children(
                             animation_name,
                             &None,
+                            &[],
                             animation,
                             sub_animation_kind,
                         )
@@ -367,11 +558,12 @@ impl NenyrParser {
             Some(format!("Ensure that all fraction patterns within the `{}` animation block have both an opening and a closing parenthesis. The syntax should follow the correct format, such as `Animation('{}') {{ Fraction(10.500, {{ ... }}), Fraction(100, {{ ... }}), ... }}`.", animation_name, animation_name)),
             &format!("A closing parenthesis `)` is missing for one of the fraction patterns in the `{}` animation. The parser expected a closing parenthesis to properly end the pattern declaration.", animation_name),
             |parser| {
-                let stops = parser.process_faction_stops(animation_name)?;
+                let (stops, is_percentage) = parser.process_faction_stops(animation_name)?;
 
                 parser.process_comma_after_fraction_stops(
                     animation_name,
                     &stops,
+                    &is_percentage,
                     animation,
                     sub_animation_kind,
                 )
@@ -389,20 +581,27 @@ impl NenyrParser {
     ///
     /// # Returns
     ///
-    /// A vector of float values representing the stops in the animation.
-    /// If no valid stops are found, the function returns `None`.
+    /// A vector of float values representing the stops in the animation, along with
+    /// whether each of them was written as a percentage, such as `30%`, which is treated
+    /// identically to the bare `30`. If no valid stops are found, the vector is `None`.
     ///
     /// # Errors
     ///
     /// Returns an error if the stops are not valid numeric values or if there
     /// are syntax issues in the stop declaration.
-    fn process_faction_stops(&mut self, animation_name: &str) -> NenyrResult<Option<Vec<f64>>> {
-        match self.current_token {
-            NenyrTokens::Number(stop) => Ok(Some(vec![stop])),
+    fn process_faction_stops(
+        &mut self,
+        animation_name: &str,
+    ) -> NenyrResult<(Option<Vec<f64>>, Vec<bool>)> {
+        match &self.current_token {
+            NenyrTokens::Number(stop) => Ok((Some(vec![*stop]), vec![false])),
+            NenyrTokens::Dimension(stop, unit) if unit == "%" => {
+                Ok((Some(vec![*stop]), vec![true]))
+            }
             NenyrTokens::SquareBracketOpen => {
-                let stops = self.process_f64_vector(animation_name)?;
+                let (stops, is_percentage) = self.process_f64_vector(animation_name)?;
 
-                Ok(Some(stops))
+                Ok((Some(stops), is_percentage))
             }
             _ => {
                 return Err(NenyrError::new(
@@ -412,7 +611,7 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error(&format!("The `{}` animation contains an invalid stop value. Each stop must be a numeric value (either float or integer), or a vector of numeric values. Non-numeric or empty values are not allowed.", animation_name)),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0009"));
             }
         }
     }
@@ -427,16 +626,18 @@ impl NenyrParser {
     ///
     /// # Returns
     ///
-    /// A vector of `f64` values representing numeric stops for the animation.
+    /// A vector of `f64` values representing numeric stops for the animation, along with
+    /// whether each of them was written as a percentage.
     ///
     /// # Errors
     ///
     /// Returns an error if the values inside the square brackets are non-numeric
     /// or if there is an issue with the syntax of the stops.
-    fn process_f64_vector(&mut self, animation_name: &str) -> NenyrResult<Vec<f64>> {
+    fn process_f64_vector(&mut self, animation_name: &str) -> NenyrResult<(Vec<f64>, Vec<bool>)> {
         self.process_next_token()?;
 
         let mut stops: Vec<f64> = Vec::new();
+        let mut is_percentage: Vec<bool> = Vec::new();
 
         loop_while_not!(
             self,
@@ -449,8 +650,15 @@ impl NenyrParser {
             {
                 self.processing_state.set_nested_block_active(true);
 
-                match self.current_token {
-                    NenyrTokens::Number(stop) => stops.push(stop),
+                match &self.current_token {
+                    NenyrTokens::Number(stop) => {
+                        stops.push(*stop);
+                        is_percentage.push(false);
+                    }
+                    NenyrTokens::Dimension(stop, unit) if unit == "%" => {
+                        stops.push(*stop);
+                        is_percentage.push(true);
+                    }
                     _ => {
                         return Err(NenyrError::new(
                             Some(format!("Ensure that all stops in the `{}` animation are valid numeric values, either a single float or integer, or a vector of numeric values. Stops define the points in the animation timeline, and must be numeric to function correctly. Examples of valid stops include a single integer like `10`, a float like `15.5`, or a vector of values such as `[10, 15, 20.5]`. Use the following syntax to correctly define stops: `Animation('{}') {{ Fraction(10, {{ ... }}) }}` or `Animation('{}') {{ Fraction([10, 15.5, 20], {{ ... }}), ... }}`.", animation_name, animation_name, animation_name)),
@@ -459,7 +667,7 @@ impl NenyrParser {
                             self.add_nenyr_token_to_error(&&format!("The `{}` animation contains an invalid stop value. Each stop must be a numeric value (either float or integer), or a vector of numeric values. Non-numeric or empty values are not allowed.", animation_name)),
                             NenyrErrorKind::SyntaxError,
                             self.get_tracing(),
-                        ));
+                        ).with_code("N0010"));
                     }
                 }
             }
@@ -467,7 +675,7 @@ impl NenyrParser {
 
         self.processing_state.set_nested_block_active(false);
 
-        Ok(stops)
+        Ok((stops, is_percentage))
     }
 
     /// Processes a comma after a fraction stop in a Nenyr animation block.
@@ -481,6 +689,7 @@ impl NenyrParser {
     ///
     /// * `animation_name` - The name of the animation being processed.
     /// * `stops` - A reference to an optional vector of fraction stops for the animation.
+    /// * `is_percentage` - Whether each stop was written as a percentage.
     /// * `animation` - A mutable reference to the NenyrAnimation object being built.
     /// * `sub_animation_kind` - A reference to the kind of sub-animation being processed.
     ///
@@ -492,6 +701,7 @@ impl NenyrParser {
         &mut self,
         animation_name: &str,
         stops: &Option<Vec<f64>>,
+        is_percentage: &[bool],
         animation: &mut NenyrAnimation,
         sub_animation_kind: &NenyrSubAnimationKind,
     ) -> NenyrResult<()> {
@@ -509,6 +719,7 @@ impl NenyrParser {
                     parser.process_animation_children(
                         animation_name,
                         stops,
+                        is_percentage,
                         animation,
                         sub_animation_kind,
                     )
@@ -525,7 +736,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&format!("A comma was expected after the stops declaration of the fraction patterns in the `{}` animation, but none was found. The parser requires a comma to separate the stop values from the subsequent object of properties.", animation_name)),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0011"))
     }
 
     /// Processes the child properties of an animation in the Nenyr DSL.
@@ -537,6 +748,7 @@ impl NenyrParser {
     ///
     /// * `animation_name` - The name of the animation being processed.
     /// * `stops` - A reference to an optional vector of fraction stops.
+    /// * `is_percentage` - Whether each fraction stop was written as a percentage.
     /// * `animation` - A mutable reference to the NenyrAnimation object being built.
     /// * `sub_animation_kind` - A reference to the kind of sub-animation being processed.
     ///
@@ -553,10 +765,11 @@ impl NenyrParser {
         &mut self,
         animation_name: &str,
         stops: &Option<Vec<f64>>,
+        is_percentage: &[bool],
         animation: &mut NenyrAnimation,
         sub_animation_kind: &NenyrSubAnimationKind,
     ) -> NenyrResult<()> {
-        let mut keyframe: IndexMap<String, String> = IndexMap::new();
+        let mut keyframe: IndexMap<String, Arc<str>> = IndexMap::new();
 
         loop_while_not!(
             self,
@@ -570,11 +783,15 @@ impl NenyrParser {
                 .set_complementary_block_active(is_active),
             {
                 self.process_animation_property(animation_name, &mut keyframe)?;
+                self.check_block_properties_limit(
+                    keyframe.len(),
+                    &format!("one of the patterns in the `{}` animation", animation_name),
+                )?;
             }
         );
 
         // Create the animation inside the `animation`.
-        animation.add_animation_keyframe(sub_animation_kind, stops, keyframe);
+        animation.add_animation_keyframe(sub_animation_kind, stops, is_percentage, keyframe);
         self.processing_state.set_complementary_block_active(false);
 
         Ok(())
@@ -601,18 +818,17 @@ impl NenyrParser {
     fn process_animation_property(
         &mut self,
         animation_name: &str,
-        keyframe: &mut IndexMap<String, String>,
+        keyframe: &mut IndexMap<String, Arc<str>>,
     ) -> NenyrResult<()> {
         self.processing_state.set_complementary_block_active(true);
 
         if let Some(property) = self.convert_nenyr_property_to_css_property(&self.current_token) {
             return self.process_animation_value(animation_name, property, keyframe);
         } else if let NenyrTokens::Identifier(nickname) = self.current_token.clone() {
-            return self.process_animation_value(
-                animation_name,
-                format!("nickname;{}", nickname),
-                keyframe,
-            );
+            let location = format!("one of the patterns in the `{}` animation", animation_name);
+            let property = self.resolve_alias_property(&nickname, &location)?;
+
+            return self.process_animation_value(animation_name, property, keyframe);
         }
 
         Err(NenyrError::new(
@@ -622,7 +838,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&format!("One of the properties inside one of the patterns in the `{}` animation is not either an alias or a valid property.", animation_name)),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0012"))
     }
 
     /// Parses and assigns a value to a given property in an animation keyframe.
@@ -650,7 +866,7 @@ impl NenyrParser {
         &mut self,
         animation_name: &str,
         property: String,
-        keyframe: &mut IndexMap<String, String>,
+        keyframe: &mut IndexMap<String, Arc<str>>,
     ) -> NenyrResult<()> {
         self.process_next_token()?;
         self.parse_colon_delimiter(
@@ -665,7 +881,9 @@ impl NenyrParser {
             false
         )?;
 
-        if self.is_valid_style_syntax(&value) {
+        let location = format!("one of the patterns in the `{}` animation", animation_name);
+
+        if let Some(value) = self.process_property_value(&property, value, &location)? {
             keyframe.insert(property, value);
 
             return Ok(());
@@ -678,13 +896,16 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&format!("The `{}` property inside one of the patterns in the `{}` animation contains an invalid value, and it could not be validated.", &property, animation_name)),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0013"))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::NenyrParser;
+    use crate::{
+        error::NenyrErrorKind, options::IncompleteTransitivePolicy,
+        types::animations::NenyrAnimation, NenyrParser,
+    };
 
     #[test]
     fn animation_fraction_is_valid() {
@@ -723,7 +944,38 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Ok((\"giddyRespond\", NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }))".to_string()
+            "Ok((\"giddyRespond\", NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }))".to_string()
+        );
+    }
+
+    #[test]
+    fn keyframes_is_parsed_as_animation() {
+        let keyframes_nenyr = "Keyframes('spin') {
+        From({ transform: 'rotate(0deg)' }),
+        To({ transform: 'rotate(360deg)' })
+    }";
+        let animation_nenyr = keyframes_nenyr.replacen("Keyframes", "Animation", 1);
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(keyframes_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        let (keyframes_name, keyframes_animation) = parser.process_animation_method().unwrap();
+
+        parser.setup_dependencies(animation_nenyr, "".to_string());
+
+        let _ = parser.process_next_token();
+        let (animation_name, animation) = parser.process_animation_method().unwrap();
+
+        assert_eq!(keyframes_name, animation_name);
+        assert!(keyframes_animation.is_keyframes_declaration);
+        assert!(!animation.is_keyframes_declaration);
+        assert_eq!(
+            NenyrAnimation {
+                is_keyframes_declaration: false,
+                ..keyframes_animation
+            },
+            animation
         );
     }
 
@@ -752,7 +1004,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Ok((\"spiritedSavings\", NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }))".to_string()
+            "Ok((\"spiritedSavings\", NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }))".to_string()
         );
     }
 
@@ -771,7 +1023,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"})] }))".to_string()
+            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"})] }))".to_string()
         );
     }
 
@@ -790,10 +1042,141 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [Halfway({\"border\": \"1px solid red\"})] }))".to_string()
+            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [Halfway({\"border\": \"1px solid red\"})] }))".to_string()
         );
     }
 
+    #[test]
+    fn halfway_only_animation_must_warn_when_checked() {
+        let raw_nenyr = "Animation('grotesquePtarmigan') {
+        Halfway({
+            border: '1px solid red'
+        })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_animation_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+
+        parser.set_incomplete_transitive_policy(IncompleteTransitivePolicy::Warn);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_animation_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "The `grotesquePtarmigan` animation is a transitive animation declaring neither a `From` nor a `To` pattern, so it has no start or end keyframe.");
+    }
+
+    #[test]
+    fn inconsistent_progressive_steps_must_warn_when_checked() {
+        let raw_nenyr = "Animation('spiritedSavings') {
+        Progressive({
+            width: '${myVar}'
+        }),
+        Progressive({
+            border: '10px solid red',
+            backgroundColor: 'blue',
+            height: '100px',
+            width: '200px'
+        }),
+        Progressive({
+            backgroundColor: 'pink'
+        })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_animation_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+
+        parser.set_inconsistent_progressive_steps_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_animation_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "The steps of the `spiritedSavings` progressive animation declare inconsistent properties. The `width`, `border`, `background-color`, `height` properties are missing from at least one step.");
+    }
+
+    #[test]
+    fn consistent_progressive_steps_must_not_warn() {
+        let raw_nenyr = "Animation('fadeScale') {
+        Progressive({ opacity: '0.2', transform: 'scale(0.8)' }),
+        Progressive({ transform: 'scale(1)', opacity: '0.5' })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.set_inconsistent_progressive_steps_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_animation_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn from_and_to_animation_must_pass_the_strict_check() {
+        let raw_nenyr = "Animation('grotesquePtarmigan') {
+        From({
+            border: '1px solid red'
+        }),
+        Halfway({
+            border: '2px solid red'
+        }),
+        To({
+            border: '3px solid red'
+        })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.set_incomplete_transitive_policy(IncompleteTransitivePolicy::Error);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_animation_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn halfway_only_animation_must_fail_the_strict_check() {
+        let raw_nenyr = "Animation('grotesquePtarmigan') {
+        Halfway({
+            border: '1px solid red'
+        })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.set_incomplete_transitive_policy(IncompleteTransitivePolicy::Error);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        match parser.process_animation_method() {
+            Err(err) => {
+                assert_eq!(err.get_error_kind(), NenyrErrorKind::ValidationError);
+                assert_eq!(err.get_error_message(), "The `grotesquePtarmigan` animation is a transitive animation declaring neither a `From` nor a `To` pattern, so it has no start or end keyframe.");
+            }
+            Ok(animation) => panic!("{:?}", animation),
+        }
+    }
+
     #[test]
     fn animation_to_is_valid() {
         let raw_nenyr = "Animation('grotesquePtarmigan') {
@@ -813,7 +1196,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }))".to_string()
+            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }))".to_string()
         );
     }
 
@@ -842,7 +1225,44 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }))".to_string()
+            "Ok((\"grotesquePtarmigan\", NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }))".to_string()
+        );
+    }
+
+    #[test]
+    fn animation_fraction_with_percentage_stops_is_valid() {
+        let raw_nenyr = "Animation('slideIn') {
+        Fraction(0, { opacity: '0' }),
+        Fraction(30%, { opacity: '0.3' }),
+        Fraction([60%, 80], { opacity: '0.8' }),
+        Fraction(100, { opacity: '1' })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!("{:?}", parser.process_animation_method()),
+            "Ok((\"slideIn\", NenyrAnimation { animation_name: \"slideIn\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [0.0], is_percentage: [false], properties: {\"opacity\": \"0\"} }, Fraction { stops: [30.0], is_percentage: [true], properties: {\"opacity\": \"0.3\"} }, Fraction { stops: [60.0, 80.0], is_percentage: [true, false], properties: {\"opacity\": \"0.8\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"opacity\": \"1\"} }] }))".to_string()
+        );
+    }
+
+    #[test]
+    fn animation_fraction_with_non_percentage_unit_stops_is_not_valid() {
+        let raw_nenyr = "Animation('slideIn') {
+        Fraction(30px, { opacity: '0.3' })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!("{:?}", parser.process_animation_method()),
+            "Err(NenyrError { error_code: \"N0009\", suggestion: Some(\"Ensure that all stops in the `slideIn` animation are valid numeric values, either a single float or integer, or a vector of numeric values. Stops define the points in the animation timeline, and must be numeric to function correctly. Examples of valid stops include a single integer like `10`, a float like `15.5`, or a vector of values such as `[10, 15, 20.5]`. Use the following syntax to correctly define stops: `Animation('slideIn') { Fraction(10, { ... }) }` or `Animation('slideIn') { Fraction([10, 15.5, 20], { ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `slideIn` animation contains an invalid stop value. Each stop must be a numeric value (either float or integer), or a vector of numeric values. Non-numeric or empty values are not allowed. However, found `30px` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Animation('slideIn') {\"), line_after: Some(\"    }\"), error_line: Some(\"        Fraction(30px, { opacity: '0.3' })\"), error_on_line: 2, error_on_col: 22, error_on_pos: 44 } })".to_string()
         );
     }
 
@@ -883,7 +1303,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Err(NenyrError { suggestion: Some(\"Ensure that all fraction patterns inside the `giddyRespond` animation block declaration are enclosed with both an opening and closing parenthesis. Correct syntax example: `Animation('giddyRespond') { Fraction([25, 50], { ... }), Fraction([75, 100], { ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"One of the fraction patterns in the `giddyRespond` animation is missing an open parenthesis `(` after the pattern keyword declaration. The parser expected a parenthesis to begin the fraction pattern definition. However, found `40` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        }),\"), line_after: Some(\"            // Este é um comentário de linha.\"), error_line: Some(\"        Fraction 40, {\"), error_on_line: 10, error_on_col: 20, error_on_pos: 299 } })".to_string()
+            "Err(NenyrError { error_code: \"N0033\", suggestion: Some(\"Ensure that all fraction patterns inside the `giddyRespond` animation block declaration are enclosed with both an opening and closing parenthesis. Correct syntax example: `Animation('giddyRespond') { Fraction([25, 50], { ... }), Fraction([75, 100], { ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"One of the fraction patterns in the `giddyRespond` animation is missing an open parenthesis `(` after the pattern keyword declaration. The parser expected a parenthesis to begin the fraction pattern definition. However, found `40` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        }),\"), line_after: Some(\"            // Este é um comentário de linha.\"), error_line: Some(\"        Fraction 40, {\"), error_on_line: 10, error_on_col: 20, error_on_pos: 299 } })".to_string()
         );
     }
 
@@ -912,7 +1332,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Err(NenyrError { suggestion: Some(\"After the open parenthesis, an opening curly bracket `{` is required to properly define the properties block in `spiritedSavings` animation. Ensure the pattern follows the correct Nenyr syntax, such as `Animation('spiritedSavings') { From({ ... }), Halfway({ ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `spiritedSavings` animation was expected to receive an object as a value, but an opening curly bracket `{` was not found after the open parenthesis. However, found `BackgroundColor` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        Progressive(\"), line_after: Some(\"        }),\"), error_line: Some(\"            backgroundColor: 'pink'\"), error_on_line: 13, error_on_col: 28, error_on_pos: 345 } })".to_string()
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"After the open parenthesis, an opening curly bracket `{` is required to properly define the properties block in `spiritedSavings` animation. Ensure the pattern follows the correct Nenyr syntax, such as `Animation('spiritedSavings') { From({ ... }), Halfway({ ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `spiritedSavings` animation was expected to receive an object as a value, but an opening curly bracket `{` was not found after the open parenthesis. However, found `BackgroundColor` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        Progressive(\"), line_after: Some(\"        }),\"), error_line: Some(\"            backgroundColor: 'pink'\"), error_on_line: 13, error_on_col: 28, error_on_pos: 345 } })".to_string()
         );
     }
 
@@ -931,7 +1351,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Err(NenyrError { suggestion: Some(\"Ensure that each property is defined with a colon after it. The correct syntax is: `pattern({ width: 'property value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `width` property inside one of the patterns in the `grotesquePtarmigan` animation is missing a colon after the property keyword definition. However, found `${myVar}` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        From({\"), line_after: Some(\"        })\"), error_line: Some(\"            width '${myVar}'\"), error_on_line: 3, error_on_col: 29, error_on_pos: 77 } })".to_string()
+            "Err(NenyrError { error_code: \"N0034\", suggestion: Some(\"Ensure that each property is defined with a colon after it. The correct syntax is: `pattern({ width: 'property value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `width` property inside one of the patterns in the `grotesquePtarmigan` animation is missing a colon after the property keyword definition. However, found `${myVar}` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        From({\"), line_after: Some(\"        })\"), error_line: Some(\"            width '${myVar}'\"), error_on_line: 3, error_on_col: 29, error_on_pos: 77 } })".to_string()
         );
     }
 
@@ -950,7 +1370,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Err(NenyrError { suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `grotesquePtarmigan` animation declaration. Please refer to the documentation to verify which patterns are permitted inside animations. Example: `Declare Animation('grotesquePtarmigan') { Fraction([25, 50], { ... }), Fraction([75, 100], { ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `grotesquePtarmigan` animation contains an invalid pattern statement. Please ensure that all methods within the animation are correctly defined and formatted. However, found `Halfway0` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Animation('grotesquePtarmigan') {\"), line_after: Some(\"            border: '1px solid red'\"), error_line: Some(\"        Halfway0({\"), error_on_line: 2, error_on_col: 17, error_on_pos: 50 } })".to_string()
+            "Err(NenyrError { error_code: \"N0006\", suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `grotesquePtarmigan` animation declaration. Please refer to the documentation to verify which patterns are permitted inside animations. Example: `Declare Animation('grotesquePtarmigan') { Fraction([25, 50], { ... }), Fraction([75, 100], { ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `grotesquePtarmigan` animation contains an invalid pattern statement. Please ensure that all methods within the animation are correctly defined and formatted. However, found `Halfway0` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Animation('grotesquePtarmigan') {\"), line_after: Some(\"            border: '1px solid red'\"), error_line: Some(\"        Halfway0({\"), error_on_line: 2, error_on_col: 17, error_on_pos: 50 } })".to_string()
         );
     }
 
@@ -973,7 +1393,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Err(NenyrError { suggestion: Some(\"Ensure that the animation name in the `Animation` declaration is properly closed with a parenthesis `)`. The correct syntax is: `Animation('animationName') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `Animation` declaration is missing a closing parenthesis `)` after the animation name. However, found `{` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: Some(\"        To({\"), error_line: Some(\"Animation('grotesquePtarmigan' {\"), error_on_line: 1, error_on_col: 33, error_on_pos: 32 } })".to_string()
+            "Err(NenyrError { error_code: \"N0032\", suggestion: Some(\"Ensure that the animation name in the `Animation` declaration is properly closed with a parenthesis `)`. The correct syntax is: `Animation('animationName') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `Animation` declaration is missing a closing parenthesis `)` after the animation name. However, found `{` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: Some(\"        To({\"), error_line: Some(\"Animation('grotesquePtarmigan' {\"), error_on_line: 1, error_on_col: 33, error_on_pos: 32 } })".to_string()
         );
     }
 
@@ -1002,7 +1422,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Err(NenyrError { suggestion: Some(\"All `Animation` declarations must have a non-empty string as a name. The name should contain only alphanumeric characters, with the first character being a letter. The correct syntax is: `Animation('animationName') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `Animation` declaration must receive a name that is a non-empty string, but no animation name was found. However, found `` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: Some(\"        From({\"), error_line: Some(\"Animation('') {\"), error_on_line: 1, error_on_col: 13, error_on_pos: 12 } })".to_string()
+            "Err(NenyrError { error_code: \"N0053\", suggestion: Some(\"All `Animation` declarations must have a non-empty string as a name. The name should contain only alphanumeric characters, with the first character being a letter. The correct syntax is: `Animation('animationName') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `Animation` declaration must receive a name that is a non-empty string, but no animation name was found. However, found `` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: Some(\"        From({\"), error_line: Some(\"Animation('') {\"), error_on_line: 1, error_on_col: 13, error_on_pos: 12 } })".to_string()
         );
     }
 
@@ -1019,7 +1439,7 @@ mod tests {
                 height: '100px',
                 width: '200px'
             }),
-            Progressive(
+            Progressive({
                 backgroundColor: 'pink'
             }),
             Fraction(30, {
@@ -1039,7 +1459,99 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_animation_method()),
-            "Err(NenyrError { suggestion: Some(\"After the open parenthesis, an opening curly bracket `{` is required to properly define the properties block in `spiritedSavings` animation. Ensure the pattern follows the correct Nenyr syntax, such as `Animation('spiritedSavings') { From({ ... }), Halfway({ ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `spiritedSavings` animation was expected to receive an object as a value, but an opening curly bracket `{` was not found after the open parenthesis. However, found `BackgroundColor` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"            Progressive(\"), line_after: Some(\"            }),\"), error_line: Some(\"                backgroundColor: 'pink'\"), error_on_line: 13, error_on_col: 32, error_on_pos: 393 } })".to_string()
+            "Err(NenyrError { error_code: \"N0007\", suggestion: Some(\"Use either `Fraction` patterns or `Progressive` patterns inside the `spiritedSavings` animation, but not both. To define the keyframe stops explicitly, replace the `Progressive` patterns with `Fraction` patterns, e.g. `Fraction(50, { ... })`; otherwise, replace the `Fraction` patterns with `Progressive` patterns, e.g. `Progressive({ ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `spiritedSavings` animation started with a `Progressive` pattern and cannot receive a `Fraction` pattern. Fraction animations use explicit stops; Progressive animations auto-distribute — they cannot be combined.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"            }),\"), line_after: Some(\"                // Este é um comentário de linha.\"), error_line: Some(\"            Fraction(30, {\"), error_on_line: 15, error_on_col: 21, error_on_pos: 439 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn duplicated_animation_names_are_not_valid() {
+        let raw_nenyr = "Construct Central {
+    Declare Animation('giddyRespond') {
+        From({ width: '10px' })
+    },
+    Declare Animation('giddyRespond') {
+        To({ width: '20px' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        assert_eq!(
+            format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string())),
+            "Err(NenyrError { error_code: \"N0004\", suggestion: Some(\"Ensure that each animation declared within the context has a unique name. Either rename one of the `giddyRespond` animations or merge their patterns into a single declaration.\"), context_name: Some(\"Central\"), context_path: \"\", error_message: \"The `giddyRespond` animation has already been declared on line 2, column 22, and cannot be declared again within the same context. However, found `)` instead.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: Some(\"    },\"), line_after: Some(\"        To({ width: '20px' })\"), error_line: Some(\"    Declare Animation('giddyRespond') {\"), error_on_line: 5, error_on_col: 38, error_on_pos: 136 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn distinct_animation_names_are_valid() {
+        let raw_nenyr = "Construct Central {
+    Declare Animation('giddyRespond') {
+        From({ width: '10px' })
+    },
+    Declare Animation('spiritedSavings') {
+        To({ width: '20px' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_ok());
+    }
+
+    #[test]
+    fn fraction_then_progressive_animation_is_not_valid() {
+        let raw_nenyr = "Animation('spiritedSavings') {
+            Fraction(30, {
+                width: '10px'
+            }),
+            Progressive({
+                width: '20px'
+            })
+        }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!("{:?}", parser.process_animation_method()),
+            "Err(NenyrError { error_code: \"N0007\", suggestion: Some(\"Use either `Fraction` patterns or `Progressive` patterns inside the `spiritedSavings` animation, but not both. To define the keyframe stops explicitly, replace the `Progressive` patterns with `Fraction` patterns, e.g. `Fraction(50, { ... })`; otherwise, replace the `Fraction` patterns with `Progressive` patterns, e.g. `Progressive({ ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `spiritedSavings` animation started with a `Fraction` pattern and cannot receive a `Progressive` pattern. Fraction animations use explicit stops; Progressive animations auto-distribute — they cannot be combined.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"            }),\"), line_after: Some(\"                width: '20px'\"), error_line: Some(\"            Progressive({\"), error_on_line: 5, error_on_col: 24, error_on_pos: 127 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn keyframes_exceeding_the_properties_limit_are_not_valid() {
+        let small_nenyr = "Animation('giddyRespond') {
+        From({
+            backgroundColor: 'blue',
+            height: '100px'
+        })
+    }";
+        let large_nenyr = "Animation('giddyRespond') {
+        From({
+            backgroundColor: 'blue',
+            height: '100px',
+            width: '200px'
+        })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.set_max_block_properties(2);
+        parser.setup_dependencies(small_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_animation_method().is_ok());
+
+        parser.setup_dependencies(large_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        let error = parser.process_animation_method().unwrap_err();
+
+        assert_eq!(error.get_error_kind(), NenyrErrorKind::ValidationError);
+        assert_eq!(
+            error.get_error_message(),
+            "The properties block of one of the patterns in the `giddyRespond` animation declares more than 2 properties, exceeding the configured limit."
         );
+        assert_eq!(error.get_line(), 5);
     }
 }
@@ -0,0 +1,278 @@
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+use crate::{
+    converters::property::NenyrPropertyConverter,
+    error::{NenyrError, NenyrErrorKind},
+    loop_while_not,
+    tokens::NenyrTokens,
+    NenyrParser, NenyrResult,
+};
+
+/// `NenyrAtRulePattern` describes a class pattern guarding a block of properties behind a
+/// prelude, mirroring a CSS at-rule, such as the `Supports` and `Container` patterns.
+///
+/// It holds the names used to build the messages of the pattern, along with the codes of
+/// the errors raised while parsing its properties block.
+pub(crate) struct NenyrAtRulePattern {
+    /// The name of the pattern, such as `Supports`.
+    pub pattern_name: &'static str,
+    /// The name given to the prelude in the messages, such as `feature query`.
+    pub prelude_name: &'static str,
+    /// The prelude used in the examples of the suggestions, such as `(display: grid)`.
+    pub prelude_example: &'static str,
+    /// The code raised when the prelude is not followed by a comma.
+    pub missing_comma_code: &'static str,
+    /// The code raised when a property is neither a Nenyr property nor an alias.
+    pub invalid_property_code: &'static str,
+    /// The code raised when a value fails the style syntax validation.
+    pub invalid_value_code: &'static str,
+}
+
+impl NenyrParser {
+    /// Processes a class pattern guarding a block of properties behind a prelude, such as
+    /// `Supports('(display: grid)', { ... })`.
+    ///
+    /// The prelude is retrieved by the given closure, since each pattern validates its own
+    /// prelude, while the parentheses, the comma and the properties block are parsed here.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the pattern is declared.
+    /// - `pattern`: The `NenyrAtRulePattern` describing the pattern being parsed.
+    /// - `retrieve_prelude`: A closure retrieving and validating the prelude of the pattern.
+    ///
+    /// # Returns
+    /// - `NenyrResult<(String, IndexMap<String, Arc<str>>)>`: The prelude, along with the
+    ///   property-value pairs declared inside the block.
+    ///
+    /// # Errors
+    /// - Returns an error if the parentheses, the comma or the curly brackets are missing.
+    /// - Returns an error if the prelude is not valid.
+    /// - Returns an error if any of the properties or values inside the block are invalid.
+    pub(crate) fn process_at_rule_pattern<F>(
+        &mut self,
+        class_name: &str,
+        pattern: &NenyrAtRulePattern,
+        mut retrieve_prelude: F,
+    ) -> NenyrResult<(String, IndexMap<String, Arc<str>>)>
+    where
+        F: FnMut(&mut Self) -> NenyrResult<String>,
+    {
+        let NenyrAtRulePattern {
+            pattern_name,
+            prelude_example,
+            ..
+        } = pattern;
+
+        self.process_next_token()?;
+
+        self.parse_parenthesized_delimiter(
+            Some(format!("Ensure that the `{}` pattern in `{}` class is followed by an open parenthesis `(` right after the `{}` keyword. Follow the correct Nenyr syntax: `Class('{}') {{ {}('{}', {{ ... }}) }}`.", pattern_name, class_name, pattern_name, class_name, pattern_name, prelude_example)),
+            &format!("The `{}` class contains a `{}` pattern declaration that was expected to have an open parenthesis `(` right after the keyword `{}`, but none was found.", class_name, pattern_name, pattern_name),
+            Some(format!("Ensure that the `{}` pattern in `{}` class has a closing parenthesis `)` after the properties block to properly complete the declaration. Follow the correct Nenyr syntax: `Class('{}') {{ {}('{}', {{ ... }}) }}`.", pattern_name, class_name, class_name, pattern_name, prelude_example)),
+            &format!("The `{}` class contains a `{}` pattern declaration that is missing a closing parenthesis `)` after the properties block.", class_name, pattern_name),
+            |parser| {
+                let prelude = retrieve_prelude(parser)?;
+                let properties = parser.process_comma_after_prelude(class_name, pattern, &prelude)?;
+
+                Ok((prelude, properties))
+            },
+        )
+    }
+
+    /// Ensures that the prelude is followed by a comma and parses the properties block.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the pattern is declared.
+    /// - `pattern`: The `NenyrAtRulePattern` describing the pattern being parsed.
+    /// - `prelude`: The validated prelude guarding the properties block.
+    ///
+    /// # Returns
+    /// - `NenyrResult<IndexMap<String, Arc<str>>>`: The property-value pairs declared inside the block.
+    ///
+    /// # Errors
+    /// - Returns an error if the comma after the prelude is missing or if the properties
+    ///   block is not properly enclosed by curly brackets.
+    fn process_comma_after_prelude(
+        &mut self,
+        class_name: &str,
+        pattern: &NenyrAtRulePattern,
+        prelude: &str,
+    ) -> NenyrResult<IndexMap<String, Arc<str>>> {
+        let NenyrAtRulePattern {
+            pattern_name,
+            prelude_name,
+            missing_comma_code,
+            ..
+        } = pattern;
+
+        if let NenyrTokens::Comma = self.current_token {
+            self.process_next_token()?;
+
+            let properties = self.parse_curly_bracketed_delimiter(
+                Some(format!("After the {}, an opening curly bracket `{{` is required to properly define the properties block of the `{}` pattern in `{}` class. Ensure the pattern follows the correct Nenyr syntax, such as `Class('{}') {{ {}('{}', {{ ... }}) }}`.", prelude_name, pattern_name, class_name, class_name, pattern_name, prelude)),
+                &format!("The `{}` pattern in the `{}` class was expected to receive an object of properties as its last argument, but an opening curly bracket `{{` was not found after the {}.", pattern_name, class_name, prelude_name),
+                Some(format!("Ensure that the properties block of the `{}` pattern in `{}` class is properly closed with a closing curly bracket `}}`. The correct syntax should look like: `Class('{}') {{ {}('{}', {{ ... }}) }}`.", pattern_name, class_name, class_name, pattern_name, prelude)),
+                &format!("The `{}` pattern in the `{}` class is missing a closing curly bracket `}}` to properly close the properties block.", pattern_name, class_name),
+                |parser| parser.process_at_rule_block(class_name, pattern, prelude),
+            )?;
+
+            self.process_next_token()?;
+
+            return Ok(properties);
+        }
+
+        Err(NenyrError::new(
+            Some(format!("Ensure that a comma is placed after the {} of the `{}` pattern in the `{}` class. The correct syntax is: `{}('{}', {{ propertyName: 'value', ... }})`.", prelude_name, pattern_name, class_name, pattern_name, prelude)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(&format!("A comma was expected after the {} of the `{}` pattern in the `{}` class, but none was found. The parser requires a comma to separate the {} from the subsequent object of properties.", prelude_name, pattern_name, class_name, prelude_name)),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code(missing_comma_code))
+    }
+
+    /// Processes the properties block of a pattern guarded by a prelude.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the pattern is declared.
+    /// - `pattern`: The `NenyrAtRulePattern` describing the pattern being parsed.
+    /// - `prelude`: The prelude guarding the properties block.
+    ///
+    /// # Returns
+    /// - `NenyrResult<IndexMap<String, Arc<str>>>`: The property-value pairs declared inside the block.
+    ///
+    /// # Errors
+    /// - Returns an error on duplicated or missing commas, or on invalid properties and values.
+    fn process_at_rule_block(
+        &mut self,
+        class_name: &str,
+        pattern: &NenyrAtRulePattern,
+        prelude: &str,
+    ) -> NenyrResult<IndexMap<String, Arc<str>>> {
+        let pattern_name = pattern.pattern_name;
+        let mut properties: IndexMap<String, Arc<str>> = IndexMap::new();
+
+        loop_while_not!(
+            self,
+            Some(format!("Remove any duplicated commas from the properties block of the `{}` pattern in the `{}` class. Ensure proper syntax by following valid delimiters. Example: `{}('{}', {{ display: 'flex', gap: '1rem', ... }})`.", pattern_name, class_name, pattern_name, prelude)),
+            &format!("A duplicated comma was found in the properties block of the `{}` pattern in the `{}` class. The parser expected to find a new property statement but none was found.", pattern_name, class_name),
+            Some(format!("Ensure that a comma is placed after each property definition inside the `{}` pattern in the `{}` class to separate elements correctly. Example: `{}('{}', {{ display: 'flex', gap: '1rem', ... }})`.", pattern_name, class_name, pattern_name, prelude)),
+            &format!("All properties in the `{}` pattern inside the `{}` class block must be separated by commas. A comma is missing in the properties block of the `{}` pattern. The parser expected a comma to separate elements but did not find one.", pattern_name, class_name, pattern_name),
+            || self.processing_state.is_nested_block_active(),
+            |is_active| self.processing_state.set_nested_block_active(is_active),
+            {
+                self.process_at_rule_property(class_name, pattern, &mut properties)?;
+                self.check_block_properties_limit(
+                    properties.len(),
+                    &format!("the `{}` pattern in the `{}` class", pattern_name, class_name),
+                )?;
+            }
+        );
+
+        self.processing_state.set_nested_block_active(false);
+
+        Ok(properties)
+    }
+
+    /// Retrieves a property declared inside a pattern guarded by a prelude, accepting either
+    /// a valid Nenyr property or an alias.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the pattern is declared.
+    /// - `pattern`: The `NenyrAtRulePattern` describing the pattern being parsed.
+    /// - `properties`: The map receiving the parsed property-value pairs.
+    ///
+    /// # Errors
+    /// - Returns an error if the current token is neither a property nor an alias.
+    fn process_at_rule_property(
+        &mut self,
+        class_name: &str,
+        pattern: &NenyrAtRulePattern,
+        properties: &mut IndexMap<String, Arc<str>>,
+    ) -> NenyrResult<()> {
+        let pattern_name = pattern.pattern_name;
+
+        self.processing_state.set_nested_block_active(true);
+
+        if let Some(property) = self.convert_nenyr_property_to_css_property(&self.current_token) {
+            return self.process_at_rule_value(class_name, pattern, property, properties);
+        } else if let NenyrTokens::Identifier(nickname) = self.current_token.clone() {
+            let location = format!(
+                "the `{}` pattern in the `{}` class",
+                pattern_name, class_name
+            );
+            let property = self.resolve_alias_property(&nickname, &location)?;
+
+            return self.process_at_rule_value(class_name, pattern, property, properties);
+        }
+
+        Err(NenyrError::new(
+            Some(format!("Ensure that all properties inside the `{}` pattern in the `{}` class are either an alias or a valid property. Please verify the documentation to know which properties are valid inside the class patterns.", pattern_name, class_name)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(&format!("One of the properties inside the `{}` pattern in the `{}` class is not either an alias or a valid property.", pattern_name, class_name)),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code(pattern.invalid_property_code))
+    }
+
+    /// Retrieves and validates the value assigned to a property inside a pattern guarded by
+    /// a prelude.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the pattern is declared.
+    /// - `pattern`: The `NenyrAtRulePattern` describing the pattern being parsed.
+    /// - `property`: The CSS property (or alias) receiving the value.
+    /// - `properties`: The map receiving the parsed property-value pairs.
+    ///
+    /// # Errors
+    /// - Returns an error if the colon is missing, if the value is not a non-empty string,
+    ///   or if the value fails the style syntax validation.
+    fn process_at_rule_value(
+        &mut self,
+        class_name: &str,
+        pattern: &NenyrAtRulePattern,
+        property: String,
+        properties: &mut IndexMap<String, Arc<str>>,
+    ) -> NenyrResult<()> {
+        let NenyrAtRulePattern {
+            pattern_name,
+            prelude_example,
+            ..
+        } = pattern;
+
+        self.process_next_token()?;
+        self.parse_colon_delimiter(
+            Some(format!("Ensure that each property is defined with a colon after it. The correct syntax is: `{}('{}', {{ {}: 'property value', ... }})`.", pattern_name, prelude_example, &property)),
+            &format!("The `{}` property inside the `{}` pattern in the `{}` class is missing a colon after the property keyword definition.", &property, pattern_name, class_name),
+            true
+        )?;
+
+        let value = self.parse_string_literal(
+            Some(format!("Ensure that all properties are assigned non-empty string values. You can either remove the property or specify a non-empty string value for it: `{}('{}', {{ {}: 'property value', ... }})`.", pattern_name, prelude_example, &property)),
+            &format!("The `{}` property inside the `{}` pattern in the `{}` class should receive a non-empty string as a value, but none was found.", &property, pattern_name, class_name),
+            false
+        )?;
+
+        let location = format!(
+            "the `{}` pattern in the `{}` class",
+            pattern_name, class_name
+        );
+
+        if let Some(value) = self.process_property_value(&property, value, &location)? {
+            properties.insert(property, value);
+
+            return Ok(());
+        }
+
+        Err(NenyrError::new(
+            Some("Ensure that all values are semantically correct to be validated. Please refer to the documentation to verify the correct way to define values.".to_string()),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(&format!("The `{}` property inside the `{}` pattern in the `{}` class contains an invalid value, and it could not be validated.", &property, pattern_name, class_name)),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code(pattern.invalid_value_code))
+    }
+}
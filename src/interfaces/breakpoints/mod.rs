@@ -59,6 +59,7 @@ impl NenyrParser {
     /// Returns an error if:
     /// - Duplicated commas are found in the patterns block.
     /// - A pattern is missing a required comma for separation.
+    /// - Neither the `MobileFirst` nor the `DesktopFirst` schema was declared.
     fn process_breakpoints_children(&mut self) -> NenyrResult<NenyrBreakpoints> {
         let mut breakpoints = NenyrBreakpoints::new();
 
@@ -77,9 +78,112 @@ impl NenyrParser {
 
         self.processing_state.set_block_active(false);
 
+        if breakpoints.mobile_first.is_none() && breakpoints.desktop_first.is_none() {
+            return Err(NenyrError::new(
+                Some("Declare at least one schema inside the `Breakpoints` declaration, or remove the declaration entirely. Example: `Declare Breakpoints({ MobileFirst({ onMobTablet: '768px' }) })`.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                "The `Breakpoints` declaration does not define any schema. At least one of the `MobileFirst` or `DesktopFirst` schemas must be declared.".to_string(),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0014"));
+        }
+
+        if self.options.warn_on_shared_breakpoint_values {
+            self.check_shared_breakpoint_values(&breakpoints)?;
+        }
+
+        if self.options.warn_on_unordered_breakpoints {
+            self.check_breakpoints_ordering(&breakpoints)?;
+        }
+
         Ok(breakpoints)
     }
 
+    /// Raises a warning for each value shared by a `MobileFirst` and a `DesktopFirst` breakpoint.
+    ///
+    /// Using the same threshold in both schemas is valid, but it usually comes from a copy-paste
+    /// mistake, so the author is asked to confirm it. This check only runs when the
+    /// `warn_on_shared_breakpoint_values` option is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `breakpoints`: The breakpoints collected from the `Breakpoints` declaration.
+    fn check_shared_breakpoint_values(
+        &mut self,
+        breakpoints: &NenyrBreakpoints,
+    ) -> NenyrResult<()> {
+        if let (Some(mobile_first), Some(desktop_first)) =
+            (&breakpoints.mobile_first, &breakpoints.desktop_first)
+        {
+            for (mobile_name, mobile_value) in mobile_first {
+                for (desktop_name, desktop_value) in desktop_first {
+                    let shared_value = mobile_value.trim();
+
+                    if shared_value == desktop_value.trim() {
+                        self.add_warning(
+                            "W0003",
+                            Some(format!("Confirm that sharing the `{}` value between the `MobileFirst` and `DesktopFirst` schemas is intentional, or adjust one of the breakpoints to its expected threshold.", shared_value)),
+                            format!("The `{}` value is used by both the `{}` breakpoint in `MobileFirst` and the `{}` breakpoint in `DesktopFirst`.", shared_value, mobile_name, desktop_name),
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Raises a warning for each breakpoint breaking the expected ordering of its schema.
+    ///
+    /// The `MobileFirst` thresholds are expected to increase and the `DesktopFirst` ones to
+    /// decrease, so each pixel value is compared against the preceding pixel value of the
+    /// same schema. Values expressed in other units are skipped. This check only runs when
+    /// the `warn_on_unordered_breakpoints` option is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `breakpoints`: The breakpoints collected from the `Breakpoints` declaration.
+    fn check_breakpoints_ordering(&mut self, breakpoints: &NenyrBreakpoints) -> NenyrResult<()> {
+        let schemas = [
+            ("MobileFirst", &breakpoints.mobile_first, true),
+            ("DesktopFirst", &breakpoints.desktop_first, false),
+        ];
+
+        for (schema_name, schema, is_ascending) in schemas {
+            let mut preceding: Option<(&str, &str, f64)> = None;
+
+            for (name, value) in schema.iter().flatten() {
+                let Some(magnitude) = parse_pixel_magnitude(value) else {
+                    continue;
+                };
+
+                if let Some((preceding_name, preceding_value, preceding_magnitude)) = preceding {
+                    let is_unordered = match is_ascending {
+                        true => magnitude < preceding_magnitude,
+                        false => magnitude > preceding_magnitude,
+                    };
+
+                    if is_unordered {
+                        let comparison = if is_ascending { "smaller" } else { "larger" };
+
+                        self.add_warning(
+                            "W0004",
+                            Some(format!("Reorder the breakpoints of the `{}` schema so that their thresholds {}, or fix the value of the `{}` breakpoint.", schema_name, if is_ascending { "increase" } else { "decrease" }, name)),
+                            format!("The `{}` breakpoint in `{}` is set to `{}`, which is {} than the `{}` of the preceding `{}` breakpoint.", name, schema_name, value.trim(), comparison, preceding_value.trim(), preceding_name),
+                        )?;
+
+                        continue;
+                    }
+                }
+
+                preceding = Some((name, value, magnitude));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Processes an individual pattern within the `Breakpoints` declaration.
     ///
     /// This method checks the current token to determine if it matches valid breakpoint patterns (e.g.,
@@ -113,7 +217,7 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error("The `Breakpoints` declaration contains an invalid pattern statement. Please ensure that all methods within `Breakpoints` are correctly defined and formatted."),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0015"));
             }
         }
     }
@@ -234,7 +338,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error("The `Breakpoints` declaration contains an invalid identifier for the breakpoint name. Please ensure the identifier follows the required format."),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0016"))
     }
 
     /// Parses and validates the value associated with a specific breakpoint.
@@ -284,10 +388,15 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&format!("The `{}` breakpoint in the `Breakpoint` declaration contains an invalid value and could not be validated.", identifier)),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0017"))
     }
 }
 
+/// Parses the magnitude of a breakpoint value expressed in pixels, such as `780px`.
+fn parse_pixel_magnitude(value: &str) -> Option<f64> {
+    value.trim().strip_suffix("px")?.trim().parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::NenyrParser;
@@ -342,12 +451,12 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_breakpoints_method()),
-            "Err(NenyrError { suggestion: Some(\"Ensure that all patterns inside the `Breakpoints` block declaration are enclosed with both an opening and a closing parenthesis. Correct syntax example: `Declare Breakpoints({ MobileFirst({ ... }), DesktopFirst({ ... }) })`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `Breakpoints` declaration is missing an open parenthesis `(` after the pattern keyword. The parser expected a parenthesis to begin the pattern definition. However, found `{` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        }),\"), line_after: Some(\"            onDeskTablet: '780px',\"), error_line: Some(\"        DesktopFirst{\"), error_on_line: 8, error_on_col: 22, error_on_pos: 201 } })".to_string()
+            "Err(NenyrError { error_code: \"N0033\", suggestion: Some(\"Ensure that all patterns inside the `Breakpoints` block declaration are enclosed with both an opening and a closing parenthesis. Correct syntax example: `Declare Breakpoints({ MobileFirst({ ... }), DesktopFirst({ ... }) })`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `Breakpoints` declaration is missing an open parenthesis `(` after the pattern keyword. The parser expected a parenthesis to begin the pattern definition. However, found `{` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        }),\"), line_after: Some(\"            onDeskTablet: '780px',\"), error_line: Some(\"        DesktopFirst{\"), error_on_line: 8, error_on_col: 22, error_on_pos: 201 } })".to_string()
         );
     }
 
     #[test]
-    fn empty_themes_are_valid() {
+    fn empty_themes_are_not_valid() {
         let raw_nenyr = "Breakpoints({ })
     })";
         let mut parser = NenyrParser::new();
@@ -357,7 +466,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_breakpoints_method()),
-            "Ok(NenyrBreakpoints { mobile_first: None, desktop_first: None })".to_string()
+            "Err(NenyrError { error_code: \"N0014\", suggestion: Some(\"Declare at least one schema inside the `Breakpoints` declaration, or remove the declaration entirely. Example: `Declare Breakpoints({ MobileFirst({ onMobTablet: '768px' }) })`.\"), context_name: None, context_path: \"\", error_message: \"The `Breakpoints` declaration does not define any schema. At least one of the `MobileFirst` or `DesktopFirst` schemas must be declared.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: None, line_after: Some(\"    })\"), error_line: Some(\"Breakpoints({ })\"), error_on_line: 1, error_on_col: 16, error_on_pos: 15 } })".to_string()
         );
     }
 
@@ -419,4 +528,124 @@ mod tests {
             "Ok(NenyrBreakpoints { mobile_first: Some({}), desktop_first: Some({}) })".to_string()
         );
     }
+
+    #[test]
+    fn shared_breakpoint_values_must_raise_warning() {
+        let raw_nenyr = "Breakpoints({
+        MobileFirst({
+            onMobTablet: '780px',
+            onMobDesktop: '1240px'
+        }),
+        DesktopFirst({
+            onDeskTablet: '780px',
+            onDeskDesktop: '1440px'
+        })
+    })";
+        let mut parser = NenyrParser::new();
+
+        parser.set_shared_breakpoint_values_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert!(parser.process_breakpoints_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].get_message(),
+            "The `780px` value is used by both the `onMobTablet` breakpoint in `MobileFirst` and the `onDeskTablet` breakpoint in `DesktopFirst`.".to_string()
+        );
+    }
+
+    #[test]
+    fn distinct_breakpoint_values_must_not_raise_warning() {
+        let raw_nenyr = "Breakpoints({
+        MobileFirst({
+            onMobTablet: '780px',
+            onMobDesktop: '1240px'
+        }),
+        DesktopFirst({
+            onDeskTablet: '1024px',
+            onDeskDesktop: '1440px'
+        })
+    })";
+        let mut parser = NenyrParser::new();
+
+        parser.set_shared_breakpoint_values_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert!(parser.process_breakpoints_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn shared_breakpoint_values_warning_must_be_opt_in() {
+        let raw_nenyr = "Breakpoints({
+        MobileFirst({ onMobTablet: '780px' }),
+        DesktopFirst({ onDeskTablet: '780px' })
+    })";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert!(parser.process_breakpoints_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn unordered_breakpoint_values_must_raise_warning() {
+        let raw_nenyr = "Breakpoints({
+        MobileFirst({
+            onMobTablet: '780px',
+            onMobDesktop: '1240px',
+            onMobXl: '1000px',
+            onMobXxl: '1440px'
+        }),
+        DesktopFirst({
+            onDeskDesktop: '1440px',
+            onDeskTablet: '780px'
+        })
+    })";
+        let mut parser = NenyrParser::new();
+
+        parser.set_unordered_breakpoints_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert!(parser.process_breakpoints_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].get_message(),
+            "The `onMobXl` breakpoint in `MobileFirst` is set to `1000px`, which is smaller than the `1240px` of the preceding `onMobDesktop` breakpoint.".to_string()
+        );
+    }
+
+    #[test]
+    fn ordered_breakpoint_values_must_not_raise_warning() {
+        let raw_nenyr = "Breakpoints({
+        MobileFirst({
+            onMobTablet: '780px',
+            onMobDesktop: '1240px',
+            onMobXl: '1440px'
+        }),
+        DesktopFirst({
+            onDeskDesktop: '1440px',
+            onDeskTablet: '780px'
+        })
+    })";
+        let mut parser = NenyrParser::new();
+
+        parser.set_unordered_breakpoints_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert!(parser.process_breakpoints_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+    }
 }
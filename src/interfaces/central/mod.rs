@@ -2,7 +2,7 @@ use crate::{
     error::{NenyrError, NenyrErrorKind},
     loop_while_not,
     tokens::NenyrTokens,
-    types::central::CentralContext,
+    types::{central::CentralContext, declaration::NenyrDeclaration},
     NenyrParser, NenyrResult,
 };
 
@@ -61,6 +61,10 @@ impl NenyrParser {
     fn retrieve_central_context_block(&mut self) -> NenyrResult<CentralContext> {
         let mut central_context = CentralContext::new();
 
+        // Refills the maps of the AST given to `parse_into`, if any, instead of allocating new ones.
+        (central_context.animations, central_context.classes) =
+            std::mem::take(&mut self.reusable_maps);
+
         loop_while_not!(
             self,
             Some("Remove any duplicated commas from the Nenyr central context to ensure proper syntax. The parser expects the `Declare` keyword to follow valid delimiters.".to_string()),
@@ -128,46 +132,34 @@ impl NenyrParser {
     fn process_central_methods(&mut self, central_context: &mut CentralContext) -> NenyrResult<()> {
         self.processing_state.set_context_active(true);
 
-        match self.current_token {
-            NenyrTokens::Imports => {
-                let imports = self.process_imports_method()?;
-
-                central_context.add_imports_to_context(imports);
-            }
-            NenyrTokens::Typefaces => {
-                let typefaces = self.process_typefaces_method()?;
-
-                central_context.add_typefaces_to_context(typefaces);
-            }
+        let declaration = match self.current_token {
+            NenyrTokens::Imports => NenyrDeclaration::Imports(self.process_imports_method()?),
+            NenyrTokens::Typefaces => NenyrDeclaration::Typefaces(self.process_typefaces_method()?),
             NenyrTokens::Breakpoints => {
-                let breakpoints = self.process_breakpoints_method()?;
-
-                central_context.add_breakpoints_to_context(breakpoints);
-            }
-            NenyrTokens::Aliases => {
-                let aliases = self.process_aliases_method()?;
-
-                central_context.add_aliases_to_context(aliases);
+                NenyrDeclaration::Breakpoints(self.process_breakpoints_method()?)
             }
+            NenyrTokens::Aliases => NenyrDeclaration::Aliases(self.process_aliases_method(false)?),
             NenyrTokens::Variables => {
-                let variables = self.process_variables_method(false)?;
-
-                central_context.add_variables_to_context(variables);
+                NenyrDeclaration::Variables(self.process_variables_method(false)?)
             }
+            NenyrTokens::Const => NenyrDeclaration::Consts(self.process_consts_method()?),
             NenyrTokens::Themes => {
-                let themes = self.process_themes_method()?;
+                NenyrDeclaration::Themes(Box::new(self.process_themes_method()?))
+            }
+            NenyrTokens::Mixin => {
+                let (mixin_name, properties) = self.process_mixin_method()?;
 
-                central_context.add_themes_to_context(themes);
+                NenyrDeclaration::Mixin(mixin_name, properties)
             }
-            NenyrTokens::Animation => {
+            NenyrTokens::Animation | NenyrTokens::Keyframes => {
                 let (animation_name, animation) = self.process_animation_method()?;
 
-                central_context.add_animation_to_context(animation_name, animation);
+                NenyrDeclaration::Animation(animation_name, animation)
             }
             NenyrTokens::Class => {
                 let (class_name, style_class) = self.process_class_method()?;
 
-                central_context.add_style_class_to_context(class_name, style_class);
+                NenyrDeclaration::Class(class_name, Box::new(style_class))
             }
             _ => {
                 return Err(NenyrError::new(
@@ -177,8 +169,18 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error("One of the methods in the central context is either not a valid Nenyr method or is not supported within the central context."),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0018"));
             }
+        };
+
+        self.stats.count_declaration();
+        self.collect_declaration_comments(&declaration);
+
+        if let Some(declaration) = self
+            .declaration_sink
+            .consume(declaration, self.options.keep_sunk_declarations)
+        {
+            central_context.add_declaration_to_context(declaration);
         }
 
         Ok(())
@@ -187,7 +189,7 @@ impl NenyrParser {
 
 #[cfg(test)]
 mod tests {
-    use crate::NenyrParser;
+    use crate::{types::central::CentralContext, NenyrParser};
 
     #[test]
     fn central_context_is_valid() {
@@ -247,7 +249,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, themes: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) })".to_string()
         );
     }
 
@@ -310,10 +312,26 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Err(NenyrError { suggestion: Some(\"After the open parenthesis, an opening curly bracket `{` is required to properly define the panoramic block in `myTestingClass` class. Ensure the panoramic pattern follows the correct Nenyr syntax, such as `Class('myTestingClass') { PanoramicViewer({ ... }) }`.\"), context_name: Some(\"Central\"), context_path: \"\", error_message: \"The panoramic pattern in the `myTestingClass` class was expected to receive an object as a value, but an opening curly bracket `{` was not found after the open parenthesis. However, found `myBreakpoint` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        PanoramicViewer(\"), line_after: Some(\"                Stylesheet({\"), error_line: Some(\"            myBreakpoint({\"), error_on_line: 39, error_on_col: 25, error_on_pos: 1166 } })".to_string()
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"After the open parenthesis, an opening curly bracket `{` is required to properly define the panoramic block in `myTestingClass` class. Ensure the panoramic pattern follows the correct Nenyr syntax, such as `Class('myTestingClass') { PanoramicViewer({ ... }) }`.\"), context_name: Some(\"Central\"), context_path: \"\", error_message: \"The panoramic pattern in the `myTestingClass` class was expected to receive an object as a value, but an opening curly bracket `{` was not found after the open parenthesis. However, found `myBreakpoint` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        PanoramicViewer(\"), line_after: Some(\"                Stylesheet({\"), error_line: Some(\"            myBreakpoint({\"), error_on_line: 39, error_on_col: 25, error_on_pos: 1166 } })".to_string()
         );
     }
 
+    #[test]
+    fn empty_central_is_valid() {
+        for raw_nenyr in ["Central {}", "Central {\n    // No declarations yet.\n}"] {
+            let mut parser = NenyrParser::new();
+
+            parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+            let _ = parser.process_next_token();
+
+            assert_eq!(
+                format!("{:?}", parser.process_central_context()),
+                "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
+            );
+        }
+    }
+
     #[test]
     fn only_imports_central_is_valid() {
         let raw_nenyr = "Central {
@@ -337,7 +355,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: Some(NenyrImports { values: {\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": (), \"https://fonts.googleapis.com/css2?family=Roboto:ital,wght@0,100;0,300;0,400;0,500;0,700;0,900;1,100;1,300;1,400;1,500;1,700;1,900&display=swap\": (), \"https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap\": (), \"../../../mocks/imports/another_external.css\": (), \"../../../mocks/imports/external_styles.css\": (), \"../../../mocks/imports/styles.css\": ()} }), typefaces: None, breakpoints: None, aliases: None, variables: None, themes: None, animations: None, classes: None })".to_string()
+            "Ok(CentralContext { imports: Some(NenyrImports { values: {\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": None, \"https://fonts.googleapis.com/css2?family=Roboto:ital,wght@0,100;0,300;0,400;0,500;0,700;0,900;1,100;1,300;1,400;1,500;1,700;1,900&display=swap\": None, \"https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap\": None, \"../../../mocks/imports/another_external.css\": None, \"../../../mocks/imports/external_styles.css\": None, \"../../../mocks/imports/styles.css\": None} }), typefaces: None, breakpoints: None, aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -363,7 +381,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: Some(NenyrTypefaces { values: {\"roseMartin\": \"../../../mocks/typefaces/rosemartin.regular.otf\", \"regularEot\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.eot\", \"regularSvg\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.svg\", \"regularTtf\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.ttf\", \"regularWoff\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.woff\", \"regularWoff2\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.woff2\"} }), breakpoints: None, aliases: None, variables: None, themes: None, animations: None, classes: None })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: Some(NenyrTypefaces { values: {\"roseMartin\": \"../../../mocks/typefaces/rosemartin.regular.otf\", \"regularEot\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.eot\", \"regularSvg\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.svg\", \"regularTtf\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.ttf\", \"regularWoff\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.woff\", \"regularWoff2\": \"../../../mocks/typefaces/showa-source-curry.regular-webfont.woff2\"} }), breakpoints: None, aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -392,10 +410,50 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: Some(NenyrBreakpoints { mobile_first: Some({\"onMobTablet\": \"780px\", \"onMobDesktop\": \"1240px\", \"onMobXl\": \"1440px\", \"onMobXXl\": \"2240px\"}), desktop_first: Some({\"onDeskTablet\": \"780px\", \"onDeskDesktop\": \"1240px\", \"onDeskXl\": \"1440px\", \"onDeskXXl\": \"2240px\"}) }), aliases: None, variables: None, themes: None, animations: None, classes: None })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: Some(NenyrBreakpoints { mobile_first: Some({\"onMobTablet\": \"780px\", \"onMobDesktop\": \"1240px\", \"onMobXl\": \"1440px\", \"onMobXXl\": \"2240px\"}), desktop_first: Some({\"onDeskTablet\": \"780px\", \"onDeskDesktop\": \"1240px\", \"onDeskXl\": \"1440px\", \"onDeskXXl\": \"2240px\"}) }), aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
+    #[test]
+    fn breakpoint_names_are_listed_in_declaration_order() {
+        let raw_nenyr = "Central {
+        Declare Breakpoints({
+        MobileFirst({
+            onMobTablet: '780px',
+            onMobDesktop: '1240px',
+            onMobXl: '1440px',
+            onMobXXl: '2240px'
+        }),
+        DesktopFirst({
+            onDeskTablet: '780px',
+            onDeskDesktop: '1240px',
+            onDeskXl: '1440px',
+            onDeskXXl: '2240px'
+        })
+    })}";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        let central_context = parser.process_central_context().unwrap();
+
+        assert_eq!(
+            central_context.breakpoint_names(),
+            vec![
+                "onMobTablet",
+                "onMobDesktop",
+                "onMobXl",
+                "onMobXXl",
+                "onDeskTablet",
+                "onDeskDesktop",
+                "onDeskXl",
+                "onDeskXXl"
+            ]
+        );
+        assert!(CentralContext::new().breakpoint_names().is_empty());
+    }
+
     #[test]
     fn only_themes_central_is_valid() {
         let raw_nenyr = "Central {
@@ -423,7 +481,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#CCCCCC\", \"accentColorVar\": \"#FF5733\"} }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#333333\", \"secondaryColor\": \"#666666\", \"accentColorVar\": \"#FF5733\"} }) }), animations: None, classes: None })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, consts: None, themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#CCCCCC\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\"), \"secondaryColor\": Text(\"#666666\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None }), mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -446,7 +504,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: None, themes: None, animations: None, classes: None })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: None, consts: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -470,7 +528,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), themes: None, animations: None, classes: None })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), consts: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -547,7 +605,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, themes: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: None })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: None })".to_string()
         );
     }
 
@@ -609,7 +667,104 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_central_context()),
-            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, themes: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) })".to_string()
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) })".to_string()
+        );
+    }
+
+    #[test]
+    fn consts_central_is_valid() {
+        let raw_nenyr = "Central {
+    Declare Const({
+        siteWidth: '1200px',
+        brandColor: '#FF6677'
+    }),
+    Declare Variables({
+        primaryColor: 'red'
+    })
+}";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!("{:?}", parser.process_central_context()),
+            "Ok(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: Some(NenyrVariables { values: {\"primaryColor\": Text(\"red\")}, dimensions: None, expressions: None }), consts: Some(NenyrConsts { values: {\"siteWidth\": \"1200px\", \"brandColor\": \"#FF6677\"} }), themes: None, mixins: None, animations: None, classes: None })".to_string()
+        );
+    }
+
+    #[test]
+    fn consts_colliding_with_variables_are_not_valid() {
+        let declarations = [
+            "Declare Variables({ siteWidth: '960px' }), Declare Const({ siteWidth: '1200px' })",
+            "Declare Const({ siteWidth: '1200px' }), Declare Variables({ siteWidth: '960px' })",
+            "Declare Const({ siteWidth: '1200px' }), Declare Themes({ Dark({ Variables({ siteWidth: '960px' }) }) })",
+        ];
+        let error_messages = [
+            "The `siteWidth` constant collides with the `siteWidth` variable declared in the `Variables` declaration. Constants and variables cannot share the same name.",
+            "The `siteWidth` variable collides with the `siteWidth` constant declared in the `Const` declaration. Constants and variables cannot share the same name.",
+            "In the `Themes` block, the `siteWidth` variable collides with the `siteWidth` constant declared in the `Const` declaration. Constants and variables cannot share the same name.",
+        ];
+
+        for (declaration, error_message) in declarations.iter().zip(error_messages) {
+            let mut parser = NenyrParser::new();
+            parser.setup_dependencies(format!("Central {{ {} }}", declaration), "".to_string());
+
+            let _ = parser.process_next_token();
+            let error = parser.process_central_context().unwrap_err();
+
+            assert_eq!(error.error_message, error_message);
+        }
+    }
+
+    #[test]
+    fn theme_aliases_colliding_with_context_aliases_are_not_valid() {
+        let declarations = [
+            "Declare Aliases({ bgd: color }), Declare Themes({ Light({ Aliases({ bgd: backgroundColor }) }) })",
+            "Declare Themes({ Dark({ Aliases({ bgd: backgroundColor }) }) }), Declare Aliases({ bgd: color })",
+        ];
+        let error_messages = [
+            "In the `Themes` block, the `bgd` alias refers to the `background-color` property, but the `Aliases` declaration of the context already maps it to the `color` property.",
+            "The `bgd` alias refers to the `color` property, but the `Themes` block already maps it to the `background-color` property.",
+        ];
+
+        for (declaration, error_message) in declarations.iter().zip(error_messages) {
+            let mut parser = NenyrParser::new();
+            parser.setup_dependencies(format!("Central {{ {} }}", declaration), "".to_string());
+
+            let _ = parser.process_next_token();
+            let error = parser.process_central_context().unwrap_err();
+
+            assert_eq!(error.get_error_code(), "N0105");
+            assert_eq!(error.error_message, error_message);
+        }
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(
+            "Central { Declare Aliases({ bgd: color }), Declare Themes({ Light({ Aliases({ bgd: color }) }) }) }".to_string(),
+            "".to_string(),
+        );
+
+        let _ = parser.process_next_token();
+
+        assert!(parser.process_central_context().is_ok());
+    }
+
+    #[test]
+    fn redeclared_consts_are_not_valid() {
+        let raw_nenyr = "Central {
+    Declare Const({ siteWidth: '1200px' }),
+    Declare Const({ siteWidth: '960px' })
+}";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        let error = parser.process_central_context().unwrap_err();
+
+        assert_eq!(
+            error.error_message,
+            "The `siteWidth` constant has already been declared and cannot be redeclared, as constants are immutable."
         );
     }
 }
@@ -1,12 +1,123 @@
+use indexmap::IndexMap;
+use std::sync::Arc;
+
 use crate::{
-    error::{NenyrError, NenyrErrorKind},
+    error::{NenyrError, NenyrErrorKind, NenyrErrorTracing},
     loop_while_not,
     tokens::NenyrTokens,
     types::class::NenyrStyleClass,
-    validators::identifier::NenyrIdentifierValidator,
+    validators::{identifier::NenyrIdentifierValidator, style_syntax::is_css_wide_keyword},
     NenyrParser, NenyrResult,
 };
 
+/// The shorthand properties checked for conflicts, along with the longhands they reset.
+const SHORTHAND_LONGHANDS: [(&str, &[&str]); 12] = [
+    (
+        "background",
+        &[
+            "background-color",
+            "background-image",
+            "background-position",
+            "background-size",
+            "background-repeat",
+            "background-attachment",
+            "background-origin",
+            "background-clip",
+        ],
+    ),
+    (
+        "border",
+        &[
+            "border-width",
+            "border-style",
+            "border-color",
+            "border-top",
+            "border-right",
+            "border-bottom",
+            "border-left",
+        ],
+    ),
+    (
+        "border-radius",
+        &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+    ),
+    (
+        "margin",
+        &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+    ),
+    (
+        "padding",
+        &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+    ),
+    (
+        "font",
+        &[
+            "font-style",
+            "font-variant",
+            "font-weight",
+            "font-stretch",
+            "font-size",
+            "line-height",
+            "font-family",
+        ],
+    ),
+    ("flex", &["flex-grow", "flex-shrink", "flex-basis"]),
+    (
+        "outline",
+        &["outline-color", "outline-style", "outline-width"],
+    ),
+    ("overflow", &["overflow-x", "overflow-y"]),
+    ("gap", &["row-gap", "column-gap"]),
+    (
+        "transition",
+        &[
+            "transition-property",
+            "transition-duration",
+            "transition-timing-function",
+            "transition-delay",
+        ],
+    ),
+    (
+        "animation",
+        &[
+            "animation-name",
+            "animation-duration",
+            "animation-timing-function",
+            "animation-delay",
+            "animation-iteration-count",
+            "animation-direction",
+            "animation-fill-mode",
+            "animation-play-state",
+        ],
+    ),
+];
+
+/// The single-word keywords of the `animation` shorthand that may precede the animation name.
+const ANIMATION_KEYWORDS: [&str; 12] = [
+    "none",
+    "linear",
+    "ease",
+    "infinite",
+    "normal",
+    "reverse",
+    "alternate",
+    "forwards",
+    "backwards",
+    "both",
+    "running",
+    "paused",
+];
+
 impl NenyrParser {
     /// Parses a `Class` declaration in the Nenyr syntax.
     ///
@@ -28,6 +139,7 @@ impl NenyrParser {
     /// - Missing or misplaced parentheses around the class name or deriving name.
     /// - Invalid or empty class or deriving name.
     /// - Missing curly brackets for the class block.
+    /// - A class with the same name already declared within the current context.
     ///
     /// # Returns
     ///
@@ -35,6 +147,8 @@ impl NenyrParser {
     /// `NenyrStyleClass` object, which encapsulates the styles and derived properties
     /// for the class.
     pub(crate) fn process_class_method(&mut self) -> NenyrResult<(String, NenyrStyleClass)> {
+        let declaration_tracing = self.get_tracing();
+
         self.process_next_token()?;
 
         let class_name = self.retrieve_class_or_deriving_name(
@@ -48,17 +162,59 @@ impl NenyrParser {
             "The validation of the class name failed. The provided name does not meet the required format.",
         )?;
 
+        self.validate_class_name_uniqueness(&class_name, declaration_tracing)?;
         self.process_next_token()?;
 
         let deriving_from = self.retrieve_deriving_from(&class_name)?;
 
-        self.parse_curly_bracketed_delimiter(
+        let declaration = self.parse_curly_bracketed_delimiter(
             Some(format!("Ensure that the `{}` class or deriving name declaration is followed by an opening curly bracket `{{` to properly define the class block. The correct syntax is: `Declare Class('{}') {{ ... }}` or `Declare Class('{}') Deriving('layoutName') {{ ... }}`.", &class_name, &class_name, &class_name)),
             &format!("An opening curly bracket `{{` was expected after the `{}` class or deriving name declaration to start the class block, but it was not found.", &class_name),
             Some(format!("Ensure that each class definition block is properly closed with a corresponding closing curly bracket `}}`. Example: `Declare Class('{}') {{ ... }}` or `Declare Class('{}') Deriving('layoutName') {{ ... }}`.", &class_name, &class_name)),
             &format!("A closing curly bracket `}}` was expected to terminate the `{}` class definition block, but it was not found.", &class_name),
             |parser| parser.retrieve_class_block(&class_name, &deriving_from),
-        )
+        )?;
+
+        self.processing_state
+            .register_class_span(&class_name, self.lexer.token_span().1);
+
+        Ok(declaration)
+    }
+
+    /// Ensures that the class name has not been declared before within the current context.
+    ///
+    /// Since classes are stored by name, a second declaration with the same name would
+    /// silently overwrite the first one. This method registers the declaration and raises
+    /// an error pointing to the second declaration, while the error message references the
+    /// line and column of the original one.
+    ///
+    /// # Parameters
+    /// - `class_name`: The name of the class being declared.
+    /// - `declaration_tracing`: The position of the current class declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` of kind `ValidationError` if the class name was already declared.
+    fn validate_class_name_uniqueness(
+        &mut self,
+        class_name: &str,
+        declaration_tracing: NenyrErrorTracing,
+    ) -> NenyrResult<()> {
+        if let Some(previous_tracing) = self
+            .processing_state
+            .register_class_declaration(class_name, declaration_tracing)
+        {
+            return Err(NenyrError::new(
+                Some(format!("Ensure that each class declared within the context has a unique name. Either rename one of the `{}` classes or merge their patterns into a single declaration.", class_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("The `{}` class has already been declared on line {}, column {}, and cannot be declared again within the same context.", class_name, previous_tracing.get_line(), previous_tracing.get_column())),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0019"));
+        }
+
+        Ok(())
     }
 
     /// Retrieves the name of the parent class from which the current class derives.
@@ -138,13 +294,13 @@ impl NenyrParser {
         suggestion_on_invalid: Option<String>,
         error_message_on_invalid: &str,
     ) -> NenyrResult<String> {
-        let current_name = self.parse_parenthesized_delimiter(
+        let (current_name, literal_start) = self.parse_parenthesized_delimiter(
             suggestion_on_open,
             error_message_on_open,
             suggestion_on_close,
             error_message_on_close,
             |parser| {
-                parser.parse_string_literal(
+                parser.parse_spanned_string_literal(
                     suggestion_on_parse_literal.clone(),
                     error_message_on_parse_literal,
                     true,
@@ -159,8 +315,9 @@ impl NenyrParser {
                 self.context_path.to_string(),
                 self.add_nenyr_token_to_error(error_message_on_invalid),
                 NenyrErrorKind::SyntaxError,
-                self.get_tracing(),
-            ));
+                self.get_identifier_tracing(literal_start, &current_name),
+            )
+            .with_code("N0020"));
         }
 
         Ok(current_name)
@@ -207,13 +364,172 @@ impl NenyrParser {
 
         self.processing_state.set_block_active(false);
 
+        for (breakpoint_name, properties) in self.processing_state.take_pending_includes() {
+            let properties_count =
+                style_class.include_style_rules(breakpoint_name.as_deref(), &properties);
+            let location = match &breakpoint_name {
+                Some(breakpoint_name) => format!(
+                    "the `{}` panoramic pattern in the `{}` class",
+                    breakpoint_name, class_name
+                ),
+                None => format!("one of the patterns in the `{}` class", class_name),
+            };
+
+            self.check_block_properties_limit(properties_count, &location)?;
+        }
+
+        if self.options.warn_on_shorthand_conflicts {
+            self.check_shorthand_conflicts(&style_class)?;
+        }
+
+        if self.options.warn_on_undeclared_animations {
+            self.check_undeclared_animations(&style_class)?;
+        }
+
         Ok((class_name.to_string(), style_class))
     }
+
+    /// Raises a warning for each shorthand property declared after one of its longhands
+    /// in the same block of the class.
+    ///
+    /// The shorthand resets the longhands declared before it, which is usually a mistake.
+    /// Every block of the class is checked, including the `Supports`, `Container`,
+    /// responsive and media type blocks. This check only runs when the `warn_on_shorthand_conflicts`
+    /// option is enabled. Aliased properties are resolved to the CSS property they refer to.
+    ///
+    /// # Parameters
+    /// - `style_class`: The class collected from the `Class` declaration.
+    fn check_shorthand_conflicts(&mut self, style_class: &NenyrStyleClass) -> NenyrResult<()> {
+        let class_name = &style_class.class_name;
+
+        for (block_name, properties) in Self::collect_class_blocks(style_class) {
+            let css_properties: Vec<String> = properties
+                .keys()
+                .map(|property| self.get_css_property(property).to_string())
+                .collect();
+
+            for (shorthand, longhands) in SHORTHAND_LONGHANDS {
+                let shorthand_idx = match css_properties.iter().rposition(|p| p == shorthand) {
+                    Some(shorthand_idx) => shorthand_idx,
+                    None => continue,
+                };
+
+                for longhand in longhands {
+                    if !matches!(css_properties.iter().position(|p| p == longhand), Some(longhand_idx) if longhand_idx < shorthand_idx)
+                    {
+                        continue;
+                    }
+
+                    self.add_warning(
+                        "W0005",
+                        Some(format!("Move the `{}` shorthand before the `{}` longhand, or merge the `{}` value into the shorthand.", shorthand, longhand, longhand)),
+                        format!("The `{}` shorthand is declared after the `{}` longhand in the {} block of the `{}` class, resetting the longhand.", shorthand, longhand, block_name, class_name),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Raises a warning for each animation referenced by the `animation` or `animation-name`
+    /// properties of the class that was not declared in the current context.
+    ///
+    /// The values are free-form, so the leading identifier of each comma-separated animation
+    /// is taken as its name, skipping the durations and the keywords of the `animation` shorthand. Only the
+    /// animations declared before the class are known at this point. This check only runs
+    /// when the `warn_on_undeclared_animations` option is enabled.
+    ///
+    /// # Parameters
+    /// - `style_class`: The class collected from the `Class` declaration.
+    fn check_undeclared_animations(&mut self, style_class: &NenyrStyleClass) -> NenyrResult<()> {
+        let class_name = &style_class.class_name;
+        let mut warnings = vec![];
+
+        for (block_name, properties) in Self::collect_class_blocks(style_class) {
+            for property in ["animation", "animation-name"] {
+                let Some(value) = properties.get(property) else {
+                    continue;
+                };
+
+                for animation in value.split(',') {
+                    let animation_name = animation.split_whitespace().find(|token| {
+                        self.is_valid_identifier(token)
+                            && !ANIMATION_KEYWORDS.contains(token)
+                            && !is_css_wide_keyword(token)
+                    });
+
+                    if let Some(animation_name) = animation_name {
+                        if !self.processing_state.is_animation_declared(animation_name) {
+                            warnings.push((
+                                property,
+                                animation_name.to_string(),
+                                block_name.clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (property, animation_name, block_name) in warnings {
+            self.add_warning(
+                "W0007",
+                Some(format!("Declare the `{}` animation before the `{}` class through `Declare Animation('{}') {{ ... }}`, or fix the animation name.", animation_name, class_name, animation_name)),
+                format!("The `{}` property in the {} block of the `{}` class references the `{}` animation, which was not declared in the context.", property, block_name, class_name, animation_name),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Collects every block of the class along with a readable name, including the
+    /// `Supports`, `Container`, responsive and media type blocks.
+    fn collect_class_blocks(
+        style_class: &NenyrStyleClass,
+    ) -> Vec<(String, &IndexMap<String, Arc<str>>)> {
+        let mut blocks: Vec<(String, &IndexMap<String, Arc<str>>)> = vec![];
+
+        for patterns in [
+            &style_class.style_patterns,
+            &style_class.supports_patterns,
+            &style_class.container_patterns,
+        ] {
+            blocks.extend(
+                patterns
+                    .iter()
+                    .flatten()
+                    .map(|(pattern_name, properties)| (format!("`{}`", pattern_name), properties)),
+            );
+        }
+
+        for (breakpoint_name, panoramic_patterns) in
+            style_class.responsive_patterns.iter().flatten()
+        {
+            blocks.extend(panoramic_patterns.iter().map(|(pattern_name, properties)| {
+                (
+                    format!("`{}` of the `{}` breakpoint", pattern_name, breakpoint_name),
+                    properties,
+                )
+            }));
+        }
+
+        for (media_type, media_patterns) in style_class.media_patterns.iter().flatten() {
+            blocks.extend(media_patterns.iter().map(|(pattern_name, properties)| {
+                (
+                    format!("`{}` of the `{}` media type", pattern_name, media_type),
+                    properties,
+                )
+            }));
+        }
+
+        blocks
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{types::class::NenyrStyleClass, NenyrParser};
+    use crate::{options::DuplicatedStylesheetPolicy, types::class::NenyrStyleClass, NenyrParser};
 
     fn mock_class() -> NenyrStyleClass {
         let mut cls = NenyrStyleClass::new("myClassName".to_string(), None);
@@ -221,22 +537,22 @@ mod tests {
         cls.add_style_rule(
             "_stylesheet".to_string(),
             "background-color".to_string(),
-            "blue".to_string(),
+            "blue".into(),
         );
         cls.add_style_rule(
             "_stylesheet".to_string(),
             "border".to_string(),
-            "10px solid red".to_string(),
+            "10px solid red".into(),
         );
         cls.add_style_rule(
             "_stylesheet".to_string(),
             "height".to_string(),
-            "100px".to_string(),
+            "100px".into(),
         );
         cls.add_style_rule(
             "_stylesheet".to_string(),
             "width".to_string(),
-            "200px".to_string(),
+            "200px".into(),
         );
 
         cls.set_importance(true);
@@ -245,25 +561,25 @@ mod tests {
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "background-color".to_string(),
-            "blue".to_string(),
+            "blue".into(),
         );
         cls.add_responsive_style_rule(
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "border".to_string(),
-            "10px solid red".to_string(),
+            "10px solid red".into(),
         );
         cls.add_responsive_style_rule(
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "height".to_string(),
-            "100px".to_string(),
+            "100px".into(),
         );
         cls.add_responsive_style_rule(
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "width".to_string(),
-            "200px".to_string(),
+            "200px".into(),
         );
 
         cls
@@ -322,7 +638,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_class_method()),
-            "Ok((\"myTestingClass\", NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: Some(\"discreteAudio\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {}, \"onDeskDesktop\": {}}) }))".to_string()
+            "Ok((\"myTestingClass\", NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: Some(\"discreteAudio\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {}, \"onDeskDesktop\": {}}), media_patterns: None }))".to_string()
         );
     }
 
@@ -345,7 +661,7 @@ mod tests {
 
         parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
 
-        assert_eq!(format!("{:?}", parser.process_class_method()), "Err(NenyrError { suggestion: Some(\"Remove any duplicated commas from the `myTestingClass` class inner block to ensure proper syntax. The parser expects every pattern block to follow valid delimiters. Example: `Declare Class('myTestingClass') { Stylesheet({ ... }), PanoramicViewer({ ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"A duplicated comma was found inside the `myTestingClass` class block. The parser expected to find a new pattern block, but it was not found. However, found `,` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        }),\"), line_after: None, error_line: Some(\"    ,\"), error_on_line: 13, error_on_col: 6, error_on_pos: 365 } })".to_string());
+        assert_eq!(format!("{:?}", parser.process_class_method()), "Err(NenyrError { error_code: \"N0098\", suggestion: Some(\"Remove any duplicated commas from the `myTestingClass` class inner block to ensure proper syntax. The parser expects every pattern block to follow valid delimiters. Example: `Declare Class('myTestingClass') { Stylesheet({ ... }), PanoramicViewer({ ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"A duplicated comma was found inside the `myTestingClass` class block. The parser expected to find a new pattern block, but it was not found. However, found `,` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        }),\"), line_after: None, error_line: Some(\"    ,\"), error_on_line: 13, error_on_col: 6, error_on_pos: 365 } })".to_string());
     }
 
     #[test]
@@ -385,7 +701,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_class_method()),
-            "Ok((\"miniatureTrogon\", NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }))".to_string()
+            "Ok((\"miniatureTrogon\", NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }))".to_string()
         );
     }
 
@@ -426,7 +742,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_class_method()),
-            "Err(NenyrError { suggestion: Some(\"Ensure that an opening parenthesis `(` is placed after the keyword `Class` to properly define the class name. The correct syntax is: `Class('className') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The declaration block of `Class` was expecting an open parenthesis `(` after the keyword `Class`, but none was found. However, found `miniatureTrogon` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: Some(\"        Important(true),\"), error_line: Some(\"'miniatureTrogon') Deriving('discreteAudio') {\"), error_on_line: 1, error_on_col: 18, error_on_pos: 17 } })".to_string()
+            "Err(NenyrError { error_code: \"N0033\", suggestion: Some(\"Ensure that an opening parenthesis `(` is placed after the keyword `Class` to properly define the class name. The correct syntax is: `Class('className') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The declaration block of `Class` was expecting an open parenthesis `(` after the keyword `Class`, but none was found. However, found `miniatureTrogon` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: Some(\"        Important(true),\"), error_line: Some(\"'miniatureTrogon') Deriving('discreteAudio') {\"), error_on_line: 1, error_on_col: 18, error_on_pos: 17 } })".to_string()
         );
     }
 
@@ -437,6 +753,366 @@ mod tests {
 
         parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
 
-        assert_eq!(format!("{:?}", parser.process_class_method()), "Err(NenyrError { suggestion: Some(\"Ensure that an opening parenthesis `(` is placed after the keyword `Class` to properly define the class name. The correct syntax is: `Class('className') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The declaration block of `Class` was expecting an open parenthesis `(` after the keyword `Class`, but none was found. However, found `EndOfLine` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: None, error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string());
+        assert_eq!(format!("{:?}", parser.process_class_method()), "Err(NenyrError { error_code: \"N0033\", suggestion: Some(\"Ensure that an opening parenthesis `(` is placed after the keyword `Class` to properly define the class name. The correct syntax is: `Class('className') { ... }`.\"), context_name: None, context_path: \"\", error_message: \"The declaration block of `Class` was expecting an open parenthesis `(` after the keyword `Class`, but none was found. However, found `EndOfFile` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: None, error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string());
+    }
+
+    #[test]
+    fn invalid_class_name_must_point_at_the_offending_character() {
+        let raw_nenyr = "Class('my Name') {
+        Stylesheet({ display: 'block' })
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        let error = parser.process_class_method().unwrap_err();
+
+        assert_eq!(error.error_tracing.error_on_line, 1);
+        assert_eq!(error.error_tracing.error_on_col, 10);
+        assert_eq!(error.error_tracing.error_on_pos, 9);
+        assert_eq!(&raw_nenyr[error.error_tracing.error_on_pos..][..1], " ");
+    }
+
+    #[test]
+    fn duplicated_class_names_are_not_valid() {
+        let raw_nenyr = "Construct Central {
+    Declare Class('myTestingClass') {
+        Stylesheet({ display: 'block' })
+    },
+    Declare Class('myTestingClass') {
+        Stylesheet({ display: 'flex' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        assert_eq!(
+            format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string())),
+            "Err(NenyrError { error_code: \"N0019\", suggestion: Some(\"Ensure that each class declared within the context has a unique name. Either rename one of the `myTestingClass` classes or merge their patterns into a single declaration.\"), context_name: Some(\"Central\"), context_path: \"\", error_message: \"The `myTestingClass` class has already been declared on line 2, column 18, and cannot be declared again within the same context. However, found `)` instead.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: Some(\"    },\"), line_after: Some(\"        Stylesheet({ display: 'flex' })\"), error_line: Some(\"    Declare Class('myTestingClass') {\"), error_on_line: 5, error_on_col: 36, error_on_pos: 141 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn distinct_class_names_are_valid() {
+        let raw_nenyr = "Construct Central {
+    Declare Class('myTestingClass') {
+        Stylesheet({ display: 'block' })
+    },
+    Declare Class('anotherTestingClass') {
+        Stylesheet({ display: 'flex' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_ok());
+    }
+
+    #[test]
+    fn shorthand_after_longhand_must_warn_when_checked() {
+        let raw_nenyr = "('miniatureTrogon') Deriving('discreteAudio') {
+        Stylesheet({
+            backgroundColor: '${accentColorVar}',
+            backgroundColor: '#0000FF',
+            background: '#00FF00',
+            padding: '${m15px21}'
+        }),
+        Hover({
+            background: '${secondaryColor}',
+            backgroundColor: '#0000FF'
+        })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        assert!(parser.process_class_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+
+        parser.set_shorthand_conflict_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        assert!(parser.process_class_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "The `background` shorthand is declared after the `background-color` longhand in the `_stylesheet` block of the `miniatureTrogon` class, resetting the longhand.");
+    }
+
+    #[test]
+    fn aliased_shorthand_after_longhand_must_warn_when_checked() {
+        let raw_nenyr = "('miniatureTrogon') {
+        Stylesheet({
+            bgdColor: '#0000FF',
+            bgd: '#00FF00'
+        })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.set_shorthand_conflict_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        parser
+            .processing_state
+            .register_alias_declaration("bgd", "background", false);
+        parser
+            .processing_state
+            .register_alias_declaration("bgdColor", "background-color", false);
+
+        assert!(parser.process_class_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "The `background` shorthand is declared after the `background-color` longhand in the `_stylesheet` block of the `miniatureTrogon` class, resetting the longhand.");
+    }
+
+    #[test]
+    fn references_to_undeclared_animations_are_reported() {
+        let raw_nenyr = "('miniatureTrogon') {
+        Stylesheet({
+            animation: '2s ease-in giddyRespond, slideOut 1s infinite'
+        }),
+        Hover({
+            animationName: 'none'
+        })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.set_undeclared_animation_warning(true);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let tracing = parser.get_tracing();
+        parser
+            .processing_state
+            .register_animation_declaration("giddyRespond", tracing.clone());
+        parser
+            .processing_state
+            .register_animation_declaration("slideOut", tracing);
+
+        assert!(parser.process_class_method().is_ok());
+        assert!(parser.get_warnings().is_empty());
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        assert!(parser.process_class_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].code, "W0007");
+        assert_eq!(warnings[0].message, "The `animation` property in the `_stylesheet` block of the `miniatureTrogon` class references the `giddyRespond` animation, which was not declared in the context.");
+        assert_eq!(warnings[1].message, "The `animation` property in the `_stylesheet` block of the `miniatureTrogon` class references the `slideOut` animation, which was not declared in the context.");
+    }
+
+    #[test]
+    fn single_stylesheet_class_is_valid() {
+        let raw_nenyr = "('miniatureTrogon') {
+        Stylesheet({ color: 'red', padding: '10px' }),
+        Hover({ color: 'blue' })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.set_duplicated_stylesheet_policy(DuplicatedStylesheetPolicy::Error);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let (_, class) = parser.process_class_method().unwrap();
+
+        assert_eq!(
+            format!("{:?}", class.style_patterns),
+            "Some({\"_stylesheet\": {\"color\": \"red\", \"padding\": \"10px\"}, \":hover\": {\"color\": \"blue\"}})"
+        );
+        assert!(parser.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn duplicated_stylesheets_are_merged_or_rejected() {
+        let raw_nenyr = "('miniatureTrogon') {
+        Stylesheet({ color: 'red', padding: '10px' }),
+        PanoramicViewer({
+            onMobTablet({
+                Stylesheet({ display: 'block' }),
+                Stylesheet({ margin: '0' })
+            })
+        }),
+        Stylesheet({ color: 'blue', margin: '0' })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.set_duplicated_stylesheet_policy(DuplicatedStylesheetPolicy::Merge);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let (_, class) = parser.process_class_method().unwrap();
+
+        assert_eq!(
+            format!("{:?}", class.style_patterns),
+            "Some({\"_stylesheet\": {\"color\": \"blue\", \"padding\": \"10px\", \"margin\": \"0\"}})"
+        );
+        assert_eq!(
+            format!("{:?}", class.responsive_patterns),
+            "Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\", \"margin\": \"0\"}}})"
+        );
+        assert!(parser.get_warnings().is_empty());
+
+        parser.set_duplicated_stylesheet_policy(DuplicatedStylesheetPolicy::Warn);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        assert!(parser.process_class_method().is_ok());
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].code, "W0008");
+        assert_eq!(warnings[0].message, "The `Stylesheet` pattern is declared more than once in the `onMobTablet` breakpoint of the `miniatureTrogon` class, so its blocks are merged into a single rule.");
+        assert_eq!(warnings[1].message, "The `Stylesheet` pattern is declared more than once in the `miniatureTrogon` class, so its blocks are merged into a single rule.");
+
+        parser.set_duplicated_stylesheet_policy(DuplicatedStylesheetPolicy::Error);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let error = parser.process_class_method().unwrap_err();
+
+        assert_eq!(error.get_error_code(), "N0103");
+        assert_eq!(error.get_error_message(), "The `Stylesheet` pattern is declared more than once in the `onMobTablet` breakpoint of the `miniatureTrogon` class, but only a single `Stylesheet` pattern is allowed per scope.");
+        assert_eq!(error.error_tracing.error_on_line, 6);
+    }
+
+    #[test]
+    fn produced_selectors_specificity_is_computed() {
+        let raw_nenyr = "('miniatureTrogon') {
+        Stylesheet({ color: 'red' }),
+        Hover({ color: 'blue' }),
+        Before({ content: '\"\"' }),
+        Not('.disabled', { opacity: '1' }),
+        Supports('(display: grid)', { display: 'grid' }),
+        Container('sidebar', '(min-width: 400px)', { display: 'flex' }),
+        PanoramicViewer({
+            onMobTablet({ After({ display: 'none' }) })
+        })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let (_, class) = parser.process_class_method().unwrap();
+
+        assert_eq!(
+            class.selector_specificities(""),
+            vec![
+                (".miniatureTrogon".to_string(), (0, 1, 0)),
+                (".miniatureTrogon:hover".to_string(), (0, 2, 0)),
+                (".miniatureTrogon::before".to_string(), (0, 1, 1)),
+                (".miniatureTrogon:not(.disabled)".to_string(), (0, 2, 0)),
+                (
+                    "@supports (display: grid) .miniatureTrogon".to_string(),
+                    (0, 1, 0)
+                ),
+                (
+                    "@container sidebar (min-width: 400px) .miniatureTrogon".to_string(),
+                    (0, 1, 0)
+                ),
+                (
+                    "@onMobTablet .miniatureTrogon::after".to_string(),
+                    (0, 1, 1)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn produced_selectors_are_listed() {
+        let raw_nenyr = "('miniatureTrogon') Deriving('discreteAudio') {
+        Stylesheet({
+            backgroundColor: '#0000FF'
+        }),
+        Hover({
+            background: '${secondaryColor}'
+        }),
+        PanoramicViewer({
+            onMobTablet({
+                Stylesheet({
+                    display: 'block'
+                })
+            }),
+            onDeskDesktop({
+                Hover({
+                    bgd: '${secondaryColor}'
+                })
+            })
+        })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let (_, class) = parser.process_class_method().unwrap();
+
+        assert_eq!(
+            class.produced_selectors(""),
+            vec![
+                ".miniatureTrogon".to_string(),
+                ".miniatureTrogon:hover".to_string(),
+                "@onMobTablet .miniatureTrogon".to_string(),
+                "@onDeskDesktop .miniatureTrogon:hover".to_string(),
+            ]
+        );
+        assert_eq!(
+            class.produced_selectors("central-")[1],
+            ".central-miniatureTrogon:hover".to_string()
+        );
+    }
+
+    #[test]
+    fn responsive_declarations_are_flattened() {
+        let raw_nenyr = "('miniatureTrogon') Deriving('discreteAudio') {
+        PanoramicViewer({
+            onMobTablet({
+                Stylesheet({
+                    display: 'block'
+                })
+            }),
+            onDeskDesktop({
+                Hover({
+                    bgd: '${secondaryColor}',
+                    pdg: '${m15px}'
+                })
+            })
+        })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let (_, class) = parser.process_class_method().unwrap();
+        let declarations: Vec<_> = class.responsive_declarations().collect();
+
+        assert_eq!(declarations.len(), 3);
+        assert!(declarations.contains(&(
+            "onDeskDesktop",
+            ":hover",
+            "nickname;bgd",
+            "${secondaryColor}"
+        )));
+    }
+
+    #[test]
+    fn raw_string_values_are_valid() {
+        let raw_nenyr = "('pageGrid') {
+        Stylesheet({
+            gridTemplateAreas: `\"header header\"
+                \"main side\"`
+        })
+    },";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let (_, class) = parser.process_class_method().unwrap();
+
+        assert_eq!(
+            class.declarations().collect::<Vec<_>>(),
+            vec![(
+                "grid-template-areas",
+                "\"header header\"\n                \"main side\""
+            )]
+        );
     }
 }
@@ -0,0 +1,231 @@
+use crate::{
+    error::{NenyrError, NenyrErrorKind},
+    loop_while_not,
+    tokens::NenyrTokens,
+    types::consts::NenyrConsts,
+    validators::variable_value::NenyrVariableValueValidator,
+    NenyrParser, NenyrResult,
+};
+
+impl NenyrParser {
+    /// Processes the `Const` declaration block in Nenyr syntax.
+    ///
+    /// This method expects the `Const` keyword to be followed by a set of parenthesis, within which
+    /// a curly-bracketed object defines the constants. Constants are immutable: each name can only be
+    /// declared once, and it cannot be shared with a variable declared through `Variables`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a `NenyrError` if:
+    /// - The `Const` block does not begin with an opening parenthesis `(`.
+    /// - The `Const` block does not include a closing parenthesis `)`.
+    /// - The properties block does not start with an opening curly bracket `{`.
+    /// - The properties block does not end with a closing curly bracket `}`.
+    /// - A constant is redeclared or collides with a variable name.
+    /// - There are any syntax issues within the properties block, such as duplicated commas.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `NenyrConsts` instance containing the parsed constant definitions.
+    pub(crate) fn process_consts_method(&mut self) -> NenyrResult<NenyrConsts> {
+        self.process_next_token()?;
+
+        self.parse_parenthesized_delimiter(
+            Some("Ensure that the `Const` declaration block is enclosed with both an opening and a closing parenthesis. Correct syntax example: `Declare Const({ ... })`.".to_string()),
+            "The `Const` block is missing an opening parenthesis `(` after the `Const` keyword. The parser expected an opening parenthesis to begin the constant declarations.",
+            Some("Ensure that the `Const` block includes both an opening and a closing parenthesis. The syntax should follow the correct format: `Declare Const({ ... })`.".to_string()),
+            "A closing parenthesis `)` is missing for the `Const` declaration block. The parser expected a closing parenthesis to properly end the constant declarations.",
+            |parser| {
+                let consts = parser.parse_curly_bracketed_delimiter(
+                    Some("After the opening parenthesis, an opening curly bracket `{` is required to properly define the properties block in the `Const` declaration. Ensure the pattern follows correct Nenyr syntax, like `Declare Const({ key: 'value', ... })`.".to_string()),
+                    "The `Const` declaration block was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis.",
+                    Some("Ensure that the properties block within the `Const` declaration is properly closed with a closing curly bracket `}`. The correct syntax should look like: `Declare Const({ key: 'value', ... })`.".to_string()),
+                    "The `Const` declaration block is missing a closing curly bracket `}` to properly close the properties block.",
+                    Self::process_consts_children,
+                )?;
+
+                parser.process_next_token()?;
+
+                Ok(consts)
+            },
+        )
+    }
+
+    /// Processes the children of the `Const` declaration block.
+    ///
+    /// This method iteratively processes constant identifiers and their corresponding values,
+    /// ensuring that the properties are correctly defined and separated by commas.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` if:
+    /// - Duplicated commas are found within the properties block.
+    /// - Commas are missing between properties.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `NenyrConsts` instance containing all parsed constants from the properties block.
+    fn process_consts_children(&mut self) -> NenyrResult<NenyrConsts> {
+        let mut consts = NenyrConsts::new();
+
+        loop_while_not!(
+            self,
+            Some("Remove any duplicated commas from the properties block in the `Const` declaration. Ensure proper syntax by following valid delimiters. Example: `Declare Const({ key: 'value', anotherKey: 'anotherValue', ... })`.".to_string()),
+            "A duplicated comma was found in the properties block of the `Const` declarations. The parser expected to find a new property statement but none was found.",
+            Some("Ensure that a comma is placed after each property definition inside the `Const` declaration to separate elements correctly. Proper syntax is required for the parser to process the context. Example: `Declare Const({ key: 'value', anotherKey: 'anotherValue', ... })`.".to_string()),
+            "The properties in the `Const` declaration must be separated by commas. A comma is missing between the properties in the `Const` declaration. The parser expected a comma to separate elements but did not find one.",
+            || self.processing_state.is_block_active(),
+            |is_active| self.processing_state.set_block_active(is_active),
+            {
+                self.process_const_identifier(&mut consts)?;
+            }
+        );
+
+        self.processing_state.set_block_active(false);
+
+        Ok(consts)
+    }
+
+    /// Processes an individual constant identifier within the `Const` declaration.
+    ///
+    /// The identifier must not have been declared before as a constant, nor as a variable
+    /// within the same context.
+    ///
+    /// # Parameters
+    ///
+    /// - `consts`: A mutable reference to the `NenyrConsts` instance to which the constant will be added.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` if:
+    /// - The identifier is not a valid identifier (must be alphanumeric and start with a letter).
+    /// - The identifier was already declared as a constant or as a variable.
+    fn process_const_identifier(&mut self, consts: &mut NenyrConsts) -> NenyrResult<()> {
+        self.processing_state.set_block_active(true);
+
+        let NenyrTokens::Identifier(identifier) = self.current_token.clone() else {
+            return Err(NenyrError::new(
+                Some("Specify a valid identifier for the constant that consists only of alphanumeric characters, with the first character being a letter. For example: 'siteWidth', 'brandColor', etc.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error("The `Const` declaration contains an invalid identifier for the constant name. Please ensure the identifier follows the required format."),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0021"));
+        };
+
+        let error_message = if self.processing_state.is_const_declared(&identifier)
+            || consts.values.contains_key(&identifier)
+        {
+            Some(format!("The `{}` constant has already been declared and cannot be redeclared, as constants are immutable.", identifier))
+        } else if self.processing_state.is_variable_declared(&identifier) {
+            Some(format!("The `{}` constant collides with the `{}` variable declared in the `Variables` declaration. Constants and variables cannot share the same name.", identifier, identifier))
+        } else {
+            None
+        };
+
+        if let Some(error_message) = error_message {
+            return Err(NenyrError::new(
+                Some("Rename the constant so that its name is unique among the constants and variables declared within the context.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                error_message,
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0022"));
+        }
+
+        self.process_const_value(identifier, consts)
+    }
+
+    /// Processes the value associated with a constant identifier.
+    ///
+    /// This method expects a colon after the identifier, followed by a non-empty string holding
+    /// a valid value, which is then added to the constants.
+    ///
+    /// # Parameters
+    ///
+    /// - `identifier`: A string representing the constant identifier.
+    /// - `consts`: A mutable reference to the `NenyrConsts` instance to which the constant will be added.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` if:
+    /// - The colon after the constant name is missing.
+    /// - The value is not a non-empty string or could not be validated.
+    fn process_const_value(
+        &mut self,
+        identifier: String,
+        consts: &mut NenyrConsts,
+    ) -> NenyrResult<()> {
+        self.process_next_token()?;
+        self.parse_colon_delimiter(
+            Some(format!("Ensure that each constant is defined with a colon after it. The correct syntax is: `Const({{ {}: 'constant value', ... }})`.", identifier)),
+            &format!("The `{}` constant in the `Const` declaration is missing a colon after the constant name definition.", identifier),
+            true
+        )?;
+
+        let value = self.parse_string_literal(
+            Some(format!("Ensure that all constants are assigned non-empty string values. You can either remove the constant or specify a non-empty string value for it: `Const({{ {}: 'constant value', ... }})`.", identifier)),
+            &format!("The `{}` constant in the `Const` declaration should receive a non-empty string as a value, but none was found.", identifier),
+            false
+        )?;
+
+        if self.is_valid_variable_value(&value) {
+            self.processing_state
+                .register_const_declaration(&identifier);
+            consts.add_const(identifier, self.normalize_hex_case(value));
+
+            return Ok(());
+        }
+
+        Err(NenyrError::new(
+            Some("Ensure that all constant values are semantically correct to be validated. Please refer to the documentation to verify the correct way to define constant values.".to_string()),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(&format!("The `{}` constant in the `Const` declaration contains an invalid value and could not be validated.", identifier)),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code("N0023"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NenyrParser;
+
+    #[test]
+    fn consts_are_valid() {
+        let raw_nenyr = "Const({
+        siteWidth: '1200px',
+        brandColor: '#FF6677'
+    })";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_consts_method()),
+            "Ok(NenyrConsts { values: {\"siteWidth\": \"1200px\", \"brandColor\": \"#FF6677\"} })"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn consts_cannot_be_redeclared() {
+        let raw_nenyr = "Const({
+        siteWidth: '1200px',
+        siteWidth: '960px'
+    })";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_consts_method()),
+            "Err(NenyrError { error_code: \"N0022\", suggestion: Some(\"Rename the constant so that its name is unique among the constants and variables declared within the context.\"), context_name: None, context_path: \"\", error_message: \"The `siteWidth` constant has already been declared and cannot be redeclared, as constants are immutable.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: Some(\"        siteWidth: '1200px',\"), line_after: Some(\"    })\"), error_line: Some(\"        siteWidth: '960px'\"), error_on_line: 3, error_on_col: 18, error_on_pos: 54 } })".to_string()
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{
+    error::{NenyrError, NenyrErrorKind},
+    interfaces::at_rules::NenyrAtRulePattern,
+    tokens::NenyrTokens,
+    types::class::NenyrStyleClass,
+    validators::{
+        container_query::NenyrContainerQueryValidator, identifier::NenyrIdentifierValidator,
+    },
+    NenyrParser, NenyrResult,
+};
+
+/// The `Container` pattern, which guards its properties behind a container query.
+const CONTAINER_PATTERN: NenyrAtRulePattern = NenyrAtRulePattern {
+    pattern_name: "Container",
+    prelude_name: "container query",
+    prelude_example: "(min-width: 400px)",
+    missing_comma_code: "N0027",
+    invalid_property_code: "N0028",
+    invalid_value_code: "N0029",
+};
+
+/// Keywords reserved by the CSS `@container` rule, which cannot be used as container names.
+const RESERVED_CONTAINER_NAMES: [&str; 4] = ["none", "and", "not", "or"];
+
+impl NenyrParser {
+    /// Processes the `Container` pattern declared within a Nenyr style class.
+    ///
+    /// The `Container` pattern guards a block of properties behind a container query, mirroring the
+    /// CSS `@container` rule. It expects a container query string, optionally preceded by the name
+    /// of the queried container, followed by a block of properties, all enclosed within parentheses:
+    ///
+    /// ```nenyr
+    /// Class('className') {
+    ///     Container('(min-width: 400px)', {
+    ///         display: 'flex'
+    ///     }),
+    ///     Container('sidebar', '(min-width: 400px)', {
+    ///         gap: '1rem'
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the `Container` pattern is declared.
+    /// - `style_class`: A mutable reference to the `NenyrStyleClass` receiving the guarded properties.
+    ///
+    /// # Returns
+    /// - `NenyrResult<()>`: Returns `Ok(())` when the pattern is successfully parsed and stored in
+    ///   the style class.
+    ///
+    /// # Errors
+    /// - Returns an error if the parentheses, the commas or the curly brackets are missing.
+    /// - Returns an error if the container name or the container query are invalid.
+    /// - Returns an error if any of the properties or values inside the block are invalid.
+    pub(crate) fn process_container_pattern(
+        &mut self,
+        class_name: &str,
+        style_class: &mut NenyrStyleClass,
+    ) -> NenyrResult<()> {
+        let (container_query, properties) =
+            self.process_at_rule_pattern(class_name, &CONTAINER_PATTERN, |parser| {
+                parser.retrieve_container_query(class_name)
+            })?;
+
+        style_class.add_container_pattern(container_query, properties);
+
+        Ok(())
+    }
+
+    /// Retrieves and validates the container query of a `Container` pattern.
+    ///
+    /// The first argument is either the container query itself or the name of the queried
+    /// container. In the latter case, the container query is expected as the second argument and
+    /// both are joined into the `@container` prelude (e.g., `sidebar (min-width: 400px)`).
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the `Container` pattern is declared.
+    ///
+    /// # Returns
+    /// - `NenyrResult<String>`: The validated container query prelude, trimmed of surrounding whitespace.
+    ///
+    /// # Errors
+    /// - Returns an error if the first argument is neither a valid container query nor a valid
+    ///   container name, or if a named container is not followed by a valid container query.
+    fn retrieve_container_query(&mut self, class_name: &str) -> NenyrResult<String> {
+        let first_argument = self.parse_string_literal(
+            Some(format!("Ensure that the `Container` pattern in `{}` class receives a non-empty string containing the container query, or the container name, as its first argument. Correct syntax: `Container('(min-width: 400px)', {{ ... }})` or `Container('sidebar', '(min-width: 400px)', {{ ... }})`.", class_name)),
+            &format!("The `Container` pattern in the `{}` class was expected to receive a container query or a container name as its first argument, but none was found.", class_name),
+            false,
+        )?;
+
+        if self.is_valid_container_query(&first_argument) {
+            self.process_next_token()?;
+
+            return Ok(first_argument.trim().to_string());
+        }
+
+        if self.is_valid_container_name(&first_argument) {
+            self.process_next_token()?;
+
+            if let NenyrTokens::Comma = self.current_token {
+                self.process_next_token()?;
+
+                let container_condition =
+                    self.retrieve_container_condition(class_name, &first_argument)?;
+
+                return Ok(format!("{} {}", first_argument, container_condition));
+            }
+
+            return Err(NenyrError::new(
+                Some(format!("Ensure that a comma is placed after the `{}` container name of the `Container` pattern in the `{}` class. The correct syntax is: `Container('{}', '(min-width: 400px)', {{ propertyName: 'value', ... }})`.", &first_argument, class_name, &first_argument)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("A comma was expected after the container name of the `Container` pattern in the `{}` class, but none was found. The parser requires a comma to separate the container name from the container query.", class_name)),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0024"));
+        }
+
+        Err(NenyrError::new(
+            Some("A valid container query must be composed of balanced `(name: value)` size features or range comparisons, which can be combined using the `and`, `or` and `not` operators. A named container must be an alphanumeric identifier followed by its container query. Examples: `'(min-width: 400px)'`, `'(width > 400px) and (height < 800px)'`, `'sidebar', '(min-width: 400px)'`.".to_string()),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(&format!("The `{}` argument of the `Container` pattern in the `{}` class is neither a valid container query nor a valid container name.", first_argument, class_name)),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code("N0025"))
+    }
+
+    /// Retrieves and validates the container query following a container name.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the `Container` pattern is declared.
+    /// - `container_name`: The name of the queried container.
+    ///
+    /// # Returns
+    /// - `NenyrResult<String>`: The validated container query, trimmed of surrounding whitespace.
+    ///
+    /// # Errors
+    /// - Returns an error if the container query is not a non-empty string or if it does not
+    ///   follow the balanced condition form.
+    fn retrieve_container_condition(
+        &mut self,
+        class_name: &str,
+        container_name: &str,
+    ) -> NenyrResult<String> {
+        let container_condition = self.parse_string_literal(
+            Some(format!("Ensure that the `{}` container name of the `Container` pattern in `{}` class is followed by a non-empty string containing the container query. Correct syntax: `Container('{}', '(min-width: 400px)', {{ ... }})`.", container_name, class_name, container_name)),
+            &format!("The `Container` pattern in the `{}` class was expected to receive a container query after the `{}` container name, but none was found.", class_name, container_name),
+            false,
+        )?;
+
+        if !self.is_valid_container_query(&container_condition) {
+            return Err(NenyrError::new(
+                Some("A valid container query must be composed of balanced `(name: value)` size features or range comparisons, which can be combined using the `and`, `or` and `not` operators. Examples: `'(min-width: 400px)'`, `'(width > 400px) and (height < 800px)'`, `'not (orientation: portrait)'`.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("The `{}` container query of the `{}` container in the `Container` pattern of the `{}` class failed to be validated.", container_condition, container_name, class_name)),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0026"));
+        }
+
+        self.process_next_token()?;
+
+        Ok(container_condition.trim().to_string())
+    }
+
+    /// Checks whether a string is a valid container name, which must be an identifier other
+    /// than the keywords reserved by the CSS `@container` rule.
+    fn is_valid_container_name(&self, container_name: &str) -> bool {
+        self.is_valid_identifier(container_name)
+            && !RESERVED_CONTAINER_NAMES.contains(&container_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use crate::{types::class::NenyrStyleClass, NenyrParser};
+
+    #[test]
+    fn anonymous_container_is_valid() {
+        let raw_nenyr = "Container('(min-width: 400px)', { display: 'flex', gap: '1rem' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        styles.add_container_pattern(
+            "(min-width: 400px)".to_string(),
+            IndexMap::from([
+                ("display".to_string(), "flex".into()),
+                ("gap".to_string(), "1rem".into()),
+            ]),
+        );
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            parser.process_patterns_methods("myClassName", &mut style_class, false, &None),
+            Ok(())
+        );
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn named_container_is_valid() {
+        let raw_nenyr =
+            "Container('sidebar', '(width > 400px) and (height < 800px)', { display: 'flex' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        styles.add_container_pattern(
+            "sidebar (width > 400px) and (height < 800px)".to_string(),
+            IndexMap::from([("display".to_string(), "flex".into())]),
+        );
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            parser.process_patterns_methods("myClassName", &mut style_class, false, &None),
+            Ok(())
+        );
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn container_with_malformed_query_is_not_valid() {
+        let raw_nenyr = "Container('(min-width: 400px', { display: 'flex' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0025\", suggestion: Some(\"A valid container query must be composed of balanced `(name: value)` size features or range comparisons, which can be combined using the `and`, `or` and `not` operators. A named container must be an alphanumeric identifier followed by its container query. Examples: `'(min-width: 400px)'`, `'(width > 400px) and (height < 800px)'`, `'sidebar', '(min-width: 400px)'`.\"), context_name: None, context_path: \"\", error_message: \"The `(min-width: 400px` argument of the `Container` pattern in the `myClassName` class is neither a valid container query nor a valid container name. However, found `(min-width: 400px` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Container('(min-width: 400px', { display: 'flex' })\"), error_on_line: 1, error_on_col: 30, error_on_pos: 29 } })"
+        );
+        assert_eq!(style_class.container_patterns, None);
+    }
+
+    #[test]
+    fn container_exceeding_the_properties_limit_is_not_valid() {
+        let raw_nenyr =
+            "Container('(min-width: 400px)', { display: 'grid', gap: '1rem', width: '10px' })";
+
+        let mut parser = NenyrParser::new();
+        parser.set_max_block_properties(2);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        let error = parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .unwrap_err();
+
+        assert_eq!(
+            error.get_error_message(),
+            "The properties block of the `Container` pattern in the `myClassName` class declares more than 2 properties, exceeding the configured limit."
+        );
+    }
+
+    #[test]
+    fn container_with_whitespace_only_value_is_not_valid() {
+        let raw_nenyr = "Container('(min-width: 400px)', { display: '   ' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        let error = parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .unwrap_err();
+
+        assert_eq!(
+            error.get_error_message(),
+            "The `display` property inside the `Container` pattern in the `myClassName` class receives a value made only of whitespace, which is semantically empty."
+        );
+    }
+
+    #[test]
+    fn named_container_with_reserved_name_is_not_valid() {
+        let raw_nenyr = "Container('none', '(min-width: 400px)', { display: 'flex' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        assert!(parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .is_err());
+        assert_eq!(style_class.container_patterns, None);
+    }
+
+    #[test]
+    fn container_inside_panoramic_is_not_valid() {
+        let raw_nenyr = "PanoramicViewer({ myBreakpoint({ Container('(min-width: 400px)', { display: 'flex' }) }) })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        assert!(parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .is_err());
+    }
+}
@@ -100,7 +100,8 @@ impl NenyrParser {
                 self.add_nenyr_token_to_error(error_message_on_close),
                 NenyrErrorKind::SyntaxError,
                 self.get_tracing(),
-            ));
+            )
+            .with_code("N0030"));
         }
 
         // Returns an error if the opening curly bracket is missing
@@ -111,7 +112,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message_on_open),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0031"))
     }
 
     /// Parses an expression that is enclosed within parentheses, ensuring both the
@@ -175,7 +177,8 @@ impl NenyrParser {
                 self.add_nenyr_token_to_error(error_message_on_close),
                 NenyrErrorKind::SyntaxError,
                 self.get_tracing(),
-            ));
+            )
+            .with_code("N0032"));
         }
 
         // Returns an error if the opening parenthesis is missing
@@ -186,7 +189,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message_on_open),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0033"))
     }
 
     /// Parses a colon (`:`) delimiter from the current token and optionally
@@ -230,7 +234,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0034"))
     }
 
     /// Parses content enclosed within square brackets (`[ ... ]`) using a custom parsing function.
@@ -292,7 +297,8 @@ impl NenyrParser {
                 self.add_nenyr_token_to_error(error_message_on_close),
                 NenyrErrorKind::SyntaxError,
                 self.get_tracing(),
-            ));
+            )
+            .with_code("N0035"));
         }
 
         // Returns an error if the opening square bracket is missing
@@ -303,7 +309,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message_on_open),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0036"))
     }
 }
 
@@ -0,0 +1,238 @@
+use crate::{
+    error::{NenyrError, NenyrErrorKind},
+    tokens::NenyrTokens,
+    types::variables::{NenyrDimension, NenyrExpression, NenyrOperator, NenyrVariables},
+    validators::{
+        identifier::NenyrIdentifierValidator, variable_value::NenyrVariableValueValidator,
+    },
+    NenyrParser, NenyrResult,
+};
+
+impl NenyrParser {
+    /// Processes a computed value assigned to a variable, such as
+    /// `doubleSpacing: calc('${spacing}' * 2)`.
+    ///
+    /// The expression applies the `+`, `-`, `*` and `/` operators over numbers, `number+unit`
+    /// values and references to other variables, and may group operations between parentheses.
+    /// Multiplication and division bind tighter than addition and subtraction.
+    ///
+    /// # Parameters
+    /// - `identifier`: A `String` that represents the name of the variable being defined.
+    /// - `variables`: A mutable reference to a `NenyrVariables` instance, where the
+    ///   successfully parsed variable will be added, keeping both its expression and its
+    ///   textual form.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the parentheses of the expression are not balanced, if an
+    /// operand is missing or invalid, or if a referenced variable was not declared before.
+    pub(crate) fn process_expression_variable_value(
+        &mut self,
+        identifier: String,
+        variables: &mut NenyrVariables,
+    ) -> NenyrResult<()> {
+        self.process_next_token()?;
+
+        let expression = self.parse_parenthesized_delimiter(
+            Some(format!("Wrap the computed value in parentheses after the `calc` keyword. The correct syntax is: `Variables({{ {}: calc('${{spacing}}' * 2), ... }})`.", identifier)),
+            &format!("The computed value of the `{}` variable is missing an opening parenthesis `(` after the `calc` keyword.", identifier),
+            Some(format!("Ensure that every parenthesis opened in the computed value of the `{}` variable is closed. The correct syntax is: `Variables({{ {}: calc('${{spacing}}' * 2), ... }})`.", identifier, identifier)),
+            &format!("The computed value of the `{}` variable is missing a closing parenthesis `)`, or an operator is missing between its operands.", identifier),
+            |parser| parser.parse_expression_sum(&identifier, variables),
+        )?;
+
+        variables.add_expression_variable(identifier, expression);
+
+        Ok(())
+    }
+
+    /// Parses a sequence of terms joined by the `+` and `-` operators.
+    fn parse_expression_sum(
+        &mut self,
+        identifier: &str,
+        variables: &NenyrVariables,
+    ) -> NenyrResult<NenyrExpression> {
+        let mut expression = self.parse_expression_product(identifier, variables)?;
+
+        loop {
+            let operator = match self.current_token {
+                NenyrTokens::Plus => NenyrOperator::Add,
+                NenyrTokens::Minus => NenyrOperator::Subtract,
+                _ => return Ok(expression),
+            };
+
+            self.process_next_token()?;
+
+            expression = NenyrExpression::Binary {
+                operator,
+                left: Box::new(expression),
+                right: Box::new(self.parse_expression_product(identifier, variables)?),
+            };
+        }
+    }
+
+    /// Parses a sequence of operands joined by the `*` and `/` operators.
+    fn parse_expression_product(
+        &mut self,
+        identifier: &str,
+        variables: &NenyrVariables,
+    ) -> NenyrResult<NenyrExpression> {
+        let mut expression = self.parse_expression_operand(identifier, variables)?;
+
+        loop {
+            let operator = match self.current_token {
+                NenyrTokens::Asterisk => NenyrOperator::Multiply,
+                NenyrTokens::Slash => NenyrOperator::Divide,
+                _ => return Ok(expression),
+            };
+
+            self.process_next_token()?;
+
+            expression = NenyrExpression::Binary {
+                operator,
+                left: Box::new(expression),
+                right: Box::new(self.parse_expression_operand(identifier, variables)?),
+            };
+        }
+    }
+
+    /// Parses a single operand of a computed value, leaving the parser on the token that
+    /// follows it.
+    ///
+    /// An operand is either a number, a `number+unit` value, a reference to another variable
+    /// written as `'${variableName}'`, or a nested expression between parentheses.
+    fn parse_expression_operand(
+        &mut self,
+        identifier: &str,
+        variables: &NenyrVariables,
+    ) -> NenyrResult<NenyrExpression> {
+        let operand = match self.current_token.clone() {
+            NenyrTokens::Number(value) => NenyrExpression::Number(value),
+            NenyrTokens::Dimension(value, unit) => {
+                if !self.is_valid_unit(&unit) {
+                    return Err(NenyrError::new(
+                        Some("Ensure that unquoted values are composed of a number immediately followed by a valid CSS unit, such as `px`, `rem`, `%` or `deg`.".to_string()),
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        format!("The computed value of the `{}` variable contains the `{}` unit, which is not a valid CSS unit.", identifier, unit),
+                        NenyrErrorKind::SyntaxError,
+                        self.get_tracing(),
+                    ).with_code("N0037"));
+                }
+
+                NenyrExpression::Dimension(NenyrDimension { value, unit })
+            }
+            NenyrTokens::StringLiteral { value, .. } => {
+                self.retrieve_expression_reference(identifier, &value, variables)?
+            }
+            NenyrTokens::ParenthesisOpen => self.parse_parenthesized_delimiter(
+                None,
+                "",
+                Some(format!("Ensure that every parenthesis opened in the computed value of the `{}` variable is closed. The correct syntax is: `Variables({{ {}: calc(('${{spacing}}' + 2px) * 2), ... }})`.", identifier, identifier)),
+                &format!("A group in the computed value of the `{}` variable is missing a closing parenthesis `)`, or an operator is missing between its operands.", identifier),
+                |parser| parser.parse_expression_sum(identifier, variables),
+            )?,
+            _ => {
+                return Err(NenyrError::new(
+                    Some(format!("Ensure that every operator in the computed value is placed between two operands, each one being a number, a `number+unit` value, a variable reference or a group between parentheses: `Variables({{ {}: calc('${{spacing}}' * 2), ... }})`.", identifier)),
+                    self.context_name.clone(),
+                    self.context_path.to_string(),
+                    self.add_nenyr_token_to_error(&format!("The computed value of the `{}` variable expected an operand, but none was found.", identifier)),
+                    NenyrErrorKind::SyntaxError,
+                    self.get_tracing(),
+                ).with_code("N0038"));
+            }
+        };
+
+        self.process_next_token()?;
+
+        Ok(operand)
+    }
+
+    /// Retrieves the variable referenced by a quoted operand such as `'${spacing}'`.
+    ///
+    /// The referenced variable must have been declared before the computed value, either
+    /// earlier in the same `Variables` block or as a variable or constant of the context.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the operand is not a variable reference, or if it
+    /// references the variable being declared or a variable that was not declared.
+    fn retrieve_expression_reference(
+        &self,
+        identifier: &str,
+        operand: &str,
+        variables: &NenyrVariables,
+    ) -> NenyrResult<NenyrExpression> {
+        let reference = operand
+            .strip_prefix("${")
+            .and_then(|operand| operand.strip_suffix('}'))
+            .filter(|reference| self.is_valid_identifier(reference));
+
+        let Some(reference) = reference else {
+            return Err(NenyrError::new(
+                Some(format!("Write variable references as `'${{variableName}}'`, and use unquoted numbers or `number+unit` values for the remaining operands: `Variables({{ {}: calc('${{spacing}}' * 2), ... }})`.", identifier)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The computed value of the `{}` variable contains the `{}` operand, which is not a valid variable reference.", identifier, operand),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0039"));
+        };
+
+        let is_known = reference != identifier
+            && (variables.values.contains_key(reference)
+                || self.processing_state.is_variable_declared(reference)
+                || self.processing_state.is_const_declared(reference));
+
+        if is_known {
+            return Ok(NenyrExpression::Reference(reference.to_string()));
+        }
+
+        Err(NenyrError::new(
+            Some(format!("Declare the `{}` variable before using it in a computed value, or reference another variable or constant of the context.", reference)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            format!("The computed value of the `{}` variable references the `{}` variable, which was not declared.", identifier, reference),
+            NenyrErrorKind::ValidationError,
+            self.get_tracing(),
+        ).with_code("N0040"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NenyrParser;
+
+    #[test]
+    fn computed_variables_are_valid() {
+        let raw_nenyr = "Variables({
+        spacing: 8px,
+        gutter: '16px',
+        doubleSpacing: calc('${spacing}' * 2),
+        halfGap: calc(('${spacing}' + '${gutter}') / 2 - 1px)
+    })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Ok(NenyrVariables { values: {\"spacing\": Text(\"8px\"), \"gutter\": Text(\"16px\"), \"doubleSpacing\": Text(\"calc(${spacing} * 2)\"), \"halfGap\": Text(\"calc((${spacing} + ${gutter}) / 2 - 1px)\")}, dimensions: Some({\"spacing\": NenyrDimension { value: 8.0, unit: \"px\" }}), expressions: Some({\"doubleSpacing\": Binary { operator: Multiply, left: Reference(\"spacing\"), right: Number(2.0) }, \"halfGap\": Binary { operator: Subtract, left: Binary { operator: Divide, left: Binary { operator: Add, left: Reference(\"spacing\"), right: Reference(\"gutter\") }, right: Number(2.0) }, right: Dimension(NenyrDimension { value: 1.0, unit: \"px\" }) }}) })".to_string()
+        );
+    }
+
+    #[test]
+    fn computed_variables_referencing_unknown_variables_are_not_valid() {
+        let raw_nenyr = "Variables({
+        spacing: 8px,
+        doubleSpacing: calc('${gap}' * 2)
+    })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Err(NenyrError { error_code: \"N0040\", suggestion: Some(\"Declare the `gap` variable before using it in a computed value, or reference another variable or constant of the context.\"), context_name: None, context_path: \"\", error_message: \"The computed value of the `doubleSpacing` variable references the `gap` variable, which was not declared.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: Some(\"        spacing: 8px,\"), line_after: Some(\"    })\"), error_line: Some(\"        doubleSpacing: calc('${gap}' * 2)\"), error_on_line: 3, error_on_col: 37, error_on_pos: 70 } })".to_string()
+        );
+    }
+}
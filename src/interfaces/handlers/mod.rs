@@ -1,4 +1,12 @@
-use crate::{error::NenyrErrorTracing, tokens::NenyrTokens, NenyrParser, NenyrResult};
+use crate::{
+    error::{NenyrDiagnostic, NenyrError, NenyrErrorKind, NenyrErrorTracing, Severity},
+    options::{DiagnosticsLimitPolicy, UnknownPropertyPolicy},
+    tokens::{token_kind, NenyrTokens},
+    types::declaration::NenyrDeclaration,
+    validators::{identifier::NenyrIdentifierValidator, style_syntax::NenyrStyleSyntaxValidator},
+    NenyrParser, NenyrResult,
+};
+use std::sync::Arc;
 
 /// # NenyrParser Handlers
 ///
@@ -23,6 +31,20 @@ impl NenyrParser {
     /// encounters an issue while retrieving the next token.
     pub(crate) fn process_next_token(&mut self) -> NenyrResult<()> {
         self.current_token = self.lexer.next_token()?;
+        self.stats.count_token();
+
+        if self.options.record_token_histogram {
+            *self
+                .token_histogram
+                .entry(token_kind(&self.current_token))
+                .or_insert(0) += 1;
+        }
+
+        // Semicolons are only tokenized when allowed, and then they separate the same way
+        // as commas do.
+        if self.current_token == NenyrTokens::Semicolon {
+            self.current_token = NenyrTokens::Comma;
+        }
 
         Ok(())
     }
@@ -40,11 +62,306 @@ impl NenyrParser {
         self.lexer.trace_lexer_position()
     }
 
+    /// Retrieves the tracing information pointing at the first invalid character of an
+    /// identifier declared through a string literal.
+    ///
+    /// # Parameters
+    /// - `literal_start`: The byte position of the opening quote of the string literal.
+    /// - `identifier`: The identifier held by the string literal.
+    ///
+    /// # Returns
+    /// Returns a `NenyrErrorTracing` located at the offending character, falling back to
+    /// the current lexer position when the identifier is valid.
+    pub(crate) fn get_identifier_tracing(
+        &self,
+        literal_start: usize,
+        identifier: &str,
+    ) -> NenyrErrorTracing {
+        match self.find_invalid_identifier_char(identifier) {
+            Some(idx) => self.lexer.trace_position(literal_start + 1 + idx),
+            None => self.get_tracing(),
+        }
+    }
+
+    /// Records a non-fatal warning at the current position of the parser.
+    ///
+    /// When a diagnostics limit is set and already reached, the warning is discarded. The
+    /// first discarded warning records a final `Info` diagnostic marking that the limit was
+    /// reached, or stops the parsing when the `DiagnosticsLimitPolicy` is `Abort`.
+    ///
+    /// # Parameters
+    /// - `code`: The stable code identifying the warning, such as `W0003`.
+    /// - `suggestion`: An optional suggestion on how to address the warning.
+    /// - `warning_message`: A message explaining the reason for the warning.
+    ///
+    /// # Returns
+    /// A `NenyrError` when the limit is reached and the policy is `Abort`.
+    pub(crate) fn add_warning(
+        &mut self,
+        code: &'static str,
+        suggestion: Option<String>,
+        warning_message: String,
+    ) -> NenyrResult<()> {
+        if let Some(limit) = self.options.diagnostics_limit {
+            if self.diagnostics.len() > limit {
+                return Ok(());
+            }
+
+            if self.diagnostics.len() == limit {
+                let suggestion = Some("Address the diagnostics already reported, or raise the limit set through `NenyrParser::set_diagnostics_limit`.".to_string());
+                let message = format!("Too many diagnostics were raised. The collection stopped after reaching the limit of {} diagnostics.", limit);
+
+                if self.options.diagnostics_limit_policy == DiagnosticsLimitPolicy::Abort {
+                    return Err(NenyrError::new(
+                        suggestion,
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        message,
+                        NenyrErrorKind::Other,
+                        self.get_tracing(),
+                    )
+                    .with_code("N0041"));
+                }
+
+                self.diagnostics.push(
+                    NenyrDiagnostic::new(
+                        Severity::Info,
+                        suggestion,
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        message,
+                        NenyrErrorKind::Other,
+                        self.get_tracing(),
+                    )
+                    .with_code("I0001"),
+                );
+
+                return Ok(());
+            }
+        }
+
+        self.diagnostics.push(
+            NenyrDiagnostic::new(
+                Severity::Warning,
+                suggestion,
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                warning_message,
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            )
+            .with_code(code),
+        );
+
+        Ok(())
+    }
+
+    /// Resolves a property name that is not a Nenyr property into an alias reference.
+    ///
+    /// Aliases declared earlier in the current context are always accepted. Any other name
+    /// is handled according to the `UnknownPropertyPolicy` set in the parser options: it is
+    /// either rejected, kept with a warning, or kept silently.
+    ///
+    /// # Parameters
+    /// - `nickname`: The property name found in the property position.
+    /// - `location`: A description of where the property was found, used in the messages.
+    ///
+    /// # Returns
+    /// The `nickname;`-prefixed alias reference, or a `NenyrError` when the policy is `Error`.
+    pub(crate) fn resolve_alias_property(
+        &mut self,
+        nickname: &str,
+        location: &str,
+    ) -> NenyrResult<String> {
+        if !self.processing_state.is_alias_declared(nickname) {
+            let suggestion = format!("Declare `{}` inside the `Aliases` declaration of the current context before using it, or replace it with a valid Nenyr property. Example: `Declare Aliases({{ {}: backgroundColor }})`.", nickname, nickname);
+            let message = format!("The `{}` property inside {} is neither a valid Nenyr property nor an alias declared in the current context.", nickname, location);
+
+            match self.options.unknown_property_policy {
+                UnknownPropertyPolicy::Error => {
+                    return Err(NenyrError::new(
+                        Some(suggestion),
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        message,
+                        NenyrErrorKind::ValidationError,
+                        self.get_tracing(),
+                    )
+                    .with_code("N0042"));
+                }
+                UnknownPropertyPolicy::Warn => {
+                    self.add_warning("W0006", Some(suggestion), message)?
+                }
+                UnknownPropertyPolicy::PassThrough => {}
+            }
+        }
+
+        Ok(format!("nickname;{}", nickname))
+    }
+
+    /// Retrieves the CSS property a property name stands for, resolving the `nickname;`-prefixed
+    /// alias references through the aliases declared in the current context.
+    ///
+    /// # Parameters
+    /// - `property`: The CSS property name, or the `nickname;`-prefixed alias reference.
+    ///
+    /// # Returns
+    /// The CSS property the alias refers to, or the given name when it is not a declared alias.
+    pub(crate) fn get_css_property<'a>(&'a self, property: &'a str) -> &'a str {
+        property
+            .strip_prefix("nickname;")
+            .and_then(|nickname| self.processing_state.get_alias_property(nickname))
+            .unwrap_or(property)
+    }
+
+    /// Attaches the comments skipped since the previous declaration, including the ones
+    /// found inside the given declaration, to it. Nothing is attached unless the comments
+    /// are collected.
+    ///
+    /// # Parameters
+    /// - `declaration`: The declaration whose block just finished parsing.
+    pub(crate) fn collect_declaration_comments(&mut self, declaration: &NenyrDeclaration) {
+        let comments = self.lexer.take_comments();
+
+        if !comments.is_empty() {
+            self.comments
+                .add_declaration_comments(declaration.comment_key(), comments);
+        }
+    }
+
+    /// Lowercases the hex colors of a value when the `normalize_hex_case` option is enabled.
+    ///
+    /// # Parameters
+    /// - `value`: The value of a variable or style property.
+    ///
+    /// # Returns
+    /// The normalized value, or the value untouched when the option is disabled.
+    pub(crate) fn normalize_hex_case(&self, value: String) -> String {
+        if self.options.normalize_hex_case {
+            return self.lowercase_hex_colors(value);
+        }
+
+        value
+    }
+
+    /// Ensures that the value assigned to a style property is not made only of whitespace,
+    /// which would pass the non-empty check while being semantically empty.
+    ///
+    /// # Parameters
+    /// - `value`: The value assigned to the property.
+    /// - `property`: The property receiving the value.
+    /// - `location`: A description of the block declaring the property, used in the error message.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` of kind `SyntaxError` naming the property when its value is blank.
+    pub(crate) fn check_blank_property_value(
+        &self,
+        value: &str,
+        property: &str,
+        location: &str,
+    ) -> NenyrResult<()> {
+        if !value.trim().is_empty() {
+            return Ok(());
+        }
+
+        Err(NenyrError::new(
+            Some(format!("Either remove the `{}` property or assign a non-empty value to it: `pattern({{ {}: 'property value', ... }})`.", property, property)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            format!("The `{}` property inside {} receives a value made only of whitespace, which is semantically empty.", property, location),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code("N0043"))
+    }
+
+    /// Validates the value assigned to a style property and turns it into the value to be
+    /// stored, applying the property-specific normalization, the whitespace and hex case
+    /// options, and the value interning.
+    ///
+    /// # Parameters
+    /// - `property`: The property receiving the value, which may be an alias reference.
+    /// - `value`: The value assigned to the property.
+    /// - `location`: A description of the block declaring the property, used in the error message.
+    ///
+    /// # Returns
+    /// - `Some(Arc<str>)` with the value to be stored.
+    /// - `None` if the value is not valid for the property, letting the caller raise its error.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` of kind `SyntaxError` when the value is blank.
+    pub(crate) fn process_property_value(
+        &mut self,
+        property: &str,
+        value: String,
+        location: &str,
+    ) -> NenyrResult<Option<Arc<str>>> {
+        self.check_blank_property_value(&value, property, location)?;
+
+        if !self.is_valid_style_syntax(&value) {
+            return Ok(None);
+        }
+
+        let value = match self.normalize_property_value(self.get_css_property(property), value) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let value = self.normalize_value_whitespace(value);
+        let value = self.normalize_hex_case(value);
+
+        Ok(Some(self.value_interner.intern(value)))
+    }
+
+    /// Ensures that a block of properties does not exceed the limit set through
+    /// `NenyrParser::set_max_block_properties`.
+    ///
+    /// # Parameters
+    /// - `properties_count`: The number of properties declared by the block so far.
+    /// - `location`: A description of the block, used in the error message.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` of kind `ValidationError` when the block declares more
+    /// properties than the limit allows.
+    pub(crate) fn check_block_properties_limit(
+        &self,
+        properties_count: usize,
+        location: &str,
+    ) -> NenyrResult<()> {
+        match self.options.max_block_properties {
+            Some(limit) if properties_count > limit => Err(NenyrError::new(
+                Some(format!("Split the properties into smaller blocks, or raise the limit set through `NenyrParser::set_max_block_properties`, which allows at most {} properties per block.", limit)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The properties block of {} declares more than {} properties, exceeding the configured limit.", location, limit),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0044")),
+            _ => Ok(()),
+        }
+    }
+
+    /// Collapses the runs of whitespace of a style property value into single spaces when
+    /// the `normalize_value_whitespace` option is enabled.
+    ///
+    /// # Parameters
+    /// - `value`: The value of a style property, already validated.
+    ///
+    /// # Returns
+    /// The normalized value, or the value untouched when the option is disabled.
+    pub(crate) fn normalize_value_whitespace(&self, value: String) -> String {
+        if self.options.normalize_value_whitespace {
+            return self.collapse_whitespace(value);
+        }
+
+        value
+    }
+
     /// Sets the context name for the current parsing operation.
     ///
     /// This method allows the user to define a context name, which can be helpful
     /// for tracking the current parsing state and generating contextual error messages.
-    /// It also updates the lexer with the new context name.
+    /// It also updates the lexer with the new context name. When a context name was
+    /// injected through `NenyrParser::parse_with_context_name`, it is used instead.
     ///
     /// # Parameters
     /// - `context_name`: An `Option<String>` representing the context name to be set.
@@ -53,6 +370,8 @@ impl NenyrParser {
     /// # Returns
     /// This method does not return a value.
     pub(crate) fn set_context_name(&mut self, context_name: Option<String>) {
+        let context_name = self.context_name_override.clone().or(context_name);
+
         self.context_name = context_name.clone();
         self.lexer.set_context_name(context_name);
     }
@@ -82,8 +401,11 @@ impl NenyrParser {
             NenyrTokens::SquareBracketClose => "]",
             NenyrTokens::Colon => ":",
             NenyrTokens::Identifier(val) => &val.to_owned(),
-            NenyrTokens::StringLiteral(val) => &val.to_owned(),
+            NenyrTokens::StringLiteral { value, .. } | NenyrTokens::RawString(value) => {
+                &value.to_owned()
+            }
             NenyrTokens::Number(num) => &num.to_string(),
+            NenyrTokens::Dimension(num, unit) => &format!("{}{}", num, unit),
             other => &format!("{:?}", other),
         };
 
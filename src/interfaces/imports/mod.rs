@@ -3,7 +3,7 @@ use crate::{
     loop_while_not,
     tokens::NenyrTokens,
     types::imports::NenyrImports,
-    validators::import::NenyrImportValidator,
+    validators::{identifier::NenyrIdentifierValidator, import::NenyrImportValidator},
     NenyrParser, NenyrResult,
 };
 
@@ -111,16 +111,20 @@ impl NenyrParser {
         if let NenyrTokens::Import = self.current_token {
             self.process_next_token()?;
 
-            let value = self.parse_parenthesized_delimiter(
+            let (value, layer) = self.parse_parenthesized_delimiter(
                 Some("Ensure that an opening parenthesis `(` is placed after the keyword `Import` to properly define the import path. The correct syntax is: `Import('path')`.".to_string()),
                 "The statement of `Import` was expecting an open parenthesis `(` after the keyword `Import`, but none was found.",
-                Some("Ensure that the import path in the `Import` statement is properly closed with a parenthesis `)`. The correct syntax is: `Import('path')`.".to_string()),
+                Some("Ensure that the import path in the `Import` statement is properly closed with a parenthesis `)`. The correct syntax is: `Import('path')` or `Import('path', Layer('layerName'))`.".to_string()),
                 "The `Import` statement is missing a closing parenthesis `)` after the import path.",
-                |parser| parser.parse_string_literal(
-                    Some("All `Import` statements require a non-empty string as a path. This path must be either a valid URL or a relative path to an existing archive in the source directory, based on the context path. The correct syntax is: `Import('path')`.".to_string()),
-                    "The `Import` declaration expects a path in the form of a non-empty string, but no valid path value was provided.",
-                    true
-                ),
+                |parser| {
+                    let value = parser.parse_string_literal(
+                        Some("All `Import` statements require a non-empty string as a path. This path must be either a valid URL or a relative path to an existing archive in the source directory, based on the context path. The correct syntax is: `Import('path')`.".to_string()),
+                        "The `Import` declaration expects a path in the form of a non-empty string, but no valid path value was provided.",
+                        true
+                    )?;
+
+                    Ok((value, parser.process_import_layer()?))
+                },
             )?;
 
             if !self.is_valid_import(&value) {
@@ -131,10 +135,10 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error(&format!("The `{}` import in the `Imports` declaration is an invalid value and could not be validated.", value)),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0045"));
             }
 
-            imports.add_import(value);
+            imports.add_import(value, layer);
 
             return Ok(());
         }
@@ -146,7 +150,72 @@ impl NenyrParser {
             self.add_nenyr_token_to_error("An invalid property was detected within the `Imports` vector. Each element inside `Imports` should be an `Import` statement."),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0046"))
+    }
+
+    /// Processes the optional `Layer('layerName')` argument of an `Import` statement.
+    ///
+    /// The layer assigns the imported stylesheet to a named CSS cascade layer, as in
+    /// `Import('path', Layer('base'))`. When no comma follows the import path, the import
+    /// is not layered.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(String))` with the layer name if a `Layer` argument was declared.
+    /// - `Ok(None)` if the import has no layer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The comma after the import path is not followed by a `Layer` argument.
+    /// - The `Layer` argument is not properly parenthesized or does not receive a non-empty string.
+    /// - The layer name is not a valid identifier.
+    fn process_import_layer(&mut self) -> NenyrResult<Option<String>> {
+        if self.current_token != NenyrTokens::Comma {
+            return Ok(None);
+        }
+
+        self.process_next_token()?;
+
+        if self.current_token != NenyrTokens::Layer {
+            return Err(NenyrError::new(
+                Some("The only argument accepted after the import path is the cascade layer of the import. The correct syntax is: `Import('path', Layer('layerName'))`.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error("The `Import` statement expected a `Layer` argument after the comma following the import path, but none was found."),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0047"));
+        }
+
+        self.process_next_token()?;
+
+        let (layer, literal_start) = self.parse_parenthesized_delimiter(
+            Some("Ensure that an opening parenthesis `(` is placed after the keyword `Layer` to properly define the layer name. The correct syntax is: `Import('path', Layer('layerName'))`.".to_string()),
+            "The `Layer` argument of the `Import` statement was expecting an open parenthesis `(` after the keyword `Layer`, but none was found.",
+            Some("Ensure that the layer name is properly closed with a parenthesis `)`. The correct syntax is: `Import('path', Layer('layerName'))`.".to_string()),
+            "The `Layer` argument of the `Import` statement is missing a closing parenthesis `)` after the layer name.",
+            |parser| parser.parse_spanned_string_literal(
+                Some("The `Layer` argument requires a non-empty string as the layer name. The correct syntax is: `Import('path', Layer('layerName'))`.".to_string()),
+                "The `Layer` argument of the `Import` statement expects a layer name in the form of a non-empty string, but none was found.",
+                true
+            ),
+        )?;
+
+        if !self.is_valid_identifier(&layer) {
+            return Err(NenyrError::new(
+                Some("A layer name must start with an alphabetic character and contain only alphanumeric characters. Example: `Import('path', Layer('base'))`.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("The `{}` layer name in the `Import` statement is not a valid identifier.", layer)),
+                NenyrErrorKind::SyntaxError,
+                self.get_identifier_tracing(literal_start, &layer),
+            ).with_code("N0048"));
+        }
+
+        self.process_next_token()?;
+
+        Ok(Some(layer))
     }
 }
 
@@ -227,7 +296,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_imports_method()),
-            "Err(NenyrError { suggestion: Some(\"Remove any duplicated commas from the properties block in the `Imports` declaration. Ensure proper syntax by following valid delimiters. Example: `Declare Imports([ Import(' ... '), Import(' ... '), ... ])`.\"), context_name: None, context_path: \"src/interfaces/imports/central.nyr\", error_message: \"A duplicated comma was found in the properties block of the `Imports` declarations. The parser expected to find a new property statement but none was found. However, found `,` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        Import('https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap'),\"), line_after: Some(\"        Import('../../../mocks/imports/external_styles.css'),\"), error_line: Some(\"        Import('../../../mocks/imports/another_external.css'),,\"), error_on_line: 5, error_on_col: 64, error_on_pos: 403 } })".to_string()
+            "Err(NenyrError { error_code: \"N0098\", suggestion: Some(\"Remove any duplicated commas from the properties block in the `Imports` declaration. Ensure proper syntax by following valid delimiters. Example: `Declare Imports([ Import(' ... '), Import(' ... '), ... ])`.\"), context_name: None, context_path: \"src/interfaces/imports/central.nyr\", error_message: \"A duplicated comma was found in the properties block of the `Imports` declarations. The parser expected to find a new property statement but none was found. However, found `,` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        Import('https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap'),\"), line_after: Some(\"        Import('../../../mocks/imports/external_styles.css'),\"), error_line: Some(\"        Import('../../../mocks/imports/another_external.css'),,\"), error_on_line: 5, error_on_col: 64, error_on_pos: 403 } })".to_string()
         );
     }
 
@@ -246,4 +315,38 @@ mod tests {
             "Ok(NenyrImports { values: {} })".to_string()
         );
     }
+
+    #[test]
+    fn layered_imports_are_valid() {
+        let raw_nenyr = "Imports([
+        Import('https://fonts.googleapis.com/css2?family=Matemasie&display=swap', Layer('fonts')),
+        Import('https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap'),
+        Import('https://cdn.jsdelivr.net/npm/normalize.css/normalize.css', Layer('reset'))
+    ])";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!("{:?}", parser.process_imports_method()),
+            "Ok(NenyrImports { values: {\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": Some(\"fonts\"), \"https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap\": None, \"https://cdn.jsdelivr.net/npm/normalize.css/normalize.css\": Some(\"reset\")} })".to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_import_layer_name_is_not_valid() {
+        let raw_nenyr = "Imports([
+        Import('https://fonts.googleapis.com/css2?family=Matemasie&display=swap', Layer('base-reset'))
+    ])";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!("{:?}", parser.process_imports_method()),
+            "Err(NenyrError { error_code: \"N0048\", suggestion: Some(\"A layer name must start with an alphabetic character and contain only alphanumeric characters. Example: `Import('path', Layer('base'))`.\"), context_name: None, context_path: \"\", error_message: \"The `base-reset` layer name in the `Import` statement is not a valid identifier. However, found `)` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Imports([\"), line_after: Some(\"    ])\"), error_line: Some(\"        Import('https://fonts.googleapis.com/css2?family=Matemasie&display=swap', Layer('base-reset'))\"), error_on_line: 2, error_on_col: 94, error_on_pos: 103 } })".to_string()
+        );
+    }
 }
@@ -46,12 +46,13 @@ impl NenyrParser {
 
         Err(NenyrError::new(
             suggestion,
-            None,
+            self.context_name.clone(),
             self.context_path.to_string(),
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0049"))
     }
 
     /// Parses the `Declare` keyword in the Nenyr document.
@@ -76,6 +77,8 @@ impl NenyrParser {
         error_message: &str,
     ) -> NenyrResult<()> {
         if let NenyrTokens::Declare = self.current_token {
+            self.processing_state
+                .set_declaration_start(self.lexer.token_span().0);
             self.process_next_token()?;
 
             return Ok(());
@@ -88,7 +91,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0050"))
     }
 }
 
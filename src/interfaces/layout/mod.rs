@@ -2,7 +2,7 @@ use crate::{
     error::{NenyrError, NenyrErrorKind},
     loop_while_not,
     tokens::NenyrTokens,
-    types::layout::LayoutContext,
+    types::{declaration::NenyrDeclaration, layout::LayoutContext},
     validators::identifier::NenyrIdentifierValidator,
     NenyrParser, NenyrResult,
 };
@@ -65,12 +65,12 @@ impl NenyrParser {
     /// - Will return an error if the layout name is missing or does not adhere to the
     ///   valid identifier rules.
     fn retrieve_layout_name(&mut self) -> NenyrResult<String> {
-        let layout_name = self.parse_parenthesized_delimiter(
+        let (layout_name, literal_start) = self.parse_parenthesized_delimiter(
             Some("Ensure that the `Layout` keyword is followed by an open parenthesis `(` for proper declaration. Example: `Construct Layout('layoutName') { ... }`.".to_string()),
             "Expected an open parenthesis `(` after the `Layout` keyword to declare the layout name, but it was not found.",
             Some("Ensure that the layout name in the layout declaration is properly closed with a parenthesis `)`. Example: `Construct Layout('layoutName') { ... }`.".to_string()),
             "Expected a closing parenthesis `)` after the layout name in the layout declaration, but it was not found.",
-            |parser| parser.parse_string_literal(
+            |parser| parser.parse_spanned_string_literal(
                 Some("Ensure that the layout context name declaration includes a valid name. It should consist only of letters and numbers, with the first character being a letter. Example: `Construct Layout('layoutName01') { ... }`.".to_string()),
                 "The layout context name declaration must receive a non-empty string as the layout name, but none was found.",
                 true
@@ -84,8 +84,8 @@ impl NenyrParser {
                 self.context_path.to_string(),
                 self.add_nenyr_token_to_error("The validation of the layout context name failed. The provided name does not meet the required format."),
                 NenyrErrorKind::SyntaxError,
-                self.get_tracing(),
-            ));
+                self.get_identifier_tracing(literal_start, &layout_name),
+            ).with_code("N0051"));
         }
 
         Ok(layout_name)
@@ -112,6 +112,10 @@ impl NenyrParser {
     fn retrieve_layout_context_block(&mut self, layout_name: &str) -> NenyrResult<LayoutContext> {
         let mut layout_context = LayoutContext::new(layout_name.to_string());
 
+        // Refills the maps of the AST given to `parse_into`, if any, instead of allocating new ones.
+        (layout_context.animations, layout_context.classes) =
+            std::mem::take(&mut self.reusable_maps);
+
         loop_while_not!(
             self,
             Some("Remove any duplicated commas from the layout context to ensure proper syntax. The parser expects the `Declare` keyword to follow valid delimiters. Example: `Construct Layout('layoutName') { Declare Class({ ... }), Declare Animation({ ... }), ... }`.".to_string()),
@@ -176,31 +180,28 @@ impl NenyrParser {
     fn process_layout_methods(&mut self, layout_context: &mut LayoutContext) -> NenyrResult<()> {
         self.processing_state.set_context_active(true);
 
-        match self.current_token {
-            NenyrTokens::Aliases => {
-                let aliases = self.process_aliases_method()?;
-
-                layout_context.add_aliases_to_context(aliases);
-            }
+        let declaration = match self.current_token {
+            NenyrTokens::Aliases => NenyrDeclaration::Aliases(self.process_aliases_method(false)?),
             NenyrTokens::Variables => {
-                let variables = self.process_variables_method(false)?;
-
-                layout_context.add_variables_to_context(variables);
+                NenyrDeclaration::Variables(self.process_variables_method(false)?)
             }
             NenyrTokens::Themes => {
-                let themes = self.process_themes_method()?;
+                NenyrDeclaration::Themes(Box::new(self.process_themes_method()?))
+            }
+            NenyrTokens::Mixin => {
+                let (mixin_name, properties) = self.process_mixin_method()?;
 
-                layout_context.add_themes_to_context(themes);
+                NenyrDeclaration::Mixin(mixin_name, properties)
             }
-            NenyrTokens::Animation => {
+            NenyrTokens::Animation | NenyrTokens::Keyframes => {
                 let (animation_name, animation) = self.process_animation_method()?;
 
-                layout_context.add_animation_to_context(animation_name, animation);
+                NenyrDeclaration::Animation(animation_name, animation)
             }
             NenyrTokens::Class => {
                 let (class_name, style_class) = self.process_class_method()?;
 
-                layout_context.add_style_class_to_context(class_name, style_class);
+                NenyrDeclaration::Class(class_name, Box::new(style_class))
             }
             _ => {
                 return Err(NenyrError::new(
@@ -210,8 +211,18 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error("One of the methods in the layout context is either not a valid Nenyr method or is not supported within the layout context."),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0052"));
             }
+        };
+
+        self.stats.count_declaration();
+        self.collect_declaration_comments(&declaration);
+
+        if let Some(declaration) = self
+            .declaration_sink
+            .consume(declaration, self.options.keep_sunk_declarations)
+        {
+            layout_context.add_declaration_to_context(declaration);
         }
 
         Ok(())
@@ -376,10 +387,29 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_layout_context()),
-            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#CCCCCC\", \"accentColorVar\": \"#FF5733\"} }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#333333\", \"secondaryColor\": \"#666666\", \"accentColorVar\": \"#FF5733\"} }) }), animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) })".to_string()
+            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#CCCCCC\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\"), \"secondaryColor\": Text(\"#666666\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None }), mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) })".to_string()
         );
     }
 
+    #[test]
+    fn empty_layout_is_valid() {
+        for raw_nenyr in [
+            "Layout('hellishAdobe') {}",
+            "Layout('hellishAdobe') { /* Empty. */ }",
+        ] {
+            let mut parser = NenyrParser::new();
+
+            parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+            let _ = parser.process_next_token();
+
+            assert_eq!(
+                format!("{:?}", parser.process_layout_context()),
+                "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
+            );
+        }
+    }
+
     #[test]
     fn only_themes_in_layout_is_valid() {
         let raw_nenyr = "Layout('hellishAdobe') {
@@ -406,7 +436,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_layout_context()),
-            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: None, themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#CCCCCC\", \"accentColorVar\": \"#FF5733\"} }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#333333\", \"secondaryColor\": \"#666666\", \"accentColorVar\": \"#FF5733\"} }) }), animations: None, classes: None })".to_string()
+            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: None, themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#CCCCCC\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\"), \"secondaryColor\": Text(\"#666666\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None }), mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -428,7 +458,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_layout_context()),
-            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: None, themes: None, animations: None, classes: None })".to_string()
+            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: None, themes: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -451,7 +481,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_layout_context()),
-            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), themes: None, animations: None, classes: None })".to_string()
+            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), themes: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -527,7 +557,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_layout_context()),
-            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: None, themes: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: None })".to_string()
+            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: None, themes: None, mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: None })".to_string()
         );
     }
 
@@ -588,7 +618,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_layout_context()),
-            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: None, themes: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) })".to_string()
+            "Ok(LayoutContext { layout_name: \"hellishAdobe\", aliases: None, variables: None, themes: None, mixins: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) })".to_string()
         );
     }
 }
@@ -22,6 +22,8 @@ use crate::{
 impl NenyrParser {
     /// Parses a string literal from the token stream and handles its validation.
     ///
+    /// Backtick-delimited raw strings are accepted wherever a string literal is expected.
+    ///
     /// # Parameters
     /// - `suggestion`: An optional suggestion string that is added to the error message
     ///   when the parsing of a string literal fails.
@@ -38,7 +40,7 @@ impl NenyrParser {
     ///
     /// # Errors
     /// This method returns a `NenyrError` in the following situations:
-    /// - **Missing or Invalid String Literal**: If the current token is not a string literal,
+    /// - **Missing or Invalid String Literal**: If the current token is not a string literal or a raw string,
     ///   or the literal is empty, an error is generated using the provided `suggestion` and
     ///   `error_message`.
     pub(crate) fn parse_string_literal(
@@ -47,8 +49,10 @@ impl NenyrParser {
         error_message: &str,
         with_next_move: bool,
     ) -> NenyrResult<String> {
-        // Check if the current token is a string literal
-        if let NenyrTokens::StringLiteral(val) = self.current_token.clone() {
+        // Check if the current token is a string literal or a raw string
+        if let NenyrTokens::StringLiteral { value: val, .. } | NenyrTokens::RawString(val) =
+            self.current_token.clone()
+        {
             // Ensure the string is not empty
             if !val.is_empty() {
                 // Move to the next token if requested
@@ -69,7 +73,28 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0053"))
+    }
+
+    /// Parses a string literal exactly like `parse_string_literal`, also returning the byte
+    /// position where the literal starts (its opening quote) within the raw input.
+    ///
+    /// This is used for names validated after being parsed, so an error can point at the
+    /// exact offending character inside the literal.
+    ///
+    /// # Returns
+    /// - `NenyrResult<(String, usize)>`: The string literal along with its start position.
+    pub(crate) fn parse_spanned_string_literal(
+        &mut self,
+        suggestion: Option<String>,
+        error_message: &str,
+        with_next_move: bool,
+    ) -> NenyrResult<(String, usize)> {
+        let literal_start = self.lexer.token_span().0;
+        let value = self.parse_string_literal(suggestion, error_message, with_next_move)?;
+
+        Ok((value, literal_start))
     }
 
     /// Parses a boolean literal (`true` or `false`) from the current token and optionally
@@ -126,7 +151,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0054"))
     }
 
     /// Parses an identifier literal from the current token.
@@ -178,7 +204,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0055"))
     }
 }
 
@@ -0,0 +1,403 @@
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+use crate::{
+    converters::property::NenyrPropertyConverter,
+    error::{NenyrError, NenyrErrorKind},
+    loop_while_not,
+    tokens::NenyrTokens,
+    validators::identifier::NenyrIdentifierValidator,
+    NenyrParser, NenyrResult,
+};
+
+impl NenyrParser {
+    /// Processes the `Mixin` declaration, which defines a reusable bundle of properties.
+    ///
+    /// # Syntax
+    /// Expected syntax for a mixin declaration:
+    ///
+    /// ```nenyr
+    /// Declare Mixin('flexCenter') {
+    ///     display: 'flex',
+    ///     justifyContent: 'center'
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    /// Returns a tuple containing the mixin name and the properties it declares.
+    ///
+    /// # Errors
+    /// Will return a `NenyrError` if:
+    /// - The mixin name is improperly formatted.
+    /// - A mixin with the same name was already declared within the current context.
+    /// - Curly brackets are missing to delimit the properties block.
+    /// - Any of the properties is not valid.
+    pub(crate) fn process_mixin_method(
+        &mut self,
+    ) -> NenyrResult<(String, IndexMap<String, Arc<str>>)> {
+        self.process_next_token()?;
+
+        let mixin_name = self.retrieve_mixin_name()?;
+
+        if self.processing_state.get_mixin(&mixin_name).is_some() {
+            return Err(NenyrError::new(
+                Some(format!("Ensure that each mixin declared within the context has a unique name. Either rename one of the `{}` mixins or merge their properties into a single declaration.", mixin_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The `{}` mixin has already been declared and cannot be declared again within the same context.", mixin_name),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0056"));
+        }
+
+        self.process_next_token()?;
+
+        let properties = self.parse_curly_bracketed_delimiter(
+            Some(format!("Ensure that the `{}` mixin name declaration is followed by an opening curly bracket `{{` to properly define the properties block. The correct syntax is: `Declare Mixin('{}') {{ display: 'flex', ... }}`.", &mixin_name, &mixin_name)),
+            &format!("An opening curly bracket `{{` was expected after the `{}` mixin name declaration to start the properties block, but it was not found.", &mixin_name),
+            Some(format!("Ensure that the properties block of the mixin is properly closed with a corresponding closing curly bracket `}}`. Example: `Declare Mixin('{}') {{ display: 'flex', ... }}`.", &mixin_name)),
+            &format!("A closing curly bracket `}}` was expected to terminate the properties block of the `{}` mixin, but it was not found.", &mixin_name),
+            |parser| parser.process_mixin_children(&mixin_name),
+        )?;
+
+        self.processing_state
+            .register_mixin_declaration(&mixin_name, properties.clone());
+
+        Ok((mixin_name, properties))
+    }
+
+    /// Retrieves the name of the mixin declared between the parentheses following the
+    /// `Mixin` keyword, ensuring it is a valid identifier.
+    ///
+    /// # Errors
+    /// - A `NenyrError` will be returned if the parentheses are missing, or if the mixin
+    ///   name is empty or contains invalid characters.
+    fn retrieve_mixin_name(&mut self) -> NenyrResult<String> {
+        let (mixin_name, literal_start) = self.parse_parenthesized_delimiter(
+            Some("Ensure that an opening parenthesis `(` is placed after the keyword `Mixin` to properly define the mixin name. The correct syntax is: `Mixin('mixinName') { ... }`.".to_string()),
+            "The declaration block of `Mixin` was expecting an open parenthesis `(` after the keyword `Mixin`, but none was found.",
+            Some("Ensure that the mixin name in the `Mixin` declaration is properly closed with a parenthesis `)`. The correct syntax is: `Mixin('mixinName') { ... }`.".to_string()),
+            "The `Mixin` declaration is missing a closing parenthesis `)` after the mixin name.",
+            |parser| parser.parse_spanned_string_literal(
+                Some("All `Mixin` declarations must have a non-empty string as a name. The name should contain only alphanumeric characters, with the first character being a letter. The correct syntax is: `Mixin('mixinName') { ... }`.".to_string()),
+                "The `Mixin` declaration must receive a name that is a non-empty string, but no mixin name was found.",
+                true
+            ),
+        )?;
+
+        if !self.is_valid_identifier(&mixin_name) {
+            return Err(NenyrError::new(
+                Some("A valid mixin name should contain only alphanumeric characters, with the first character being an alphabetic letter. Examples: `'flexCenter'`, `'cardBase01'`, etc.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error("The validation of the mixin name failed. The provided name does not meet the required format."),
+                NenyrErrorKind::SyntaxError,
+                self.get_identifier_tracing(literal_start, &mixin_name),
+            ).with_code("N0057"));
+        }
+
+        Ok(mixin_name)
+    }
+
+    /// Processes the properties declared within the block of a mixin.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if duplicated or missing commas are found within the block,
+    /// or if any of the properties is not valid.
+    fn process_mixin_children(
+        &mut self,
+        mixin_name: &str,
+    ) -> NenyrResult<IndexMap<String, Arc<str>>> {
+        let mut properties = IndexMap::new();
+
+        loop_while_not!(
+            self,
+            Some(format!("Remove any duplicated commas from the properties block of the `{}` mixin. Ensure proper syntax by following valid delimiters. Example: `Declare Mixin('{}') {{ display: 'flex', justifyContent: 'center', ... }}`.", mixin_name, mixin_name)),
+            &format!("A duplicated comma was found in the properties block of the `{}` mixin. The parser expected to find a new property statement but none was found.", mixin_name),
+            Some(format!("Ensure that a comma is placed after each property definition inside the `{}` mixin to separate elements correctly. Example: `Declare Mixin('{}') {{ display: 'flex', justifyContent: 'center', ... }}`.", mixin_name, mixin_name)),
+            &format!("All properties in the `{}` mixin must be separated by commas. A comma is missing between the properties of the mixin. The parser expected a comma to separate elements but did not find one.", mixin_name),
+            || self.processing_state.is_block_active(),
+            |is_active| self.processing_state.set_block_active(is_active),
+            {
+                self.retrieve_mixin_property(mixin_name, &mut properties)?;
+                self.check_block_properties_limit(
+                    properties.len(),
+                    &format!("the `{}` mixin", mixin_name),
+                )?;
+            }
+        );
+
+        self.processing_state.set_block_active(false);
+
+        Ok(properties)
+    }
+
+    /// Retrieves a single property of a mixin, which must be either a valid Nenyr property
+    /// or an alias, followed by a colon and a non-empty string holding a valid value.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the property is not recognized, if the colon is missing,
+    /// or if the value is empty or could not be validated.
+    fn retrieve_mixin_property(
+        &mut self,
+        mixin_name: &str,
+        properties: &mut IndexMap<String, Arc<str>>,
+    ) -> NenyrResult<()> {
+        self.processing_state.set_block_active(true);
+
+        let property = if let Some(property) =
+            self.convert_nenyr_property_to_css_property(&self.current_token)
+        {
+            property
+        } else if let NenyrTokens::Identifier(nickname) = self.current_token.clone() {
+            let location = format!("the `{}` mixin", mixin_name);
+
+            self.resolve_alias_property(&nickname, &location)?
+        } else {
+            return Err(NenyrError::new(
+                    Some(format!("Ensure that all properties inside the `{}` mixin are either an alias or a valid property. Please verify the documentation to know which properties are valid.", mixin_name)),
+                    self.context_name.clone(),
+                    self.context_path.to_string(),
+                    self.add_nenyr_token_to_error(&format!("One of the properties inside the `{}` mixin is not either an alias or a valid property.", mixin_name)),
+                    NenyrErrorKind::SyntaxError,
+                    self.get_tracing(),
+                ).with_code("N0058"));
+        };
+
+        self.process_next_token()?;
+        self.parse_colon_delimiter(
+            Some(format!("Ensure that each property is defined with a colon after it. The correct syntax is: `Declare Mixin('{}') {{ {}: 'property value', ... }}`.", mixin_name, &property)),
+            &format!("The `{}` property inside the `{}` mixin is missing a colon after the property keyword definition.", &property, mixin_name),
+            true
+        )?;
+
+        let value = self.parse_string_literal(
+            Some(format!("Ensure that all properties are assigned non-empty string values. You can either remove the property or specify a non-empty string value for it: `Declare Mixin('{}') {{ {}: 'property value', ... }}`.", mixin_name, &property)),
+            &format!("The `{}` property inside the `{}` mixin should receive a non-empty string as a value, but none was found.", &property, mixin_name),
+            false
+        )?;
+
+        let location = format!("the `{}` mixin", mixin_name);
+
+        if let Some(value) = self.process_property_value(&property, value, &location)? {
+            properties.insert(property, value);
+
+            return Ok(());
+        }
+
+        Err(NenyrError::new(
+            Some("Ensure that all values are semantically correct to be validated. Please refer to the documentation to verify the correct way to define values.".to_string()),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(&format!("The `{}` property inside the `{}` mixin contains an invalid value, and it could not be validated.", &property, mixin_name)),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code("N0059"))
+    }
+
+    /// Processes the `Include` pattern, which expands the properties of a mixin declared
+    /// within the current context into the stylesheet of the class.
+    ///
+    /// The properties are expanded once the whole class block is parsed, so a property
+    /// declared by the class always takes precedence over the mixin one, regardless of
+    /// where the `Include` pattern is placed.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class including the mixin.
+    /// - `breakpoint_name`: The panoramic breakpoint including the mixin, if any.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the parentheses are missing, or if no mixin with the given
+    /// name was declared within the current context.
+    pub(crate) fn process_include_pattern(
+        &mut self,
+        class_name: &str,
+        breakpoint_name: &Option<String>,
+    ) -> NenyrResult<()> {
+        self.process_next_token()?;
+
+        let mixin_name = self.parse_parenthesized_delimiter(
+            Some(format!("Ensure that the `Include` pattern in the `{}` class is followed by an open parenthesis `(` right after the `Include` keyword. Follow the correct Nenyr syntax: `Include('mixinName')`.", class_name)),
+            &format!("The `{}` class contains an `Include` pattern declaration that was expected to have an open parenthesis `(` right after the keyword `Include`, but none was found.", class_name),
+            Some(format!("Ensure that the `Include` pattern in the `{}` class has a closing parenthesis `)` after the mixin name. Follow the correct Nenyr syntax: `Include('mixinName')`.", class_name)),
+            &format!("The `{}` class contains an `Include` pattern declaration that is missing a closing parenthesis `)` after the mixin name.", class_name),
+            |parser| parser.parse_string_literal(
+                Some(format!("Ensure that the `Include` pattern in the `{}` class receives the name of a mixin as a non-empty string. Follow the correct Nenyr syntax: `Include('mixinName')`.", class_name)),
+                &format!("The `Include` pattern in the `{}` class must receive a mixin name that is a non-empty string, but none was found.", class_name),
+                true
+            ),
+        )?;
+
+        match self.processing_state.get_mixin(&mixin_name) {
+            Some(properties) => {
+                let properties = properties.clone();
+
+                self.processing_state
+                    .register_pending_include(breakpoint_name.clone(), properties);
+
+                Ok(())
+            }
+            None => Err(NenyrError::new(
+                Some(format!("Declare the `{}` mixin within the current context before including it, or remove the `Include('{}')` pattern from the `{}` class. Example: `Declare Mixin('{}') {{ display: 'flex', ... }}`.", mixin_name, mixin_name, class_name, mixin_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The `{}` class includes the `{}` mixin, but no mixin with this name was declared within the current context.", class_name, mixin_name),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0060")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::NenyrErrorKind, types::ast::NenyrAst, NenyrParser};
+
+    #[test]
+    fn mixin_is_valid() {
+        let raw_nenyr = "Mixin('flexCenter') {
+        display: 'flex',
+        justifyContent: 'center'
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_mixin_method()),
+            "Ok((\"flexCenter\", {\"display\": \"flex\", \"justify-content\": \"center\"}))"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn mixin_with_invalid_property_is_not_valid() {
+        let raw_nenyr = "Mixin('flexCenter') {
+        display: 'flex',
+        justifyContent
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_mixin_method()),
+            "Err(NenyrError { error_code: \"N0034\", suggestion: Some(\"Ensure that each property is defined with a colon after it. The correct syntax is: `Declare Mixin('flexCenter') { justify-content: 'property value', ... }`.\"), context_name: None, context_path: \"\", error_message: \"The `justify-content` property inside the `flexCenter` mixin is missing a colon after the property keyword definition. However, found `}` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        justifyContent\"), line_after: None, error_line: Some(\"    }\"), error_on_line: 4, error_on_col: 6, error_on_pos: 75 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn mixin_with_whitespace_only_value_is_not_valid() {
+        let raw_nenyr = "Mixin('flexCenter') {
+        display: 'flex',
+        justifyContent: '   '
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        let error = parser.process_mixin_method().unwrap_err();
+
+        assert_eq!(
+            error.get_error_message(),
+            "The `justify-content` property inside the `flexCenter` mixin receives a value made only of whitespace, which is semantically empty."
+        );
+    }
+
+    #[test]
+    fn included_mixin_is_expanded_into_the_class_stylesheet() {
+        let raw_nenyr = "Construct Central {
+    Declare Mixin('flexCenter') {
+        display: 'flex',
+        justifyContent: 'center'
+    },
+    Declare Class('myCard') {
+        Include('flexCenter'),
+        Stylesheet({ display: 'grid', padding: '10px' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        let Ok(NenyrAst::CentralContext(context)) =
+            parser.parse(raw_nenyr.to_string(), "".to_string())
+        else {
+            panic!("The central context was expected to be parsed.");
+        };
+
+        assert_eq!(
+            format!("{:?}", context.mixins),
+            "Some(NenyrMixins { values: {\"flexCenter\": {\"display\": \"flex\", \"justify-content\": \"center\"}} })".to_string()
+        );
+        assert_eq!(
+            format!("{:?}", context.classes),
+            "Some({\"myCard\": NenyrStyleClass { class_name: \"myCard\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"justify-content\": \"center\", \"display\": \"grid\", \"padding\": \"10px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: None, media_patterns: None }})".to_string()
+        );
+    }
+
+    #[test]
+    fn mixin_exceeding_the_properties_limit_is_not_valid() {
+        let raw_nenyr = "Mixin('flexCenter') {
+        display: 'flex',
+        justifyContent: 'center',
+        alignItems: 'center'
+    }";
+        let mut parser = NenyrParser::new();
+
+        parser.set_max_block_properties(2);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        let error = parser.process_mixin_method().unwrap_err();
+
+        assert_eq!(error.get_error_kind(), NenyrErrorKind::ValidationError);
+        assert_eq!(
+            error.get_error_message(),
+            "The properties block of the `flexCenter` mixin declares more than 2 properties, exceeding the configured limit."
+        );
+    }
+
+    #[test]
+    fn included_mixin_exceeding_the_properties_limit_is_not_valid() {
+        let raw_nenyr = "Construct Central {
+    Declare Mixin('flexCenter') {
+        display: 'flex',
+        justifyContent: 'center'
+    },
+    Declare Class('myCard') {
+        Include('flexCenter'),
+        Stylesheet({ padding: '10px', margin: '0' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        parser.set_max_block_properties(2);
+
+        let error = parser
+            .parse(raw_nenyr.to_string(), "".to_string())
+            .unwrap_err();
+
+        assert_eq!(error.get_error_kind(), NenyrErrorKind::ValidationError);
+        assert_eq!(
+            error.get_error_message(),
+            "The properties block of one of the patterns in the `myCard` class declares more than 2 properties, exceeding the configured limit."
+        );
+    }
+
+    #[test]
+    fn unknown_mixin_is_not_valid() {
+        let raw_nenyr = "Construct Central {
+    Declare Class('myCard') {
+        Include('flexCenter')
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        assert_eq!(
+            format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string())),
+            "Err(NenyrError { error_code: \"N0060\", suggestion: Some(\"Declare the `flexCenter` mixin within the current context before including it, or remove the `Include('flexCenter')` pattern from the `myCard` class. Example: `Declare Mixin('flexCenter') { display: 'flex', ... }`.\"), context_name: Some(\"Central\"), context_path: \"\", error_message: \"The `myCard` class includes the `flexCenter` mixin, but no mixin with this name was declared within the current context.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: Some(\"    Declare Class('myCard') {\"), line_after: Some(\"    }\"), error_line: Some(\"        Include('flexCenter')\"), error_on_line: 3, error_on_col: 30, error_on_pos: 79 } })".to_string()
+        );
+    }
+}
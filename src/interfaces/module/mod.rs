@@ -2,7 +2,7 @@ use crate::{
     error::{NenyrError, NenyrErrorKind},
     loop_while_not,
     tokens::NenyrTokens,
-    types::module::ModuleContext,
+    types::{declaration::NenyrDeclaration, module::ModuleContext},
     validators::identifier::NenyrIdentifierValidator,
     NenyrParser, NenyrResult,
 };
@@ -128,13 +128,13 @@ impl NenyrParser {
         suggestion_on_invalid: Option<String>,
         error_message_on_invalid: &str,
     ) -> NenyrResult<String> {
-        let module_name = self.parse_parenthesized_delimiter(
+        let (module_name, literal_start) = self.parse_parenthesized_delimiter(
             suggestion_on_open,
             error_message_on_open,
             suggestion_on_close,
             error_message_on_close,
             |parser| {
-                parser.parse_string_literal(
+                parser.parse_spanned_string_literal(
                     suggestion_on_parse_literal.clone(),
                     error_message_on_parse_literal,
                     true,
@@ -149,8 +149,9 @@ impl NenyrParser {
                 self.context_path.to_string(),
                 self.add_nenyr_token_to_error(error_message_on_invalid),
                 NenyrErrorKind::SyntaxError,
-                self.get_tracing(),
-            ));
+                self.get_identifier_tracing(literal_start, &module_name),
+            )
+            .with_code("N0061"));
         }
 
         Ok(module_name)
@@ -187,6 +188,10 @@ impl NenyrParser {
         let mut module_context =
             ModuleContext::new(module_name.to_string(), extending_from.clone());
 
+        // Refills the maps of the AST given to `parse_into`, if any, instead of allocating new ones.
+        (module_context.animations, module_context.classes) =
+            std::mem::take(&mut self.reusable_maps);
+
         loop_while_not!(
             self,
             Some("Remove any duplicated commas from the module context to ensure proper syntax. The parser expects the `Declare` keyword to follow valid delimiters. Example: `Construct Module('moduleName') { Declare Class({ ... }), Declare Animation({ ... }), ... }`.".to_string()),
@@ -260,26 +265,25 @@ impl NenyrParser {
     fn process_module_methods(&mut self, module_context: &mut ModuleContext) -> NenyrResult<()> {
         self.processing_state.set_context_active(true);
 
-        match self.current_token {
-            NenyrTokens::Aliases => {
-                let aliases = self.process_aliases_method()?;
-
-                module_context.add_aliases_to_context(aliases);
-            }
+        let declaration = match self.current_token {
+            NenyrTokens::Aliases => NenyrDeclaration::Aliases(self.process_aliases_method(false)?),
             NenyrTokens::Variables => {
-                let variables = self.process_variables_method(false)?;
+                NenyrDeclaration::Variables(self.process_variables_method(false)?)
+            }
+            NenyrTokens::Mixin => {
+                let (mixin_name, properties) = self.process_mixin_method()?;
 
-                module_context.add_variables_to_context(variables);
+                NenyrDeclaration::Mixin(mixin_name, properties)
             }
-            NenyrTokens::Animation => {
+            NenyrTokens::Animation | NenyrTokens::Keyframes => {
                 let (animation_name, animation) = self.process_animation_method()?;
 
-                module_context.add_animation_to_context(animation_name, animation);
+                NenyrDeclaration::Animation(animation_name, animation)
             }
             NenyrTokens::Class => {
                 let (class_name, style_class) = self.process_class_method()?;
 
-                module_context.add_style_class_to_context(class_name, style_class);
+                NenyrDeclaration::Class(class_name, Box::new(style_class))
             }
             _ => {
                 return Err(NenyrError::new(
@@ -289,8 +293,18 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error("One of the methods in the module context is either not a valid Nenyr method or is not supported within the module context."),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0062"));
             }
+        };
+
+        self.stats.count_declaration();
+        self.collect_declaration_comments(&declaration);
+
+        if let Some(declaration) = self
+            .declaration_sink
+            .consume(declaration, self.options.keep_sunk_declarations)
+        {
+            module_context.add_declaration_to_context(declaration);
         }
 
         Ok(())
@@ -438,10 +452,29 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_module_context()),
-            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) })".to_string()
+            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) })".to_string()
         );
     }
 
+    #[test]
+    fn empty_module_is_valid() {
+        for raw_nenyr in [
+            "Module('ultimateFeel') Extending('hellishAdobe') {}",
+            "Module('ultimateFeel') Extending('hellishAdobe') {\n\n}",
+        ] {
+            let mut parser = NenyrParser::new();
+
+            parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+            let _ = parser.process_next_token();
+
+            assert_eq!(
+                format!("{:?}", parser.process_module_context()),
+                "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: None, variables: None, mixins: None, animations: None, classes: None })".to_string()
+            );
+        }
+    }
+
     #[test]
     fn only_aliases_module_is_valid() {
         let raw_nenyr = "Module('ultimateFeel') Extending('hellishAdobe') {
@@ -460,7 +493,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_module_context()),
-            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: None, animations: None, classes: None })".to_string()
+            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: None, mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -483,7 +516,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_module_context()),
-            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: None, variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), animations: None, classes: None })".to_string()
+            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: None, variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), mixins: None, animations: None, classes: None })".to_string()
         );
     }
 
@@ -559,7 +592,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_module_context()),
-            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: None, variables: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: None })".to_string()
+            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: None, variables: None, mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: None })".to_string()
         );
     }
 
@@ -620,7 +653,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", parser.process_module_context()),
-            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: None, variables: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) })".to_string()
+            "Ok(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: None, variables: None, mixins: None, animations: None, classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) })".to_string()
         );
     }
 }
@@ -3,7 +3,7 @@ use crate::{
     loop_while_not,
     tokens::NenyrTokens,
     types::class::NenyrStyleClass,
-    validators::identifier::NenyrIdentifierValidator,
+    validators::{breakpoint::NenyrBreakpointValidator, identifier::NenyrIdentifierValidator},
     NenyrParser, NenyrResult,
 };
 
@@ -121,6 +121,10 @@ impl NenyrParser {
         class_name: &str,
         style_class: &mut NenyrStyleClass,
     ) -> NenyrResult<()> {
+        if let NenyrTokens::StringLiteral { value, .. } = self.current_token.clone() {
+            return self.retrieve_panoramic_media_type(class_name, value, style_class);
+        }
+
         let breakpoint_name = self.parse_identifier_literal(
             Some(format!("Ensure that the breakpoint is a valid identifier as specified within the `Breakpoints` declaration block in the central context. The identifier must match one of the names used to define a breakpoint. Example: `Class('{}') {{ PanoramicViewer({{ validBreakpointIdentifier({{ ... }}), ... }}) }}`.", class_name)),
             &format!("The `PanoramicViewer` pattern in the `{}` class contains an unrecognized breakpoint identifier.", class_name),
@@ -135,12 +139,82 @@ impl NenyrParser {
                 self.add_nenyr_token_to_error(&format!("The current `{}` breakpoint of the panoramic pattern in the `{}` class failed to be validated.", breakpoint_name, class_name)),
                 NenyrErrorKind::SyntaxError,
                 self.get_tracing(),
-            ));
+            ).with_code("N0063"));
         }
 
         self.processing_state.set_complementary_block_active(true);
         style_class.reset_panoramic_node(&breakpoint_name);
 
+        self.process_panoramic_entry(class_name, &breakpoint_name, style_class)
+    }
+
+    /// Retrieves and validates a media type within the panoramic block, such as the
+    /// `'print'` of `PanoramicViewer({ 'print'({ ... }) })`.
+    ///
+    /// The patterns of the media type are stored apart from the breakpoints ones, in the
+    /// `media_patterns` of the style class, so that they are emitted as `@media print { ... }`.
+    ///
+    /// # Arguments
+    /// - `class_name`: The class in which the media type is declared.
+    /// - `media_type`: The media type, as written between the quotes.
+    /// - `style_class`: A mutable reference to the style class being modified.
+    ///
+    /// # Errors
+    /// - Throws a `ValidationError` if the media type is not one of `all`, `print`, `screen`
+    ///   or `speech`, or an error if delimiters are missing.
+    fn retrieve_panoramic_media_type(
+        &mut self,
+        class_name: &str,
+        media_type: String,
+        style_class: &mut NenyrStyleClass,
+    ) -> NenyrResult<()> {
+        if !self.is_valid_media_type(&media_type) {
+            return Err(NenyrError::new(
+                Some(format!("Use one of the `all`, `print`, `screen` or `speech` media types, or an unquoted breakpoint identifier. Example: `Class('{}') {{ PanoramicViewer({{ 'print'({{ ... }}), ... }}) }}`.", class_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The `PanoramicViewer` pattern in the `{}` class contains the `{}` media type, which is not a valid media type.", class_name, media_type),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0064"));
+        }
+
+        self.process_next_token()?;
+        self.processing_state.set_complementary_block_active(true);
+
+        // The patterns are parsed apart so they never mix with a breakpoint of the same name.
+        let mut media_class = NenyrStyleClass::new(class_name.to_string(), None);
+        media_class.reset_panoramic_node(&media_type);
+
+        self.process_panoramic_entry(class_name, &media_type, &mut media_class)?;
+
+        let patterns = media_class
+            .responsive_patterns
+            .and_then(|mut responsive_patterns| responsive_patterns.shift_remove(&media_type))
+            .unwrap_or_default();
+
+        style_class.add_media_patterns(media_type, patterns);
+
+        Ok(())
+    }
+
+    /// Parses the parenthesized block of patterns following a breakpoint identifier or a
+    /// media type within the panoramic block.
+    ///
+    /// # Arguments
+    /// - `class_name`: The class in which the block is declared.
+    /// - `breakpoint_name`: The breakpoint identifier or the media type owning the block.
+    /// - `style_class`: A mutable reference to the style class receiving the patterns.
+    ///
+    /// # Errors
+    /// - Throws an error if the parentheses or the curly brackets are missing, or if any of
+    ///   the patterns is invalid.
+    fn process_panoramic_entry(
+        &mut self,
+        class_name: &str,
+        breakpoint_name: &str,
+        style_class: &mut NenyrStyleClass,
+    ) -> NenyrResult<()> {
         // First, parse the expression within the parentheses.
         self.parse_parenthesized_delimiter(
             Some(format!("Ensure that each breakpoint statement must be followed by an opening parenthesis. Correct syntax is required for proper declaration. Example: `Class('{}') {{ PanoramicViewer({{ {}({{ ... }}), ... }}) }}`.", class_name, breakpoint_name)),
@@ -155,7 +229,7 @@ impl NenyrParser {
                     Some(format!("Ensure that the `{}` breakpoint's patterns statement is properly closed with a curly bracket `}}`. Correct syntax should be: `Class('{}') {{ PanoramicViewer({{ {}({{ ... }}), ... }}) }}`.", breakpoint_name, class_name, breakpoint_name)),
                     &format!("A missing closing curly bracket `}}` was expected to properly close the `{}` breakpoint's patterns statement in the `{}` class.", breakpoint_name, class_name),
                     |parser| {
-                        parser.process_panoramic_children(class_name, &breakpoint_name, style_class)
+                        parser.process_panoramic_children(class_name, breakpoint_name, style_class)
                     },
                 )?;
 
@@ -224,6 +298,8 @@ impl NenyrParser {
 
 #[cfg(test)]
 mod tests {
+    use indexmap::IndexMap;
+
     use crate::{types::class::NenyrStyleClass, NenyrParser};
 
     #[test]
@@ -239,13 +315,13 @@ mod tests {
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "background-color".to_string(),
-            "blue".to_string(),
+            "blue".into(),
         );
         styles.add_responsive_style_rule(
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "border".to_string(),
-            "10px solid red".to_string(),
+            "10px solid red".into(),
         );
 
         let _ = parser.process_panoramic_pattern("myClassName", &mut style_class);
@@ -266,7 +342,7 @@ mod tests {
                 "{:?}",
                 parser.process_panoramic_pattern("myClassName", &mut style_class)
             ),
-            "Err(NenyrError { suggestion: Some(\"After the open parenthesis, an opening curly bracket `{` is required to properly define the properties block in `myClassName` class. Ensure the pattern follows the correct Nenyr syntax, such as `Class('myClassName') { Stylesheet({ ... }), Hover({ ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `myClassName` class was expected to receive an object as a value, but an opening curly bracket `{` was not found after the open parenthesis. However, found `BackgroundColor` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"({ myBreakpoint({ Stylesheet( backgroundColor: 'blue', border: '10px solid red' }) }) })\"), error_on_line: 1, error_on_col: 46, error_on_pos: 45 } })".to_string()
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"After the open parenthesis, an opening curly bracket `{` is required to properly define the properties block in `myClassName` class. Ensure the pattern follows the correct Nenyr syntax, such as `Class('myClassName') { Stylesheet({ ... }), Hover({ ... }), ... }`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `myClassName` class was expected to receive an object as a value, but an opening curly bracket `{` was not found after the open parenthesis. However, found `BackgroundColor` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"({ myBreakpoint({ Stylesheet( backgroundColor: 'blue', border: '10px solid red' }) }) })\"), error_on_line: 1, error_on_col: 46, error_on_pos: 45 } })".to_string()
         );
     }
 
@@ -284,13 +360,13 @@ mod tests {
             "myBreakpoint".to_string(),
             "::after".to_string(),
             "background-color".to_string(),
-            "blue".to_string(),
+            "blue".into(),
         );
         styles.add_responsive_style_rule(
             "myBreakpoint".to_string(),
             "::after".to_string(),
             "border".to_string(),
-            "10px solid red".to_string(),
+            "10px solid red".into(),
         );
 
         let _ = parser.process_panoramic_pattern("myClassName", &mut style_class);
@@ -312,7 +388,52 @@ mod tests {
                 "{:?}",
                 parser.process_panoramic_pattern("myClassName", &mut style_class)
             ),
-            "Err(NenyrError { suggestion: Some(\"Ensure that a comma is placed after each breakpoint block inside the `myClassName` class to separate elements correctly. Proper syntax is required for the parser to process the context. Example: `Declare Class('myClassName') { PanoramicViewer({ myBreakpoint({ ... }) }) }`.\"), context_name: None, context_path: \"\", error_message: \"All breakpoint inside the `myClassName` class block must be separated by commas. A comma is missing after the breakpoint block definition. The parser expected a comma to separate elements but did not find one. However, found `)` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"({ myBreakpoint({ After({ backgroundColor: 'blue', border: '10px solid red' }) ) })\"), error_on_line: 1, error_on_col: 81, error_on_pos: 80 } })".to_string()
+            "Err(NenyrError { error_code: \"N0099\", suggestion: Some(\"Ensure that a comma is placed after each breakpoint block inside the `myClassName` class to separate elements correctly. Proper syntax is required for the parser to process the context. Example: `Declare Class('myClassName') { PanoramicViewer({ myBreakpoint({ ... }) }) }`.\"), context_name: None, context_path: \"\", error_message: \"All breakpoint inside the `myClassName` class block must be separated by commas. A comma is missing after the breakpoint block definition. The parser expected a comma to separate elements but did not find one. However, found `)` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"({ myBreakpoint({ After({ backgroundColor: 'blue', border: '10px solid red' }) ) })\"), error_on_line: 1, error_on_col: 81, error_on_pos: 80 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn panoramic_viewer_media_type_is_valid() {
+        let raw_nenyr = "({ myBreakpoint({ Stylesheet({ color: 'blue' }) }), 'print'({ Stylesheet({ display: 'none' }) }) })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        styles.add_responsive_style_rule(
+            "myBreakpoint".to_string(),
+            "_stylesheet".to_string(),
+            "color".to_string(),
+            "blue".into(),
+        );
+        styles.add_media_patterns(
+            "print".to_string(),
+            IndexMap::from([(
+                "_stylesheet".to_string(),
+                IndexMap::from([("display".to_string(), "none".into())]),
+            )]),
+        );
+
+        let _ = parser.process_panoramic_pattern("myClassName", &mut style_class);
+
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn panoramic_viewer_media_type_is_not_valid() {
+        let raw_nenyr = "({ 'television'({ Stylesheet({ display: 'none' }) }) })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_panoramic_pattern("myClassName", &mut style_class)
+            ),
+            "Err(NenyrError { error_code: \"N0064\", suggestion: Some(\"Use one of the `all`, `print`, `screen` or `speech` media types, or an unquoted breakpoint identifier. Example: `Class('myClassName') { PanoramicViewer({ 'print'({ ... }), ... }) }`.\"), context_name: None, context_path: \"\", error_message: \"The `PanoramicViewer` pattern in the `myClassName` class contains the `television` media type, which is not a valid media type.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"({ 'television'({ Stylesheet({ display: 'none' }) }) })\"), error_on_line: 1, error_on_col: 16, error_on_pos: 15 } })".to_string()
         );
     }
 }
@@ -2,9 +2,10 @@ use crate::{
     converters::{property::NenyrPropertyConverter, style_pattern::NenyrStylePatternConverter},
     error::{NenyrError, NenyrErrorKind},
     loop_while_not,
+    options::DuplicatedStylesheetPolicy,
     tokens::NenyrTokens,
     types::class::NenyrStyleClass,
-    validators::style_syntax::NenyrStyleSyntaxValidator,
+    validators::pseudo_argument::NenyrPseudoArgumentValidator,
     NenyrParser, NenyrResult,
 };
 
@@ -12,9 +13,9 @@ impl NenyrParser {
     /// Processes the pattern methods declared within a Nenyr style class.
     ///
     /// This method parses tokens that represent patterns such as `Important`, `PanoramicViewer`,
-    /// and others, and handles the state transitions required for applying styles. It differentiates
-    /// between normal and panoramic contexts and ensures that nested patterns like `PanoramicViewer`
-    /// are correctly handled or flagged as errors.
+    /// `Supports`, `Container`, and others, and handles the state transitions required for applying
+    /// styles. It differentiates between normal and panoramic contexts and ensures that nested patterns
+    /// like `PanoramicViewer` are correctly handled or flagged as errors.
     ///
     /// # Arguments
     /// - `class_name`: A string representing the name of the class being parsed.
@@ -58,11 +59,58 @@ impl NenyrParser {
                         self.add_nenyr_token_to_error(&format!("The `{}` class contains a nested `PanoramicViewer` declaration, which is forbidden. Nested `PanoramicViewer` patterns are not allowed in Nenyr syntax.", class_name)),
                         NenyrErrorKind::SyntaxError,
                         self.get_tracing(),
-                    ));
+                    ).with_code("N0065"));
                 }
 
                 return self.process_panoramic_pattern(class_name, style_class);
             }
+            NenyrTokens::Supports => {
+                if is_panoramic {
+                    return Err(NenyrError::new(
+                        Some(format!("Move the `Supports` pattern out of the `PanoramicViewer` pattern. The `Supports` method must be used as a direct child of the class. Example: `Declare Class('{}') {{ Supports('(display: grid)', {{ ... }}) }}`.", class_name)),
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        self.add_nenyr_token_to_error(&format!("The `{}` class contains a `Supports` declaration nested within a `PanoramicViewer` pattern, which is forbidden.", class_name)),
+                        NenyrErrorKind::SyntaxError,
+                        self.get_tracing(),
+                    ).with_code("N0066"));
+                }
+
+                return self.process_supports_pattern(class_name, style_class);
+            }
+            NenyrTokens::Container => {
+                if is_panoramic {
+                    return Err(NenyrError::new(
+                        Some(format!("Move the `Container` pattern out of the `PanoramicViewer` pattern. The `Container` method must be used as a direct child of the class. Example: `Declare Class('{}') {{ Container('(min-width: 400px)', {{ ... }}) }}`.", class_name)),
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        self.add_nenyr_token_to_error(&format!("The `{}` class contains a `Container` declaration nested within a `PanoramicViewer` pattern, which is forbidden.", class_name)),
+                        NenyrErrorKind::SyntaxError,
+                        self.get_tracing(),
+                    ).with_code("N0067"));
+                }
+
+                return self.process_container_pattern(class_name, style_class);
+            }
+            NenyrTokens::Group => {
+                return self.process_group_pattern(
+                    class_name,
+                    is_panoramic,
+                    style_class,
+                    breakpoint_name,
+                );
+            }
+            NenyrTokens::Include => {
+                return self.process_include_pattern(class_name, breakpoint_name);
+            }
+            NenyrTokens::Not | NenyrTokens::NthChild | NenyrTokens::Attribute => {
+                return self.process_functional_pseudo_pattern(
+                    class_name,
+                    is_panoramic,
+                    style_class,
+                    breakpoint_name,
+                );
+            }
             _ => {
                 if let Some(pattern_name) =
                     self.convert_nenyr_style_pattern_to_pseudo_selector(&self.current_token)
@@ -85,7 +133,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&format!("The `{}` class contains an invalid pattern statement. Please ensure that all methods within the class are correctly defined and formatted.", class_name)),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0068"))
     }
 
     /// Retrieves the `Important` value associated with a pattern.
@@ -119,6 +167,48 @@ impl NenyrParser {
         )
     }
 
+    /// Handles a `Stylesheet` pattern declared more than once in the same scope, according to
+    /// the `DuplicatedStylesheetPolicy` set in the parser options: its properties are either
+    /// merged into the ones declared before, merged with a warning, or rejected with an error.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class declaring the pattern.
+    /// - `breakpoint_name`: The breakpoint or media type containing the pattern, if any.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the policy is `DuplicatedStylesheetPolicy::Error`.
+    fn check_duplicated_stylesheet(
+        &mut self,
+        class_name: &str,
+        breakpoint_name: &Option<String>,
+    ) -> NenyrResult<()> {
+        let scope = match breakpoint_name {
+            Some(breakpoint_name) => format!(
+                "the `{}` breakpoint of the `{}` class",
+                breakpoint_name, class_name
+            ),
+            None => format!("the `{}` class", class_name),
+        };
+        let suggestion = format!("Move the properties of the duplicated `Stylesheet` pattern into the first one, declaring a single `Stylesheet` pattern in {}.", scope);
+
+        match self.options.duplicated_stylesheet_policy {
+            DuplicatedStylesheetPolicy::Error => Err(NenyrError::new(
+                Some(suggestion),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The `Stylesheet` pattern is declared more than once in {}, but only a single `Stylesheet` pattern is allowed per scope.", scope),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0103")),
+            DuplicatedStylesheetPolicy::Warn => self.add_warning(
+                "W0008",
+                Some(suggestion),
+                format!("The `Stylesheet` pattern is declared more than once in {}, so its blocks are merged into a single rule.", scope),
+            ),
+            DuplicatedStylesheetPolicy::Merge => Ok(()),
+        }
+    }
+
     /// Handles sections enclosed in both parentheses and curly brackets for a given pattern.
     ///
     /// This method processes tokens for patterns that require both parentheses and curly brackets,
@@ -145,6 +235,12 @@ impl NenyrParser {
         style_class: &mut NenyrStyleClass,
         breakpoint_name: &Option<String>,
     ) -> NenyrResult<()> {
+        if pattern_name == "_stylesheet"
+            && style_class.has_pattern_node(breakpoint_name.as_deref(), pattern_name)
+        {
+            self.check_duplicated_stylesheet(class_name, breakpoint_name)?;
+        }
+
         self.process_next_token()?;
 
         // First, parse the expression within the parentheses.
@@ -177,6 +273,348 @@ impl NenyrParser {
         )
     }
 
+    /// Processes the functional pseudo-class patterns declared within a Nenyr style class.
+    ///
+    /// The `Not` and `NthChild` patterns receive an argument before their block of properties,
+    /// which is emitted inside the `:not(...)` and `:nth-child(...)` pseudo-classes respectively,
+    /// while the `Attribute` pattern receives an attribute selector emitted as is:
+    ///
+    /// ```nenyr
+    /// Class('className') {
+    ///     Not('.disabled', { opacity: '1' }),
+    ///     NthChild('odd', { backgroundColor: 'gray' }),
+    ///     Attribute('[data-state="open"]', { display: 'block' })
+    /// }
+    /// ```
+    ///
+    /// The properties are stored in the style patterns of the class, keyed by the full
+    /// pseudo-class, e.g. `:not(.disabled)` or `:nth-child(odd)`, or by the attribute
+    /// selector, e.g. `[data-state="open"]`.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the pattern is declared.
+    /// - `is_panoramic`: A boolean indicating whether the current context is panoramic.
+    /// - `style_class`: A mutable reference to the `NenyrStyleClass` receiving the properties.
+    /// - `breakpoint_name`: An optional string representing a breakpoint for responsive design.
+    ///
+    /// # Errors
+    /// - Returns an error if the parentheses, the comma or the curly brackets are missing.
+    /// - Returns an error if the argument is not a valid selector for `Not`, a valid
+    ///   `An+B` expression for `NthChild`, or a valid attribute selector for `Attribute`.
+    /// - Returns an error if any of the properties or values inside the block are invalid.
+    fn process_functional_pseudo_pattern(
+        &mut self,
+        class_name: &str,
+        is_panoramic: bool,
+        style_class: &mut NenyrStyleClass,
+        breakpoint_name: &Option<String>,
+    ) -> NenyrResult<()> {
+        let (keyword, pseudo_class, example) = match self.current_token {
+            NenyrTokens::Not => ("Not", ":not", "'.disabled'"),
+            NenyrTokens::Attribute => ("Attribute", "", "'[data-state=\"open\"]'"),
+            _ => ("NthChild", ":nth-child", "'2n + 1'"),
+        };
+
+        self.process_next_token()?;
+
+        self.parse_parenthesized_delimiter(
+            Some(format!("Ensure that the `{}` pattern in `{}` class is followed by an open parenthesis `(` right after the `{}` keyword. Follow the correct Nenyr syntax: `Class('{}') {{ {}({}, {{ ... }}) }}`.", keyword, class_name, keyword, class_name, keyword, example)),
+            &format!("The `{}` class contains a `{}` pattern declaration that was expected to have an open parenthesis `(` right after the keyword `{}`, but none was found.", class_name, keyword, keyword),
+            Some(format!("Ensure that the `{}` pattern in `{}` class has a closing parenthesis `)` after the properties block to properly complete the declaration. Follow the correct Nenyr syntax: `Class('{}') {{ {}({}, {{ ... }}) }}`.", keyword, class_name, class_name, keyword, example)),
+            &format!("The `{}` class contains a `{}` pattern declaration that is missing a closing parenthesis `)` after the properties block.", class_name, keyword),
+            |parser| {
+                let argument = parser.retrieve_pseudo_argument(keyword, class_name, example)?;
+                let pattern_name = match keyword {
+                    "Attribute" => argument,
+                    _ => format!("{}({})", pseudo_class, argument),
+                };
+
+                parser.process_comma_after_pseudo_argument(
+                    keyword,
+                    &pattern_name,
+                    class_name,
+                    is_panoramic,
+                    style_class,
+                    breakpoint_name,
+                )
+            },
+        )
+    }
+
+    /// Processes the `Group` pattern declared within a Nenyr style class.
+    ///
+    /// The `Group` pattern receives a list of style patterns followed by a single block of
+    /// properties, which is applied to every listed pattern:
+    ///
+    /// ```nenyr
+    /// Class('className') {
+    ///     Group([Hover, Focus], { color: 'red' })
+    /// }
+    /// ```
+    ///
+    /// The group is expanded while parsing, so each listed pattern receives its own entry in
+    /// the style patterns of the class, exactly as if it had been declared individually.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the pattern is declared.
+    /// - `is_panoramic`: A boolean indicating whether the current context is panoramic.
+    /// - `style_class`: A mutable reference to the `NenyrStyleClass` receiving the properties.
+    /// - `breakpoint_name`: An optional string representing a breakpoint for responsive design.
+    ///
+    /// # Errors
+    /// - Returns an error if the parentheses, the square brackets, the comma or the curly
+    ///   brackets are missing.
+    /// - Returns an error if the list is empty or contains anything other than a pseudo pattern.
+    /// - Returns an error if any of the properties or values inside the block are invalid.
+    fn process_group_pattern(
+        &mut self,
+        class_name: &str,
+        is_panoramic: bool,
+        style_class: &mut NenyrStyleClass,
+        breakpoint_name: &Option<String>,
+    ) -> NenyrResult<()> {
+        self.process_next_token()?;
+
+        self.parse_parenthesized_delimiter(
+            Some(format!("Ensure that the `Group` pattern in `{}` class is followed by an open parenthesis `(` right after the `Group` keyword. Follow the correct Nenyr syntax: `Class('{}') {{ Group([Hover, Focus], {{ ... }}) }}`.", class_name, class_name)),
+            &format!("The `{}` class contains a `Group` pattern declaration that was expected to have an open parenthesis `(` right after the keyword `Group`, but none was found.", class_name),
+            Some(format!("Ensure that the `Group` pattern in `{}` class has a closing parenthesis `)` after the properties block to properly complete the declaration. Follow the correct Nenyr syntax: `Class('{}') {{ Group([Hover, Focus], {{ ... }}) }}`.", class_name, class_name)),
+            &format!("The `{}` class contains a `Group` pattern declaration that is missing a closing parenthesis `)` after the properties block.", class_name),
+            |parser| {
+                let pattern_names = parser.parse_square_bracketed_delimiter(
+                    Some(format!("Ensure that the `Group` pattern in `{}` class receives a list of patterns enclosed by square brackets as its first argument. Correct syntax: `Group([Hover, Focus], {{ ... }})`.", class_name)),
+                    &format!("The `Group` pattern in the `{}` class was expected to receive a list of patterns as its first argument, but an opening square bracket `[` was not found.", class_name),
+                    Some(format!("Ensure that the list of patterns of the `Group` pattern in `{}` class is closed with a closing square bracket `]`. Correct syntax: `Group([Hover, Focus], {{ ... }})`.", class_name)),
+                    &format!("The list of patterns of the `Group` pattern in the `{}` class is missing a closing square bracket `]`.", class_name),
+                    |parser| parser.retrieve_group_pattern_names(class_name),
+                )?;
+
+                parser.process_next_token()?;
+                parser.process_comma_after_group_patterns(
+                    &pattern_names,
+                    class_name,
+                    is_panoramic,
+                    style_class,
+                    breakpoint_name,
+                )
+            },
+        )
+    }
+
+    /// Retrieves the pseudo patterns listed by a `Group` pattern, converted into their
+    /// selector keys and without duplicates.
+    ///
+    /// # Errors
+    /// - Returns an error if the list is empty, if a listed item is not a pseudo pattern,
+    ///   or if the items are not separated by commas.
+    fn retrieve_group_pattern_names(&mut self, class_name: &str) -> NenyrResult<Vec<String>> {
+        let mut pattern_names: Vec<String> = vec![];
+        let mut is_comma_expected = false;
+
+        loop_while_not!(
+            self,
+            Some(format!("Remove any duplicated commas from the list of patterns of the `Group` pattern in the `{}` class. Example: `Group([Hover, Focus], {{ ... }})`.", class_name)),
+            &format!("A duplicated comma was found in the list of patterns of the `Group` pattern in the `{}` class. The parser expected to find a new pattern but none was found.", class_name),
+            Some(format!("Ensure that a comma is placed after each pattern in the list of the `Group` pattern in the `{}` class. Example: `Group([Hover, Focus], {{ ... }})`.", class_name)),
+            &format!("The patterns listed by the `Group` pattern in the `{}` class must be separated by commas. The parser expected a comma to separate elements but did not find one.", class_name),
+            || is_comma_expected,
+            |is_active| is_comma_expected = is_active,
+            {
+                is_comma_expected = true;
+
+                let pattern_name = match self.current_token {
+                    NenyrTokens::Stylesheet => None,
+                    _ => self.convert_nenyr_style_pattern_to_pseudo_selector(&self.current_token),
+                };
+
+                match pattern_name {
+                    Some(pattern_name) if !pattern_names.contains(&pattern_name) => {
+                        pattern_names.push(pattern_name)
+                    }
+                    Some(_) => {}
+                    None => {
+                        return Err(NenyrError::new(
+                            Some("The `Group` pattern only accepts pseudo patterns, such as `Hover`, `Focus`, `Active`, `Before` or `After`. Please refer to the documentation to verify which patterns are permitted.".to_string()),
+                            self.context_name.clone(),
+                            self.context_path.to_string(),
+                            self.add_nenyr_token_to_error(&format!("The `Group` pattern in the `{}` class lists an item that is not a valid pseudo pattern.", class_name)),
+                            NenyrErrorKind::SyntaxError,
+                            self.get_tracing(),
+                        ).with_code("N0069"));
+                    }
+                }
+            }
+        );
+
+        if pattern_names.is_empty() {
+            return Err(NenyrError::new(
+                Some(format!("List at least one pseudo pattern in the `Group` pattern of the `{}` class, or remove the empty group. Example: `Group([Hover, Focus], {{ ... }})`.", class_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The `Group` pattern in the `{}` class lists no pattern to be styled.", class_name),
+                NenyrErrorKind::ValidationError,
+                self.get_tracing(),
+            ).with_code("N0070"));
+        }
+
+        Ok(pattern_names)
+    }
+
+    /// Ensures that the list of patterns of a `Group` pattern is followed by a comma, parses
+    /// the properties block under the first listed pattern, and copies it to the others.
+    ///
+    /// # Errors
+    /// - Returns an error if the comma after the list is missing or if the properties
+    ///   block is not properly enclosed by curly brackets.
+    fn process_comma_after_group_patterns(
+        &mut self,
+        pattern_names: &[String],
+        class_name: &str,
+        is_panoramic: bool,
+        style_class: &mut NenyrStyleClass,
+        breakpoint_name: &Option<String>,
+    ) -> NenyrResult<()> {
+        let NenyrTokens::Comma = self.current_token else {
+            return Err(NenyrError::new(
+                Some(format!("Ensure that a comma is placed after the list of patterns of the `Group` pattern in the `{}` class. The correct syntax is: `Group([Hover, Focus], {{ propertyName: 'value', ... }})`.", class_name)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("A comma was expected after the list of patterns of the `Group` pattern in the `{}` class, but none was found. The parser requires a comma to separate the list from the subsequent object of properties.", class_name)),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0071"));
+        };
+
+        self.process_next_token()?;
+
+        let source_pattern = &pattern_names[0];
+
+        self.parse_curly_bracketed_delimiter(
+            Some(format!("After the list of patterns, an opening curly bracket `{{` is required to properly define the properties block of the `Group` pattern in `{}` class. Ensure the pattern follows the correct Nenyr syntax, such as `Class('{}') {{ Group([Hover, Focus], {{ ... }}) }}`.", class_name, class_name)),
+            &format!("The `Group` pattern in the `{}` class was expected to receive an object of properties as the second argument, but an opening curly bracket `{{` was not found after the list of patterns.", class_name),
+            Some(format!("Ensure that the properties block of the `Group` pattern in `{}` class is properly closed with a closing curly bracket `}}`. The correct syntax should look like: `Class('{}') {{ Group([Hover, Focus], {{ ... }}) }}`.", class_name, class_name)),
+            &format!("The `Group` pattern in the `{}` class is missing a closing curly bracket `}}` to properly close the properties block.", class_name),
+            |parser| {
+                parser.handle_method_block(
+                    source_pattern,
+                    class_name,
+                    is_panoramic,
+                    style_class,
+                    breakpoint_name,
+                )
+            },
+        )?;
+
+        for target_pattern in &pattern_names[1..] {
+            style_class.copy_pattern_node(
+                breakpoint_name.as_deref().filter(|_| is_panoramic),
+                source_pattern,
+                target_pattern,
+            );
+        }
+
+        self.process_next_token()
+    }
+
+    /// Retrieves and validates the argument of a `Not`, `NthChild` or `Attribute` pattern.
+    ///
+    /// # Returns
+    /// - `NenyrResult<String>`: The validated argument, trimmed of surrounding whitespace.
+    ///
+    /// # Errors
+    /// - Returns an error if the argument is not a non-empty string, or if it fails to be validated
+    ///   as a selector (for `Not`), as an `An+B` expression (for `NthChild`) or as an
+    ///   attribute selector (for `Attribute`).
+    fn retrieve_pseudo_argument(
+        &mut self,
+        keyword: &str,
+        class_name: &str,
+        example: &str,
+    ) -> NenyrResult<String> {
+        let argument = self.parse_string_literal(
+            Some(format!("Ensure that the `{}` pattern in `{}` class receives a non-empty string as its first argument. Correct syntax: `{}({}, {{ ... }})`.", keyword, class_name, keyword, example)),
+            &format!("The `{}` pattern in the `{}` class was expected to receive an argument as its first argument, but none was found.", keyword, class_name),
+            false,
+        )?;
+
+        let (is_valid, suggestion) = match keyword {
+            "Not" => (
+                self.is_valid_selector_argument(&argument),
+                "The `Not` pattern expects a selector list, such as `'.disabled'`, `'#main'`, `'[type=\"text\"]'` or `'.active, :focus'`.",
+            ),
+            "Attribute" => (
+                self.is_valid_attribute_argument(&argument),
+                "The `Attribute` pattern expects one or more non-empty attribute selectors enclosed by balanced square brackets, such as `'[disabled]'`, `'[data-state=\"open\"]'` or `'[href$=\".pdf\"]'`.",
+            ),
+            _ => (
+                self.is_valid_nth_argument(&argument),
+                "The `NthChild` pattern expects `'odd'`, `'even'`, an integer or an `An+B` expression, such as `'3'`, `'2n'` or `'-n + 3'`.",
+            ),
+        };
+
+        if !is_valid {
+            return Err(NenyrError::new(
+                Some(suggestion.to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("The `{}` argument of the `{}` pattern in the `{}` class failed to be validated.", argument, keyword, class_name)),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0072"));
+        }
+
+        self.process_next_token()?;
+
+        Ok(argument.trim().to_string())
+    }
+
+    /// Ensures that the argument of a `Not`, `NthChild` or `Attribute` pattern is followed by
+    /// a comma and parses the properties block under the given pattern name.
+    ///
+    /// # Errors
+    /// - Returns an error if the comma after the argument is missing or if the properties
+    ///   block is not properly enclosed by curly brackets.
+    fn process_comma_after_pseudo_argument(
+        &mut self,
+        keyword: &str,
+        pattern_name: &str,
+        class_name: &str,
+        is_panoramic: bool,
+        style_class: &mut NenyrStyleClass,
+        breakpoint_name: &Option<String>,
+    ) -> NenyrResult<()> {
+        if let NenyrTokens::Comma = self.current_token {
+            self.process_next_token()?;
+
+            self.parse_curly_bracketed_delimiter(
+                Some(format!("After the argument, an opening curly bracket `{{` is required to properly define the properties block of the `{}` pattern in `{}` class. Ensure the pattern follows the correct Nenyr syntax, such as `Class('{}') {{ {}('...', {{ ... }}) }}`.", keyword, class_name, class_name, keyword)),
+                &format!("The `{}` pattern in the `{}` class was expected to receive an object of properties as the second argument, but an opening curly bracket `{{` was not found after the argument.", keyword, class_name),
+                Some(format!("Ensure that the properties block of the `{}` pattern in `{}` class is properly closed with a closing curly bracket `}}`. The correct syntax should look like: `Class('{}') {{ {}('...', {{ ... }}) }}`.", keyword, class_name, class_name, keyword)),
+                &format!("The `{}` pattern in the `{}` class is missing a closing curly bracket `}}` to properly close the properties block.", keyword, class_name),
+                |parser| {
+                    parser.handle_method_block(
+                        pattern_name,
+                        class_name,
+                        is_panoramic,
+                        style_class,
+                        breakpoint_name,
+                    )
+                },
+            )?;
+
+            return self.process_next_token();
+        }
+
+        Err(NenyrError::new(
+            Some(format!("Ensure that a comma is placed after the argument of the `{}` pattern in the `{}` class. The correct syntax is: `{}('...', {{ propertyName: 'value', ... }})`.", keyword, class_name, keyword)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(&format!("A comma was expected after the argument of the `{}` pattern in the `{}` class, but none was found. The parser requires a comma to separate the argument from the subsequent object of properties.", keyword, class_name)),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code("N0073"))
+    }
+
     /// Handles the processing of a method block for the given pattern and class name.
     ///
     /// This method determines whether the method block is panoramic based on the
@@ -252,8 +690,10 @@ impl NenyrParser {
         class_name: &str,
         style_class: &mut NenyrStyleClass,
     ) -> NenyrResult<()> {
-        // Set/Reset the pattern node before inserting into it.
-        style_class.reset_pattern_node(pattern_name);
+        // Set/Reset the pattern node before inserting into it, merging a duplicated `Stylesheet`.
+        if pattern_name != "_stylesheet" || !style_class.has_pattern_node(None, pattern_name) {
+            style_class.reset_pattern_node(pattern_name);
+        }
 
         loop_while_not!(
             self,
@@ -265,6 +705,17 @@ impl NenyrParser {
             |is_active| self.processing_state.set_nested_block_active(is_active),
             {
                 self.retrieve_nenyr_property(pattern_name, class_name, false, "", style_class)?;
+
+                let properties_count = style_class
+                    .style_patterns
+                    .as_ref()
+                    .and_then(|patterns| patterns.get(pattern_name))
+                    .map_or(0, |properties| properties.len());
+
+                self.check_block_properties_limit(
+                    properties_count,
+                    &format!("one of the patterns in the `{}` class", class_name),
+                )?;
             }
         );
 
@@ -300,8 +751,13 @@ impl NenyrParser {
         style_class: &mut NenyrStyleClass,
         breakpoint_name: &str,
     ) -> NenyrResult<()> {
-        // Set/Reset the pattern node on panoramic node before inserting into it.
-        style_class.reset_pattern_node_on_panoramic_node(breakpoint_name, pattern_name);
+        // Set/Reset the pattern node on panoramic node before inserting into it, merging a
+        // duplicated `Stylesheet`.
+        if pattern_name != "_stylesheet"
+            || !style_class.has_pattern_node(Some(breakpoint_name), pattern_name)
+        {
+            style_class.reset_pattern_node_on_panoramic_node(breakpoint_name, pattern_name);
+        }
 
         loop_while_not!(
             self,
@@ -319,6 +775,21 @@ impl NenyrParser {
                     breakpoint_name,
                     style_class,
                 )?;
+
+                let properties_count = style_class
+                    .responsive_patterns
+                    .as_ref()
+                    .and_then(|panoramic_nodes| panoramic_nodes.get(breakpoint_name))
+                    .and_then(|patterns| patterns.get(pattern_name))
+                    .map_or(0, |properties| properties.len());
+
+                self.check_block_properties_limit(
+                    properties_count,
+                    &format!(
+                        "the `{}` panoramic pattern in the `{}` class",
+                        breakpoint_name, class_name
+                    ),
+                )?;
             }
         );
 
@@ -389,10 +860,13 @@ impl NenyrParser {
                 style_class,
             );
         } else if let NenyrTokens::Identifier(nickname) = self.current_token.clone() {
+            let location = format!("one of the patterns in the `{}` class", class_name);
+            let property = self.resolve_alias_property(&nickname, &location)?;
+
             return self.retrieve_nenyr_value(
                 pattern_name,
                 class_name,
-                format!("nickname;{}", nickname),
+                property,
                 is_panoramic,
                 breakpoint_name,
                 style_class,
@@ -418,7 +892,8 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        )
+        .with_code("N0074"))
     }
 
     /// Retrieves the value associated with a Nenyr property and validates it.
@@ -497,8 +972,16 @@ impl NenyrParser {
         };
 
         let value = self.parse_string_literal(Some(suggestion), &error_message, false)?;
+        let location = if is_panoramic {
+            format!(
+                "the `{}` panoramic pattern in the `{}` class",
+                breakpoint_name, class_name
+            )
+        } else {
+            format!("one of the patterns in the `{}` class", class_name)
+        };
 
-        if self.is_valid_style_syntax(&value) {
+        if let Some(value) = self.process_property_value(&property, value, &location)? {
             if is_panoramic {
                 style_class.add_responsive_style_rule(
                     breakpoint_name.to_string(),
@@ -526,7 +1009,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0075"))
     }
 }
 
@@ -546,12 +1029,12 @@ mod tests {
         styles.add_style_rule(
             "_stylesheet".to_string(),
             "background-color".to_string(),
-            "blue".to_string(),
+            "blue".into(),
         );
         styles.add_style_rule(
             "_stylesheet".to_string(),
             "border".to_string(),
-            "10px solid red".to_string(),
+            "10px solid red".into(),
         );
 
         let _ = parser.process_next_token();
@@ -573,7 +1056,7 @@ mod tests {
                 "{:?}",
                 parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
             ),
-            "Err(NenyrError { suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `myClassName` class declaration. Please refer to the documentation to verify which patterns are permitted inside classes. Example: `Declare Class('myClassName') { Stylesheet({ ... }) }`.\"), context_name: None, context_path: \"\", error_message: \"The `myClassName` class contains an invalid pattern statement. Please ensure that all methods within the class are correctly defined and formatted. However, found `StartOfFile` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Stylesheet{ backgroundColor: 'blue', border: '10px solid red' })\"), error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string()
+            "Err(NenyrError { error_code: \"N0068\", suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `myClassName` class declaration. Please refer to the documentation to verify which patterns are permitted inside classes. Example: `Declare Class('myClassName') { Stylesheet({ ... }) }`.\"), context_name: None, context_path: \"\", error_message: \"The `myClassName` class contains an invalid pattern statement. Please ensure that all methods within the class are correctly defined and formatted. However, found `StartOfFile` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Stylesheet{ backgroundColor: 'blue', border: '10px solid red' })\"), error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string()
         );
     }
 
@@ -589,12 +1072,12 @@ mod tests {
         styles.add_style_rule(
             ":hover".to_string(),
             "background-color".to_string(),
-            "blue".to_string(),
+            "blue".into(),
         );
         styles.add_style_rule(
             ":hover".to_string(),
             "border".to_string(),
-            "10px solid red".to_string(),
+            "10px solid red".into(),
         );
 
         let _ = parser.process_next_token();
@@ -616,7 +1099,7 @@ mod tests {
                 "{:?}",
                 parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
             ),
-            "Err(NenyrError { suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `myClassName` class declaration. Please refer to the documentation to verify which patterns are permitted inside classes. Example: `Declare Class('myClassName') { Stylesheet({ ... }) }`.\"), context_name: None, context_path: \"\", error_message: \"The `myClassName` class contains an invalid pattern statement. Please ensure that all methods within the class are correctly defined and formatted. However, found `StartOfFile` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Hover({ backgroundColor: 'blue', border: '10px solid red' )\"), error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string()
+            "Err(NenyrError { error_code: \"N0068\", suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `myClassName` class declaration. Please refer to the documentation to verify which patterns are permitted inside classes. Example: `Declare Class('myClassName') { Stylesheet({ ... }) }`.\"), context_name: None, context_path: \"\", error_message: \"The `myClassName` class contains an invalid pattern statement. Please ensure that all methods within the class are correctly defined and formatted. However, found `StartOfFile` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Hover({ backgroundColor: 'blue', border: '10px solid red' )\"), error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string()
         )
     }
 
@@ -633,13 +1116,13 @@ mod tests {
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "background-color".to_string(),
-            "blue".to_string(),
+            "blue".into(),
         );
         styles.add_responsive_style_rule(
             "myBreakpoint".to_string(),
             "_stylesheet".to_string(),
             "border".to_string(),
-            "10px solid red".to_string(),
+            "10px solid red".into(),
         );
 
         let _ = parser.process_next_token();
@@ -671,7 +1154,346 @@ mod tests {
                     &Some("myBreakpoint".to_string())
                 )
             ),
-            "Err(NenyrError { suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `myClassName` class declaration. Please refer to the documentation to verify which patterns are permitted inside classes. Example: `Declare Class('myClassName') { Stylesheet({ ... }) }`.\"), context_name: None, context_path: \"\", error_message: \"The `myClassName` class contains an invalid pattern statement. Please ensure that all methods within the class are correctly defined and formatted. However, found `StartOfFile` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"PanoramicViewer({ myBreakpoint( Stylesheet({ backgroundColor: 'blue', border: '10px solid red' }) }) })\"), error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string()
+            "Err(NenyrError { error_code: \"N0068\", suggestion: Some(\"Fix or remove the invalid pattern declaration. Only valid and permitted patterns are allowed within the `myClassName` class declaration. Please refer to the documentation to verify which patterns are permitted inside classes. Example: `Declare Class('myClassName') { Stylesheet({ ... }) }`.\"), context_name: None, context_path: \"\", error_message: \"The `myClassName` class contains an invalid pattern statement. Please ensure that all methods within the class are correctly defined and formatted. However, found `StartOfFile` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"PanoramicViewer({ myBreakpoint( Stylesheet({ backgroundColor: 'blue', border: '10px solid red' }) }) })\"), error_on_line: 1, error_on_col: 1, error_on_pos: 0 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn content_values_are_quoted() {
+        let raw_nenyr = "After({ content: 'hello', content: 'none' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        let _ = parser.process_patterns_methods("myClassName", &mut style_class, false, &None);
+
+        assert_eq!(
+            format!("{:?}", style_class.style_patterns),
+            "Some({\"::after\": {\"content\": \"none\"}})".to_string()
+        );
+
+        let raw_nenyr = "After({ content: 'hello' })";
+
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        let _ = parser.process_patterns_methods("myClassName", &mut style_class, false, &None);
+
+        assert_eq!(
+            format!("{:?}", style_class.style_patterns),
+            "Some({\"::after\": {\"content\": \"\\\"hello\\\"\"}})".to_string()
+        );
+    }
+
+    #[test]
+    fn aliased_content_values_are_quoted() {
+        let raw_nenyr = "After({ txt: 'hello' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        parser
+            .processing_state
+            .register_alias_declaration("txt", "content", false);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        let _ = parser.process_patterns_methods("myClassName", &mut style_class, false, &None);
+
+        assert_eq!(
+            format!("{:?}", style_class.style_patterns),
+            "Some({\"::after\": {\"nickname;txt\": \"\\\"hello\\\"\"}})".to_string()
+        );
+    }
+
+    #[test]
+    fn content_value_with_unsupported_function_is_not_valid() {
+        let raw_nenyr = "After({ content: 'rotate(45deg)' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0075\", suggestion: Some(\"Ensure that all values are semantically correct to be validated. Please refer to the documentation to verify the correct way to define values.\"), context_name: None, context_path: \"\", error_message: \"The `content` property inside one of the patterns in the `myClassName` class contains an invalid value, and it could not be validated. However, found `rotate(45deg)` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"After({ content: 'rotate(45deg)' })\"), error_on_line: 1, error_on_col: 33, error_on_pos: 32 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn not_is_valid() {
+        let raw_nenyr = "Not('.disabled', { opacity: '1', cursor: 'pointer' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        styles.add_style_rule(
+            ":not(.disabled)".to_string(),
+            "opacity".to_string(),
+            "1".into(),
+        );
+        styles.add_style_rule(
+            ":not(.disabled)".to_string(),
+            "cursor".to_string(),
+            "pointer".into(),
+        );
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            parser.process_patterns_methods("myClassName", &mut style_class, false, &None),
+            Ok(())
+        );
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn nth_child_is_valid() {
+        let raw_nenyr = "NthChild('odd', { backgroundColor: 'gray' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        styles.add_style_rule(
+            ":nth-child(odd)".to_string(),
+            "background-color".to_string(),
+            "gray".into(),
+        );
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            parser.process_patterns_methods("myClassName", &mut style_class, false, &None),
+            Ok(())
+        );
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn nth_child_with_invalid_argument_is_not_valid() {
+        let raw_nenyr = "NthChild('odds', { backgroundColor: 'gray' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0072\", suggestion: Some(\"The `NthChild` pattern expects `'odd'`, `'even'`, an integer or an `An+B` expression, such as `'3'`, `'2n'` or `'-n + 3'`.\"), context_name: None, context_path: \"\", error_message: \"The `odds` argument of the `NthChild` pattern in the `myClassName` class failed to be validated. However, found `odds` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"NthChild('odds', { backgroundColor: 'gray' })\"), error_on_line: 1, error_on_col: 16, error_on_pos: 15 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn not_without_comma_is_not_valid() {
+        let raw_nenyr = "Not('.disabled' { opacity: '1' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0073\", suggestion: Some(\"Ensure that a comma is placed after the argument of the `Not` pattern in the `myClassName` class. The correct syntax is: `Not('...', { propertyName: 'value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"A comma was expected after the argument of the `Not` pattern in the `myClassName` class, but none was found. The parser requires a comma to separate the argument from the subsequent object of properties. However, found `{` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Not('.disabled' { opacity: '1' })\"), error_on_line: 1, error_on_col: 18, error_on_pos: 17 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn attribute_is_valid() {
+        let raw_nenyr = "Attribute('[data-state=\"open\"]', { display: 'block' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        styles.add_style_rule(
+            "[data-state=\"open\"]".to_string(),
+            "display".to_string(),
+            "block".into(),
+        );
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            parser.process_patterns_methods("myClassName", &mut style_class, false, &None),
+            Ok(())
+        );
+        assert_eq!(style_class, styles);
+        assert_eq!(
+            style_class.produced_selectors(""),
+            vec![".myClassName[data-state=\"open\"]".to_string()]
+        );
+    }
+
+    #[test]
+    fn attribute_with_unbalanced_brackets_is_not_valid() {
+        let raw_nenyr = "Attribute('[data-state=\"open\"', { display: 'block' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0072\", suggestion: Some(\"The `Attribute` pattern expects one or more non-empty attribute selectors enclosed by balanced square brackets, such as `'[disabled]'`, `'[data-state=\\\"open\\\"]'` or `'[href$=\\\".pdf\\\"]'`.\"), context_name: None, context_path: \"\", error_message: \"The `[data-state=\\\"open\\\"` argument of the `Attribute` pattern in the `myClassName` class failed to be validated. However, found `[data-state=\\\"open\\\"` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Attribute('[data-state=\\\"open\\\"', { display: 'block' })\"), error_on_line: 1, error_on_col: 31, error_on_pos: 30 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn group_is_valid() {
+        let raw_nenyr =
+            "Group([Hover, Focus], { backgroundColor: 'blue', border: '10px solid red' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        for pattern_name in [":hover", ":focus"] {
+            styles.add_style_rule(
+                pattern_name.to_string(),
+                "background-color".to_string(),
+                "blue".into(),
+            );
+            styles.add_style_rule(
+                pattern_name.to_string(),
+                "border".to_string(),
+                "10px solid red".into(),
+            );
+        }
+
+        let _ = parser.process_next_token();
+
+        assert!(parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .is_ok());
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn group_inside_panoramic_is_valid() {
+        let raw_nenyr = "PanoramicViewer({ myBreakpoint({ Group([Hover, Active], { backgroundColor: 'blue' }) }) })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        for pattern_name in [":hover", ":active"] {
+            styles.add_responsive_style_rule(
+                "myBreakpoint".to_string(),
+                pattern_name.to_string(),
+                "background-color".to_string(),
+                "blue".into(),
+            );
+        }
+
+        let _ = parser.process_next_token();
+
+        assert!(parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .is_ok());
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn empty_group_is_not_valid() {
+        let raw_nenyr = "Group([], { backgroundColor: 'blue' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0070\", suggestion: Some(\"List at least one pseudo pattern in the `Group` pattern of the `myClassName` class, or remove the empty group. Example: `Group([Hover, Focus], { ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `Group` pattern in the `myClassName` class lists no pattern to be styled.\", error_kind: ValidationError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Group([], { backgroundColor: 'blue' })\"), error_on_line: 1, error_on_col: 9, error_on_pos: 8 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn group_with_invalid_pattern_is_not_valid() {
+        let raw_nenyr = "Group([Hover, Stylesheet], { backgroundColor: 'blue' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0069\", suggestion: Some(\"The `Group` pattern only accepts pseudo patterns, such as `Hover`, `Focus`, `Active`, `Before` or `After`. Please refer to the documentation to verify which patterns are permitted.\"), context_name: None, context_path: \"\", error_message: \"The `Group` pattern in the `myClassName` class lists an item that is not a valid pseudo pattern. However, found `Stylesheet` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Group([Hover, Stylesheet], { backgroundColor: 'blue' })\"), error_on_line: 1, error_on_col: 25, error_on_pos: 24 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn whitespace_only_values_are_not_valid() {
+        let raw_nenyr = "Stylesheet({ backgroundColor: 'blue', border: '   ' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+        let _ = parser.process_next_token();
+
+        let error = parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .unwrap_err();
+
+        assert_eq!(
+            error.get_error_message(),
+            "The `border` property inside one of the patterns in the `myClassName` class receives a value made only of whitespace, which is semantically empty."
+        );
+    }
+
+    #[test]
+    fn values_with_internal_spaces_are_valid() {
+        let raw_nenyr = "Stylesheet({ border: ' 10px  solid red ' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+        let _ = parser.process_next_token();
+
+        assert!(parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .is_ok());
+        assert_eq!(
+            style_class.declarations().collect::<Vec<_>>(),
+            vec![("border", " 10px  solid red ")]
         );
     }
 }
@@ -0,0 +1,201 @@
+use crate::{
+    error::{NenyrError, NenyrErrorKind},
+    interfaces::at_rules::NenyrAtRulePattern,
+    types::class::NenyrStyleClass,
+    validators::feature_query::NenyrFeatureQueryValidator,
+    NenyrParser, NenyrResult,
+};
+
+/// The `Supports` pattern, which guards its properties behind a feature query.
+const SUPPORTS_PATTERN: NenyrAtRulePattern = NenyrAtRulePattern {
+    pattern_name: "Supports",
+    prelude_name: "feature query",
+    prelude_example: "(display: grid)",
+    missing_comma_code: "N0077",
+    invalid_property_code: "N0078",
+    invalid_value_code: "N0079",
+};
+
+impl NenyrParser {
+    /// Processes the `Supports` pattern declared within a Nenyr style class.
+    ///
+    /// The `Supports` pattern guards a block of properties behind a feature query, mirroring the
+    /// CSS `@supports` rule. It expects a feature query string followed by a comma and a block of
+    /// properties, all enclosed within parentheses:
+    ///
+    /// ```nenyr
+    /// Class('className') {
+    ///     Supports('(display: grid)', {
+    ///         display: 'grid',
+    ///         gap: '1rem'
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the `Supports` pattern is declared.
+    /// - `style_class`: A mutable reference to the `NenyrStyleClass` receiving the guarded properties.
+    ///
+    /// # Returns
+    /// - `NenyrResult<()>`: Returns `Ok(())` when the pattern is successfully parsed and stored in
+    ///   the style class.
+    ///
+    /// # Errors
+    /// - Returns an error if the parentheses, the comma or the curly brackets are missing.
+    /// - Returns an error if the feature query is empty or is not a balanced `(property: value)` condition.
+    /// - Returns an error if any of the properties or values inside the block are invalid.
+    pub(crate) fn process_supports_pattern(
+        &mut self,
+        class_name: &str,
+        style_class: &mut NenyrStyleClass,
+    ) -> NenyrResult<()> {
+        let (feature_query, properties) =
+            self.process_at_rule_pattern(class_name, &SUPPORTS_PATTERN, |parser| {
+                parser.retrieve_feature_query(class_name)
+            })?;
+
+        style_class.add_supports_pattern(feature_query, properties);
+
+        Ok(())
+    }
+
+    /// Retrieves and validates the feature query of a `Supports` pattern.
+    ///
+    /// # Arguments
+    /// - `class_name`: The name of the class where the `Supports` pattern is declared.
+    ///
+    /// # Returns
+    /// - `NenyrResult<String>`: The validated feature query, trimmed of surrounding whitespace.
+    ///
+    /// # Errors
+    /// - Returns an error if the feature query is not a non-empty string or if it does not
+    ///   follow the balanced `(property: value)` form.
+    fn retrieve_feature_query(&mut self, class_name: &str) -> NenyrResult<String> {
+        let feature_query = self.parse_string_literal(
+            Some(format!("Ensure that the `Supports` pattern in `{}` class receives a non-empty string containing the feature query as its first argument. Correct syntax: `Supports('(display: grid)', {{ ... }})`.", class_name)),
+            &format!("The `Supports` pattern in the `{}` class was expected to receive a feature query as its first argument, but none was found.", class_name),
+            false,
+        )?;
+
+        if !self.is_valid_feature_query(&feature_query) {
+            return Err(NenyrError::new(
+                Some("A valid feature query must be composed of balanced `(property: value)` conditions, which can be combined using the `and`, `or` and `not` operators. Examples: `'(display: grid)'`, `'not (display: grid)'`, `'(display: flex) and (gap: 1rem)'`.".to_string()),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                self.add_nenyr_token_to_error(&format!("The `{}` feature query of the `Supports` pattern in the `{}` class failed to be validated.", feature_query, class_name)),
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0076"));
+        }
+
+        self.process_next_token()?;
+
+        Ok(feature_query.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use crate::{types::class::NenyrStyleClass, NenyrParser};
+
+    #[test]
+    fn supports_is_valid() {
+        let raw_nenyr =
+            "Supports('(display: grid) and (gap: 1rem)', { display: 'grid', gap: '1rem' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut styles = NenyrStyleClass::new("myClassName".to_string(), None);
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        styles.add_supports_pattern(
+            "(display: grid) and (gap: 1rem)".to_string(),
+            IndexMap::from([
+                ("display".to_string(), "grid".into()),
+                ("gap".to_string(), "1rem".into()),
+            ]),
+        );
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            parser.process_patterns_methods("myClassName", &mut style_class, false, &None),
+            Ok(())
+        );
+        assert_eq!(style_class, styles);
+    }
+
+    #[test]
+    fn supports_with_malformed_query_is_not_valid() {
+        let raw_nenyr = "Supports('(display: grid', { display: 'grid' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!(
+                "{:?}",
+                parser.process_patterns_methods("myClassName", &mut style_class, false, &None)
+            ),
+            "Err(NenyrError { error_code: \"N0076\", suggestion: Some(\"A valid feature query must be composed of balanced `(property: value)` conditions, which can be combined using the `and`, `or` and `not` operators. Examples: `'(display: grid)'`, `'not (display: grid)'`, `'(display: flex) and (gap: 1rem)'`.\"), context_name: None, context_path: \"\", error_message: \"The `(display: grid` feature query of the `Supports` pattern in the `myClassName` class failed to be validated. However, found `(display: grid` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Supports('(display: grid', { display: 'grid' })\"), error_on_line: 1, error_on_col: 26, error_on_pos: 25 } })".to_string()
+        );
+        assert_eq!(style_class.supports_patterns, None);
+    }
+
+    #[test]
+    fn supports_with_whitespace_only_value_is_not_valid() {
+        let raw_nenyr = "Supports('(display: grid)', { display: '   ' })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        let error = parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .unwrap_err();
+
+        assert_eq!(
+            error.get_error_message(),
+            "The `display` property inside the `Supports` pattern in the `myClassName` class receives a value made only of whitespace, which is semantically empty."
+        );
+    }
+
+    #[test]
+    fn supports_exceeding_the_properties_limit_is_not_valid() {
+        let raw_nenyr =
+            "Supports('(display: grid)', { display: 'grid', gap: '1rem', width: '10px' })";
+
+        let mut parser = NenyrParser::new();
+        parser.set_max_block_properties(2);
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        let error = parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .unwrap_err();
+
+        assert_eq!(
+            error.get_error_message(),
+            "The properties block of the `Supports` pattern in the `myClassName` class declares more than 2 properties, exceeding the configured limit."
+        );
+    }
+
+    #[test]
+    fn supports_inside_panoramic_is_not_valid() {
+        let raw_nenyr = "PanoramicViewer({ myBreakpoint({ Supports('(display: grid)', { display: 'grid' }) }) })";
+
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+        let mut style_class = NenyrStyleClass::new("myClassName".to_string(), None);
+
+        let _ = parser.process_next_token();
+        assert!(parser
+            .process_patterns_methods("myClassName", &mut style_class, false, &None)
+            .is_err());
+    }
+}
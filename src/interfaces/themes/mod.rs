@@ -98,7 +98,7 @@ impl NenyrParser {
                     self.add_nenyr_token_to_error("The `Themes` declaration contains an invalid pattern statement. Please ensure that all methods within `Themes` are correctly defined and formatted."),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0080"));
             }
         }
     }
@@ -131,7 +131,7 @@ impl NenyrParser {
                     "One of the patterns in the `Themes` declaration was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis.",
                     Some("Ensure that the patterns block within the pattern in `Themes` declaration is properly closed with a closing curly bracket `}`. The correct syntax should look like: `Declare Themes({ Light({ ... }), Dark({ ... }) })`.".to_string()),
                     "One of the patterns in the `Themes` declaration is missing a closing curly bracket `}` to properly close the patterns block.",
-                    |parser| parser.handle_themes_variables(schema_kind, themes),
+                    |parser| parser.handle_themes_declarations(schema_kind, themes),
                 )?;
 
                 parser.process_next_token()
@@ -139,46 +139,60 @@ impl NenyrParser {
         )
     }
 
-    /// Handles the variables associated with a theme pattern.
+    /// Handles the declarations associated with a theme pattern.
     ///
-    /// This function processes any `Variables` keyword present within the current pattern block,
-    /// adding them to the specified theme. If the current token is not a recognized pattern or
-    /// variable, it raises a syntax error indicating an invalid declaration.
+    /// This function processes the comma-separated `Variables`, `Aliases` and `Typefaces`
+    /// declarations present within the current pattern block, adding them to the specified
+    /// theme. If the current token is not one of those declarations, it raises a syntax error
+    /// indicating an invalid declaration.
     ///
     /// # Errors
     /// Returns a `NenyrResult<()>` which will be an error if:
     /// - An unsupported pattern declaration is found.
-    /// - Variables are improperly defined within the theme pattern.
-    fn handle_themes_variables(
+    /// - Variables, aliases or typefaces are improperly defined within the theme pattern.
+    fn handle_themes_declarations(
         &mut self,
         schema_kind: &NenyrThemesKind,
         themes: &mut NenyrThemes,
     ) -> NenyrResult<()> {
-        match self.current_token {
-            NenyrTokens::CurlyBracketClose => {}
-            NenyrTokens::Variables => {
-                let variables = self.process_variables_method(true)?;
+        loop {
+            match self.current_token {
+                NenyrTokens::CurlyBracketClose => return Ok(()),
+                NenyrTokens::Variables => {
+                    let variables = self.process_variables_method(true)?;
 
-                themes.add_variables(schema_kind, variables);
-                self.process_next_token()?;
+                    themes.add_variables(schema_kind, variables);
+                }
+                NenyrTokens::Aliases => {
+                    let aliases = self.process_aliases_method(true)?;
+
+                    themes.add_aliases(schema_kind, aliases);
+                }
+                NenyrTokens::Typefaces => {
+                    let typefaces = self.process_typefaces_method()?;
 
-                if let NenyrTokens::Comma = self.current_token {
-                    self.process_next_token()?;
+                    themes.add_typefaces(schema_kind, typefaces);
+                }
+                _ => {
+                    return Err(NenyrError::new(
+                        Some("Please fix or remove the unsupported pattern declaration from `Themes`. Refer to the documentation to verify how to properly define a `Themes` method.".to_string()),
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        self.add_nenyr_token_to_error("The `Themes` method contains an invalid pattern statement. Only the `Light` or `Dark` patterns are allowed within `Themes`, and each can receive only `Variables`, `Aliases` and `Typefaces` declarations."),
+                        NenyrErrorKind::SyntaxError,
+                        self.get_tracing(),
+                    ).with_code("N0081"));
                 }
             }
-            _ => {
-                return Err(NenyrError::new(
-                    Some("Please fix or remove the unsupported pattern declaration from `Themes`. Refer to the documentation to verify how to properly define a `Themes` method.".to_string()),
-                    self.context_name.clone(),
-                    self.context_path.to_string(),
-                    self.add_nenyr_token_to_error("The `Themes` method contains an invalid pattern statement. Only the `MobileFirst` or `DesktopFirst` patterns are allowed within `Themes`, and each can receive only a `Variables` declaration."),
-                    NenyrErrorKind::SyntaxError,
-                    self.get_tracing(),
-                ));
+
+            self.process_next_token()?;
+
+            if let NenyrTokens::Comma = self.current_token {
+                self.process_next_token()?;
+            } else {
+                return Ok(());
             }
         }
-
-        Ok(())
     }
 }
 
@@ -210,7 +224,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_themes_method()),
-            "Ok(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#CCCCCC\", \"accentColorVar\": \"#FF5733\"} }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#333333\", \"secondaryColor\": \"#666666\", \"accentColorVar\": \"#FF5733\"} }) })".to_string()
+            "Ok(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#CCCCCC\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\"), \"secondaryColor\": Text(\"#666666\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None })".to_string()
         );
     }
 
@@ -238,7 +252,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_themes_method()),
-            "Err(NenyrError { suggestion: Some(\"After the opening parenthesis, an opening curly bracket `{` is required to properly define the patterns block in `Themes` declaration. Ensure the pattern follows the correct Nenyr syntax, such as `Declare Themes({ Light({ ... }), Dark({ ... }) })`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `Themes` declaration was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis. However, found `Variables` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        Light(\"), line_after: Some(\"                primaryColor: '#FFFFFF',\"), error_line: Some(\"            Variables({\"), error_on_line: 3, error_on_col: 22, error_on_pos: 45 } })".to_string()
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"After the opening parenthesis, an opening curly bracket `{` is required to properly define the patterns block in `Themes` declaration. Ensure the pattern follows the correct Nenyr syntax, such as `Declare Themes({ Light({ ... }), Dark({ ... }) })`.\"), context_name: None, context_path: \"\", error_message: \"One of the patterns in the `Themes` declaration was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis. However, found `Variables` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        Light(\"), line_after: Some(\"                primaryColor: '#FFFFFF',\"), error_line: Some(\"            Variables({\"), error_on_line: 3, error_on_col: 22, error_on_pos: 45 } })".to_string()
         );
     }
 
@@ -251,7 +265,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_themes_method()),
-            "Ok(NenyrThemes { light_schema: None, dark_schema: None })".to_string()
+            "Ok(NenyrThemes { light_schema: None, dark_schema: None, light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None })".to_string()
         );
     }
 
@@ -272,7 +286,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_themes_method()),
-            "Ok(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#CCCCCC\", \"accentColorVar\": \"#FF5733\"} }), dark_schema: None })".to_string()
+            "Ok(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#CCCCCC\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), dark_schema: None, light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None })".to_string()
         );
     }
 
@@ -293,7 +307,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_themes_method()),
-            "Ok(NenyrThemes { light_schema: None, dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#333333\", \"secondaryColor\": \"#666666\", \"accentColorVar\": \"#FF5733\"} }) })".to_string()
+            "Ok(NenyrThemes { light_schema: None, dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\"), \"secondaryColor\": Text(\"#666666\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None })".to_string()
         );
     }
 
@@ -309,7 +323,29 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_themes_method()),
-            "Ok(NenyrThemes { light_schema: None, dark_schema: None })".to_string()
+            "Ok(NenyrThemes { light_schema: None, dark_schema: None, light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None })".to_string()
+        );
+    }
+
+    #[test]
+    fn themes_with_variables_and_aliases_are_valid() {
+        let raw_nenyr = "Themes({
+        Light({
+            Variables({ primaryColor: '#FFFFFF' }),
+            Aliases({ bgd: backgroundColor })
+        }),
+        Dark({
+            Aliases({ bgd: background }),
+            Variables({ primaryColor: '#333333' }),
+        })
+    })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_themes_method()),
+            "Ok(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\")}, dimensions: None, expressions: None }), light_aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\"} }), dark_aliases: Some(NenyrAliases { values: {\"bgd\": \"background\"} }), light_typefaces: None, dark_typefaces: None })".to_string()
         );
     }
 }
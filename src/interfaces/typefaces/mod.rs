@@ -104,7 +104,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error("The `Typefaces` declaration contains an invalid identifier for the typeface name. Please ensure the identifier follows the required format."),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0082"))
     }
 
     /// Processes the value associated with a typeface identifier.
@@ -154,7 +154,7 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(&format!("The `{}` typeface in the `Typefaces` declaration contains an invalid value and could not be validated.", identifier)),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0083"))
     }
 }
 
@@ -204,7 +204,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_typefaces_method()),
-            "Err(NenyrError { suggestion: Some(\"Ensure that all typeface values are semantically correct to be validated. Please refer to the documentation to verify the correct way to define typeface values.\"), context_name: None, context_path: \"src/interfaces/typefaces/central.nyr\", error_message: \"The `regularWoff` typeface in the `Typefaces` declaration contains an invalid value and could not be validated. However, found `../../mocks/typefaces/showa-source-curry.regular-webfont.woff` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        regularTtf: '../../../mocks/typefaces/showa-source-curry.regular-webfont.ttf',\"), line_after: Some(\"        regularWoff2: '../../../mocks/typefaces/showa-source-curry.regular-webfont.woff2'\"), error_line: Some(\"        regularWoff: '../../mocks/typefaces/showa-source-curry.regular-webfont.woff',\"), error_on_line: 6, error_on_col: 85, error_on_pos: 428 } })".to_string()
+            "Err(NenyrError { error_code: \"N0083\", suggestion: Some(\"Ensure that all typeface values are semantically correct to be validated. Please refer to the documentation to verify the correct way to define typeface values.\"), context_name: None, context_path: \"src/interfaces/typefaces/central.nyr\", error_message: \"The `regularWoff` typeface in the `Typefaces` declaration contains an invalid value and could not be validated. However, found `../../mocks/typefaces/showa-source-curry.regular-webfont.woff` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"        regularTtf: '../../../mocks/typefaces/showa-source-curry.regular-webfont.ttf',\"), line_after: Some(\"        regularWoff2: '../../../mocks/typefaces/showa-source-curry.regular-webfont.woff2'\"), error_line: Some(\"        regularWoff: '../../mocks/typefaces/showa-source-curry.regular-webfont.woff',\"), error_on_line: 6, error_on_col: 85, error_on_pos: 428 } })".to_string()
         );
     }
 
@@ -2,7 +2,7 @@ use crate::{
     error::{NenyrError, NenyrErrorKind},
     loop_while_not,
     tokens::NenyrTokens,
-    types::variables::NenyrVariables,
+    types::variables::{NenyrDimension, NenyrVariables},
     validators::variable_value::NenyrVariableValueValidator,
     NenyrParser, NenyrResult,
 };
@@ -161,9 +161,33 @@ impl NenyrParser {
         self.processing_state.set_block_active(true);
 
         if let NenyrTokens::Identifier(identifier) = self.current_token.clone() {
+            self.check_variable_const_collision(is_from_themes, &identifier)?;
+
+            if !is_from_themes {
+                self.processing_state
+                    .register_variable_declaration(&identifier);
+            }
+
             return self.process_variable_value(is_from_themes, identifier, variables);
         }
 
+        if let NenyrTokens::Number(number) = self.current_token {
+            let error_message = if is_from_themes {
+                format!("In the `Themes` block, the `Variables` declaration uses the number `{}` as a variable name, but variable names must be identifiers starting with a letter.", number)
+            } else {
+                format!("The `Variables` declaration uses the number `{}` as a variable name, but variable names must be identifiers starting with a letter.", number)
+            };
+
+            return Err(NenyrError::new(
+                Some(format!("Start the variable name with a letter, keeping the digits after it. For example: `Variables({{ size{}: 'value', ... }})`.", number)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                error_message,
+                NenyrErrorKind::SyntaxError,
+                self.get_tracing(),
+            ).with_code("N0084"));
+        }
+
         let error_message = if is_from_themes {
             "In the `Themes` block, the `Variables` declaration contains an invalid identifier for the variable name. Please ensure the identifier follows the required format."
         } else {
@@ -177,7 +201,43 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0085"))
+    }
+
+    /// Ensures that a variable declared through `Variables` does not share its name with a
+    /// constant declared through `Const`, as constants cannot be overridden.
+    ///
+    /// # Parameters
+    /// - `is_from_themes`: A boolean indicating whether the variable is declared within a
+    ///   `Themes` block. This affects the error message generated.
+    /// - `identifier`: The name of the variable being declared.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` of kind `ValidationError` if a constant with the same name
+    /// was already declared within the current context.
+    fn check_variable_const_collision(
+        &self,
+        is_from_themes: bool,
+        identifier: &str,
+    ) -> NenyrResult<()> {
+        if !self.processing_state.is_const_declared(identifier) {
+            return Ok(());
+        }
+
+        let error_message = if is_from_themes {
+            format!("In the `Themes` block, the `{}` variable collides with the `{}` constant declared in the `Const` declaration. Constants and variables cannot share the same name.", identifier, identifier)
+        } else {
+            format!("The `{}` variable collides with the `{}` constant declared in the `Const` declaration. Constants and variables cannot share the same name.", identifier, identifier)
+        };
+
+        Err(NenyrError::new(
+            Some("Rename the variable so that its name is unique among the constants and variables declared within the context.".to_string()),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            error_message,
+            NenyrErrorKind::ValidationError,
+            self.get_tracing(),
+        ).with_code("N0086"))
     }
 
     /// Processes the value of a variable within a `Variables` or `Themes` declaration.
@@ -200,7 +260,8 @@ impl NenyrParser {
     /// This function can return a `NenyrResult<()>`, which may encapsulate errors
     /// of type `NenyrError` if any of the following conditions are not met:
     /// - The variable declaration is missing a colon (`:`) after the variable name.
-    /// - The variable value is expected to be a non-empty string but is not found.
+    /// - The variable value is expected to be a non-empty string, an unquoted `number+unit`
+    ///   value, such as `8px`, or a `calc(...)` computed value, but none is found.
     /// - The variable value does not pass validation checks defined by `is_valid_variable_value`.
     fn process_variable_value(
         &mut self,
@@ -222,6 +283,23 @@ impl NenyrParser {
             true
         )?;
 
+        if let NenyrTokens::SquareBracketOpen = self.current_token {
+            return self.process_list_variable_value(is_from_themes, identifier, variables);
+        }
+
+        if matches!(&self.current_token, NenyrTokens::Identifier(keyword) if keyword == "calc") {
+            return self.process_expression_variable_value(identifier, variables);
+        }
+
+        if let NenyrTokens::Dimension(value, unit) = self.current_token.clone() {
+            return self.process_dimension_variable_value(
+                is_from_themes,
+                identifier,
+                NenyrDimension { value, unit },
+                variables,
+            );
+        }
+
         let error_message = if is_from_themes {
             &format!("The `{}` variable declaration in the `Themes` block should receive a non-empty string as a value, but none was found.", identifier)
         } else {
@@ -235,7 +313,7 @@ impl NenyrParser {
         )?;
 
         if self.is_valid_variable_value(&value) {
-            variables.add_variable(identifier, value);
+            variables.add_variable(identifier, self.normalize_hex_case(value));
 
             return Ok(());
         }
@@ -253,7 +331,155 @@ impl NenyrParser {
             self.add_nenyr_token_to_error(error_message),
             NenyrErrorKind::SyntaxError,
             self.get_tracing(),
-        ))
+        ).with_code("N0087"))
+    }
+
+    /// Processes an array literal assigned to a variable, such as
+    /// `shadows: ['0 1px 2px #000', '0 2px 4px #333']`.
+    ///
+    /// Each element must be a non-empty string holding a valid variable value, and the array
+    /// must contain at least one element.
+    ///
+    /// # Parameters
+    /// - `is_from_themes`: A boolean indicating whether the variable is being processed
+    ///   within the context of a `Themes` block. This affects the error messages generated.
+    /// - `identifier`: A `String` that represents the name of the variable being defined.
+    /// - `variables`: A mutable reference to a `NenyrVariables` instance, where the
+    ///   successfully parsed variable will be added upon validation.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the array is not closed, is empty, or contains an element
+    /// that is not a valid non-empty string.
+    fn process_list_variable_value(
+        &mut self,
+        is_from_themes: bool,
+        identifier: String,
+        variables: &mut NenyrVariables,
+    ) -> NenyrResult<()> {
+        let elements = self.parse_square_bracketed_delimiter(
+            None,
+            "",
+            Some(format!("Ensure that the array assigned to the `{}` variable is properly closed with a closing square bracket `]`. The correct syntax is: `Variables({{ {}: ['first value', 'second value'], ... }})`.", identifier, identifier)),
+            &format!("The array assigned to the `{}` variable is missing a closing square bracket `]`.", identifier),
+            |parser| parser.process_list_variable_elements(&identifier),
+        )?;
+
+        if !elements.is_empty() {
+            variables.add_list_variable(identifier, elements);
+
+            return Ok(());
+        }
+
+        let error_message = if is_from_themes {
+            &format!("In the `Themes` block, the `{}` variable declaration receives an empty array, but at least one element is required.", identifier)
+        } else {
+            &format!("The `{}` variable in the `Variables` declaration receives an empty array, but at least one element is required.", identifier)
+        };
+
+        Err(NenyrError::new(
+            Some(format!("Add at least one non-empty string to the array or remove the variable: `Variables({{ {}: ['first value', 'second value'], ... }})`.", identifier)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(error_message),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code("N0088"))
+    }
+
+    /// Processes the elements of an array literal assigned to a variable, validating each
+    /// one of them.
+    ///
+    /// # Parameters
+    /// - `identifier`: The name of the variable receiving the array.
+    ///
+    /// # Returns
+    /// - `NenyrResult<Vec<String>>`: The elements of the array, in declaration order.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the commas between the elements are missing or duplicated,
+    /// or if an element is not a valid non-empty string.
+    fn process_list_variable_elements(&mut self, identifier: &str) -> NenyrResult<Vec<String>> {
+        let mut elements = Vec::new();
+
+        loop_while_not!(
+            self,
+            Some(format!("Remove any duplicated commas from the array assigned to the `{}` variable. Example: `Variables({{ {}: ['first value', 'second value'], ... }})`.", identifier, identifier)),
+            &format!("A duplicated comma was found in the array assigned to the `{}` variable. The parser expected to find a new element but none was found.", identifier),
+            Some(format!("Ensure that a comma is placed after each element of the array assigned to the `{}` variable. Example: `Variables({{ {}: ['first value', 'second value'], ... }})`.", identifier, identifier)),
+            &format!("The elements of the array assigned to the `{}` variable must be separated by commas, but a comma is missing.", identifier),
+            || self.processing_state.is_nested_block_active(),
+            |is_active| self.processing_state.set_nested_block_active(is_active),
+            {
+                self.processing_state.set_nested_block_active(true);
+
+                let element = self.parse_string_literal(
+                    Some(format!("Ensure that every element of the array assigned to the `{}` variable is a non-empty string: `Variables({{ {}: ['first value', 'second value'], ... }})`.", identifier, identifier)),
+                    &format!("The array assigned to the `{}` variable should only contain non-empty strings, but an invalid element was found.", identifier),
+                    false
+                )?;
+
+                if !self.is_valid_variable_value(&element) {
+                    return Err(NenyrError::new(
+                        Some("Ensure that all variable values are semantically correct to be validated. Please refer to the documentation to verify the correct way to define variable values.".to_string()),
+                        self.context_name.clone(),
+                        self.context_path.to_string(),
+                        self.add_nenyr_token_to_error(&format!("The array assigned to the `{}` variable contains an invalid element and could not be validated.", identifier)),
+                        NenyrErrorKind::SyntaxError,
+                        self.get_tracing(),
+                    ).with_code("N0089"));
+                }
+
+                elements.push(self.normalize_hex_case(element));
+            }
+        );
+
+        self.processing_state.set_nested_block_active(false);
+
+        Ok(elements)
+    }
+
+    /// Processes an unquoted `number+unit` value assigned to a variable, such as `8px`.
+    ///
+    /// The unit is validated before the variable is stored, keeping both its typed form and
+    /// its string representation.
+    ///
+    /// # Parameters
+    /// - `is_from_themes`: A boolean indicating whether the variable is being processed
+    ///   within the context of a `Themes` block. This affects the error messages generated.
+    /// - `identifier`: A `String` that represents the name of the variable being defined.
+    /// - `dimension`: The `NenyrDimension` assigned to the variable.
+    /// - `variables`: A mutable reference to a `NenyrVariables` instance, where the
+    ///   successfully parsed variable will be added upon validation.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` if the unit is not a valid CSS unit.
+    fn process_dimension_variable_value(
+        &mut self,
+        is_from_themes: bool,
+        identifier: String,
+        dimension: NenyrDimension,
+        variables: &mut NenyrVariables,
+    ) -> NenyrResult<()> {
+        if self.is_valid_unit(&dimension.unit) {
+            variables.add_dimension_variable(identifier, dimension);
+
+            return Ok(());
+        }
+
+        let error_message = if is_from_themes {
+            &format!("In the `Themes` block, the `{}` variable declaration contains the `{}` unit, which is not a valid CSS unit.", identifier, dimension.unit)
+        } else {
+            &format!("The `{}` variable in the `Variables` declaration contains the `{}` unit, which is not a valid CSS unit.", identifier, dimension.unit)
+        };
+
+        Err(NenyrError::new(
+            Some(format!("Ensure that unquoted values are composed of a number immediately followed by a valid CSS unit, such as `px`, `rem`, `%` or `deg`. Otherwise, wrap the value in quotes: `Variables({{ {}: '{}', ... }})`.", identifier, dimension)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            self.add_nenyr_token_to_error(error_message),
+            NenyrErrorKind::SyntaxError,
+            self.get_tracing(),
+        ).with_code("N0090"))
     }
 }
 
@@ -278,7 +504,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_variables_method(false)),
-            "Ok(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} })".to_string()
+            "Ok(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None })".to_string()
         );
     }
 
@@ -299,7 +525,7 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_variables_method(false)),
-            "Err(NenyrError { suggestion: Some(\"Ensure that each variable is defined with a colon after it. The correct syntax is: `Variables({ myColor: 'variable value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `myColor` variable in the `Variables` declaration is missing a colon after the variable name definition. However, found `#FF6677` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Variables({\"), line_after: Some(\"        grayColor: 'gray',\"), error_line: Some(\"        myColor '#FF6677',\"), error_on_line: 2, error_on_col: 26, error_on_pos: 37 } })".to_string()
+            "Err(NenyrError { error_code: \"N0034\", suggestion: Some(\"Ensure that each variable is defined with a colon after it. The correct syntax is: `Variables({ myColor: 'variable value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `myColor` variable in the `Variables` declaration is missing a colon after the variable name definition. However, found `#FF6677` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Variables({\"), line_after: Some(\"        grayColor: 'gray',\"), error_line: Some(\"        myColor '#FF6677',\"), error_on_line: 2, error_on_col: 26, error_on_pos: 37 } })".to_string()
         );
     }
 
@@ -312,7 +538,97 @@ mod tests {
         let _ = parser.process_next_token();
         assert_eq!(
             format!("{:?}", parser.process_variables_method(false)),
-            "Ok(NenyrVariables { values: {} })".to_string()
+            "Ok(NenyrVariables { values: {}, dimensions: None, expressions: None })".to_string()
+        );
+    }
+
+    #[test]
+    fn unquoted_dimension_variables_are_valid() {
+        let raw_nenyr = "Variables({
+        myColor: '#FF6677',
+        spacing: 8px,
+        gutter: 1.5rem
+    })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Ok(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"spacing\": Text(\"8px\"), \"gutter\": Text(\"1.5rem\")}, dimensions: Some({\"spacing\": NenyrDimension { value: 8.0, unit: \"px\" }, \"gutter\": NenyrDimension { value: 1.5, unit: \"rem\" }}), expressions: None })"
+        );
+    }
+
+    #[test]
+    fn unquoted_dimension_variables_with_invalid_unit_are_not_valid() {
+        let raw_nenyr = "Variables({
+        spacing: 8pixels
+    })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Err(NenyrError { error_code: \"N0090\", suggestion: Some(\"Ensure that unquoted values are composed of a number immediately followed by a valid CSS unit, such as `px`, `rem`, `%` or `deg`. Otherwise, wrap the value in quotes: `Variables({ spacing: '8pixels', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `spacing` variable in the `Variables` declaration contains the `pixels` unit, which is not a valid CSS unit. However, found `8pixels` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Variables({\"), line_after: Some(\"    })\"), error_line: Some(\"        spacing: 8pixels\"), error_on_line: 2, error_on_col: 25, error_on_pos: 36 } })"
+        );
+    }
+
+    #[test]
+    fn numeric_variable_names_are_not_valid() {
+        let raw_nenyr = "Variables({
+        1: 'x'
+    })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Err(NenyrError { error_code: \"N0084\", suggestion: Some(\"Start the variable name with a letter, keeping the digits after it. For example: `Variables({ size1: 'value', ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `Variables` declaration uses the number `1` as a variable name, but variable names must be identifiers starting with a letter.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"Variables({\"), line_after: Some(\"    })\"), error_line: Some(\"        1: 'x'\"), error_on_line: 2, error_on_col: 10, error_on_pos: 21 } })"
+        );
+    }
+
+    #[test]
+    fn scalar_and_array_variables_are_valid() {
+        let raw_nenyr = "Variables({
+        primaryColor: '#FF6677',
+        shadows: ['0 1px 2px #000', '0 2px 4px #333',],
+        fonts: ['Inter']
+    })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Ok(NenyrVariables { values: {\"primaryColor\": Text(\"#FF6677\"), \"shadows\": List([\"0 1px 2px #000\", \"0 2px 4px #333\"]), \"fonts\": List([\"Inter\"])}, dimensions: None, expressions: None })".to_string()
+        );
+    }
+
+    #[test]
+    fn empty_array_variables_are_not_valid() {
+        let raw_nenyr = "Variables({ shadows: [] })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Err(NenyrError { error_code: \"N0088\", suggestion: Some(\"Add at least one non-empty string to the array or remove the variable: `Variables({ shadows: ['first value', 'second value'], ... })`.\"), context_name: None, context_path: \"\", error_message: \"The `shadows` variable in the `Variables` declaration receives an empty array, but at least one element is required. However, found `]` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Variables({ shadows: [] })\"), error_on_line: 1, error_on_col: 24, error_on_pos: 23 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn array_variables_with_invalid_elements_are_not_valid() {
+        let raw_nenyr = "Variables({ shadows: ['0 1px 2px #000', 10] })";
+        let mut parser = NenyrParser::new();
+        parser.setup_dependencies(raw_nenyr.to_string(), "".to_string());
+
+        let _ = parser.process_next_token();
+        assert_eq!(
+            format!("{:?}", parser.process_variables_method(false)),
+            "Err(NenyrError { error_code: \"N0053\", suggestion: Some(\"Ensure that every element of the array assigned to the `shadows` variable is a non-empty string: `Variables({ shadows: ['first value', 'second value'], ... })`.\"), context_name: None, context_path: \"\", error_message: \"The array assigned to the `shadows` variable should only contain non-empty strings, but an invalid element was found. However, found `10` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: None, line_after: None, error_line: Some(\"Variables({ shadows: ['0 1px 2px #000', 10] })\"), error_on_line: 1, error_on_col: 43, error_on_pos: 42 } })".to_string()
         );
     }
 }
@@ -0,0 +1,110 @@
+use std::{collections::HashSet, sync::Arc};
+
+/// A pool of shared string values used by the Nenyr parser.
+///
+/// Large contexts tend to repeat the same property values (e.g. `'blue'` or
+/// `'${accentColorVar}'`) many times. When interning is enabled, every value stored in
+/// the style property maps is looked up in the pool first, so identical values share
+/// a single allocation instead of each one holding its own copy.
+///
+/// Values are shared through `Arc<str>` rather than `Rc<str>`, keeping the AST
+/// `Send` and `Sync`.
+///
+/// When interning is disabled, which is the default, each value receives its own
+/// allocation and the pool stays empty.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct NenyrValueInterner {
+    /// Indicates whether the values must be shared through the pool.
+    is_enabled: bool,
+    /// The values already interned during the current parsing.
+    pool: HashSet<Arc<str>>,
+}
+
+impl NenyrValueInterner {
+    /// Creates a new `NenyrValueInterner` with interning disabled and an empty pool.
+    pub fn new() -> Self {
+        Self {
+            is_enabled: false,
+            pool: HashSet::new(),
+        }
+    }
+
+    /// Enables or disables the interning of values.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_enabled` - Whether identical values must share the same allocation.
+    pub fn set_enabled(&mut self, is_enabled: bool) {
+        self.is_enabled = is_enabled;
+
+        if !is_enabled {
+            self.pool.clear();
+        }
+    }
+
+    /// Converts the value into a shared `Arc<str>`.
+    ///
+    /// If interning is enabled and an identical value was interned before, the existing
+    /// allocation is returned. Otherwise, a new allocation is created and, when interning
+    /// is enabled, kept in the pool for the next lookups.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to be interned.
+    ///
+    /// # Returns
+    ///
+    /// * `Arc<str>` - The shared representation of the value.
+    pub fn intern(&mut self, value: String) -> Arc<str> {
+        if !self.is_enabled {
+            return Arc::from(value);
+        }
+
+        if let Some(interned_value) = self.pool.get(value.as_str()) {
+            return interned_value.clone();
+        }
+
+        let interned_value: Arc<str> = Arc::from(value);
+
+        self.pool.insert(interned_value.clone());
+
+        interned_value
+    }
+
+    /// Drops every value kept in the pool, preserving whether interning is enabled.
+    pub fn clear(&mut self) {
+        self.pool.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::NenyrValueInterner;
+
+    #[test]
+    fn interned_values_must_share_allocation() {
+        let mut interner = NenyrValueInterner::new();
+
+        interner.set_enabled(true);
+
+        let first_value = interner.intern("blue".to_string());
+        let second_value = interner.intern("blue".to_string());
+        let third_value = interner.intern("red".to_string());
+
+        assert!(Arc::ptr_eq(&first_value, &second_value));
+        assert!(!Arc::ptr_eq(&first_value, &third_value));
+    }
+
+    #[test]
+    fn disabled_interner_must_not_share_allocation() {
+        let mut interner = NenyrValueInterner::new();
+
+        let first_value = interner.intern("blue".to_string());
+        let second_value = interner.intern("blue".to_string());
+
+        assert_eq!(first_value, second_value);
+        assert!(!Arc::ptr_eq(&first_value, &second_value));
+    }
+}
@@ -30,6 +30,11 @@ use crate::{
 ///   which may provide additional information about the source code's origin.
 /// * `context_name`: An optional `String` representing the name of the Nenyr context,
 ///   which can be useful for distinguishing between different scopes or modules within the Nenyr document.
+/// * `forbid_line_comments`: Whether line comments (`//`) must be rejected, allowing only block comments.
+/// * `allow_semicolons`: Whether semicolons (`;`) are tokenized instead of being rejected as unknown tokens.
+/// * `tab_width`: The number of columns a tab character (`'\t'`) advances the column counter by.
+/// * `collect_comments`: Whether the skipped comments must be kept, to be taken through `Lexer::take_comments`.
+/// * `comments`: The comments skipped since they were last taken, when they are collected.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Lexer {
     /// The raw input source written in Nenyr language, borrowed for the lifetime of the lexer.
@@ -37,6 +42,8 @@ pub struct Lexer {
     /// The current position in the input string (in bytes), used to track which
     /// character the lexer is processing.
     position: usize,
+    /// The position (in bytes) where the last returned token starts.
+    token_start: usize,
     /// The current line number in the input, starts at 1 and increments with each newline.
     line: usize,
     /// The current column number within the current line, resets to 1 after each newline.
@@ -45,6 +52,16 @@ pub struct Lexer {
     context_path: String,
     /// An optional name of the context, useful for distinguishing between different scopes or modules in the Nenyr document.
     context_name: Option<String>,
+    /// Whether line comments must raise an error instead of being skipped.
+    forbid_line_comments: bool,
+    /// Whether semicolons must be tokenized instead of raising an unknown token error.
+    allow_semicolons: bool,
+    /// The number of columns counted for each tab character, defaults to 1.
+    tab_width: usize,
+    /// Whether the skipped comments must be kept instead of being discarded.
+    collect_comments: bool,
+    /// The comments skipped since they were last taken, including their markers.
+    comments: Vec<String>,
 }
 
 impl Lexer {
@@ -67,9 +84,15 @@ impl Lexer {
             raw_nenyr,
             context_path,
             position: 0,
+            token_start: 0,
             line: 1,
             column: 1,
             context_name: None,
+            forbid_line_comments: false,
+            allow_semicolons: false,
+            tab_width: 1,
+            collect_comments: false,
+            comments: Vec::new(),
         }
     }
 
@@ -86,6 +109,64 @@ impl Lexer {
         self.context_name = context_name;
     }
 
+    /// Sets whether line comments (`//`) are forbidden.
+    ///
+    /// When forbidden, the lexer raises a `NenyrError` pointing at the `//` instead of skipping
+    /// the comment. Block comments (`/* */`) remain allowed.
+    ///
+    /// # Parameters
+    ///
+    /// * `is_forbidden`: Whether line comments must be rejected.
+    pub fn set_line_comments_forbidden(&mut self, is_forbidden: bool) {
+        self.forbid_line_comments = is_forbidden;
+    }
+
+    /// Sets whether the skipped comments are collected.
+    ///
+    /// When collected, every line and block comment is kept with its markers, e.g.
+    /// `// comment` or `/* comment */`, until taken through `Lexer::take_comments`.
+    ///
+    /// # Parameters
+    ///
+    /// * `is_collected`: Whether the comments must be kept.
+    pub fn set_comments_collected(&mut self, is_collected: bool) {
+        self.collect_comments = is_collected;
+    }
+
+    /// Takes the comments skipped since the last call, leaving none behind.
+    ///
+    /// # Returns
+    ///
+    /// The collected comments, in source order. It is always empty when the comments are
+    /// not collected.
+    pub fn take_comments(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.comments)
+    }
+
+    /// Sets whether semicolons (`;`) are tokenized.
+    ///
+    /// When allowed, each `;` produces a `NenyrTokens::Semicolon`. Otherwise, the lexer keeps
+    /// raising the unknown token error for it.
+    ///
+    /// # Parameters
+    ///
+    /// * `is_allowed`: Whether semicolons must be tokenized.
+    pub fn set_semicolons_allowed(&mut self, is_allowed: bool) {
+        self.allow_semicolons = is_allowed;
+    }
+
+    /// Sets the number of columns a tab character advances the column counter by.
+    ///
+    /// Editors usually render tabs wider than a single column, so matching their tab width
+    /// makes the columns reported in errors point at the same place the user sees.
+    ///
+    /// # Parameters
+    ///
+    /// * `tab_width`: The number of columns counted for each tab character.
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = tab_width;
+    }
+
     /// Traces the lexer’s current line by retrieving the text of the line at the
     /// specified index in the input. This is useful for error reporting and debugging,
     /// providing the user with context about where an error occurred.
@@ -118,13 +199,63 @@ impl Lexer {
     /// A `NenyrErrorTracing` struct that contains the context around the current
     /// lexer position for debugging purposes.
     pub fn trace_lexer_position(&self) -> NenyrErrorTracing {
-        let line_before = if let Some(idx) = self.line.checked_sub(2) {
+        self.trace_line_and_column(self.line, self.column, self.position)
+    }
+
+    /// Provides the tracing information for an arbitrary byte position of the input,
+    /// counting lines and columns the same way the lexer does while advancing. This is
+    /// used to point errors at a location inside an already consumed token, such as
+    /// an invalid character within a string literal.
+    ///
+    /// # Parameters
+    ///
+    /// * `position`: The byte position of the input to be traced. A position that falls
+    ///   inside a multi-byte character is traced from the start of that character.
+    ///
+    /// # Returns
+    ///
+    /// A `NenyrErrorTracing` struct that contains the context around the given position.
+    pub fn trace_position(&self, position: usize) -> NenyrErrorTracing {
+        let consumed = self.slice_between(0, self.floor_char_boundary(position));
+        let mut chars = consumed.chars().peekable();
+        let mut line = 1;
+        let mut column = 1;
+
+        while let Some(char) = chars.next() {
+            match char {
+                '\n' => {
+                    line += 1;
+                    column = 1;
+                }
+                '\r' => {
+                    if chars.peek() != Some(&'\n') {
+                        line += 1;
+                        column = 1;
+                    }
+                }
+                '\t' => column += self.tab_width,
+                _ => column += char.len_utf8(),
+            }
+        }
+
+        self.trace_line_and_column(line, column, consumed.len())
+    }
+
+    /// Builds the tracing information for the given line, column and byte position,
+    /// attaching the lines of context surrounding it.
+    fn trace_line_and_column(
+        &self,
+        line: usize,
+        column: usize,
+        position: usize,
+    ) -> NenyrErrorTracing {
+        let line_before = if let Some(idx) = line.checked_sub(2) {
             self.trace_lexer_line(idx)
         } else {
             None
         };
 
-        let error_line = if let Some(idx) = self.line.checked_sub(1) {
+        let error_line = if let Some(idx) = line.checked_sub(1) {
             self.trace_lexer_line(idx)
         } else {
             None
@@ -132,11 +263,11 @@ impl Lexer {
 
         NenyrErrorTracing::new(
             line_before,
-            self.trace_lexer_line(self.line),
+            self.trace_lexer_line(line),
             error_line,
-            self.line,
-            self.column,
-            self.position,
+            line,
+            column,
+            position,
         )
     }
 
@@ -158,7 +289,26 @@ impl Lexer {
             format!("The current token `{}` is not supported within Nenyr syntax. Please verify the token and ensure it adheres to the Nenyr language rules.", unknown_token),
             NenyrErrorKind::SyntaxError,
             self.trace_lexer_position(),
-        )
+        ).with_code("N0091")
+    }
+
+    /// Raises an error when a line comment is found while line comments are forbidden.
+    ///
+    /// The lexer is moved back to the first slash, so the tracing points at the `//`.
+    fn raise_line_comment_error(&mut self) -> NenyrError {
+        let slash_len = '/'.len_utf8();
+
+        self.position = self.position.saturating_sub(slash_len);
+        self.column = self.column.saturating_sub(slash_len);
+
+        NenyrError::new(
+            Some("Line comments are forbidden by the parser configuration. Replace the `//` comment with a block comment, e.g. `/* comment */`, or remove it.".to_string()),
+            self.context_name.to_owned(),
+            self.context_path.to_string(),
+            "A line comment `//` was found, but only block comments `/* */` are allowed.".to_string(),
+            NenyrErrorKind::SyntaxError,
+            self.trace_lexer_position(),
+        ).with_code("N0092")
     }
 
     /// Retrieves the current character in the input string without advancing
@@ -170,7 +320,49 @@ impl Lexer {
     /// An `Option<char>` representing the current character. If the end of the input
     /// has been reached, this function returns `None`.
     pub fn current_char(&self) -> Option<char> {
-        self.raw_nenyr[self.position..].chars().next()
+        self.slice_from(self.position).chars().next()
+    }
+
+    /// Retrieves the input from the given byte position up to its end.
+    ///
+    /// The access is checked, so an out-of-bounds position, or one that is not on a
+    /// character boundary, yields an empty slice instead of panicking. Debug builds
+    /// assert the boundary instead, as the lexer only ever advances by whole characters.
+    fn slice_from(&self, start: usize) -> &str {
+        debug_assert!(
+            self.raw_nenyr.is_char_boundary(start),
+            "The byte position {} is not on a character boundary of the input.",
+            start
+        );
+
+        self.raw_nenyr.get(start..).unwrap_or_default()
+    }
+
+    /// Retrieves the input between the given byte positions.
+    ///
+    /// The access is checked, so an invalid range yields an empty slice instead of panicking.
+    /// Debug builds assert that both positions are on character boundaries.
+    fn slice_between(&self, start: usize, end: usize) -> &str {
+        debug_assert!(
+            self.raw_nenyr.is_char_boundary(start) && self.raw_nenyr.is_char_boundary(end),
+            "The byte range {}..{} is not on character boundaries of the input.",
+            start,
+            end
+        );
+
+        self.raw_nenyr.get(start..end).unwrap_or_default()
+    }
+
+    /// Moves the given byte position back to the closest character boundary of the input,
+    /// clamping it to the input length.
+    fn floor_char_boundary(&self, position: usize) -> usize {
+        let mut position = position.min(self.raw_nenyr.len());
+
+        while !self.raw_nenyr.is_char_boundary(position) {
+            position -= 1;
+        }
+
+        position
     }
 
     /// Advances the lexer to the next token in the input. This function processes
@@ -194,7 +386,7 @@ impl Lexer {
     /// - `Err(NenyrError)`: An error if an unknown or invalid token is encountered.
     ///
     /// This could be a keyword, identifier, symbol, string literal, number, or any
-    /// other valid token. When the end of the input is reached, an `EndOfLine` token
+    /// other valid token. When the end of the input is reached, an `EndOfFile` token
     /// is returned.
     ///
     /// # Errors
@@ -203,12 +395,19 @@ impl Lexer {
     ///   details such as the line, column, and the problematic character.
     pub fn next_token(&mut self) -> NenyrResult<NenyrTokens> {
         while let Some(char) = self.current_char() {
+            self.token_start = self.position;
+
             match char {
                 // Skip whitespace and update position and column
-                ' ' | '\t' => {
+                ' ' => {
                     self.position += char.len_utf8();
                     self.column += char.len_utf8();
                 }
+                // Skip tabs, counting the configured tab width as columns
+                '\t' => {
+                    self.position += char.len_utf8();
+                    self.column += self.tab_width;
+                }
                 // Handle newlines
                 '\n' => {
                     self.position += char.len_utf8();
@@ -218,8 +417,11 @@ impl Lexer {
                 // Handle carriage returns
                 '\r' => {
                     // Check if followed by newline
-                    if self.raw_nenyr[self.position + char.len_utf8()..].starts_with('\n') {
-                        self.position += 2;
+                    if self
+                        .slice_from(self.position + char.len_utf8())
+                        .starts_with('\n')
+                    {
+                        self.position += char.len_utf8() + '\n'.len_utf8();
                     } else {
                         self.position += char.len_utf8();
                     }
@@ -229,6 +431,8 @@ impl Lexer {
                 }
                 // Handle comments
                 '/' => {
+                    let comment_start = self.position;
+
                     self.position += char.len_utf8();
                     self.column += char.len_utf8();
 
@@ -236,10 +440,15 @@ impl Lexer {
                     if self.current_char() == Some('/') {
                         let slash_len = '/'.len_utf8();
 
+                        if self.forbid_line_comments {
+                            return Err(self.raise_line_comment_error());
+                        }
+
                         self.position += slash_len;
                         self.column += slash_len;
 
                         self.skip_line_comment();
+                        self.collect_comment(comment_start);
 
                         continue;
 
@@ -251,38 +460,40 @@ impl Lexer {
                         self.column += asterisk_len;
 
                         self.skip_block_comment();
+                        self.collect_comment(comment_start);
 
                         continue;
                     }
 
-                    return Err(self.raise_unknown_token_error('/'));
+                    return Ok(NenyrTokens::Slash);
                 }
                 // Handle delimiters and symbols
-                '(' | ')' | '{' | '}' | '[' | ']' | ',' | ':' => {
+                '(' => return Ok(self.consume_delimiter(char, NenyrTokens::ParenthesisOpen)),
+                ')' => return Ok(self.consume_delimiter(char, NenyrTokens::ParenthesisClose)),
+                '{' => return Ok(self.consume_delimiter(char, NenyrTokens::CurlyBracketOpen)),
+                '}' => return Ok(self.consume_delimiter(char, NenyrTokens::CurlyBracketClose)),
+                '[' => return Ok(self.consume_delimiter(char, NenyrTokens::SquareBracketOpen)),
+                ']' => return Ok(self.consume_delimiter(char, NenyrTokens::SquareBracketClose)),
+                ',' => return Ok(self.consume_delimiter(char, NenyrTokens::Comma)),
+                ':' => return Ok(self.consume_delimiter(char, NenyrTokens::Colon)),
+                ';' if self.allow_semicolons => {
+                    return Ok(self.consume_delimiter(char, NenyrTokens::Semicolon))
+                }
+                '+' => return Ok(self.consume_delimiter(char, NenyrTokens::Plus)),
+                '-' => return Ok(self.consume_delimiter(char, NenyrTokens::Minus)),
+                '*' => return Ok(self.consume_delimiter(char, NenyrTokens::Asterisk)),
+                // Handle string literals
+                '"' | '\'' => {
                     self.position += char.len_utf8();
                     self.column += char.len_utf8();
 
-                    match char {
-                        '(' => return Ok(NenyrTokens::ParenthesisOpen),
-                        ')' => return Ok(NenyrTokens::ParenthesisClose),
-                        '{' => return Ok(NenyrTokens::CurlyBracketOpen),
-                        '}' => return Ok(NenyrTokens::CurlyBracketClose),
-                        '[' => return Ok(NenyrTokens::SquareBracketOpen),
-                        ']' => return Ok(NenyrTokens::SquareBracketClose),
-                        ',' => return Ok(NenyrTokens::Comma),
-                        ':' => return Ok(NenyrTokens::Colon),
-                        _ => {
-                            // TODO: Replace this with a NenyrError
-                            unreachable!()
-                        }
-                    }
+                    return self.parse_string_literal(char);
                 }
-                // Handle string literals
-                '"' | '\'' => {
+                '`' => {
                     self.position += char.len_utf8();
                     self.column += char.len_utf8();
 
-                    return Ok(self.parse_string_literal(char));
+                    return self.parse_raw_string();
                 }
                 // Handle identifiers
                 'a'..='z' | 'A'..='Z' => {
@@ -302,8 +513,48 @@ impl Lexer {
             }
         }
 
-        // Return EndOfFile token when the input is exhausted
-        Ok(NenyrTokens::EndOfLine)
+        self.token_start = self.position;
+
+        // Return the EndOfFile token whenever the input is exhausted
+        Ok(NenyrTokens::EndOfFile)
+    }
+
+    /// Consumes a single delimiter or symbol character, returning its token.
+    fn consume_delimiter(&mut self, char: char, token: NenyrTokens) -> NenyrTokens {
+        self.position += char.len_utf8();
+        self.column += char.len_utf8();
+
+        token
+    }
+
+    /// Retrieves the byte span of the last token returned by `next_token`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the byte position where the token starts and the byte position right
+    /// after its end, so that `&raw_nenyr[start..end]` is the token text.
+    pub fn token_span(&self) -> (usize, usize) {
+        (self.token_start, self.position)
+    }
+
+    /// Retrieves the part of the input that was not consumed by the lexer yet.
+    ///
+    /// This is useful for debugging the parser and for tools inspecting the lexer progress.
+    ///
+    /// # Returns
+    ///
+    /// A string slice from the current position up to the end of the input.
+    pub fn remaining(&self) -> &str {
+        self.slice_from(self.position)
+    }
+
+    /// Retrieves the part of the input already consumed by the lexer.
+    ///
+    /// # Returns
+    ///
+    /// A string slice from the start of the input up to the current position.
+    pub fn consumed(&self) -> &str {
+        self.slice_between(0, self.position)
     }
 
     /// Skips over a line comment in the raw input.
@@ -326,6 +577,19 @@ impl Lexer {
         }
     }
 
+    /// Keeps the comment that was just skipped, when the comments are collected.
+    ///
+    /// # Parameters
+    ///
+    /// * `comment_start`: The position (in bytes) of the opening `//` or `/*` marker.
+    fn collect_comment(&mut self, comment_start: usize) {
+        if self.collect_comments {
+            let comment = self.slice_between(comment_start, self.position).trim_end();
+
+            self.comments.push(comment.to_string());
+        }
+    }
+
     /// Skips over a block comment in the raw input.
     ///
     /// Block comments are typically enclosed between a start marker (e.g., `/*`) and an end marker
@@ -333,7 +597,11 @@ impl Lexer {
     /// the position, line, and column counters. It also correctly handles newlines within the comment.
     fn skip_block_comment(&mut self) {
         while let Some(char) = self.current_char() {
-            if char == '*' && self.raw_nenyr[self.position + char.len_utf8()..].starts_with('/') {
+            if char == '*'
+                && self
+                    .slice_from(self.position + char.len_utf8())
+                    .starts_with('/')
+            {
                 let current_char_plus_slash_len = char.len_utf8() + '/'.len_utf8();
 
                 self.position += current_char_plus_slash_len;
@@ -375,7 +643,7 @@ impl Lexer {
             }
         }
 
-        let identifier = self.raw_nenyr[start_pos..self.position].to_string();
+        let identifier = self.slice_between(start_pos, self.position).to_string();
 
         self.match_identifier(identifier)
     }
@@ -386,14 +654,22 @@ impl Lexer {
     /// and returns a `NenyrTokens::Number` token. The position and column counters are updated as
     /// characters are processed.
     ///
-    /// # Panics
+    /// When the digits are immediately followed by letters or `%`, they are consumed as the unit of
+    /// the number and a `NenyrTokens::Dimension` token is returned instead (e.g., `8px`). The unit
+    /// itself is not validated here, leaving it to the method receiving the token.
+    ///
+    /// Underscores may be used as numeric separators between digits for readability (e.g., `1_000`),
+    /// being stripped before the number is parsed.
     ///
-    /// This method will panic if the slice of digits cannot be parsed into a valid number.
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` if an underscore is not placed between two digits, as in `1_`, `1__0`
+    /// or `1_.5`, or if the slice of digits cannot be parsed into a valid number.
     fn parse_number(&mut self) -> NenyrResult<NenyrTokens> {
         let start_pos = self.position;
 
         while let Some(char) = self.current_char() {
-            if char.is_digit(10) || char == '.' {
+            if char.is_digit(10) || char == '.' || char == '_' {
                 self.position += char.len_utf8();
                 self.column += char.len_utf8();
             } else {
@@ -401,50 +677,233 @@ impl Lexer {
             }
         }
 
-        let value = &self.raw_nenyr[start_pos..self.position];
+        let raw_value = self.slice_between(start_pos, self.position).to_string();
+
+        if !Self::has_valid_numeric_separators(&raw_value) {
+            return Err(NenyrError::new(
+                Some(format!("Place each underscore of the `{}` number between two digits, as in `1_000`, or remove the misplaced underscores.", raw_value)),
+                self.context_name.clone(),
+                self.context_path.to_string(),
+                format!("The `{}` number contains a misplaced underscore. Underscores are only allowed as separators between two digits.", raw_value),
+                NenyrErrorKind::SyntaxError,
+                self.trace_lexer_position(),
+            ).with_code("N0093"));
+        }
+
+        let value = raw_value.replace('_', "");
+        let unit_pos = self.position;
+
+        while let Some(char) = self.current_char() {
+            if char.is_ascii_alphabetic() || char == '%' {
+                self.position += char.len_utf8();
+                self.column += char.len_utf8();
+            } else {
+                break;
+            }
+        }
+
+        let unit = self.slice_between(unit_pos, self.position);
 
         match value.parse() {
-            Ok(value) => Ok(NenyrTokens::Number(value)),
+            Ok(value) if unit.is_empty() => Ok(NenyrTokens::Number(value)),
+            Ok(value) => Ok(NenyrTokens::Dimension(value, unit.to_string())),
             Err(_) => Err(NenyrError::new(
-                Some("".to_string()),
+                Some(format!("Ensure that the `{}` number is composed of digits with at most one decimal point, as in `10` or `0.5`.", raw_value)),
                 self.context_name.clone(),
                 self.context_path.to_string(),
-                "".to_string(),
+                format!("The `{}` number could not be parsed, as it is not a valid numeric value.", raw_value),
                 NenyrErrorKind::SyntaxError,
                 self.trace_lexer_position(),
-            )),
+            ).with_code("N0094")),
         }
     }
 
+    /// Checks that every underscore of a numeric literal is placed between two digits.
+    fn has_valid_numeric_separators(raw_value: &str) -> bool {
+        let chars: Vec<char> = raw_value.chars().collect();
+
+        chars.iter().enumerate().all(|(idx, char)| {
+            *char != '_'
+                || (idx
+                    .checked_sub(1)
+                    .and_then(|previous_idx| chars.get(previous_idx))
+                    .is_some_and(|previous| previous.is_ascii_digit())
+                    && chars.get(idx + 1).is_some_and(|next| next.is_ascii_digit()))
+        })
+    }
+
     /// Parses a string literal from the input, delimited by a given character.
     ///
     /// This method starts at the current position and consumes characters until it encounters the same
     /// delimiter character (like `"` or `'`) that opened the string. It returns a `NenyrTokens::StringLiteral`
-    /// token containing the extracted string (excluding the delimiters). The position and column are
+    /// token containing the extracted string (excluding the delimiters) along with the delimiter itself,
+    /// so the quote style chosen by the author can be preserved. The position and column are
     /// updated accordingly.
     ///
+    /// Strings spanning multiple lines are allowed. Their line breaks are normalized, so every `\r\n`
+    /// or lone `\r` becomes a `\n` in the extracted value, and the line counter is incremented for
+    /// each of them, keeping the tracing of the following tokens accurate.
+    ///
+    /// The `\\u{XXXX}` escapes are decoded into the character they name, e.g. `'\\u{2022}'`
+    /// becomes a bullet, while any other backslash is kept as written.
+    ///
     /// # Parameters
     ///
     /// * `entered_char` - The character that opened the string literal (e.g., `"` or `'`).
     ///
     /// # Returns
     ///
-    /// A `NenyrTokens::StringLiteral` token containing the parsed string.
-    fn parse_string_literal(&mut self, entered_char: char) -> NenyrTokens {
-        let start_pos = self.position;
+    /// A `NenyrTokens::StringLiteral` token containing the parsed string and its quote character.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` if the input ends before the closing delimiter is found, or if a
+    /// `\\u{XXXX}` escape is malformed.
+    fn parse_string_literal(&mut self, entered_char: char) -> NenyrResult<NenyrTokens> {
+        let mut value = String::new();
 
         while let Some(char) = self.current_char() {
             self.position += char.len_utf8();
             self.column += char.len_utf8();
 
             if char == entered_char {
-                break;
+                return Ok(NenyrTokens::StringLiteral {
+                    value,
+                    quote: entered_char,
+                });
+            }
+
+            if char == '\\' && self.slice_from(self.position).starts_with("u{") {
+                value.push(self.parse_unicode_escape()?);
+
+                continue;
+            }
+
+            let is_line_break = match char {
+                '\n' => true,
+                '\r' => !self.slice_from(self.position).starts_with('\n'),
+                _ => false,
+            };
+
+            if is_line_break {
+                self.line += 1;
+                self.column = 1;
+            }
+
+            match char {
+                '\r' if !is_line_break => {}
+                '\r' => value.push('\n'),
+                _ => value.push(char),
             }
         }
 
-        let value = self.raw_nenyr[start_pos..(self.position - 1)].to_string();
+        Err(NenyrError::new(
+            Some(format!("Close the string literal with a matching {} quote.", entered_char)),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            format!("A string literal opened with {} was never closed, as the end of the input was reached first.", entered_char),
+            NenyrErrorKind::SyntaxError,
+            self.trace_lexer_position(),
+        ).with_code("N0095"))
+    }
 
-        NenyrTokens::StringLiteral(value)
+    /// Parses the `\\u{XXXX}` escape of a string literal, such as the `\\u{2022}` bullet, once its
+    /// backslash has been consumed.
+    ///
+    /// The escape holds from one to six hexadecimal digits naming a Unicode code point. Any
+    /// other backslash is kept verbatim, so the CSS escapes such as `\\2022` are left untouched.
+    ///
+    /// # Returns
+    ///
+    /// The `char` named by the escape.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` pointing at the backslash if the escape is not closed, does not
+    /// hold from one to six hexadecimal digits, or names an invalid code point, such as a
+    /// surrogate.
+    fn parse_unicode_escape(&mut self) -> NenyrResult<char> {
+        let escape_start = self.position - '\\'.len_utf8();
+        let digits_start = self.position + "u{".len();
+        let rest = self.slice_from(digits_start);
+        let digits_end = rest.find(|char: char| !char.is_ascii_alphanumeric());
+        let decoded_char = digits_end
+            .filter(|&end| rest[end..].starts_with('}') && (1..=6).contains(&end))
+            .and_then(|end| u32::from_str_radix(&rest[..end], 16).ok())
+            .and_then(char::from_u32);
+
+        match (decoded_char, digits_end) {
+            (Some(decoded_char), Some(end)) => {
+                let escape_len = "u{".len() + end + "}".len();
+
+                self.position += escape_len;
+                self.column += escape_len;
+
+                Ok(decoded_char)
+            }
+            _ => {
+                let escape_end = digits_end.map_or(digits_start, |end| {
+                    digits_start + end + usize::from(rest[end..].starts_with('}'))
+                });
+                let escape = self.slice_between(escape_start, escape_end);
+
+                Err(NenyrError::new(
+                    Some("Write the Unicode escape as `\\u{XXXX}`, with one to six hexadecimal digits naming a valid code point, e.g. `\\u{2022}` for a bullet.".to_string()),
+                    self.context_name.clone(),
+                    self.context_path.to_string(),
+                    format!("The Unicode escape `{}` is not valid, as it is either malformed or does not name a valid code point.", escape),
+                    NenyrErrorKind::SyntaxError,
+                    self.trace_position(escape_start),
+                ).with_code("N0104"))
+            }
+        }
+    }
+
+    /// Parses a raw string delimited by backticks, such as `` `"header header"` ``.
+    ///
+    /// The contents are taken verbatim: quotes need no escaping and line breaks are kept as
+    /// written, while the line counter is still incremented for each of them.
+    ///
+    /// # Returns
+    ///
+    /// A `NenyrTokens::RawString` token containing the contents between the backticks.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NenyrError` if the input ends before the closing backtick is found.
+    fn parse_raw_string(&mut self) -> NenyrResult<NenyrTokens> {
+        let start_pos = self.position;
+
+        while let Some(char) = self.current_char() {
+            self.position += char.len_utf8();
+            self.column += char.len_utf8();
+
+            if char == '`' {
+                let value = self.slice_between(start_pos, self.position - char.len_utf8());
+
+                return Ok(NenyrTokens::RawString(value.to_string()));
+            }
+
+            let is_line_break = match char {
+                '\n' => true,
+                '\r' => !self.slice_from(self.position).starts_with('\n'),
+                _ => false,
+            };
+
+            if is_line_break {
+                self.line += 1;
+                self.column = 1;
+            }
+        }
+
+        Err(NenyrError::new(
+            Some("Close the raw string with a matching ` backtick.".to_string()),
+            self.context_name.clone(),
+            self.context_path.to_string(),
+            "A raw string opened with ` was never closed, as the end of the input was reached first.".to_string(),
+            NenyrErrorKind::SyntaxError,
+            self.trace_lexer_position(),
+        ).with_code("N0096"))
     }
 
     /// Matches a given identifier against predefined Nenyr keywords and returns the corresponding token.
@@ -483,10 +942,13 @@ impl Lexer {
             "Themes" => NenyrTokens::Themes,
             "Aliases" => NenyrTokens::Aliases,
             "Variables" => NenyrTokens::Variables,
+            "Const" => NenyrTokens::Const,
+            "Mixin" => NenyrTokens::Mixin,
             "Class" => NenyrTokens::Class,
 
             // Import pattern
             "Import" => NenyrTokens::Import,
+            "Layer" => NenyrTokens::Layer,
 
             // Breakpoints pattern
             "MobileFirst" => NenyrTokens::MobileFirst,
@@ -498,6 +960,7 @@ impl Lexer {
 
             // Animation pattern
             "Animation" => NenyrTokens::Animation,
+            "Keyframes" => NenyrTokens::Keyframes,
             "Fraction" => NenyrTokens::Fraction,
             "Progressive" => NenyrTokens::Progressive,
             "From" => NenyrTokens::From,
@@ -512,6 +975,13 @@ impl Lexer {
             "Important" => NenyrTokens::Important,
             "Stylesheet" => NenyrTokens::Stylesheet,
             "PanoramicViewer" => NenyrTokens::PanoramicViewer,
+            "Supports" => NenyrTokens::Supports,
+            "Container" => NenyrTokens::Container,
+            "Not" => NenyrTokens::Not,
+            "NthChild" => NenyrTokens::NthChild,
+            "Attribute" => NenyrTokens::Attribute,
+            "Group" => NenyrTokens::Group,
+            "Include" => NenyrTokens::Include,
             "Hover" => NenyrTokens::Hover,
             "Active" => NenyrTokens::Active,
             "Focus" => NenyrTokens::Focus,
@@ -800,6 +1270,59 @@ impl Lexer {
             "scrollbarWidth" => NenyrTokens::ScrollbarWidth,
             "scrollbarGutter" => NenyrTokens::ScrollbarGutter,
 
+            // Logical properties
+            "marginInline" => NenyrTokens::MarginInline,
+            "marginInlineStart" => NenyrTokens::MarginInlineStart,
+            "marginInlineEnd" => NenyrTokens::MarginInlineEnd,
+            "marginBlock" => NenyrTokens::MarginBlock,
+            "marginBlockStart" => NenyrTokens::MarginBlockStart,
+            "marginBlockEnd" => NenyrTokens::MarginBlockEnd,
+            "paddingInline" => NenyrTokens::PaddingInline,
+            "paddingInlineStart" => NenyrTokens::PaddingInlineStart,
+            "paddingInlineEnd" => NenyrTokens::PaddingInlineEnd,
+            "paddingBlock" => NenyrTokens::PaddingBlock,
+            "paddingBlockStart" => NenyrTokens::PaddingBlockStart,
+            "paddingBlockEnd" => NenyrTokens::PaddingBlockEnd,
+            "inset" => NenyrTokens::Inset,
+            "insetInline" => NenyrTokens::InsetInline,
+            "insetInlineStart" => NenyrTokens::InsetInlineStart,
+            "insetInlineEnd" => NenyrTokens::InsetInlineEnd,
+            "insetBlock" => NenyrTokens::InsetBlock,
+            "insetBlockStart" => NenyrTokens::InsetBlockStart,
+            "insetBlockEnd" => NenyrTokens::InsetBlockEnd,
+            "borderInline" => NenyrTokens::BorderInline,
+            "borderInlineStart" => NenyrTokens::BorderInlineStart,
+            "borderInlineEnd" => NenyrTokens::BorderInlineEnd,
+            "borderBlock" => NenyrTokens::BorderBlock,
+            "borderBlockStart" => NenyrTokens::BorderBlockStart,
+            "borderBlockEnd" => NenyrTokens::BorderBlockEnd,
+            "inlineSize" => NenyrTokens::InlineSize,
+            "blockSize" => NenyrTokens::BlockSize,
+            "minInlineSize" => NenyrTokens::MinInlineSize,
+            "maxInlineSize" => NenyrTokens::MaxInlineSize,
+            "minBlockSize" => NenyrTokens::MinBlockSize,
+            "maxBlockSize" => NenyrTokens::MaxBlockSize,
+
+            // Vendor-prefixed properties
+            "webkitBackdropFilter" => NenyrTokens::WebkitBackdropFilter,
+            "webkitAppearance" => NenyrTokens::WebkitAppearance,
+            "mozAppearance" => NenyrTokens::MozAppearance,
+            "webkitUserSelect" => NenyrTokens::WebkitUserSelect,
+            "mozUserSelect" => NenyrTokens::MozUserSelect,
+            "webkitBackgroundClip" => NenyrTokens::WebkitBackgroundClip,
+            "webkitTextFillColor" => NenyrTokens::WebkitTextFillColor,
+            "webkitTextStroke" => NenyrTokens::WebkitTextStroke,
+            "webkitTextStrokeColor" => NenyrTokens::WebkitTextStrokeColor,
+            "webkitTextStrokeWidth" => NenyrTokens::WebkitTextStrokeWidth,
+            "webkitLineClamp" => NenyrTokens::WebkitLineClamp,
+            "webkitBoxOrient" => NenyrTokens::WebkitBoxOrient,
+            "webkitTapHighlightColor" => NenyrTokens::WebkitTapHighlightColor,
+            "webkitFontSmoothing" => NenyrTokens::WebkitFontSmoothing,
+            "mozOsxFontSmoothing" => NenyrTokens::MozOsxFontSmoothing,
+            "webkitMaskImage" => NenyrTokens::WebkitMaskImage,
+            "webkitOverflowScrolling" => NenyrTokens::WebkitOverflowScrolling,
+            "mozTabSize" => NenyrTokens::MozTabSize,
+
             // That's means that the received identifier is not a token,
             // then return it as an Identifier.
             _ => NenyrTokens::Identifier(identifier),
@@ -816,7 +1339,7 @@ mod tests {
         let input = "";
         let mut lexer = Lexer::new(input.to_string(), "".to_string());
 
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
     }
 
     #[test]
@@ -825,7 +1348,7 @@ mod tests {
         let mut lexer = Lexer::new(input.to_string(), "".to_string());
 
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
     }
 
     #[test]
@@ -837,7 +1360,7 @@ mod tests {
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisClose));
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::CurlyBracketOpen));
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::CurlyBracketClose));
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
     }
 
     #[test]
@@ -847,7 +1370,7 @@ mod tests {
 
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisClose));
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
     }
 
     #[test]
@@ -857,9 +1380,41 @@ mod tests {
 
         assert_eq!(
             lexer.next_token(),
-            Ok(NenyrTokens::StringLiteral("hello".to_string()))
+            Ok(NenyrTokens::StringLiteral {
+                value: "hello".to_string(),
+                quote: '"',
+            })
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+    }
+
+    #[test]
+    fn string_literal_quote_must_be_preserved() {
+        let input = "'single' \"double\" 'it\"s'";
+        let mut lexer = Lexer::new(input.to_string(), "".to_string());
+
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::StringLiteral {
+                value: "single".to_string(),
+                quote: '\'',
+            })
+        );
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::StringLiteral {
+                value: "double".to_string(),
+                quote: '"',
+            })
         );
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::StringLiteral {
+                value: "it\"s".to_string(),
+                quote: '\'',
+            })
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
     }
 
     #[test]
@@ -869,7 +1424,25 @@ mod tests {
 
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisClose));
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+    }
+
+    #[test]
+    fn comments_must_be_taken_when_collected() {
+        let input = "// line comment\n( /* block\n comment */ ) / // last";
+        let mut lexer = Lexer::new(input.to_string(), "".to_string());
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+        assert!(lexer.take_comments().is_empty());
+
+        lexer.set_comments_collected(true);
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisClose));
+        assert_eq!(lexer.take_comments(), ["/* block\n comment */"]);
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Slash));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+        assert_eq!(lexer.take_comments(), ["// last"]);
+        assert!(lexer.take_comments().is_empty());
     }
 
     #[test]
@@ -878,7 +1451,7 @@ mod tests {
         let mut lexer = Lexer::new(input.to_string(), "".to_string());
 
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::Construct));
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
     }
 
     #[test]
@@ -887,7 +1460,55 @@ mod tests {
         let mut lexer = Lexer::new(input.to_string(), "".to_string());
 
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::Number(123.0)));
-        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfLine));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+    }
+
+    #[test]
+    fn numeric_separators_must_be_stripped() {
+        let mut lexer = Lexer::new("1_000 1_000.5 2_5px".to_string(), "".to_string());
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Number(1000.0)));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Number(1000.5)));
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::Dimension(25.0, "px".to_string()))
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+    }
+
+    #[test]
+    fn misplaced_numeric_separators_must_be_rejected() {
+        for input in ["1_", "1__0", "1_.5", "1._5"] {
+            let mut lexer = Lexer::new(input.to_string(), "".to_string());
+
+            match lexer.next_token() {
+                Err(err) => assert_eq!(
+                    err.get_error_message(),
+                    format!("The `{}` number contains a misplaced underscore. Underscores are only allowed as separators between two digits.", input)
+                ),
+                Ok(token) => panic!("{}: {:?}", input, token),
+            }
+        }
+    }
+
+    #[test]
+    fn test_dimension() {
+        let input = "8px 1.5rem 50%";
+        let mut lexer = Lexer::new(input.to_string(), "".to_string());
+
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::Dimension(8.0, "px".to_string()))
+        );
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::Dimension(1.5, "rem".to_string()))
+        );
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::Dimension(50.0, "%".to_string()))
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
     }
 
     #[test]
@@ -898,6 +1519,7 @@ mod tests {
         assert_eq!(
             lexer.next_token(),
             Err(NenyrError {
+                error_code: "N0091",
                 suggestion: Some("To resolve the error, please remove the unsupported token `@` from your Nenyr code and revalidate. Ensure all tokens comply with Nenyr syntax to avoid future issues.".to_string()),
                 context_name: None,
                 context_path: "".to_string(),
@@ -923,6 +1545,7 @@ mod tests {
         assert_eq!(
             lexer.next_token(),
             Err(NenyrError {
+                error_code: "N0091",
                 suggestion: Some("To resolve the error, please remove the unsupported token `@` from your Nenyr code and revalidate. Ensure all tokens comply with Nenyr syntax to avoid future issues.".to_string()),
                 context_name: None,
                 context_path: "".to_string(),
@@ -952,6 +1575,7 @@ mod tests {
         assert_eq!(
             lexer.next_token(),
             Err(NenyrError {
+                error_code: "N0091",
                 suggestion: Some("To resolve the error, please remove the unsupported token `@` from your Nenyr code and revalidate. Ensure all tokens comply with Nenyr syntax to avoid future issues.".to_string()),
                 context_name: None,
                 context_path: "".to_string(),
@@ -979,6 +1603,7 @@ mod tests {
         assert_eq!(
             lexer.next_token(),
             Err(NenyrError {
+                error_code: "N0091",
                 suggestion: Some("To resolve the error, please remove the unsupported token `@` from your Nenyr code and revalidate. Ensure all tokens comply with Nenyr syntax to avoid future issues.".to_string()),
                 context_name: None,
                 context_path: "".to_string(),
@@ -998,6 +1623,35 @@ mod tests {
         assert_eq!(lexer.next_token(), Ok(NenyrTokens::Declare));
     }
 
+    #[test]
+    fn token_span_must_be_byte_accurate() {
+        let raw_nenyr = "/* ção */ Declare  Class('açaí')";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Declare));
+        assert_eq!(
+            &raw_nenyr[lexer.token_span().0..lexer.token_span().1],
+            "Declare"
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Class));
+        assert_eq!(
+            &raw_nenyr[lexer.token_span().0..lexer.token_span().1],
+            "Class"
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::StringLiteral {
+                value: "açaí".to_string(),
+                quote: '\'',
+            })
+        );
+        assert_eq!(
+            &raw_nenyr[lexer.token_span().0..lexer.token_span().1],
+            "'açaí'"
+        );
+    }
+
     #[test]
     fn performance_test_large_valid_nenyr_vector() {
         let large_nenyr_vector: Vec<_> = (0..1_000_000).map(|_| "Construct").collect();
@@ -1033,6 +1687,7 @@ mod tests {
             assert_eq!(
                 lexer.next_token(),
                 Err(NenyrError {
+                    error_code: "N0091",
                     suggestion: Some("To resolve the error, please remove the unsupported token `@` from your Nenyr code and revalidate. Ensure all tokens comply with Nenyr syntax to avoid future issues.".to_string()),
                     context_name: None,
                     context_path: "".to_string(),
@@ -1050,4 +1705,302 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn string_literal_line_breaks_are_normalized() {
+        let raw_nenyr = "'first line\r\nsecond line\rthird line' Declare";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::StringLiteral {
+                value: "first line\nsecond line\nthird line".to_string(),
+                quote: '\'',
+            })
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Declare));
+        assert_eq!(lexer.line, 3);
+        assert_eq!(lexer.column, 20);
+    }
+
+    #[test]
+    fn remaining_must_reflect_the_unconsumed_input() {
+        let raw_nenyr = "Declare Class('box') { }";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        assert_eq!(lexer.remaining(), raw_nenyr);
+        assert_eq!(lexer.consumed(), "");
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Declare));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Class));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+
+        assert_eq!(lexer.remaining(), "'box') { }");
+        assert_eq!(lexer.consumed(), "Declare Class(");
+
+        while lexer.next_token() != Ok(NenyrTokens::EndOfFile) {}
+
+        assert_eq!(lexer.remaining(), "");
+        assert_eq!(lexer.consumed(), raw_nenyr);
+    }
+
+    #[test]
+    fn end_of_file_must_be_yielded_once_the_input_is_exhausted() {
+        let mut lexer = Lexer::new("Declare Class('box') // done\n".to_string(), "".to_string());
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Declare));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Class));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+        assert!(matches!(
+            lexer.next_token(),
+            Ok(NenyrTokens::StringLiteral { .. })
+        ));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisClose));
+
+        for _ in 0..3 {
+            assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+        }
+    }
+
+    #[test]
+    fn line_comments_must_be_rejected_when_forbidden() {
+        let raw_nenyr = "( /* block */ ) // line";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisClose));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        lexer.set_line_comments_forbidden(true);
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisClose));
+
+        match lexer.next_token() {
+            Err(err) => {
+                assert_eq!(err.get_column(), 17);
+                assert_eq!(err.get_position(), 16);
+                assert_eq!(&raw_nenyr[err.get_position()..], "// line");
+            }
+            Ok(token) => panic!("{:?}", token),
+        }
+    }
+
+    #[test]
+    fn unicode_escapes_must_be_decoded_in_string_literals() {
+        let raw_nenyr = r"'\u{2022}' 'a\u{1F600}b' '\2022'";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        for expected_value in ["\u{2022}", "a\u{1F600}b", r"\2022"] {
+            assert_eq!(
+                lexer.next_token(),
+                Ok(NenyrTokens::StringLiteral {
+                    value: expected_value.to_string(),
+                    quote: '\'',
+                })
+            );
+        }
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+    }
+
+    #[test]
+    fn malformed_unicode_escapes_must_be_rejected() {
+        let raw_values = [
+            r"'\u{ZZZZ}'",
+            r"'\u{}'",
+            r"'\u{D800}'",
+            r"'\u{1234567}'",
+            r"'\u{2022'",
+        ];
+
+        for raw_nenyr in raw_values {
+            let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+            match lexer.next_token() {
+                Err(err) => {
+                    assert_eq!(err.get_error_code(), "N0104");
+                    assert_eq!(err.get_column(), 2);
+                }
+                Ok(token) => panic!("{:?}", token),
+            }
+        }
+    }
+
+    #[test]
+    fn semicolons_must_be_tokenized_only_when_allowed() {
+        let raw_nenyr = "color: 'red';";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Color));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Colon));
+        assert!(lexer.next_token().is_ok());
+        assert!(lexer.next_token().is_err());
+
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        lexer.set_semicolons_allowed(true);
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Color));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Colon));
+        assert!(lexer.next_token().is_ok());
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Semicolon));
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::EndOfFile));
+    }
+
+    #[test]
+    fn tab_width_must_be_used_for_column_counting() {
+        let raw_nenyr = "(\n\t\t$";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+
+        match lexer.next_token() {
+            Err(err) => assert_eq!(err.get_column(), 4),
+            Ok(token) => panic!("{:?}", token),
+        }
+
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+
+        lexer.set_tab_width(4);
+
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::ParenthesisOpen));
+
+        match lexer.next_token() {
+            Err(err) => {
+                assert_eq!(err.get_line(), 2);
+                assert_eq!(err.get_column(), 10);
+                assert_eq!(err.get_position(), 5);
+            }
+            Ok(token) => panic!("{:?}", token),
+        }
+    }
+
+    #[test]
+    fn unterminated_string_literals_are_not_valid() {
+        for raw_nenyr in ["'", "\"abc", "('abc\ndef"] {
+            let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+            let mut result = lexer.next_token();
+
+            while let Ok(NenyrTokens::ParenthesisOpen) = result {
+                result = lexer.next_token();
+            }
+
+            match result {
+                Err(err) => assert!(err
+                    .get_error_message()
+                    .starts_with("A string literal opened with")),
+                Ok(token) => panic!("{}: {:?}", raw_nenyr, token),
+            }
+        }
+    }
+
+    #[test]
+    fn raw_strings_must_be_taken_verbatim() {
+        let input = "`\"header header\"\r\n  \"main side\" \\n`, 'next'";
+        let mut lexer = Lexer::new(input.to_string(), "".to_string());
+
+        assert_eq!(
+            lexer.next_token(),
+            Ok(NenyrTokens::RawString(
+                "\"header header\"\r\n  \"main side\" \\n".to_string()
+            ))
+        );
+        assert_eq!(lexer.next_token(), Ok(NenyrTokens::Comma));
+        assert_eq!(lexer.line, 2);
+    }
+
+    #[test]
+    fn unterminated_raw_strings_are_not_valid() {
+        let mut lexer = Lexer::new("`abc\ndef".to_string(), "".to_string());
+
+        match lexer.next_token() {
+            Err(err) => assert_eq!(
+                err.get_error_message(),
+                "A raw string opened with ` was never closed, as the end of the input was reached first."
+            ),
+            Ok(token) => panic!("{:?}", token),
+        }
+    }
+
+    #[test]
+    fn numbers_with_many_decimal_points_are_not_valid() {
+        let mut lexer = Lexer::new("1.2.3".to_string(), "".to_string());
+
+        match lexer.next_token() {
+            Err(err) => assert_eq!(
+                err.get_error_message(),
+                "The `1.2.3` number could not be parsed, as it is not a valid numeric value."
+            ),
+            Ok(token) => panic!("{:?}", token),
+        }
+    }
+
+    #[test]
+    fn random_inputs_must_never_panic() {
+        const ALPHABET: &[u8] = b"(){}[],:'\"/*_.-%$#@!\t\r\n 0123456789azAZ";
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next_random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        for iteration in 0..2_000 {
+            let length = (next_random() % 64) as usize;
+            let bytes: Vec<u8> = (0..length)
+                .map(|_| {
+                    let random = next_random();
+
+                    if iteration % 2 == 0 {
+                        random as u8
+                    } else {
+                        ALPHABET[(random as usize) % ALPHABET.len()]
+                    }
+                })
+                .collect();
+            let raw_nenyr = String::from_utf8_lossy(&bytes).to_string();
+            let mut lexer = Lexer::new(raw_nenyr.clone(), "".to_string());
+
+            for _ in 0..=raw_nenyr.len() {
+                match lexer.next_token() {
+                    Ok(NenyrTokens::EndOfFile) | Err(_) => break,
+                    Ok(_) => {
+                        let _ = lexer.remaining();
+                        let _ = lexer.consumed();
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn multi_byte_characters_next_to_quotes_and_line_breaks_must_not_panic() {
+        let raw_nenyr = "'é'\r\n\"ü\r\nß\"\r\n'ç'\r\n`ñ`é";
+        let mut lexer = Lexer::new(raw_nenyr.to_string(), "".to_string());
+        let mut values = vec![];
+
+        loop {
+            match lexer.next_token() {
+                Ok(NenyrTokens::EndOfFile) => break,
+                Ok(NenyrTokens::StringLiteral { value, .. }) => values.push(value),
+                Ok(token) => values.push(format!("{:?}", token)),
+                Err(err) => values.push(err.get_error_message()),
+            }
+
+            if lexer.remaining().is_empty() {
+                break;
+            }
+        }
+
+        assert_eq!(values[..3], ["é", "ü\nß", "ç"]);
+
+        for position in 0..=raw_nenyr.len() + 1 {
+            let tracing = lexer.trace_position(position);
+            assert!(tracing.error_on_pos <= raw_nenyr.len());
+        }
+    }
 }
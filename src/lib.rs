@@ -1,35 +1,62 @@
-use converters::{property::NenyrPropertyConverter, style_pattern::NenyrStylePatternConverter};
-use error::{NenyrError, NenyrErrorKind};
-use lexer::Lexer;
+use converters::{
+    css_emitter::{emit_css_with, EmitOptions},
+    property::NenyrPropertyConverter,
+    style_pattern::NenyrStylePatternConverter,
+};
+use error::{NenyrDiagnostic, NenyrError, NenyrErrorKind, Severity};
+use interner::NenyrValueInterner;
+use options::{
+    DiagnosticsLimitPolicy, DuplicatedStylesheetPolicy, IncompleteTransitivePolicy,
+    NenyrParserOptions, UnknownPropertyPolicy,
+};
+use sink::NenyrDeclarationSink;
+use stats::ParseStats;
+use std::{collections::HashMap, time::Instant};
 use store::NenyrProcessStore;
-use tokens::NenyrTokens;
-use types::ast::NenyrAst;
+use tokens::{NenyrTokenKind, NenyrTokens};
+use types::{
+    ast::{NenyrAst, NenyrReusableMaps},
+    central::CentralContext,
+    comments::NenyrComments,
+    declaration::NenyrDeclaration,
+};
 use validators::{
-    breakpoint::NenyrBreakpointValidator, identifier::NenyrIdentifierValidator,
-    import::NenyrImportValidator, style_syntax::NenyrStyleSyntaxValidator,
-    typeface::NenyrTypefaceValidator, variable_value::NenyrVariableValueValidator,
+    breakpoint::NenyrBreakpointValidator, container_query::NenyrContainerQueryValidator,
+    feature_query::NenyrFeatureQueryValidator, identifier::NenyrIdentifierValidator,
+    import::NenyrImportValidator, pseudo_argument::NenyrPseudoArgumentValidator,
+    style_syntax::NenyrStyleSyntaxValidator, typeface::NenyrTypefaceValidator,
+    variable_value::NenyrVariableValueValidator,
 };
 
-mod converters {
-    pub mod property;
-    pub mod style_pattern;
+pub mod converters {
+    pub mod box_shorthand;
+    pub mod css_emitter;
+    pub(crate) mod property;
+    pub mod specificity;
+    pub(crate) mod style_pattern;
 }
 
 mod interfaces {
     pub mod aliases;
     pub mod animations;
+    pub mod at_rules;
     pub mod breakpoints;
     pub mod central;
     pub mod class;
+    pub mod consts;
+    pub mod container;
     pub mod delimiters;
+    pub mod expressions;
     pub mod handlers;
     pub mod imports;
     pub mod keywords;
     pub mod layout;
     pub mod literals;
+    pub mod mixins;
     pub mod module;
     pub mod panoramic;
     pub mod patterns;
+    pub mod supports;
     pub mod themes;
     pub mod typefaces;
     pub mod variables;
@@ -40,10 +67,16 @@ pub mod types {
     pub mod animations;
     pub mod ast;
     pub mod breakpoints;
+    pub mod canonical;
     pub mod central;
     pub mod class;
+    pub mod collection;
+    pub mod comments;
+    pub mod consts;
+    pub mod declaration;
     pub mod imports;
     pub mod layout;
+    pub mod mixins;
     pub mod module;
     pub mod themes;
     pub mod typefaces;
@@ -52,18 +85,28 @@ pub mod types {
 
 mod validators {
     pub mod breakpoint;
+    pub mod container_query;
+    pub mod feature_query;
     pub mod identifier;
     pub mod import;
+    pub mod pseudo_argument;
     pub mod style_syntax;
     pub mod typeface;
     pub mod variable_value;
 }
 
 pub mod error;
+pub mod formatter;
+mod interner;
 mod lexer;
 mod macros;
+pub mod options;
+mod sink;
+pub mod stats;
 mod store;
-mod tokens;
+pub mod tokens;
+
+pub use lexer::Lexer;
 
 /// A type alias for results returned by Nenyr operations.
 ///
@@ -83,16 +126,37 @@ pub type NenyrResult<T> = Result<T, NenyrError>;
 /// - `lexer`: An instance of the `Lexer` used for tokenizing the raw Nenyr input.
 /// - `context_path`: A string slice representing the path to the current context being parsed.
 /// - `context_name`: An optional name for the current context, if defined.
+/// - `context_name_override`: The context name injected by `NenyrParser::parse_with_context_name`,
+///   taking precedence over the name declared by the parsed context.
 /// - `current_token`: The token currently being processed, represented as a `NenyrTokens`.
 /// - `processing_state`: An instance of `NenyrProcessStore` that maintains the state
 ///   during parsing operations.
+/// - `value_interner`: A pool of shared values, allowing identical style property values
+///   to share the same allocation when interning is enabled.
+/// - `options`: The `NenyrParserOptions` enabling the optional checks performed while parsing.
+/// - `diagnostics`: The issues found during the last parsing, either raised by the enabled
+///   checks or by a fatal error.
+/// - `declaration_sink`: The optional callback consuming each declaration as soon as it is parsed.
+/// - `stats`: The tokens and declarations counted during the last parsing.
+/// - `comments`: The comments collected during the last parsing, when enabled.
+/// - `reusable_maps`: The emptied maps of the AST given to `NenyrParser::parse_into`, refilled
+///   by the context being assembled.
 #[derive(Clone, PartialEq, Debug)]
 pub struct NenyrParser {
     lexer: Lexer,
     context_path: String,
     context_name: Option<String>,
+    context_name_override: Option<String>,
     current_token: NenyrTokens,
     processing_state: NenyrProcessStore,
+    value_interner: NenyrValueInterner,
+    options: NenyrParserOptions,
+    diagnostics: Vec<NenyrDiagnostic>,
+    declaration_sink: NenyrDeclarationSink,
+    stats: ParseStats,
+    token_histogram: HashMap<NenyrTokenKind, usize>,
+    comments: NenyrComments,
+    reusable_maps: NenyrReusableMaps,
 }
 
 impl NenyrIdentifierValidator for NenyrParser {}
@@ -103,6 +167,9 @@ impl NenyrVariableValueValidator for NenyrParser {}
 impl NenyrTypefaceValidator for NenyrParser {}
 impl NenyrImportValidator for NenyrParser {}
 impl NenyrBreakpointValidator for NenyrParser {}
+impl NenyrFeatureQueryValidator for NenyrParser {}
+impl NenyrContainerQueryValidator for NenyrParser {}
+impl NenyrPseudoArgumentValidator for NenyrParser {}
 
 impl NenyrParser {
     /// Creates a new instance of `NenyrParser`.
@@ -122,17 +189,473 @@ impl NenyrParser {
             lexer: Lexer::new("".to_string(), "".to_string()),
             context_path: "".to_string(),
             context_name: None,
+            context_name_override: None,
             current_token: NenyrTokens::StartOfFile,
             processing_state: NenyrProcessStore::new(),
+            value_interner: NenyrValueInterner::new(),
+            options: NenyrParserOptions::new(),
+            diagnostics: Vec::new(),
+            declaration_sink: NenyrDeclarationSink::new(),
+            stats: ParseStats::new(),
+            token_histogram: HashMap::new(),
+            comments: NenyrComments::new(),
+            reusable_maps: (None, None),
+        }
+    }
+
+    /// Registers a callback consuming each declaration as soon as its block finishes parsing.
+    ///
+    /// This allows very large contexts to be processed in a streaming fashion. Every
+    /// `Declare` block is handed to the callback as a `NenyrDeclaration` and is not stored
+    /// in the returned AST, which only keeps the context itself (e.g. the layout name or the
+    /// module it extends from), unless `NenyrParser::set_sunk_declarations_kept` is enabled.
+    ///
+    /// # Parameters
+    /// - `sink`: The callback invoked with each completed declaration, in source order.
+    ///
+    /// # Returns
+    /// The `NenyrParser` with the declaration sink registered.
+    pub fn with_declaration_sink(
+        mut self,
+        sink: impl FnMut(NenyrDeclaration) + Send + 'static,
+    ) -> Self {
+        self.declaration_sink.set_callback(sink);
+
+        self
+    }
+
+    /// Sets the directory the relative import paths are resolved against.
+    ///
+    /// The base path is only used by `resolved_imports`, which joins it with the relative
+    /// imports of a central context. No file is read.
+    ///
+    /// # Parameters
+    /// - `base_path`: The directory the imports are relative to, usually the directory of
+    ///   the parsed context.
+    ///
+    /// # Returns
+    /// The `NenyrParser` with the base path set.
+    pub fn with_base_path(mut self, base_path: impl Into<String>) -> Self {
+        self.options.base_path = Some(base_path.into());
+
+        self
+    }
+
+    /// Retrieves the imports of a central context, resolving the relative paths against the
+    /// base path set through `with_base_path`.
+    ///
+    /// URLs and absolute paths are kept untouched, as are every import when no base path
+    /// was set.
+    ///
+    /// # Parameters
+    /// - `central_context`: The central context holding the imports.
+    ///
+    /// # Returns
+    /// A `Vec<String>` holding the imports in declaration order.
+    pub fn resolved_imports(&self, central_context: &CentralContext) -> Vec<String> {
+        let Some(imports) = &central_context.imports else {
+            return vec![];
+        };
+
+        match &self.options.base_path {
+            Some(base_path) => imports.resolve_against(base_path),
+            None => imports.values.keys().cloned().collect(),
         }
     }
 
+    /// Enables or disables the interning of style property values.
+    ///
+    /// When enabled, identical values declared inside classes, animations and `Supports`
+    /// patterns share the same `Arc<str>` allocation within the parsed AST, reducing the
+    /// memory used by large contexts that repeat the same values. Interning is disabled
+    /// by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether identical values must share the same allocation.
+    pub fn set_value_interning(&mut self, is_enabled: bool) {
+        self.value_interner.set_enabled(is_enabled);
+    }
+
+    /// Enables or disables the warning raised when a `MobileFirst` and a `DesktopFirst`
+    /// breakpoint share the same value.
+    ///
+    /// Identical thresholds across both schemas are valid, but usually come from a
+    /// copy-paste mistake. When enabled, each shared value produces a `NenyrDiagnostic`
+    /// with the `Warning` severity.
+    /// The warning is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether shared breakpoint values must be reported.
+    pub fn set_shared_breakpoint_values_warning(&mut self, is_enabled: bool) {
+        self.options.warn_on_shared_breakpoint_values = is_enabled;
+    }
+
+    /// Enables or disables the warning raised when the pixel thresholds of a breakpoints
+    /// schema are not monotonically ordered.
+    ///
+    /// The `MobileFirst` thresholds are expected to increase and the `DesktopFirst` ones to
+    /// decrease, so a breakpoint breaking that order is likely a mistake. When enabled, each
+    /// such breakpoint produces a `NenyrDiagnostic` with the `Warning` severity. Values not
+    /// expressed in pixels are ignored. The warning is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether unordered breakpoints must be reported.
+    pub fn set_unordered_breakpoints_warning(&mut self, is_enabled: bool) {
+        self.options.warn_on_unordered_breakpoints = is_enabled;
+    }
+
+    /// Enables or disables the recording of the number of tokens of each kind processed
+    /// during a parsing, which is retrieved through `NenyrParser::token_histogram`.
+    ///
+    /// The recording is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether the token histogram must be recorded.
+    pub fn set_token_histogram(&mut self, is_enabled: bool) {
+        self.options.record_token_histogram = is_enabled;
+    }
+
+    /// Sets whether the declarations handed to the sink registered through
+    /// `NenyrParser::with_declaration_sink` must also be stored in the returned AST.
+    ///
+    /// The declarations are removed from the AST by default, so that very large contexts
+    /// are not kept in memory.
+    ///
+    /// # Parameters
+    /// - `is_kept`: Whether the sunk declarations must be kept in the AST.
+    pub fn set_sunk_declarations_kept(&mut self, is_kept: bool) {
+        self.options.keep_sunk_declarations = is_kept;
+    }
+
+    /// Retrieves the number of tokens of each kind processed during the last parsing.
+    ///
+    /// The histogram is only recorded when enabled through `NenyrParser::set_token_histogram`,
+    /// being empty otherwise. Tokens carrying a value, such as identifiers and string literals,
+    /// are counted by their kind, regardless of the value. The histogram is reset at the
+    /// beginning of every parsing.
+    ///
+    /// # Returns
+    /// A `HashMap` mapping each `NenyrTokenKind` to the number of times it was processed.
+    pub fn token_histogram(&self) -> HashMap<NenyrTokenKind, usize> {
+        self.token_histogram.clone()
+    }
+
+    /// Sets whether the comments of the parsed context must be collected.
+    ///
+    /// The comments are not part of the AST, so they are dropped by default. When collected,
+    /// they are available through `NenyrParser::get_comments` and can be written back by
+    /// `formatter::format_with_comments`.
+    ///
+    /// # Parameters
+    /// - `is_collected`: Whether the comments must be collected.
+    pub fn set_comments_collected(&mut self, is_collected: bool) {
+        self.options.collect_comments = is_collected;
+    }
+
+    /// Retrieves the comments collected during the last parsing.
+    ///
+    /// The comments are only collected when enabled through `NenyrParser::set_comments_collected`,
+    /// being empty otherwise.
+    ///
+    /// # Returns
+    /// The `NenyrComments` found before, within and after the declarations of the context.
+    pub fn get_comments(&self) -> NenyrComments {
+        self.comments.clone()
+    }
+
+    /// Enables or disables the warning raised when a shorthand property is declared after
+    /// one of its longhands in the same block, e.g. `background` after `backgroundColor`.
+    ///
+    /// The shorthand resets every longhand declared before it, so such declarations are
+    /// usually a mistake. When enabled, each conflict produces a `NenyrDiagnostic` with the
+    /// `Warning` severity naming both properties. The warning is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether shorthand and longhand conflicts must be reported.
+    pub fn set_shorthand_conflict_warning(&mut self, is_enabled: bool) {
+        self.options.warn_on_shorthand_conflicts = is_enabled;
+    }
+
+    /// Enables or disables the warning raised when a class references an animation that was
+    /// not declared in the context, e.g. `animation: 'giddyRespond 2s'` without a preceding
+    /// `Declare Animation('giddyRespond')`.
+    ///
+    /// The leading identifier of each animation in the `animation` and `animationName` values
+    /// is taken as the referenced name. Since a class may use animations declared by other
+    /// contexts, the warning is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether references to undeclared animations must be reported.
+    pub fn set_undeclared_animation_warning(&mut self, is_enabled: bool) {
+        self.options.warn_on_undeclared_animations = is_enabled;
+    }
+
+    /// Sets how the properties that are neither a Nenyr property nor an alias declared
+    /// earlier in the same context are handled.
+    ///
+    /// By default, such properties pass through as deferred alias references, since they
+    /// may refer to aliases declared by another context. Use `UnknownPropertyPolicy::Error`
+    /// to reject them, or `UnknownPropertyPolicy::Warn` to report them as warnings.
+    ///
+    /// # Parameters
+    /// - `policy`: The `UnknownPropertyPolicy` to be applied.
+    pub fn set_unknown_property_policy(&mut self, policy: UnknownPropertyPolicy) {
+        self.options.unknown_property_policy = policy;
+    }
+
+    /// Enables or disables the lowercasing of hex colors, e.g. `#FF5733` into `#ff5733`.
+    ///
+    /// When enabled, the hex colors found in variables, themes and style property values are
+    /// lowercased before being stored in the AST, producing a consistent output. The
+    /// normalization is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether hex colors must be lowercased.
+    pub fn set_hex_case_normalization(&mut self, is_enabled: bool) {
+        self.options.normalize_hex_case = is_enabled;
+    }
+
+    /// Enables or disables the collapsing of whitespace runs in style property values,
+    /// e.g. `10px  solid   red` into `10px solid red`.
+    ///
+    /// When enabled, the values declared inside classes, animations, `Supports` and
+    /// `Container` patterns have their top-level whitespace collapsed into single spaces
+    /// and trimmed once validated. The content of quoted strings and parentheses is kept
+    /// untouched. The normalization is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether the whitespace of the values must be collapsed.
+    pub fn set_value_whitespace_normalization(&mut self, is_enabled: bool) {
+        self.options.normalize_value_whitespace = is_enabled;
+    }
+
+    /// Forbids or allows line comments (`//`).
+    ///
+    /// When forbidden, any `//` found in the input stops the parsing with a `NenyrError`
+    /// pointing at the comment, while block comments (`/* */`) remain allowed. This helps
+    /// teams avoiding accidentally commented-out declarations. Line comments are allowed
+    /// by default.
+    ///
+    /// # Parameters
+    /// - `is_forbidden`: Whether line comments must be rejected.
+    pub fn set_line_comments_forbidden(&mut self, is_forbidden: bool) {
+        self.options.forbid_line_comments = is_forbidden;
+    }
+
+    /// Allows or rejects semicolons (`;`) as property separators.
+    ///
+    /// When allowed, a `;` is handled exactly as a `,`, so declarations written the CSS way,
+    /// such as `color: 'red'; padding: '10px';`, are accepted. Semicolons are rejected as
+    /// unknown tokens by default.
+    ///
+    /// # Parameters
+    /// - `is_allowed`: Whether semicolons must be accepted as separators.
+    pub fn set_semicolons_allowed(&mut self, is_allowed: bool) {
+        self.options.allow_semicolons = is_allowed;
+    }
+
+    /// Sets how the `Transitive` animations declaring neither a `From` nor a `To` pattern
+    /// must be handled.
+    ///
+    /// An animation made only of `Halfway` patterns has no start or end keyframe, which is
+    /// rarely meaningful CSS. Such animations are accepted by default. Use
+    /// `IncompleteTransitivePolicy::Warn` to report them as warnings, or
+    /// `IncompleteTransitivePolicy::Error` to reject them.
+    ///
+    /// # Parameters
+    /// - `policy`: The `IncompleteTransitivePolicy` to be applied.
+    pub fn set_incomplete_transitive_policy(&mut self, policy: IncompleteTransitivePolicy) {
+        self.options.incomplete_transitive_policy = policy;
+    }
+
+    /// Sets how a `Stylesheet` pattern declared more than once in the same class or
+    /// breakpoint scope must be handled.
+    ///
+    /// Both blocks target the same rule, so the properties of the later one are merged into
+    /// the earlier one, and the duplication is reported as a warning by default. Use
+    /// `DuplicatedStylesheetPolicy::Merge` to merge them silently, or
+    /// `DuplicatedStylesheetPolicy::Error` to reject the duplication.
+    ///
+    /// # Parameters
+    /// - `policy`: The `DuplicatedStylesheetPolicy` to be applied.
+    pub fn set_duplicated_stylesheet_policy(&mut self, policy: DuplicatedStylesheetPolicy) {
+        self.options.duplicated_stylesheet_policy = policy;
+    }
+
+    /// Enables or disables the warning raised when the steps of a `Progressive` animation
+    /// do not declare the same properties.
+    ///
+    /// The steps are distributed evenly over the animation, so a property declared only by
+    /// some of them usually jumps instead of animating smoothly. When enabled, each such
+    /// animation produces a `NenyrDiagnostic` with the `Warning` severity listing the
+    /// inconsistent properties. The warning is disabled by default.
+    ///
+    /// # Parameters
+    /// - `is_enabled`: Whether inconsistent progressive steps must be reported.
+    pub fn set_inconsistent_progressive_steps_warning(&mut self, is_enabled: bool) {
+        self.options.warn_on_inconsistent_progressive_steps = is_enabled;
+    }
+
+    /// Sets the number of columns a tab character counts for in the reported positions.
+    ///
+    /// Tabs count as a single column by default. Setting the width used by the editor makes
+    /// the `error_on_col` of the errors match the column shown in tab-indented files.
+    ///
+    /// # Parameters
+    /// - `tab_width`: The number of columns counted for each tab character.
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        self.options.tab_width = tab_width;
+    }
+
+    /// Caps the number of diagnostics collected during a parsing.
+    ///
+    /// A pathological input may raise thousands of warnings. Once `limit` diagnostics were
+    /// collected, the following ones are discarded: with `DiagnosticsLimitPolicy::Continue`
+    /// a final `Info` diagnostic marks that the limit was reached and the parsing goes on,
+    /// while `DiagnosticsLimitPolicy::Abort` stops the parsing with a `NenyrError`. The
+    /// fatal error stopping a parsing is always collected. There is no limit by default.
+    ///
+    /// # Parameters
+    /// - `limit`: The maximum number of diagnostics to be collected.
+    /// - `policy`: The `DiagnosticsLimitPolicy` applied once the limit is exceeded.
+    pub fn set_diagnostics_limit(&mut self, limit: usize, policy: DiagnosticsLimitPolicy) {
+        self.options.diagnostics_limit = Some(limit);
+        self.options.diagnostics_limit_policy = policy;
+    }
+
+    /// Caps the number of properties declared by each block of properties, that is, by each
+    /// pattern of a class (including the `Supports` and `Container` ones), by each mixin and
+    /// by each keyframe of an animation.
+    ///
+    /// This guards against pathological inputs: a block declaring more than `limit`
+    /// properties stops the parsing with a `NenyrError`. The stylesheets receiving the
+    /// properties of an included mixin are checked once the mixin is expanded. There is no
+    /// limit by default.
+    ///
+    /// # Parameters
+    /// - `limit`: The maximum number of properties declared by a block.
+    pub fn set_max_block_properties(&mut self, limit: usize) {
+        self.options.max_block_properties = Some(limit);
+    }
+
+    /// Retrieves the diagnostics collected during the last parsing.
+    ///
+    /// # Returns
+    /// A vector with every `NenyrDiagnostic` raised by the enabled checks, in the order
+    /// they were found, followed by the fatal error that stopped the parsing, if any.
+    pub fn get_diagnostics(&self) -> Vec<NenyrDiagnostic> {
+        self.diagnostics.clone()
+    }
+
+    /// Retrieves the warnings raised during the last parsing.
+    ///
+    /// # Returns
+    /// A vector with every `NenyrDiagnostic` of `Warning` severity raised by the enabled
+    /// checks, in the order they were found.
+    pub fn get_warnings(&self) -> Vec<NenyrDiagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Warning)
+            .cloned()
+            .collect()
+    }
+
+    /// Retrieves the byte span of a class declared in the last parsed context.
+    ///
+    /// The span starts at the `Declare` keyword and ends right after the closing curly
+    /// bracket of the class block. Use it with `NenyrParser::slice_for_span` and the same
+    /// raw input given to `parse` to retrieve the source of the declaration.
+    ///
+    /// # Parameters
+    /// - `class_name`: The name of the class.
+    ///
+    /// # Returns
+    /// The `(start, end)` byte positions of the declaration, or `None` if no class with
+    /// the given name was parsed.
+    pub fn class_span(&self, class_name: &str) -> Option<(usize, usize)> {
+        self.processing_state.get_class_span(class_name)
+    }
+
+    /// Retrieves the byte span of an animation declared in the last parsed context.
+    ///
+    /// The span starts at the `Declare` keyword and ends right after the closing curly
+    /// bracket of the animation block.
+    ///
+    /// # Parameters
+    /// - `animation_name`: The name of the animation.
+    ///
+    /// # Returns
+    /// The `(start, end)` byte positions of the declaration, or `None` if no animation with
+    /// the given name was parsed.
+    pub fn animation_span(&self, animation_name: &str) -> Option<(usize, usize)> {
+        self.processing_state.get_animation_span(animation_name)
+    }
+
+    /// Retrieves the portion of the raw Nenyr input covered by a span.
+    ///
+    /// Spans are byte positions into the exact input given to `parse`, so the same raw
+    /// string must be provided here. An empty string is returned if the span is out of the
+    /// input bounds or does not fall on character boundaries, which only happens when a
+    /// different input is given.
+    ///
+    /// # Parameters
+    /// - `raw`: The raw Nenyr input that was parsed.
+    /// - `span`: The `(start, end)` byte positions, as returned by `class_span` or `animation_span`.
+    ///
+    /// # Returns
+    /// The slice of the raw input covered by the span.
+    pub fn slice_for_span(raw: &str, span: (usize, usize)) -> &str {
+        raw.get(span.0..span.1).unwrap_or_default()
+    }
+
+    /// Estimates how many declarations the raw Nenyr input contains.
+    ///
+    /// This is a lightweight pre-pass meant for progress reporting: instead of tokenizing the
+    /// input, it only counts the standalone occurrences of the `Declare` keyword. Occurrences
+    /// inside comments or string literals are counted as well, so the estimate is not exact.
+    ///
+    /// # Parameters
+    /// - `raw`: The raw Nenyr input that will be parsed.
+    ///
+    /// # Returns
+    /// The estimated number of `Declare` blocks in the input.
+    pub fn estimate_declarations(raw: &str) -> usize {
+        let is_word_char = |char: char| char.is_ascii_alphanumeric() || char == '_';
+
+        raw.match_indices("Declare")
+            .filter(|(position, keyword)| {
+                let is_preceded_by_word = raw[..*position]
+                    .chars()
+                    .next_back()
+                    .is_some_and(is_word_char);
+                let is_followed_by_word = raw[position + keyword.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(is_word_char);
+
+                !is_preceded_by_word && !is_followed_by_word
+            })
+            .count()
+    }
+
     pub(crate) fn setup_dependencies(&mut self, raw_nenyr: String, context_path: String) {
         self.context_path = context_path.to_owned();
         self.lexer = Lexer::new(raw_nenyr, context_path);
-        self.context_name = None;
+        self.lexer
+            .set_line_comments_forbidden(self.options.forbid_line_comments);
+        self.lexer
+            .set_semicolons_allowed(self.options.allow_semicolons);
+        self.lexer.set_tab_width(self.options.tab_width);
+        self.lexer
+            .set_comments_collected(self.options.collect_comments);
+        self.set_context_name(None);
         self.current_token = NenyrTokens::StartOfFile;
         self.processing_state = NenyrProcessStore::new();
+        self.value_interner.clear();
+        self.diagnostics.clear();
+        self.stats = ParseStats::new();
+        self.token_histogram.clear();
+        self.comments = NenyrComments::new();
     }
 
     /// Parses the raw Nenyr input and constructs an AST.
@@ -141,18 +664,186 @@ impl NenyrParser {
     /// verifying that the context begins with the `Construct` keyword. It then calls
     /// the appropriate method to parse the current context based on the token type.
     ///
+    /// A fatal error is also recorded as a `NenyrDiagnostic` of `Error` severity, being
+    /// available through `NenyrParser::get_diagnostics` along with the collected warnings.
+    ///
     /// # Returns
     /// A `NenyrResult<NenyrAst>`, which is either the constructed AST or a `NenyrError`
     /// indicating a failure in parsing.
+    ///
+    /// # Examples
+    /// The returned `NenyrResult` must be handled, so discarding it fails to compile when
+    /// `unused_must_use` is denied:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    ///
+    /// let mut parser = nenyr::NenyrParser::new();
+    ///
+    /// parser.parse("Construct Layout('mainLayout') { }".to_string(), "".to_string());
+    /// ```
+    #[must_use = "the parsing may fail, so the returned `NenyrResult` must be handled"]
     pub fn parse(&mut self, raw_nenyr: String, context_path: String) -> NenyrResult<NenyrAst> {
         self.setup_dependencies(raw_nenyr, context_path);
-        self.process_next_token()?;
 
-        self.parse_construct_keyword(
-            Some("Ensure that every Nenyr context starts with the `Construct` keyword at the root level to properly define the scope and structure of your context.".to_string()),
-            "Expected the Nenyr context to begin with the `Construct` keyword at the root.",
-            Self::parse_current_context,
-        )
+        let nenyr_ast = self.process_next_token().and_then(|_| {
+            self.comments.leading = self.lexer.take_comments();
+            self.parse_construct_keyword(
+                Some("Ensure that every Nenyr context starts with the `Construct` keyword at the root level to properly define the scope and structure of your context.".to_string()),
+                "Expected the Nenyr context to begin with the `Construct` keyword at the root.",
+                Self::parse_current_context,
+            )
+        });
+
+        if nenyr_ast.is_ok() && self.options.collect_comments {
+            // The context is parsed up to its closing bracket, so the comments after it are
+            // only reached by lexing one more token.
+            let _ = self.lexer.next_token();
+        }
+
+        self.comments.trailing = self.lexer.take_comments();
+
+        if let Err(error) = &nenyr_ast {
+            self.diagnostics.push(NenyrDiagnostic::from(error.clone()));
+        }
+
+        nenyr_ast
+    }
+
+    /// Parses the raw Nenyr input into an existing AST, reusing its allocations.
+    ///
+    /// This method behaves like `NenyrParser::parse`, except that the result is stored in
+    /// the given AST instead of being returned. The `animations` and `classes` maps of the AST
+    /// are cleared and handed to the context being assembled, which refills them instead of
+    /// allocating new ones, keeping their capacity across reparses, e.g. in editors.
+    ///
+    /// # Parameters
+    /// - `raw_nenyr`: The raw Nenyr input to be parsed.
+    /// - `context_path`: The path of the context being parsed.
+    /// - `ast`: The AST receiving the parsed context. If the parsing fails, it keeps the kind
+    ///   and the name of its context, but its declarations are cleared.
+    ///
+    /// # Returns
+    /// A `NenyrResult<()>`, which is either empty or the `NenyrError` that stopped the parsing.
+    #[must_use = "the parsing may fail, so the returned `NenyrResult` must be handled"]
+    pub fn parse_into(
+        &mut self,
+        raw_nenyr: String,
+        context_path: String,
+        ast: &mut NenyrAst,
+    ) -> NenyrResult<()> {
+        self.reusable_maps = ast.clear();
+
+        let nenyr_ast = self.parse(raw_nenyr, context_path);
+
+        self.reusable_maps = (None, None);
+        *ast = nenyr_ast?;
+        ast.drop_empty_maps();
+
+        Ok(())
+    }
+
+    /// Parses the raw Nenyr input and emits the CSS it produces in a single call.
+    ///
+    /// A default `NenyrParser` is used for the parsing, and the resulting AST is emitted
+    /// through `emit_css_with`, so the aliases and variables declared in the context are
+    /// resolved in the output.
+    ///
+    /// # Parameters
+    /// - `raw_nenyr`: The raw Nenyr input to be parsed.
+    /// - `context_path`: The path of the context being parsed.
+    /// - `opts`: The `EmitOptions` controlling the emitted CSS.
+    ///
+    /// # Returns
+    /// A `NenyrResult<String>`, which is either the emitted stylesheet or the `NenyrError`
+    /// that stopped the parsing.
+    pub fn parse_and_emit(
+        raw_nenyr: &str,
+        context_path: &str,
+        opts: EmitOptions,
+    ) -> NenyrResult<String> {
+        let nenyr_ast = Self::new().parse(raw_nenyr.to_string(), context_path.to_string())?;
+
+        Ok(emit_css_with(&nenyr_ast, opts))
+    }
+
+    /// Parses the raw Nenyr input using an externally provided context name.
+    ///
+    /// This method behaves exactly like `NenyrParser::parse`, except that every error and
+    /// diagnostic raised during the parsing reports the given context name, even when the
+    /// input is a fragment that does not declare one, or declares a different one. This is
+    /// useful when the name comes from an external source, such as the file name.
+    ///
+    /// # Parameters
+    /// - `raw_nenyr`: The raw Nenyr input to be parsed.
+    /// - `context_path`: The path of the context being parsed.
+    /// - `context_name`: The context name reported by the errors and diagnostics.
+    ///
+    /// # Returns
+    /// A `NenyrResult<NenyrAst>`, which is either the constructed AST or a `NenyrError`
+    /// indicating a failure in parsing.
+    #[must_use = "the parsing may fail, so the returned `NenyrResult` must be handled"]
+    pub fn parse_with_context_name(
+        &mut self,
+        raw_nenyr: String,
+        context_path: String,
+        context_name: String,
+    ) -> NenyrResult<NenyrAst> {
+        self.context_name_override = Some(context_name);
+
+        let nenyr_ast = self.parse(raw_nenyr, context_path);
+
+        self.context_name_override = None;
+
+        nenyr_ast
+    }
+
+    /// Parses the raw Nenyr input, measuring the parsing.
+    ///
+    /// This method behaves exactly like `NenyrParser::parse`, additionally returning the
+    /// time spent parsing along with the number of tokens and declarations processed,
+    /// which is useful for performance monitoring in build pipelines.
+    ///
+    /// # Parameters
+    /// - `raw_nenyr`: The raw Nenyr input to be parsed.
+    /// - `context_path`: The path of the context being parsed.
+    ///
+    /// # Returns
+    /// A `NenyrResult` with the constructed AST and its `ParseStats`, or the `NenyrError`
+    /// that stopped the parsing.
+    #[must_use = "the parsing may fail, so the returned `NenyrResult` must be handled"]
+    pub fn parse_timed(
+        &mut self,
+        raw_nenyr: String,
+        context_path: String,
+    ) -> NenyrResult<(NenyrAst, ParseStats)> {
+        let started_at = Instant::now();
+        let nenyr_ast = self.parse(raw_nenyr, context_path)?;
+
+        self.stats.elapsed = started_at.elapsed();
+
+        Ok((nenyr_ast, self.stats.clone()))
+    }
+
+    /// Parses several independent Nenyr inputs in parallel.
+    ///
+    /// Each input is parsed by its own `NenyrParser` created with the default options, on the
+    /// `rayon` global thread pool, which suits projects with many `.nyr` files. This function
+    /// is only available when the `rayon` feature is enabled.
+    ///
+    /// # Parameters
+    /// - `inputs`: The raw Nenyr inputs along with their context paths.
+    ///
+    /// # Returns
+    /// A vector with the `NenyrResult` of each input, in the same order as the inputs.
+    #[cfg(feature = "rayon")]
+    pub fn parse_files_parallel(inputs: Vec<(String, String)>) -> Vec<NenyrResult<NenyrAst>> {
+        use rayon::prelude::*;
+
+        inputs
+            .into_par_iter()
+            .map(|(raw_nenyr, context_path)| NenyrParser::new().parse(raw_nenyr, context_path))
+            .collect()
     }
 
     /// Parses the current context based on the token type.
@@ -189,7 +880,7 @@ impl NenyrParser {
                     format!("The token `{:?}` is not recognized as a valid Nenyr context keyword. Please check your syntax.", self.current_token),
                     NenyrErrorKind::SyntaxError,
                     self.get_tracing(),
-                ));
+                ).with_code("N0097"));
             }
         }
     }
@@ -197,7 +888,16 @@ impl NenyrParser {
 
 #[cfg(test)]
 mod tests {
-    use crate::NenyrParser;
+    use std::sync::Arc;
+
+    use crate::{
+        converters::css_emitter::EmitOptions,
+        error::{NenyrErrorKind, Severity},
+        options::{DiagnosticsLimitPolicy, UnknownPropertyPolicy},
+        tokens::{token_kind, NenyrTokens},
+        types::{ast::NenyrAst, declaration::DeclarationCounts, module::ModuleContext},
+        NenyrParser,
+    };
 
     #[test]
     fn central_context_is_valid() {
@@ -380,7 +1080,7 @@ bgd: '${secondaryColor}', // Este é um comentário de linha.
 
         assert_eq!(
             format!("{:?}", parser.parse(raw_nenyr.to_string(), "src/central.nyr".to_string())),
-            "Ok(CentralContext(CentralContext { imports: Some(NenyrImports { values: {\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": (), \"https://fonts.googleapis.com/css2?family=Roboto:ital,wght@0,100;0,300;0,400;0,500;0,700;0,900;1,100;1,300;1,400;1,500;1,700;1,900&display=swap\": (), \"https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap\": (), \"../mocks/imports/another_external.css\": (), \"../mocks/imports/external_styles.css\": (), \"../mocks/imports/styles.css\": ()} }), typefaces: Some(NenyrTypefaces { values: {\"roseMartin\": \"../mocks/typefaces/rosemartin.regular.otf\", \"regularEot\": \"../mocks/typefaces/showa-source-curry.regular-webfont.eot\", \"regularSvg\": \"../mocks/typefaces/showa-source-curry.regular-webfont.svg\", \"regularTtf\": \"../mocks/typefaces/showa-source-curry.regular-webfont.ttf\", \"regularWoff\": \"../mocks/typefaces/showa-source-curry.regular-webfont.woff\", \"regularWoff2\": \"../mocks/typefaces/showa-source-curry.regular-webfont.woff2\"} }), breakpoints: Some(NenyrBreakpoints { mobile_first: Some({\"onMobTablet\": \"780px\", \"onMobDesktop\": \"1240px\", \"onMobXl\": \"1440px\", \"onMobXXl\": \"2240px\"}), desktop_first: Some({\"onDeskTablet\": \"780px\", \"onDeskDesktop\": \"1240px\", \"onDeskXl\": \"1440px\", \"onDeskXXl\": \"2240px\"}) }), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#CCCCCC\", \"accentColorVar\": \"#FF5733\"} }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#333333\", \"secondaryColor\": \"#666666\", \"accentColorVar\": \"#FF5733\"} }) }), animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) }))".to_string()
+            "Ok(CentralContext(CentralContext { imports: Some(NenyrImports { values: {\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": None, \"https://fonts.googleapis.com/css2?family=Roboto:ital,wght@0,100;0,300;0,400;0,500;0,700;0,900;1,100;1,300;1,400;1,500;1,700;1,900&display=swap\": None, \"https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap\": None, \"../mocks/imports/another_external.css\": None, \"../mocks/imports/external_styles.css\": None, \"../mocks/imports/styles.css\": None} }), typefaces: Some(NenyrTypefaces { values: {\"roseMartin\": \"../mocks/typefaces/rosemartin.regular.otf\", \"regularEot\": \"../mocks/typefaces/showa-source-curry.regular-webfont.eot\", \"regularSvg\": \"../mocks/typefaces/showa-source-curry.regular-webfont.svg\", \"regularTtf\": \"../mocks/typefaces/showa-source-curry.regular-webfont.ttf\", \"regularWoff\": \"../mocks/typefaces/showa-source-curry.regular-webfont.woff\", \"regularWoff2\": \"../mocks/typefaces/showa-source-curry.regular-webfont.woff2\"} }), breakpoints: Some(NenyrBreakpoints { mobile_first: Some({\"onMobTablet\": \"780px\", \"onMobDesktop\": \"1240px\", \"onMobXl\": \"1440px\", \"onMobXXl\": \"2240px\"}), desktop_first: Some({\"onDeskTablet\": \"780px\", \"onDeskDesktop\": \"1240px\", \"onDeskXl\": \"1440px\", \"onDeskXXl\": \"2240px\"}) }), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), consts: None, themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#CCCCCC\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\"), \"secondaryColor\": Text(\"#666666\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None }), mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) }))".to_string()
         );
     }
 
@@ -565,7 +1265,7 @@ bgd: '${secondaryColor}', // Este é um comentário de linha.
 
         assert_eq!(
             format!("{:?}", parser.parse(raw_nenyr.to_string(), "src/central.nyr".to_string())),
-            "Err(NenyrError { suggestion: Some(\"Ensure that the `miniatureTrogon` class or deriving name declaration is followed by an opening curly bracket `{` to properly define the class block. The correct syntax is: `Declare Class('miniatureTrogon') { ... }` or `Declare Class('miniatureTrogon') Deriving('layoutName') { ... }`.\"), context_name: Some(\"Central\"), context_path: \"src/central.nyr\", error_message: \"An opening curly bracket `{` was expected after the `miniatureTrogon` class or deriving name declaration to start the class block, but it was not found. However, found `Important` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"    Declare Class('miniatureTrogon') Deriving('discreteAudio')\"), line_after: Some(\"        Stylesheet({\"), error_line: Some(\"        Important(true),\"), error_on_line: 129, error_on_col: 18, error_on_pos: 4163 } })".to_string()
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"Ensure that the `miniatureTrogon` class or deriving name declaration is followed by an opening curly bracket `{` to properly define the class block. The correct syntax is: `Declare Class('miniatureTrogon') { ... }` or `Declare Class('miniatureTrogon') Deriving('layoutName') { ... }`.\"), context_name: Some(\"Central\"), context_path: \"src/central.nyr\", error_message: \"An opening curly bracket `{` was expected after the `miniatureTrogon` class or deriving name declaration to start the class block, but it was not found. However, found `Important` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"    Declare Class('miniatureTrogon') Deriving('discreteAudio')\"), line_after: Some(\"        Stylesheet({\"), error_line: Some(\"        Important(true),\"), error_on_line: 129, error_on_col: 18, error_on_pos: 4163 } })".to_string()
         );
     }
 
@@ -721,7 +1421,7 @@ bgd: '${secondaryColor}', // Este é um comentário de linha.
 
         assert_eq!(
             format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string())),
-            "Ok(LayoutContext(LayoutContext { layout_name: \"hellishAdobe\", aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#CCCCCC\", \"accentColorVar\": \"#FF5733\"} }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#333333\", \"secondaryColor\": \"#666666\", \"accentColorVar\": \"#FF5733\"} }) }), animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) }))".to_string()
+            "Ok(LayoutContext(LayoutContext { layout_name: \"hellishAdobe\", aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#CCCCCC\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#333333\"), \"secondaryColor\": Text(\"#666666\"), \"accentColorVar\": Text(\"#FF5733\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None }), mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) }))".to_string()
         );
     }
 
@@ -877,7 +1577,7 @@ bgd: '${secondaryColor}', // Este é um comentário de linha.
 
         assert_eq!(
             format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string())),
-            "Err(NenyrError { suggestion: Some(\"Ensure that the `giddyRespond` animation name declaration is followed by an opening curly bracket `{` to properly define the animation block. The correct syntax is: `Declare Animation('giddyRespond') { ... }`.\"), context_name: Some(\"hellishAdobe\"), context_path: \"\", error_message: \"An opening curly bracket `{` was expected after the `giddyRespond` animation name declaration to start the animation block, but it was not found. However, found `Fraction` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"    Declare Animation('giddyRespond')\"), line_after: Some(\"            // Este é um comentário de linha.\"), error_line: Some(\"        Fraction(30, {\"), error_on_line: 37, error_on_col: 17, error_on_pos: 941 } })".to_string()
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"Ensure that the `giddyRespond` animation name declaration is followed by an opening curly bracket `{` to properly define the animation block. The correct syntax is: `Declare Animation('giddyRespond') { ... }`.\"), context_name: Some(\"hellishAdobe\"), context_path: \"\", error_message: \"An opening curly bracket `{` was expected after the `giddyRespond` animation name declaration to start the animation block, but it was not found. However, found `Fraction` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"    Declare Animation('giddyRespond')\"), line_after: Some(\"            // Este é um comentário de linha.\"), error_line: Some(\"        Fraction(30, {\"), error_on_line: 37, error_on_col: 17, error_on_pos: 941 } })".to_string()
         );
     }
 
@@ -1016,7 +1716,7 @@ bgd: '${secondaryColor}', // Este é um comentário de linha.
 
         assert_eq!(
             format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string())),
-            "Ok(ModuleContext(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\"} }), animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}) }}) }))".to_string()
+            "Ok(ModuleContext(ModuleContext { module_name: \"ultimateFeel\", extending_from: Some(\"hellishAdobe\"), aliases: Some(NenyrAliases { values: {\"bgd\": \"background-color\", \"pdg\": \"padding\", \"dp\": \"display\", \"wd\": \"width\", \"hgt\": \"height\"} }), variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\")}, dimensions: None, expressions: None }), mixins: None, animations: Some({\"giddyRespond\": NenyrAnimation { animation_name: \"giddyRespond\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [30.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\", \"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"} }, Fraction { stops: [40.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [4.0], is_percentage: [false], properties: {\"nickname;bgd\": \"${accentColorVar}\"} }, Fraction { stops: [50.0, 70.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [5.0, 7.0], is_percentage: [false, false], properties: {\"background-color\": \"blue\"} }, Fraction { stops: [70.0, 80.0, 100.0], is_percentage: [false, false, false], properties: {\"transform\": \"translate(50%, 50%)\"} }] }, \"spiritedSavings\": NenyrAnimation { animation_name: \"spiritedSavings\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(3), keyframe: [Progressive({\"width\": \"${myVar}\"}), Progressive({\"border\": \"10px solid red\", \"background-color\": \"blue\", \"height\": \"100px\", \"width\": \"200px\"}), Progressive({\"background-color\": \"pink\"})] }, \"grotesquePtarmigan\": NenyrAnimation { animation_name: \"grotesquePtarmigan\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"${myVar}\"}), Halfway({\"border\": \"1px solid red\"}), To({\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"})] }}), classes: Some({\"miniatureTrogon\": NenyrStyleClass { class_name: \"miniatureTrogon\", deriving_from: Some(\"discreteAudio\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"#0000FF\", \"background\": \"#00FF00\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}, \":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px21}\", \"nickname;bdr\": \"5px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"myTestingClass\": NenyrStyleClass { class_name: \"myTestingClass\", deriving_from: None, is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"myBreakpoint\": {\"_stylesheet\": {\"background-color\": \"blue\", \"border\": \"10px solid red\", \"height\": \"100px\", \"width\": \"200px\"}}}), media_patterns: None }}) }))".to_string()
         );
     }
 
@@ -1155,7 +1855,731 @@ bgd: '${secondaryColor}', // Este é um comentário de linha.
 
         assert_eq!(
             format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string())),
-            "Err(NenyrError { suggestion: Some(\"After the opening parenthesis, an opening curly bracket `{` is required to properly define the properties block in the `Variables` declaration. Ensure the pattern follows correct Nenyr syntax, like `Variables({ key: 'value', ... })`.\"), context_name: Some(\"ultimateFeel\"), context_path: \"\", error_message: \"The `Variables` declaration block was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis. However, found `myColor` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"    Declare Variables(\"), line_after: Some(\"        grayColor: 'gray',\"), error_line: Some(\"        myColor: '#FF6677',\"), error_on_line: 11, error_on_col: 16, error_on_pos: 266 } })".to_string()
+            "Err(NenyrError { error_code: \"N0031\", suggestion: Some(\"After the opening parenthesis, an opening curly bracket `{` is required to properly define the properties block in the `Variables` declaration. Ensure the pattern follows correct Nenyr syntax, like `Variables({ key: 'value', ... })`.\"), context_name: Some(\"ultimateFeel\"), context_path: \"\", error_message: \"The `Variables` declaration block was expected to receive an object as a value, but an opening curly bracket `{` was not found after the opening parenthesis. However, found `myColor` instead.\", error_kind: SyntaxError, error_tracing: NenyrErrorTracing { line_before: Some(\"    Declare Variables(\"), line_after: Some(\"        grayColor: 'gray',\"), error_line: Some(\"        myColor: '#FF6677',\"), error_on_line: 11, error_on_col: 16, error_on_pos: 266 } })".to_string()
+        );
+    }
+
+    #[test]
+    fn interned_values_must_share_allocation() {
+        let raw_nenyr = "Construct Module('cardModule') {
+    Declare Class('card') {
+        Stylesheet({ color: 'blue', borderColor: 'blue' }),
+        Hover({ backgroundColor: 'blue' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        parser.set_value_interning(true);
+
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string());
+
+        if let Ok(NenyrAst::ModuleContext(module_context)) = ast {
+            let style_class = &module_context.classes.unwrap()["card"];
+            let style_patterns = style_class.style_patterns.as_ref().unwrap();
+            let color = &style_patterns["_stylesheet"]["color"];
+
+            assert!(Arc::ptr_eq(
+                color,
+                &style_patterns["_stylesheet"]["border-color"]
+            ));
+            assert!(Arc::ptr_eq(
+                color,
+                &style_patterns[":hover"]["background-color"]
+            ));
+        } else {
+            panic!("The module context should be parsed successfully.");
+        }
+
+        parser.set_value_interning(false);
+
+        let ast = parser.parse(raw_nenyr.to_string(), "".to_string());
+
+        if let Ok(NenyrAst::ModuleContext(module_context)) = ast {
+            let style_class = &module_context.classes.unwrap()["card"];
+            let style_patterns = style_class.style_patterns.as_ref().unwrap();
+
+            assert!(!Arc::ptr_eq(
+                &style_patterns["_stylesheet"]["color"],
+                &style_patterns["_stylesheet"]["border-color"]
+            ));
+        } else {
+            panic!("The module context should be parsed successfully.");
+        }
+    }
+
+    #[test]
+    fn declaration_source_must_be_sliced_from_span() {
+        let raw_nenyr = "Construct Layout('cardLayout') {
+    // Animação de entrada
+    Declare Animation('fadeIn') {
+        From({ opacity: '0' }),
+        To({ opacity: '1' })
+    },
+    Declare Class('card') {
+        Stylesheet({ content: 'é' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_ok());
+
+        let animation_span = parser.animation_span("fadeIn").unwrap();
+        let animation_source = NenyrParser::slice_for_span(raw_nenyr, animation_span);
+
+        assert!(animation_source.starts_with("Declare Animation("));
+        assert!(animation_source.ends_with("To({ opacity: '1' })\n    }"));
+
+        let class_span = parser.class_span("card").unwrap();
+
+        assert_eq!(
+            NenyrParser::slice_for_span(raw_nenyr, class_span),
+            "Declare Class('card') {\n        Stylesheet({ content: 'é' })\n    }"
+        );
+        assert_eq!(parser.class_span("fadeIn"), None);
+        assert_eq!(NenyrParser::slice_for_span(raw_nenyr, (0, 1000)), "");
+    }
+
+    #[test]
+    fn diagnostics_must_carry_their_severity() {
+        let raw_nenyr = "Construct Central {
+    Declare Breakpoints({
+        MobileFirst({ onMobTablet: '780px' }),
+        DesktopFirst({ onDeskTablet: '780px' })
+    }),
+    Declare Aliases({ bgd: unknownProperty })
+}";
+        let mut parser = NenyrParser::new();
+
+        parser.set_shared_breakpoint_values_warning(true);
+
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_err());
+
+        let diagnostics = parser.get_diagnostics();
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].get_severity(), Severity::Warning);
+        assert_eq!(diagnostics[1].get_severity(), Severity::Error);
+        assert_eq!(parser.get_warnings(), vec![diagnostics[0].clone()]);
+    }
+
+    #[test]
+    fn collected_diagnostics_must_be_capped_at_the_limit() {
+        let properties = (0..50)
+            .map(|idx| format!("fancyWidth{}: '{}px'", idx, idx))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let raw_nenyr = format!(
+            "Construct Module('card') Extending('mainLayout') {{
+    Declare Class('box') {{ Stylesheet({{ {} }}) }}
+}}",
+            properties
+        );
+        let mut parser = NenyrParser::new();
+
+        parser.set_unknown_property_policy(UnknownPropertyPolicy::Warn);
+        parser.set_diagnostics_limit(10, DiagnosticsLimitPolicy::Continue);
+
+        assert!(parser.parse(raw_nenyr.clone(), "".to_string()).is_ok());
+
+        let diagnostics = parser.get_diagnostics();
+
+        assert_eq!(diagnostics.len(), 11);
+        assert_eq!(parser.get_warnings().len(), 10);
+        assert_eq!(diagnostics[10].get_severity(), Severity::Info);
+        assert_eq!(
+            diagnostics[10].get_message(),
+            "Too many diagnostics were raised. The collection stopped after reaching the limit of 10 diagnostics."
+        );
+
+        parser.set_diagnostics_limit(10, DiagnosticsLimitPolicy::Abort);
+
+        let aborted_ast = parser.parse(raw_nenyr, "".to_string());
+
+        assert!(aborted_ast.is_err());
+        assert_eq!(parser.get_warnings().len(), 10);
+        assert_eq!(parser.get_diagnostics().len(), 11);
+        assert_eq!(parser.get_diagnostics()[10].get_severity(), Severity::Error);
+    }
+
+    #[test]
+    fn unknown_properties_must_follow_the_policy() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Aliases({ bgd: backgroundColor }),
+    Declare Animation('grow') { From({ bgd: 'red', fancyWidth: '1px' }) },
+    Declare Class('box') { Stylesheet({ bgd: 'blue', fancyWidth: '2px' }) }
+}";
+        let mut parser = NenyrParser::new();
+
+        let pass_through_ast = parser.parse(raw_nenyr.to_string(), "".to_string());
+
+        assert!(pass_through_ast.is_ok(), "{:?}", pass_through_ast);
+        assert!(parser.get_warnings().is_empty());
+        assert!(format!("{:?}", pass_through_ast).contains("\"nickname;fancyWidth\": \"2px\""));
+
+        parser.set_unknown_property_policy(UnknownPropertyPolicy::Warn);
+
+        assert_eq!(
+            parser.parse(raw_nenyr.to_string(), "".to_string()),
+            pass_through_ast
+        );
+
+        let warnings = parser.get_warnings();
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].get_message(), "The `fancyWidth` property inside one of the patterns in the `grow` animation is neither a valid Nenyr property nor an alias declared in the current context.");
+        assert_eq!(warnings[1].get_message(), "The `fancyWidth` property inside one of the patterns in the `box` class is neither a valid Nenyr property nor an alias declared in the current context.");
+
+        parser.set_unknown_property_policy(UnknownPropertyPolicy::Error);
+
+        match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Err(err) => {
+                assert_eq!(err.get_error_kind(), NenyrErrorKind::ValidationError);
+                assert_eq!(err.get_error_message(), warnings[0].get_message());
+            }
+            Ok(ast) => panic!("{:?}", ast),
+        }
+    }
+
+    #[test]
+    fn unused_aliases_must_be_listed() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    Declare Aliases({ bgd: background, hgt: height, wd: width }),
+    Declare Animation('grow') { From({ wd: '10px' }), To({ wd: '20px' }) },
+    Declare Class('box') { Stylesheet({ bgd: 'red', height: '10px' }) }
+}";
+        let mut parser = NenyrParser::new();
+
+        match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Ok(NenyrAst::LayoutContext(layout_context)) => {
+                assert_eq!(layout_context.unused_aliases(), vec!["hgt".to_string()]);
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn unused_animations_must_be_listed() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Aliases({ anim: animation }),
+    Declare Animation('grow') { From({ width: '10px' }), To({ width: '20px' }) },
+    Declare Animation('shrink') { From({ width: '20px' }), To({ width: '10px' }) },
+    Declare Animation('fade') { From({ opacity: '0' }), To({ opacity: '1' }) },
+    Declare Animation('spin') { From({ rotate: '0deg' }), To({ rotate: '360deg' }) },
+    Declare Class('box') { Stylesheet({ animation: 'grow 1s ease-in, fade 2s', color: 'shrink' }) },
+    Declare Class('icon') { Hover({ anim: 'spin 1s linear infinite' }) }
+}";
+        let mut parser = NenyrParser::new();
+
+        match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Ok(NenyrAst::ModuleContext(module_context)) => {
+                assert_eq!(
+                    module_context.unused_animations(),
+                    vec!["shrink".to_string()]
+                );
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn animations_and_classes_must_be_renamed_with_their_references() {
+        let raw_nenyr = "Construct Central {
+    Declare Aliases({ anim: animation, tint: color }),
+    Declare Animation('giddyRespond') { From({ width: '10px' }), To({ width: '20px' }) },
+    Declare Animation('giddyRespondSlow') { From({ width: '10px' }), To({ width: '20px' }) },
+    Declare Class('box') { Stylesheet({ animation: 'giddyRespond 1s, giddyRespondSlow 2s' }) },
+    Declare Class('icon') Deriving('box') { Hover({ anim: 'giddyRespond 1s linear' }) },
+    Declare Class('badge') { Stylesheet({ animationName: 'giddyRespond', color: 'giddyRespond', tint: 'giddyRespond' }) }
+}";
+        let mut parser = NenyrParser::new();
+
+        let mut central_context = match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Ok(NenyrAst::CentralContext(central_context)) => central_context,
+            other => panic!("{:?}", other),
+        };
+
+        assert_eq!(
+            central_context.rename_animation("giddyRespond", "giddyReply"),
+            3
+        );
+        assert_eq!(
+            central_context.rename_animation("giddyReply", "giddyRespondSlow"),
+            0
+        );
+        assert_eq!(
+            central_context.rename_animation("giddyReply", "giddy-reply"),
+            0
+        );
+
+        let animations = central_context.animations.as_ref().unwrap();
+
+        assert_eq!(
+            animations.keys().collect::<Vec<_>>(),
+            vec!["giddyReply", "giddyRespondSlow"]
+        );
+        assert_eq!(animations["giddyReply"].animation_name, "giddyReply");
+
+        let declarations: Vec<(&str, &str)> = central_context
+            .all_declarations()
+            .filter(|(owner, _, _)| ["box", "icon", "badge"].contains(owner))
+            .map(|(_, property, value)| (property, value))
+            .collect();
+
+        assert_eq!(
+            declarations,
+            vec![
+                ("animation", "giddyReply 1s, giddyRespondSlow 2s"),
+                ("nickname;anim", "giddyReply 1s linear"),
+                ("animation-name", "giddyReply"),
+                ("color", "giddyRespond"),
+                ("nickname;tint", "giddyRespond"),
+            ]
+        );
+
+        assert_eq!(central_context.rename_class("box", "card"), 1);
+        assert_eq!(central_context.rename_class("missing", "other"), 0);
+
+        let classes = central_context.classes.as_ref().unwrap();
+
+        assert_eq!(classes["card"].class_name, "card");
+        assert_eq!(classes["icon"].deriving_from.as_deref(), Some("card"));
+    }
+
+    #[test]
+    fn hex_colors_must_be_lowercased_when_enabled() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    Declare Variables({ accent: '#FF5733' }),
+    Declare Animation('glow') { From({ color: '#FF5733' }), To({ color: '#FFF' }) },
+    Declare Class('box') { Stylesheet({ border: '1px solid #FF5733' }) }
+}";
+        let mut parser = NenyrParser::new();
+
+        let untouched_ast = parser.parse(raw_nenyr.to_string(), "".to_string());
+
+        assert!(untouched_ast.is_ok(), "{:?}", untouched_ast);
+        assert_eq!(format!("{:?}", untouched_ast).matches("#FF5733").count(), 3);
+
+        parser.set_hex_case_normalization(true);
+
+        let normalized_ast = format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string()));
+
+        assert!(!normalized_ast.contains("#FF5733"));
+        assert_eq!(normalized_ast.matches("#ff5733").count(), 3);
+        assert!(normalized_ast.contains("\"color\": \"#fff\""));
+    }
+
+    #[test]
+    fn value_whitespace_must_be_collapsed_when_enabled() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Class('box') {
+        Stylesheet({ border: '10px  solid   red', width: 'calc(100%  -  10px)', content: '\"a   b\"' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        let untouched_ast = format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string()));
+
+        assert!(untouched_ast.contains("\"border\": \"10px  solid   red\""));
+
+        parser.set_value_whitespace_normalization(true);
+
+        let normalized_ast = format!("{:?}", parser.parse(raw_nenyr.to_string(), "".to_string()));
+
+        assert!(normalized_ast.contains("\"border\": \"10px solid red\""));
+        assert!(normalized_ast.contains("\"width\": \"calc(100%  -  10px)\""));
+        assert!(normalized_ast.contains("\"content\": \"\\\"a   b\\\"\""));
+    }
+
+    #[test]
+    fn line_comments_must_fail_only_when_forbidden() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    /* Block comments are always allowed. */
+    // Line comments are allowed by default.
+    Declare Variables({ accent: 'red' })
+}";
+        let mut parser = NenyrParser::new();
+
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_ok());
+
+        parser.set_line_comments_forbidden(true);
+
+        match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Err(err) => {
+                assert_eq!(err.get_line(), 3);
+                assert_eq!(err.get_column(), 5);
+                assert_eq!(
+                    err.get_error_line(),
+                    Some("    // Line comments are allowed by default.".to_string())
+                );
+            }
+            Ok(ast) => panic!("{:?}", ast),
+        }
+    }
+
+    #[test]
+    fn semicolons_must_separate_properties_only_when_allowed() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    Declare Variables({ accent: 'red'; spacing: '10px'; }),
+    Declare Class('box') {
+        Stylesheet({
+            color: '${accent}';
+            padding: '${spacing}';
+        })
+    }
+}";
+        let mut parser = NenyrParser::new();
+
+        match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Err(err) => {
+                assert_eq!(err.get_line(), 2);
+                assert_eq!(
+                    err.get_error_line(),
+                    Some("    Declare Variables({ accent: 'red'; spacing: '10px'; }),".to_string())
+                );
+            }
+            Ok(ast) => panic!("{:?}", ast),
+        }
+
+        parser.set_semicolons_allowed(true);
+
+        let mut strict_parser = NenyrParser::new();
+        let comma_separated = raw_nenyr.replace(';', ",");
+
+        assert_eq!(
+            parser.parse(raw_nenyr.to_string(), "".to_string()),
+            strict_parser.parse(comma_separated, "".to_string())
+        );
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_ok());
+    }
+
+    #[test]
+    fn error_column_must_reflect_the_tab_width() {
+        let raw_nenyr = "Construct Layout('mainLayout') {\n\tDeclare Variables({\n\t\taccent: 'red',\n\t\t$\n\t})\n}";
+        let mut parser = NenyrParser::new();
+
+        match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Err(err) => assert_eq!((err.get_line(), err.get_column()), (4, 4)),
+            Ok(ast) => panic!("{:?}", ast),
+        }
+
+        parser.set_tab_width(4);
+
+        match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Err(err) => assert_eq!((err.get_line(), err.get_column()), (4, 10)),
+            Ok(ast) => panic!("{:?}", ast),
+        }
+    }
+
+    #[test]
+    fn injected_context_name_must_be_used_by_errors() {
+        let mut parser = NenyrParser::new();
+
+        let err = parser
+            .parse_with_context_name(
+                "Declare Variables({ accent: 'red' })".to_string(),
+                "".to_string(),
+                "buttonFragment".to_string(),
+            )
+            .unwrap_err();
+
+        assert_eq!(err.get_context_name(), Some("buttonFragment".to_string()));
+
+        let err = parser
+            .parse_with_context_name(
+                "Construct Layout('mainLayout') { Declare Variables({ accent: }) }".to_string(),
+                "".to_string(),
+                "buttonFragment".to_string(),
+            )
+            .unwrap_err();
+
+        assert_eq!(err.get_context_name(), Some("buttonFragment".to_string()));
+
+        let err = parser
+            .parse(
+                "Declare Variables({ accent: 'red' })".to_string(),
+                "".to_string(),
+            )
+            .unwrap_err();
+
+        assert_eq!(err.get_context_name(), None);
+    }
+
+    #[test]
+    fn error_codes_must_identify_each_kind_of_error() {
+        let mut parser = NenyrParser::new();
+        let missing_curly_bracket = parser
+            .parse(
+                "Construct Layout('mainLayout') Declare Class('header') { }".to_string(),
+                "".to_string(),
+            )
+            .unwrap_err();
+        let invalid_identifier = parser
+            .parse(
+                "Construct Layout('mainLayout') { Declare Class('1header') { } }".to_string(),
+                "".to_string(),
+            )
+            .unwrap_err();
+
+        assert_eq!(missing_curly_bracket.get_error_code(), "N0031");
+        assert_eq!(invalid_identifier.get_error_code(), "N0020");
+        assert!(missing_curly_bracket.to_string().starts_with(&format!(
+            "error[{}]: ",
+            missing_curly_bracket.get_error_code()
+        )));
+    }
+
+    #[test]
+    fn token_histogram_must_count_the_tokens_of_each_kind() {
+        let raw_nenyr = "Construct Central {
+    Declare Aliases({
+        bgd: backgroundColor,
+        pdg: padding
+    }),
+    Declare Variables({
+        primary: '#333',
+        secondary: '#fff'
+    })
+}";
+        let mut parser = NenyrParser::new();
+
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_ok());
+        assert!(parser.token_histogram().is_empty());
+
+        parser.set_token_histogram(true);
+
+        assert!(parser.parse(raw_nenyr.to_string(), "".to_string()).is_ok());
+
+        let histogram = parser.token_histogram();
+        let count = |token: NenyrTokens| histogram.get(&token_kind(&token)).copied();
+
+        assert_eq!(count(NenyrTokens::Comma), Some(3));
+        assert_eq!(count(NenyrTokens::CurlyBracketOpen), Some(3));
+        assert_eq!(count(NenyrTokens::CurlyBracketClose), Some(3));
+        assert_eq!(count(NenyrTokens::Declare), Some(2));
+        assert_eq!(count(NenyrTokens::Identifier("".to_string())), Some(4));
+        assert_eq!(count(NenyrTokens::Construct), Some(1));
+        assert_eq!(count(NenyrTokens::Important), None);
+    }
+
+    #[test]
+    fn imports_must_be_resolved_against_the_base_path() {
+        let raw_nenyr = "Construct Central {
+    Declare Imports([
+        Import('https://fonts.googleapis.com/css2?family=Matemasie&display=swap'),
+        Import('../imports/styles.css')
+    ])
+}";
+        let mut parser = NenyrParser::new().with_base_path("mocks/nenyr");
+        let central_context = match parser.parse(raw_nenyr.to_string(), "".to_string()) {
+            Ok(NenyrAst::CentralContext(central_context)) => central_context,
+            result => panic!("{:?}", result),
+        };
+
+        assert_eq!(
+            parser.resolved_imports(&central_context),
+            vec![
+                "https://fonts.googleapis.com/css2?family=Matemasie&display=swap".to_string(),
+                "mocks/imports/styles.css".to_string(),
+            ]
+        );
+        assert_eq!(
+            NenyrParser::new().resolved_imports(&central_context)[1],
+            "../imports/styles.css"
+        );
+    }
+
+    #[test]
+    fn parse_and_emit_must_produce_the_stylesheet() {
+        let raw_nenyr = "Construct Layout('mainLayout') {
+    Declare Aliases({ bgd: backgroundColor }),
+    Declare Animation('fade') {
+        From({ opacity: '0' }),
+        To({ opacity: '1' })
+    },
+    Declare Class('header') {
+        Stylesheet({ bgd: 'blue', animationName: 'fade' }),
+        Hover({ color: 'red' })
+    }
+}";
+        let css = NenyrParser::parse_and_emit(raw_nenyr, "", EmitOptions::default()).unwrap();
+
+        assert!(css.contains("@keyframes fade {"));
+        assert!(
+            css.contains(".header {\n    background-color: blue;\n    animation-name: fade;\n}")
+        );
+        assert!(css.contains(".header:hover {"));
+
+        let minified_css = NenyrParser::parse_and_emit(
+            raw_nenyr,
+            "",
+            EmitOptions {
+                minify: true,
+                sort_properties: true,
+                custom_properties: false,
+            },
+        )
+        .unwrap();
+
+        assert!(minified_css.contains("@keyframes fade{from{opacity:0}to{opacity:1}}"));
+        assert!(minified_css.contains(".header{animation-name:fade;background-color:blue}"));
+        assert!(NenyrParser::parse_and_emit("Construct", "", EmitOptions::default()).is_err());
+    }
+
+    #[test]
+    fn parse_into_must_reuse_the_maps_of_the_ast() {
+        let first_nenyr = "Construct Layout('mainLayout') {
+    Declare Class('first') { Stylesheet({ color: 'red' }) },
+    Declare Class('second') { Stylesheet({ color: 'blue' }) },
+    Declare Class('third') { Stylesheet({ color: 'green' }) }
+}";
+        let second_nenyr = "Construct Layout('secondLayout') {
+    Declare Class('fourth') { Stylesheet({ color: 'pink' }) }
+}";
+        let mut parser = NenyrParser::new();
+        let mut ast = NenyrAst::ModuleContext(ModuleContext::new("card".to_string(), None));
+
+        parser
+            .parse_into(first_nenyr.to_string(), "".to_string(), &mut ast)
+            .unwrap();
+
+        let classes_capacity = match &ast {
+            NenyrAst::LayoutContext(layout_context) => {
+                layout_context.classes.as_ref().unwrap().capacity()
+            }
+            _ => panic!("{:?}", ast),
+        };
+
+        parser
+            .parse_into(second_nenyr.to_string(), "".to_string(), &mut ast)
+            .unwrap();
+
+        assert_eq!(
+            ast,
+            parser
+                .parse(second_nenyr.to_string(), "".to_string())
+                .unwrap()
+        );
+
+        match &ast {
+            NenyrAst::LayoutContext(layout_context) => {
+                let classes = layout_context.classes.as_ref().unwrap();
+
+                assert_eq!(classes.len(), 1);
+                assert_eq!(classes.capacity(), classes_capacity);
+            }
+            _ => panic!("{:?}", ast),
+        }
+
+        assert!(parser
+            .parse_into("Construct".to_string(), "".to_string(), &mut ast)
+            .is_err());
+        assert_eq!(ast.context_name(), Some("secondLayout"));
+        assert_eq!(ast.declaration_counts(), DeclarationCounts::default());
+    }
+
+    #[test]
+    fn ast_and_errors_must_be_send() {
+        fn assert_send<T: Send>() {}
+
+        assert_send::<NenyrAst>();
+        assert_send::<crate::error::NenyrError>();
+        assert_send::<NenyrParser>();
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn independent_contexts_must_be_parsed_in_parallel() {
+        let inputs: Vec<(String, String)> = (0..16)
+            .map(|idx| {
+                (
+                    format!(
+                        "Construct Layout('layout{}') {{ Declare Class('box') {{ Stylesheet({{ zIndex: '{}' }}) }} }}",
+                        idx, idx
+                    ),
+                    format!("layout{}.nyr", idx),
+                )
+            })
+            .collect();
+        let results = NenyrParser::parse_files_parallel(inputs);
+
+        assert_eq!(results.len(), 16);
+
+        for (idx, result) in results.iter().enumerate() {
+            let ast = result.as_ref().unwrap();
+
+            assert_eq!(ast.context_name(), Some(format!("layout{}", idx).as_str()));
+        }
+    }
+
+    #[test]
+    fn declarations_estimate_must_ignore_partial_words() {
+        let raw_nenyr = "Construct Module('card') {
+    Declare Aliases({ bgd: backgroundColor }),
+    Declare Variables({ myDeclared: 'red' }),
+    Declare Class('cardDeclare') { Stylesheet({ bgd: '${myDeclared}' }) }
+}";
+
+        assert_eq!(NenyrParser::estimate_declarations(raw_nenyr), 3);
+        assert_eq!(NenyrParser::estimate_declarations(""), 0);
+    }
+
+    #[test]
+    fn comments_must_be_skipped_at_every_token_boundary() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Aliases({ bgd: backgroundColor }),
+    Declare Variables({ gutter: '8px', accent: 'red' }),
+    Declare Animation('pulse') {
+        Fraction(30, { width: '10px' }),
+        Fraction([60, 100], { width: '20px' })
+    },
+    Declare Class('box') Deriving('base') {
+        Important(true),
+        Stylesheet({ bgd: '${accent}', padding: '${gutter}' }),
+        Hover({ color: 'blue' }),
+        PanoramicViewer({ onMobile({ Stylesheet({ display: 'none' }) }) })
+    }
+}";
+        let commented_nenyr = "/* a */ Construct /* b */ Module /* c */ ( /* d */ 'card' /* e */ ) /* f */ Extending /* g */ ('mainLayout') /* h */ {
+    Declare /* i */ Aliases /* j */ ( /* k */ { /* l */ bgd /* m */ : /* n */ backgroundColor /* o */ } /* p */ ) /* q */ ,
+    Declare Variables({ gutter /* r */ : '8px' /* s */ , /* t */ accent: 'red' }),
+    Declare Animation /* u */ ('pulse') /* v */ {
+        Fraction /* w */ ( /* x */ 30 /* y */ , /* z */ { width: '10px' }),
+        Fraction([ /* aa */ 60 /* ab */ , /* ac */ 100 /* ad */ ], { width: '20px' })
+    },
+    Declare Class('box') /* ae */ Deriving /* af */ ('base') {
+        Important /* ag */ ( /* ah */ true /* ai */ ),
+        Stylesheet /* aj */ ({ bgd: '${accent}', padding: '${gutter}' }),
+        Hover // line comment
+        ({ color: 'blue' }),
+        PanoramicViewer /* ak */ ({ onMobile /* al */ ({ Stylesheet({ display: 'none' }) }) })
+    } /* am */
+} /* an */";
+
+        let mut parser = NenyrParser::new();
+        let expected_ast = parser.parse(raw_nenyr.to_string(), "".to_string());
+
+        assert!(expected_ast.is_ok(), "{:?}", expected_ast);
+        assert_eq!(
+            parser.parse(commented_nenyr.to_string(), "".to_string()),
+            expected_ast
+        );
+    }
+
+    #[test]
+    fn comments_without_surrounding_whitespace_must_be_skipped() {
+        let raw_nenyr = "Construct Module('card') Extending('mainLayout') {
+    Declare Variables({ spacing: 8px, tone: 'a' }),
+    Declare Animation('grow') { Progressive({ width: '1px' }), Progressive({ width: '2px' }) }
+}";
+        let commented_nenyr = "Construct/**/Module('card')Extending('mainLayout'){Declare Variables({spacing:8px/*u*/,tone:'a'/**/}),Declare Animation('grow'){Progressive/**/({width:'1px'}),Progressive/**/(/**/{/**/width/**/:/**/'2px'/**/}/**/)/**/}/**/}//end";
+
+        let mut parser = NenyrParser::new();
+        let expected_ast = parser.parse(raw_nenyr.to_string(), "".to_string());
+
+        assert!(expected_ast.is_ok(), "{:?}", expected_ast);
+        assert_eq!(
+            parser.parse(commented_nenyr.to_string(), "".to_string()),
+            expected_ast
         );
     }
 }
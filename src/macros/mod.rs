@@ -56,9 +56,11 @@ macro_rules! loop_while_not {
         $set_active_state:expr,
         $body:block
     ) => {{
-        // Loop through the tokens until a closing curly bracket (`}`) is found.
+        // Loop through the tokens until a closing curly bracket (`}`) is found, or
+        // until the input is exhausted, leaving the missing delimiter to the caller.
         while $self.current_token != NenyrTokens::CurlyBracketClose
             && $self.current_token != NenyrTokens::SquareBracketClose
+            && $self.current_token != NenyrTokens::EndOfFile
         {
             // If the current token is a comma, handle it based on the active state.
             if let NenyrTokens::Comma = $self.current_token {
@@ -78,7 +80,8 @@ macro_rules! loop_while_not {
                         $self.add_nenyr_token_to_error($duplicated_comma_error_message),
                         NenyrErrorKind::SyntaxError,
                         $self.get_tracing(),
-                    ));
+                    )
+                    .with_code("N0098"));
                 }
             }
 
@@ -92,7 +95,8 @@ macro_rules! loop_while_not {
                     $self.add_nenyr_token_to_error($missing_comma_error_message),
                     NenyrErrorKind::SyntaxError,
                     $self.get_tracing(),
-                ));
+                )
+                .with_code("N0099"));
             }
 
             // Process the body of the macro, which defines the custom logic.
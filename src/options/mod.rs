@@ -0,0 +1,214 @@
+/// The policy applied to the property names that are neither a Nenyr property nor an alias
+/// declared earlier in the same context.
+///
+/// Such names are kept as deferred alias references (`nickname;name`), since they may refer
+/// to aliases declared by another context. The policy decides whether they are accepted.
+///
+/// # Variants
+/// - `Error`: The unknown property stops the parsing with a `NenyrError`.
+/// - `PassThrough`: The unknown property is kept as a deferred alias reference. This is the default.
+/// - `Warn`: The unknown property is kept, and a `NenyrDiagnostic` with the `Warning` severity is raised.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum UnknownPropertyPolicy {
+    Error,
+    #[default]
+    PassThrough,
+    Warn,
+}
+
+/// The policy applied to the `Transitive` animations declaring neither a `From` nor a `To`
+/// pattern, such as an animation made of a single `Halfway` pattern.
+///
+/// # Variants
+/// - `Allow`: The incomplete animation is accepted. This is the default.
+/// - `Warn`: The incomplete animation is kept, and a `NenyrDiagnostic` with the `Warning` severity is raised.
+/// - `Error`: The incomplete animation stops the parsing with a `NenyrError`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum IncompleteTransitivePolicy {
+    #[default]
+    Allow,
+    Warn,
+    Error,
+}
+
+/// The policy applied to a `Stylesheet` pattern declared more than once in the same class
+/// or breakpoint scope.
+///
+/// # Variants
+/// - `Merge`: The properties of the duplicated `Stylesheet` are merged into the ones declared
+///   before.
+/// - `Warn`: The properties are merged, and a `NenyrDiagnostic` with the `Warning` severity is
+///   raised. This is the default.
+/// - `Error`: The duplicated `Stylesheet` stops the parsing with a `NenyrError`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum DuplicatedStylesheetPolicy {
+    Merge,
+    #[default]
+    Warn,
+    Error,
+}
+
+/// The policy applied once the number of collected diagnostics reaches the limit set
+/// through `NenyrParser::set_diagnostics_limit`.
+///
+/// # Variants
+/// - `Continue`: The parsing continues, and the diagnostics raised beyond the limit are
+///   discarded after a final `Info` diagnostic marking the limit. This is the default.
+/// - `Abort`: The parsing stops with a `NenyrError` as soon as the limit is exceeded.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum DiagnosticsLimitPolicy {
+    #[default]
+    Continue,
+    Abort,
+}
+
+/// The options controlling the optional checks performed by the Nenyr parser.
+///
+/// Every check is disabled by default, so the parser only enforces the rules required
+/// by the Nenyr syntax unless the caller opts in through the setters exposed by
+/// `NenyrParser`. The only exception is a duplicated `Stylesheet` pattern, which raises a
+/// warning by default. The options are kept between parsings.
+///
+/// # Fields
+/// - `warn_on_shared_breakpoint_values`: Whether a warning must be raised when the same
+///   value is used by both a `MobileFirst` and a `DesktopFirst` breakpoint.
+/// - `warn_on_unordered_breakpoints`: Whether a warning must be raised when the pixel
+///   thresholds of a breakpoints schema are not monotonically ordered.
+/// - `unknown_property_policy`: How the properties that are neither a Nenyr property nor a
+///   declared alias must be handled.
+/// - `normalize_hex_case`: Whether the hex colors of the values must be lowercased.
+/// - `normalize_value_whitespace`: Whether the runs of whitespace of the style property values
+///   must be collapsed into single spaces.
+/// - `forbid_line_comments`: Whether line comments (`//`) must be rejected.
+/// - `allow_semicolons`: Whether semicolons (`;`) are accepted as property separators, the
+///   same way as commas.
+/// - `warn_on_shorthand_conflicts`: Whether a warning must be raised when a shorthand property
+///   is declared after one of its longhands in the same block.
+/// - `incomplete_transitive_policy`: How the `Transitive` animations declaring neither a
+///   `From` nor a `To` pattern must be handled.
+/// - `warn_on_inconsistent_progressive_steps`: Whether a warning must be raised when the steps
+///   of a `Progressive` animation do not declare the same properties.
+/// - `tab_width`: The number of columns counted for each tab character when reporting
+///   positions. Defaults to 1.
+/// - `diagnostics_limit`: The maximum number of diagnostics collected during a parsing.
+///   Defaults to no limit.
+/// - `diagnostics_limit_policy`: How the parsing must proceed once the diagnostics limit
+///   is exceeded.
+/// - `max_block_properties`: The maximum number of properties declared by a class pattern
+///   or an animation keyframe. Defaults to no limit.
+/// - `record_token_histogram`: Whether the number of tokens of each kind must be recorded
+///   during a parsing.
+/// - `warn_on_undeclared_animations`: Whether a warning must be raised when a class references
+///   an animation that was not declared before it in the same context.
+/// - `base_path`: The directory the relative import paths are resolved against. Defaults to
+///   no base directory.
+/// - `duplicated_stylesheet_policy`: How a `Stylesheet` pattern declared more than once in the
+///   same class or breakpoint scope must be handled.
+/// - `keep_sunk_declarations`: Whether the declarations handed to the declaration sink must
+///   also be stored in the context.
+/// - `collect_comments`: Whether the comments of the context must be collected, so that the
+///   formatter can write them back.
+#[derive(Debug, PartialEq, Clone)]
+pub struct NenyrParserOptions {
+    pub warn_on_shared_breakpoint_values: bool,
+    pub warn_on_unordered_breakpoints: bool,
+    pub unknown_property_policy: UnknownPropertyPolicy,
+    pub normalize_hex_case: bool,
+    pub normalize_value_whitespace: bool,
+    pub forbid_line_comments: bool,
+    pub allow_semicolons: bool,
+    pub warn_on_shorthand_conflicts: bool,
+    pub incomplete_transitive_policy: IncompleteTransitivePolicy,
+    pub warn_on_inconsistent_progressive_steps: bool,
+    pub tab_width: usize,
+    pub diagnostics_limit: Option<usize>,
+    pub diagnostics_limit_policy: DiagnosticsLimitPolicy,
+    pub max_block_properties: Option<usize>,
+    pub record_token_histogram: bool,
+    pub warn_on_undeclared_animations: bool,
+    pub base_path: Option<String>,
+    pub duplicated_stylesheet_policy: DuplicatedStylesheetPolicy,
+    pub keep_sunk_declarations: bool,
+    pub collect_comments: bool,
+}
+
+impl NenyrParserOptions {
+    /// Creates a new `NenyrParserOptions` with every check disabled and a tab width of 1.
+    pub fn new() -> Self {
+        Self {
+            warn_on_shared_breakpoint_values: false,
+            warn_on_unordered_breakpoints: false,
+            unknown_property_policy: UnknownPropertyPolicy::PassThrough,
+            normalize_hex_case: false,
+            normalize_value_whitespace: false,
+            forbid_line_comments: false,
+            allow_semicolons: false,
+            warn_on_shorthand_conflicts: false,
+            incomplete_transitive_policy: IncompleteTransitivePolicy::Allow,
+            warn_on_inconsistent_progressive_steps: false,
+            tab_width: 1,
+            diagnostics_limit: None,
+            diagnostics_limit_policy: DiagnosticsLimitPolicy::Continue,
+            max_block_properties: None,
+            record_token_histogram: false,
+            warn_on_undeclared_animations: false,
+            base_path: None,
+            duplicated_stylesheet_policy: DuplicatedStylesheetPolicy::Warn,
+            keep_sunk_declarations: false,
+            collect_comments: false,
+        }
+    }
+}
+
+impl Default for NenyrParserOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DiagnosticsLimitPolicy, DuplicatedStylesheetPolicy, IncompleteTransitivePolicy,
+        NenyrParserOptions, UnknownPropertyPolicy,
+    };
+
+    #[test]
+    fn options_must_be_disabled_by_default() {
+        let options = NenyrParserOptions::new();
+
+        assert_eq!(options, NenyrParserOptions::default());
+        assert!(!options.warn_on_shared_breakpoint_values);
+        assert!(!options.warn_on_unordered_breakpoints);
+        assert!(!options.normalize_hex_case);
+        assert!(!options.normalize_value_whitespace);
+        assert!(!options.forbid_line_comments);
+        assert!(!options.allow_semicolons);
+        assert!(!options.warn_on_shorthand_conflicts);
+        assert!(!options.warn_on_inconsistent_progressive_steps);
+        assert!(!options.record_token_histogram);
+        assert!(!options.warn_on_undeclared_animations);
+        assert!(!options.keep_sunk_declarations);
+        assert!(!options.collect_comments);
+        assert_eq!(options.base_path, None);
+        assert_eq!(
+            options.duplicated_stylesheet_policy,
+            DuplicatedStylesheetPolicy::Warn
+        );
+        assert_eq!(options.tab_width, 1);
+        assert_eq!(options.diagnostics_limit, None);
+        assert_eq!(options.max_block_properties, None);
+        assert_eq!(
+            options.diagnostics_limit_policy,
+            DiagnosticsLimitPolicy::Continue
+        );
+        assert_eq!(
+            options.incomplete_transitive_policy,
+            IncompleteTransitivePolicy::Allow
+        );
+        assert_eq!(
+            options.unknown_property_policy,
+            UnknownPropertyPolicy::PassThrough
+        );
+    }
+}
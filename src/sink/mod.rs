@@ -0,0 +1,132 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use crate::types::declaration::NenyrDeclaration;
+
+/// The callback receiving each declaration as soon as its block finishes parsing.
+type NenyrDeclarationCallback = dyn FnMut(NenyrDeclaration) + Send;
+
+/// Holds the optional callback registered through `NenyrParser::with_declaration_sink`.
+///
+/// The callback is shared through an `Arc<Mutex<...>>`, so cloning the parser keeps
+/// feeding the same sink and the parser remains `Send`. Two sinks are considered equal
+/// when both are empty or when both point to the same callback.
+#[derive(Clone, Default)]
+pub struct NenyrDeclarationSink {
+    callback: Option<Arc<Mutex<NenyrDeclarationCallback>>>,
+}
+
+impl NenyrDeclarationSink {
+    /// Creates a new `NenyrDeclarationSink` without any callback registered.
+    pub fn new() -> Self {
+        Self { callback: None }
+    }
+
+    /// Registers the callback receiving the parsed declarations.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback to be invoked with each completed declaration.
+    pub fn set_callback(&mut self, callback: impl FnMut(NenyrDeclaration) + Send + 'static) {
+        self.callback = Some(Arc::new(Mutex::new(callback)));
+    }
+
+    /// Hands the declaration over to the registered callback.
+    ///
+    /// # Arguments
+    ///
+    /// * `declaration` - The declaration that just finished parsing.
+    /// * `is_kept` - Whether the declaration must also be given back once handed to the
+    ///   callback, so it can still be stored in the context.
+    ///
+    /// # Returns
+    ///
+    /// * `None` - If the declaration was consumed by the callback.
+    /// * `Some(NenyrDeclaration)` - If no callback is registered, or if the declaration is
+    ///   kept, giving the declaration back so it can be stored in the context.
+    pub fn consume(
+        &self,
+        declaration: NenyrDeclaration,
+        is_kept: bool,
+    ) -> Option<NenyrDeclaration> {
+        match &self.callback {
+            Some(callback) => {
+                let mut callback = callback.lock().unwrap_or_else(|err| err.into_inner());
+
+                if is_kept {
+                    callback(declaration.clone());
+
+                    return Some(declaration);
+                }
+
+                callback(declaration);
+
+                None
+            }
+            None => Some(declaration),
+        }
+    }
+}
+
+impl fmt::Debug for NenyrDeclarationSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NenyrDeclarationSink")
+            .field("is_registered", &self.callback.is_some())
+            .finish()
+    }
+}
+
+impl PartialEq for NenyrDeclarationSink {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.callback, &other.callback) {
+            (Some(callback), Some(other_callback)) => Arc::ptr_eq(callback, other_callback),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::NenyrDeclarationSink;
+    use crate::types::{declaration::NenyrDeclaration, variables::NenyrVariables};
+
+    #[test]
+    fn empty_sink_must_give_declaration_back() {
+        let sink = NenyrDeclarationSink::new();
+        let declaration = NenyrDeclaration::Variables(NenyrVariables::new());
+
+        assert_eq!(sink.consume(declaration.clone(), false), Some(declaration));
+    }
+
+    #[test]
+    fn registered_sink_must_consume_declaration() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let sink_counter = counter.clone();
+        let mut sink = NenyrDeclarationSink::new();
+
+        sink.set_callback(move |_| {
+            sink_counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(
+            sink.consume(NenyrDeclaration::Variables(NenyrVariables::new()), false),
+            None
+        );
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            sink.consume(NenyrDeclaration::Variables(NenyrVariables::new()), true),
+            Some(NenyrDeclaration::Variables(NenyrVariables::new()))
+        );
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        assert_eq!(sink.clone(), sink);
+        assert_ne!(sink, NenyrDeclarationSink::new());
+    }
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+/// The metrics collected while parsing a Nenyr context, returned by `NenyrParser::parse_timed`.
+///
+/// The counters are reset at the start of each parsing, so they always describe the last
+/// parsed context.
+///
+/// # Fields
+/// - `elapsed`: How long the parsing took.
+/// - `token_count`: How many tokens were read from the lexer, including the end of the input.
+/// - `declaration_count`: How many `Declare` blocks were parsed.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ParseStats {
+    pub elapsed: Duration,
+    pub token_count: usize,
+    pub declaration_count: usize,
+}
+
+impl ParseStats {
+    /// Creates a new `ParseStats` with every counter set to zero.
+    pub fn new() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            token_count: 0,
+            declaration_count: 0,
+        }
+    }
+
+    /// Counts a token read from the lexer.
+    pub(crate) fn count_token(&mut self) {
+        self.token_count += 1;
+    }
+
+    /// Counts a parsed `Declare` block.
+    pub(crate) fn count_declaration(&mut self) {
+        self.declaration_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseStats;
+
+    #[test]
+    fn stats_must_start_empty_and_count() {
+        let mut stats = ParseStats::new();
+
+        assert_eq!(stats, ParseStats::default());
+
+        stats.count_token();
+        stats.count_token();
+        stats.count_declaration();
+
+        assert_eq!(stats.token_count, 2);
+        assert_eq!(stats.declaration_count, 1);
+    }
+}
@@ -1,6 +1,15 @@
-#[derive(Debug, PartialEq, Clone)]
+use indexmap::{IndexMap, IndexSet};
+use std::sync::Arc;
+
+use crate::error::NenyrErrorTracing;
+
+/// The properties of an included mixin, paired with the panoramic breakpoint including it.
+pub type PendingInclude = (Option<String>, IndexMap<String, Arc<str>>);
+
+#[derive(Debug, PartialEq, Clone, Default)]
 pub enum NenyrState {
     Active,
+    #[default]
     Inactive,
 }
 
@@ -24,7 +33,20 @@ pub enum NenyrState {
 ///
 /// The parser may use these states to determine what action should be taken or how
 /// certain conditions are interpreted based on the current state.
-#[derive(Debug, PartialEq, Clone)]
+///
+/// Besides the states, the store also keeps track of the class and animation names already
+/// declared within the current context, along with the position of their declarations, so that
+/// duplicated declarations can be reported. The aliases declared within the current context
+/// are kept as well, so that unknown properties can be told apart from aliases and the aliases
+/// of `Themes` never contradict the ones of the context, along with the
+/// constant and variable names, so that a constant never collides with a variable. The mixins
+/// declared within the current context are kept with their properties, so that the mixins
+/// included by a class can be expanded once its block is parsed. The byte
+/// spans of the parsed class and animation declarations are kept as well, allowing the source
+/// of each declaration to be retrieved.
+///
+/// The default store is equivalent to `NenyrProcessStore::new()`, with every state inactive.
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct NenyrProcessStore {
     /// Indicates if the main context is active.
     is_context_active: NenyrState,
@@ -38,6 +60,29 @@ pub struct NenyrProcessStore {
     is_extra_block_active: NenyrState,
     /// Tracks if a complementary block, which may represent an optional or secondary section, is active.
     is_complementary_block_active: NenyrState,
+    /// Keeps the position of each class declaration within the current context.
+    declared_classes: IndexMap<String, NenyrErrorTracing>,
+    /// Keeps the position of each animation declaration within the current context.
+    declared_animations: IndexMap<String, NenyrErrorTracing>,
+    /// The byte position where the declaration being processed starts.
+    declaration_start: usize,
+    /// Keeps the byte span of each class declaration within the current context.
+    class_spans: IndexMap<String, (usize, usize)>,
+    /// Keeps the byte span of each animation declaration within the current context.
+    animation_spans: IndexMap<String, (usize, usize)>,
+    /// Keeps the property of each alias declared within the current context, outside of `Themes`.
+    declared_aliases: IndexMap<String, String>,
+    /// Keeps the properties of each alias declared within the `Themes` of the current context.
+    declared_theme_aliases: IndexMap<String, Vec<String>>,
+    /// Keeps the constant names declared within the current context.
+    declared_consts: IndexSet<String>,
+    /// Keeps the variable names declared within the current context, outside of `Themes`.
+    declared_variables: IndexSet<String>,
+    /// Keeps the properties of each mixin declared within the current context.
+    declared_mixins: IndexMap<String, IndexMap<String, Arc<str>>>,
+    /// Keeps the mixin properties included by the class being processed, along with the
+    /// panoramic breakpoint including them, if any.
+    pending_includes: Vec<PendingInclude>,
 }
 
 impl NenyrProcessStore {
@@ -50,6 +95,17 @@ impl NenyrProcessStore {
             is_internal_block_active: NenyrState::Inactive,
             is_extra_block_active: NenyrState::Inactive,
             is_complementary_block_active: NenyrState::Inactive,
+            declared_classes: IndexMap::new(),
+            declared_animations: IndexMap::new(),
+            declaration_start: 0,
+            class_spans: IndexMap::new(),
+            animation_spans: IndexMap::new(),
+            declared_aliases: IndexMap::new(),
+            declared_theme_aliases: IndexMap::new(),
+            declared_consts: IndexSet::new(),
+            declared_variables: IndexSet::new(),
+            declared_mixins: IndexMap::new(),
+            pending_includes: Vec::new(),
         }
     }
 
@@ -131,6 +187,249 @@ impl NenyrProcessStore {
         }
     }
 
+    /// Registers the declaration of a class within the current context.
+    ///
+    /// If the class name was already registered, the original declaration is kept
+    /// and its tracing is returned, allowing the caller to report the duplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_name` - The name of the declared class.
+    /// * `tracing` - The position where the class was declared.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(NenyrErrorTracing)` with the position of the previous declaration if the
+    ///   class name was already declared, `None` otherwise.
+    pub fn register_class_declaration(
+        &mut self,
+        class_name: &str,
+        tracing: NenyrErrorTracing,
+    ) -> Option<NenyrErrorTracing> {
+        if let Some(previous_tracing) = self.declared_classes.get(class_name) {
+            return Some(previous_tracing.clone());
+        }
+
+        self.declared_classes
+            .insert(class_name.to_string(), tracing);
+
+        None
+    }
+
+    /// Registers the declaration of an animation within the current context.
+    ///
+    /// If the animation name was already registered, the original declaration is kept
+    /// and its tracing is returned, allowing the caller to report the duplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_name` - The name of the declared animation.
+    /// * `tracing` - The position where the animation was declared.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(NenyrErrorTracing)` with the position of the previous declaration if the
+    ///   animation name was already declared, `None` otherwise.
+    pub fn register_animation_declaration(
+        &mut self,
+        animation_name: &str,
+        tracing: NenyrErrorTracing,
+    ) -> Option<NenyrErrorTracing> {
+        if let Some(previous_tracing) = self.declared_animations.get(animation_name) {
+            return Some(previous_tracing.clone());
+        }
+
+        self.declared_animations
+            .insert(animation_name.to_string(), tracing);
+
+        None
+    }
+
+    /// Sets the byte position where the declaration being processed starts.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The byte position of the `Declare` keyword.
+    pub fn set_declaration_start(&mut self, position: usize) {
+        self.declaration_start = position;
+    }
+
+    /// Registers the byte span of a class declaration, starting at the `Declare` keyword
+    /// and ending right after the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_name` - The name of the declared class.
+    /// * `end_position` - The byte position right after the end of the declaration.
+    pub fn register_class_span(&mut self, class_name: &str, end_position: usize) {
+        self.class_spans.insert(
+            class_name.to_string(),
+            (self.declaration_start, end_position),
+        );
+    }
+
+    /// Registers the byte span of an animation declaration, starting at the `Declare` keyword
+    /// and ending right after the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_name` - The name of the declared animation.
+    /// * `end_position` - The byte position right after the end of the declaration.
+    pub fn register_animation_span(&mut self, animation_name: &str, end_position: usize) {
+        self.animation_spans.insert(
+            animation_name.to_string(),
+            (self.declaration_start, end_position),
+        );
+    }
+
+    /// Registers an alias declared within the current context, along with its property.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias_name` - The name of the alias.
+    /// * `property` - The CSS property the alias refers to.
+    /// * `is_from_themes` - Whether the alias is declared within `Themes`.
+    pub fn register_alias_declaration(
+        &mut self,
+        alias_name: &str,
+        property: &str,
+        is_from_themes: bool,
+    ) {
+        if is_from_themes {
+            self.declared_theme_aliases
+                .entry(alias_name.to_string())
+                .or_default()
+                .push(property.to_string());
+        } else {
+            self.declared_aliases
+                .insert(alias_name.to_string(), property.to_string());
+        }
+    }
+
+    /// Checks if an alias name was declared within the current context, including `Themes`.
+    pub fn is_alias_declared(&self, alias_name: &str) -> bool {
+        self.declared_aliases.contains_key(alias_name)
+            || self.declared_theme_aliases.contains_key(alias_name)
+    }
+
+    /// Retrieves the CSS property an alias declared within the current context refers to.
+    pub fn get_alias_property(&self, alias_name: &str) -> Option<&str> {
+        self.declared_aliases
+            .get(alias_name)
+            .map(|property| property.as_str())
+    }
+
+    /// Retrieves the property an alias already refers to on the other side of `Themes`, when
+    /// it differs from the given one. Aliases declared within `Themes` are compared with the
+    /// ones declared by the context, and the other way around.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias_name` - The name of the alias being declared.
+    /// * `property` - The CSS property the alias being declared refers to.
+    /// * `is_from_themes` - Whether the alias being declared is within `Themes`.
+    pub fn get_conflicting_alias_property(
+        &self,
+        alias_name: &str,
+        property: &str,
+        is_from_themes: bool,
+    ) -> Option<&str> {
+        if is_from_themes {
+            self.declared_aliases
+                .get(alias_name)
+                .map(|declared_property| declared_property.as_str())
+                .filter(|declared_property| *declared_property != property)
+        } else {
+            self.declared_theme_aliases
+                .get(alias_name)
+                .into_iter()
+                .flatten()
+                .map(|declared_property| declared_property.as_str())
+                .find(|declared_property| *declared_property != property)
+        }
+    }
+
+    /// Registers a constant name declared within the current context.
+    pub fn register_const_declaration(&mut self, const_name: &str) {
+        self.declared_consts.insert(const_name.to_string());
+    }
+
+    /// Checks if a constant name was declared within the current context.
+    pub fn is_const_declared(&self, const_name: &str) -> bool {
+        self.declared_consts.contains(const_name)
+    }
+
+    /// Checks if an animation name was declared within the current context.
+    pub fn is_animation_declared(&self, animation_name: &str) -> bool {
+        self.declared_animations.contains_key(animation_name)
+    }
+
+    /// Registers a variable name declared within the current context.
+    pub fn register_variable_declaration(&mut self, variable_name: &str) {
+        self.declared_variables.insert(variable_name.to_string());
+    }
+
+    /// Checks if a variable name was declared within the current context.
+    pub fn is_variable_declared(&self, variable_name: &str) -> bool {
+        self.declared_variables.contains(variable_name)
+    }
+
+    /// Registers a mixin declared within the current context, along with its properties.
+    ///
+    /// # Returns
+    ///
+    /// * `false` if a mixin with the same name was already declared, in which case its
+    ///   properties are kept untouched.
+    pub fn register_mixin_declaration(
+        &mut self,
+        mixin_name: &str,
+        properties: IndexMap<String, Arc<str>>,
+    ) -> bool {
+        if self.declared_mixins.contains_key(mixin_name) {
+            return false;
+        }
+
+        self.declared_mixins
+            .insert(mixin_name.to_string(), properties);
+
+        true
+    }
+
+    /// Retrieves the properties of a mixin declared within the current context.
+    pub fn get_mixin(&self, mixin_name: &str) -> Option<&IndexMap<String, Arc<str>>> {
+        self.declared_mixins.get(mixin_name)
+    }
+
+    /// Registers the properties of a mixin included by the class being processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `breakpoint_name` - The panoramic breakpoint including the mixin, or `None` when
+    ///   the mixin is included directly in the class.
+    /// * `properties` - The properties of the included mixin.
+    pub fn register_pending_include(
+        &mut self,
+        breakpoint_name: Option<String>,
+        properties: IndexMap<String, Arc<str>>,
+    ) {
+        self.pending_includes.push((breakpoint_name, properties));
+    }
+
+    /// Takes the mixin properties included by the class being processed, leaving none behind.
+    pub fn take_pending_includes(&mut self) -> Vec<PendingInclude> {
+        std::mem::take(&mut self.pending_includes)
+    }
+
+    /// Retrieves the byte span of a class declaration.
+    pub fn get_class_span(&self, class_name: &str) -> Option<(usize, usize)> {
+        self.class_spans.get(class_name).copied()
+    }
+
+    /// Retrieves the byte span of an animation declaration.
+    pub fn get_animation_span(&self, animation_name: &str) -> Option<(usize, usize)> {
+        self.animation_spans.get(animation_name).copied()
+    }
+
     /// Checks if the context is currently active.
     ///
     /// # Returns
@@ -169,6 +468,7 @@ impl NenyrProcessStore {
 #[cfg(test)]
 mod tests {
     use super::NenyrProcessStore;
+    use crate::error::NenyrErrorTracing;
 
     #[test]
     fn all_states_must_be_active() {
@@ -245,4 +545,71 @@ mod tests {
         assert_ne!(store.is_internal_block_active(), false);
         assert_ne!(store.is_nested_block_active(), true);
     }
+
+    #[test]
+    fn default_store_must_be_inactive() {
+        let store = NenyrProcessStore::default();
+
+        assert!(!store.is_block_active());
+        assert!(!store.is_complementary_block_active());
+        assert!(!store.is_context_active());
+        assert!(!store.is_extra_block_active());
+        assert!(!store.is_internal_block_active());
+        assert!(!store.is_nested_block_active());
+        assert_eq!(store, NenyrProcessStore::new());
+    }
+
+    #[test]
+    fn duplicated_class_declaration_must_return_previous_tracing() {
+        let mut store = NenyrProcessStore::new();
+        let first_tracing = NenyrErrorTracing::new(None, None, None, 1, 10, 9);
+        let second_tracing = NenyrErrorTracing::new(None, None, None, 5, 10, 50);
+
+        assert_eq!(
+            store.register_class_declaration("myClass", first_tracing.clone()),
+            None
+        );
+        assert_eq!(
+            store.register_class_declaration("anotherClass", second_tracing.clone()),
+            None
+        );
+        assert_eq!(
+            store.register_class_declaration("myClass", second_tracing),
+            Some(first_tracing)
+        );
+    }
+
+    #[test]
+    fn duplicated_animation_declaration_must_return_previous_tracing() {
+        let mut store = NenyrProcessStore::new();
+        let first_tracing = NenyrErrorTracing::new(None, None, None, 2, 22, 30);
+        let second_tracing = NenyrErrorTracing::new(None, None, None, 8, 22, 90);
+
+        assert_eq!(
+            store.register_animation_declaration("giddyRespond", first_tracing.clone()),
+            None
+        );
+        assert_eq!(
+            store.register_class_declaration("giddyRespond", second_tracing.clone()),
+            None
+        );
+        assert_eq!(
+            store.register_animation_declaration("giddyRespond", second_tracing),
+            Some(first_tracing)
+        );
+    }
+
+    #[test]
+    fn declaration_spans_must_start_at_declaration() {
+        let mut store = NenyrProcessStore::new();
+
+        store.set_declaration_start(4);
+        store.register_class_span("myClass", 40);
+        store.set_declaration_start(42);
+        store.register_animation_span("myClass", 90);
+
+        assert_eq!(store.get_class_span("myClass"), Some((4, 40)));
+        assert_eq!(store.get_animation_span("myClass"), Some((42, 90)));
+        assert_eq!(store.get_class_span("otherClass"), None);
+    }
 }
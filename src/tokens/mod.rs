@@ -1,3 +1,6 @@
+use std::mem::{discriminant, Discriminant};
+
+/// The tokens produced by the Nenyr lexer.
 #[derive(Debug, PartialEq, Clone)]
 pub enum NenyrTokens {
     // Nenyr keywords
@@ -16,10 +19,13 @@ pub enum NenyrTokens {
     Themes,
     Aliases,
     Variables,
+    Const,
+    Mixin,
     Class,
 
     // Import pattern
     Import,
+    Layer,
 
     // Breakpoints pattern
     MobileFirst,
@@ -30,6 +36,8 @@ pub enum NenyrTokens {
     Dark,
 
     // Animation pattern
+    /// The `Keyframes` method, accepted as a synonym of the `Animation` method.
+    Keyframes,
     Fraction,
     Progressive,
     From,
@@ -38,8 +46,15 @@ pub enum NenyrTokens {
 
     // Value collectors
     //Unknown(char),
-    StringLiteral(String),
+    /// A string literal, along with the quote character (`'` or `"`) that delimited it.
+    StringLiteral {
+        value: String,
+        quote: char,
+    },
+    /// A backtick-delimited raw string, whose contents are taken verbatim.
+    RawString(String),
     Number(f64),
+    Dimension(f64, String),
     Identifier(String),
 
     // Syntax tokens
@@ -50,8 +65,14 @@ pub enum NenyrTokens {
     SquareBracketOpen,
     SquareBracketClose,
     Comma,
+    /// A `;`, only produced when semicolons are allowed as property separators.
+    Semicolon,
     Colon,
-    EndOfLine,
+    Plus,
+    Minus,
+    Asterisk,
+    Slash,
+    EndOfFile,
     StartOfFile,
     True,
     False,
@@ -90,6 +111,13 @@ pub enum NenyrTokens {
     Root,
     Empty,
     PanoramicViewer,
+    Supports,
+    Container,
+    Not,
+    NthChild,
+    Attribute,
+    Group,
+    Include,
 
     // Nenyr Properties
     All,
@@ -347,4 +375,285 @@ pub enum NenyrTokens {
     ScrollbarColor,
     ScrollbarWidth,
     ScrollbarGutter,
+
+    // Logical properties
+    MarginInline,
+    MarginInlineStart,
+    MarginInlineEnd,
+    MarginBlock,
+    MarginBlockStart,
+    MarginBlockEnd,
+    PaddingInline,
+    PaddingInlineStart,
+    PaddingInlineEnd,
+    PaddingBlock,
+    PaddingBlockStart,
+    PaddingBlockEnd,
+    Inset,
+    InsetInline,
+    InsetInlineStart,
+    InsetInlineEnd,
+    InsetBlock,
+    InsetBlockStart,
+    InsetBlockEnd,
+    BorderInline,
+    BorderInlineStart,
+    BorderInlineEnd,
+    BorderBlock,
+    BorderBlockStart,
+    BorderBlockEnd,
+    InlineSize,
+    BlockSize,
+    MinInlineSize,
+    MaxInlineSize,
+    MinBlockSize,
+    MaxBlockSize,
+
+    // Vendor-prefixed properties
+    WebkitBackdropFilter,
+    WebkitAppearance,
+    MozAppearance,
+    WebkitUserSelect,
+    MozUserSelect,
+    WebkitBackgroundClip,
+    WebkitTextFillColor,
+    WebkitTextStroke,
+    WebkitTextStrokeColor,
+    WebkitTextStrokeWidth,
+    WebkitLineClamp,
+    WebkitBoxOrient,
+    WebkitTapHighlightColor,
+    WebkitFontSmoothing,
+    MozOsxFontSmoothing,
+    WebkitMaskImage,
+    WebkitOverflowScrolling,
+    MozTabSize,
+}
+
+/// The semantic categories of the Nenyr tokens, used by editors to highlight the source.
+///
+/// # Variants
+/// - `Keyword`: Nenyr keywords, methods, patterns and the `true`/`false` literals.
+/// - `Property`: Nenyr properties, such as `backgroundColor`.
+/// - `String`: String literals.
+/// - `Number`: Numeric literals, with or without a unit.
+/// - `Punctuation`: Delimiters, separators and operators, such as `(`, `{`, `,`, `:` and `*`.
+/// - `Identifier`: User-defined names, such as aliases, variables and breakpoints.
+/// - `Marker`: Tokens that do not represent any text in the source, like the start of the file
+///   and the end of the input.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TokenCategory {
+    Keyword,
+    Property,
+    String,
+    Number,
+    Punctuation,
+    Identifier,
+    Marker,
+}
+
+/// Classifies a Nenyr token into its semantic `TokenCategory`.
+///
+/// The lexer produces the `Animation` token for both the `Animation` method and the
+/// `animation` property. As the token alone cannot tell them apart, it is classified
+/// as a keyword.
+///
+/// # Parameters
+/// - `token`: The token to be classified.
+///
+/// # Returns
+/// The `TokenCategory` of the given token.
+pub fn token_category(token: &NenyrTokens) -> TokenCategory {
+    match token {
+        NenyrTokens::StringLiteral { .. } | NenyrTokens::RawString(_) => TokenCategory::String,
+        NenyrTokens::Number(_) | NenyrTokens::Dimension(_, _) => TokenCategory::Number,
+        NenyrTokens::Identifier(_) => TokenCategory::Identifier,
+        NenyrTokens::ParenthesisOpen
+        | NenyrTokens::ParenthesisClose
+        | NenyrTokens::CurlyBracketOpen
+        | NenyrTokens::CurlyBracketClose
+        | NenyrTokens::SquareBracketOpen
+        | NenyrTokens::SquareBracketClose
+        | NenyrTokens::Comma
+        | NenyrTokens::Semicolon
+        | NenyrTokens::Colon
+        | NenyrTokens::Plus
+        | NenyrTokens::Minus
+        | NenyrTokens::Asterisk
+        | NenyrTokens::Slash => TokenCategory::Punctuation,
+        NenyrTokens::EndOfFile | NenyrTokens::StartOfFile => TokenCategory::Marker,
+        NenyrTokens::Construct
+        | NenyrTokens::Central
+        | NenyrTokens::Layout
+        | NenyrTokens::Module
+        | NenyrTokens::Declare
+        | NenyrTokens::Extending
+        | NenyrTokens::Deriving
+        | NenyrTokens::Imports
+        | NenyrTokens::Typefaces
+        | NenyrTokens::Breakpoints
+        | NenyrTokens::Themes
+        | NenyrTokens::Aliases
+        | NenyrTokens::Variables
+        | NenyrTokens::Const
+        | NenyrTokens::Mixin
+        | NenyrTokens::Class
+        | NenyrTokens::Import
+        | NenyrTokens::Layer
+        | NenyrTokens::MobileFirst
+        | NenyrTokens::DesktopFirst
+        | NenyrTokens::Light
+        | NenyrTokens::Dark
+        | NenyrTokens::Animation
+        | NenyrTokens::Keyframes
+        | NenyrTokens::Fraction
+        | NenyrTokens::Progressive
+        | NenyrTokens::From
+        | NenyrTokens::Halfway
+        | NenyrTokens::To
+        | NenyrTokens::True
+        | NenyrTokens::False
+        | NenyrTokens::Important
+        | NenyrTokens::Stylesheet
+        | NenyrTokens::Hover
+        | NenyrTokens::Active
+        | NenyrTokens::Focus
+        | NenyrTokens::FirstChild
+        | NenyrTokens::LastChild
+        | NenyrTokens::FirstOfType
+        | NenyrTokens::LastOfType
+        | NenyrTokens::OnlyChild
+        | NenyrTokens::OnlyOfType
+        | NenyrTokens::Target
+        | NenyrTokens::Visited
+        | NenyrTokens::Checked
+        | NenyrTokens::Disabled
+        | NenyrTokens::Enabled
+        | NenyrTokens::ReadOnly
+        | NenyrTokens::ReadWrite
+        | NenyrTokens::PlaceholderShown
+        | NenyrTokens::Valid
+        | NenyrTokens::Invalid
+        | NenyrTokens::Required
+        | NenyrTokens::Optional
+        | NenyrTokens::Fullscreen
+        | NenyrTokens::FocusWithin
+        | NenyrTokens::FirstLine
+        | NenyrTokens::FirstLetter
+        | NenyrTokens::Before
+        | NenyrTokens::After
+        | NenyrTokens::OutOfRange
+        | NenyrTokens::Root
+        | NenyrTokens::Empty
+        | NenyrTokens::PanoramicViewer
+        | NenyrTokens::Supports
+        | NenyrTokens::Container
+        | NenyrTokens::Not
+        | NenyrTokens::NthChild
+        | NenyrTokens::Attribute
+        | NenyrTokens::Group
+        | NenyrTokens::Include => TokenCategory::Keyword,
+        // Every remaining variant is a Nenyr property.
+        _ => TokenCategory::Property,
+    }
+}
+
+impl NenyrTokens {
+    /// Checks whether the token is a reserved Nenyr keyword.
+    ///
+    /// The keywords are the tokens classified as `TokenCategory::Keyword`: the Nenyr keywords,
+    /// methods and patterns, such as `Construct`, `Declare`, `Class` and `Hover`, along with
+    /// the `true`/`false` literals. Properties, identifiers, literals, punctuation and markers
+    /// are not keywords.
+    ///
+    /// # Returns
+    /// `true` if the token is a keyword, `false` otherwise.
+    pub fn is_keyword(&self) -> bool {
+        token_category(self) == TokenCategory::Keyword
+    }
+}
+
+/// The kind of a Nenyr token, regardless of the value it carries, so that every
+/// `Identifier` token shares the same kind whatever its name is.
+pub type NenyrTokenKind = Discriminant<NenyrTokens>;
+
+/// Retrieves the `NenyrTokenKind` of a Nenyr token.
+///
+/// # Parameters
+/// - `token`: The token whose kind is retrieved.
+///
+/// # Returns
+/// The `NenyrTokenKind` of the given token.
+pub fn token_kind(token: &NenyrTokens) -> NenyrTokenKind {
+    discriminant(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{token_category, token_kind, NenyrTokens, TokenCategory};
+
+    #[test]
+    fn tokens_are_classified_by_category() {
+        let classified_tokens = vec![
+            (NenyrTokens::Construct, TokenCategory::Keyword),
+            (NenyrTokens::Hover, TokenCategory::Keyword),
+            (NenyrTokens::True, TokenCategory::Keyword),
+            (NenyrTokens::BackgroundColor, TokenCategory::Property),
+            (NenyrTokens::ScrollbarGutter, TokenCategory::Property),
+            (
+                NenyrTokens::StringLiteral {
+                    value: "blue".to_string(),
+                    quote: '\'',
+                },
+                TokenCategory::String,
+            ),
+            (NenyrTokens::Number(10.5), TokenCategory::Number),
+            (
+                NenyrTokens::Dimension(8.0, "px".to_string()),
+                TokenCategory::Number,
+            ),
+            (NenyrTokens::Comma, TokenCategory::Punctuation),
+            (NenyrTokens::Semicolon, TokenCategory::Punctuation),
+            (NenyrTokens::CurlyBracketOpen, TokenCategory::Punctuation),
+            (
+                NenyrTokens::Identifier("myColor".to_string()),
+                TokenCategory::Identifier,
+            ),
+            (NenyrTokens::EndOfFile, TokenCategory::Marker),
+        ];
+
+        for (token, category) in classified_tokens {
+            assert_eq!(
+                token_category(&token),
+                category,
+                "Token `{:?}` should be classified as `{:?}`.",
+                token,
+                category
+            );
+        }
+    }
+
+    #[test]
+    fn keywords_are_told_apart_from_other_tokens() {
+        assert!(NenyrTokens::Construct.is_keyword());
+        assert!(NenyrTokens::Hover.is_keyword());
+        assert!(NenyrTokens::PanoramicViewer.is_keyword());
+        assert!(!NenyrTokens::Identifier("myColor".to_string()).is_keyword());
+        assert!(!NenyrTokens::Comma.is_keyword());
+        assert!(!NenyrTokens::Number(10.0).is_keyword());
+        assert!(!NenyrTokens::BackgroundColor.is_keyword());
+        assert!(!NenyrTokens::EndOfFile.is_keyword());
+    }
+
+    #[test]
+    fn tokens_carrying_different_values_share_the_same_kind() {
+        assert_eq!(
+            token_kind(&NenyrTokens::Identifier("primary".to_string())),
+            token_kind(&NenyrTokens::Identifier("secondary".to_string()))
+        );
+        assert_ne!(
+            token_kind(&NenyrTokens::Comma),
+            token_kind(&NenyrTokens::Colon)
+        );
+    }
 }
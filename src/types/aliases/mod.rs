@@ -1,4 +1,5 @@
 use indexmap::IndexMap;
+use std::collections::HashSet;
 
 /// `NenyrAliases` is a struct designed to store alias mappings for commonly used Nenyr properties.
 /// It functions as a collection of key-value pairs where each key represents an alias identifier,
@@ -6,6 +7,7 @@ use indexmap::IndexMap;
 /// the Nenyr context of Galadriel CSS to simplify referencing complex or frequently used properties,
 /// promoting consistency and reducing redundancy in style declarations.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrAliases {
     /// A map that stores alias identifiers and their corresponding Nenyr properties, maintaining insertion order.
     pub values: IndexMap<String, String>,
@@ -34,6 +36,31 @@ impl NenyrAliases {
     pub(crate) fn add_alias(&mut self, identifier: String, value: String) {
         self.values.insert(identifier, value);
     }
+
+    /// Retrieves the aliases that are not referenced by any of the given properties.
+    ///
+    /// Alias usages are stored in the properties maps as `nickname;`-prefixed entries, so
+    /// every other property is ignored.
+    ///
+    /// # Parameters
+    /// - `properties`: The properties declared by the classes and animations of a context.
+    ///
+    /// # Returns
+    /// - A `Vec<String>` with the unused alias identifiers, in declaration order.
+    pub(crate) fn unused_aliases<'a>(
+        &self,
+        properties: impl Iterator<Item = &'a str>,
+    ) -> Vec<String> {
+        let used_aliases: HashSet<&str> = properties
+            .filter_map(|property| property.strip_prefix("nickname;"))
+            .collect();
+
+        self.values
+            .keys()
+            .filter(|identifier| !used_aliases.contains(identifier.as_str()))
+            .cloned()
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -57,4 +84,19 @@ mod tests {
         aliases.add_alias("dp".to_string(), "display".to_string());
         assert_eq!(aliases.values.get("dp"), Some(&"display".to_string()));
     }
+
+    #[test]
+    fn unused_aliases_must_ignore_referenced_ones() {
+        let mut aliases = NenyrAliases::new();
+
+        aliases.add_alias("bgd".to_string(), "background".to_string());
+        aliases.add_alias("hgt".to_string(), "height".to_string());
+
+        let properties = vec!["nickname;bgd", "height", "nickname;unknown"];
+
+        assert_eq!(
+            aliases.unused_aliases(properties.into_iter()),
+            vec!["hgt".to_string()]
+        );
+    }
 }
@@ -1,5 +1,10 @@
 use indexmap::IndexMap;
+use std::collections::HashSet;
 use std::ops::Add;
+use std::sync::Arc;
+
+use super::aliases::NenyrAliases;
+use super::class::{can_rename, rename_key, replace_identifier, NenyrStyleClass};
 
 /// Represents the kind of animation in Nenyr.
 ///
@@ -11,6 +16,7 @@ use std::ops::Add;
 /// - `Transitive`: A smooth transitioning animation.
 /// - `None`: Indicates that no animation kind has been applied.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NenyrAnimationKind {
     Fraction,
     Progressive,
@@ -29,6 +35,7 @@ pub enum NenyrAnimationKind {
 /// - `Halfway`: A keyframe set at the midpoint of the animation.
 /// - `To`: The final keyframe at the end of the animation.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NenyrSubAnimationKind {
     Fraction,
     Progressive,
@@ -37,6 +44,45 @@ pub enum NenyrSubAnimationKind {
     To,
 }
 
+impl NenyrSubAnimationKind {
+    /// Maps a keyframe of this sub-kind to its CSS `@keyframes` selector.
+    ///
+    /// - `From`, `Halfway` and `To` map to `0%`, `50%` and `100%` respectively.
+    /// - `Fraction` uses its own stops, joined into a selector list (e.g. `30%, 60%`).
+    /// - `Progressive` distributes its steps evenly between `0%` and `100%` based on the
+    ///   keyframe position. A single progressive step covers the whole animation and maps to `100%`.
+    ///
+    /// ### Parameters:
+    /// - `stops`: The stops of the keyframe, relevant only for `Fraction` keyframes.
+    /// - `index`: The position of the keyframe among the progressive steps.
+    /// - `total`: The number of progressive steps in the animation (its `progressive_count`).
+    ///
+    /// ### Returns:
+    /// The CSS keyframe selector as a `String`.
+    pub fn keyframe_selector(&self, stops: Option<&[f64]>, index: usize, total: usize) -> String {
+        match self {
+            NenyrSubAnimationKind::Fraction => stops
+                .unwrap_or(&[0.0])
+                .iter()
+                .map(|stop| format!("{}%", stop))
+                .collect::<Vec<_>>()
+                .join(", "),
+            NenyrSubAnimationKind::Progressive => {
+                if total <= 1 {
+                    return "100%".to_string();
+                }
+
+                let percentage = index as f64 * 100.0 / (total - 1) as f64;
+
+                format!("{}%", (percentage * 100.0).round() / 100.0)
+            }
+            NenyrSubAnimationKind::From => "0%".to_string(),
+            NenyrSubAnimationKind::Halfway => "50%".to_string(),
+            NenyrSubAnimationKind::To => "100%".to_string(),
+        }
+    }
+}
+
 /// Describes the keyframe structure for a Nenyr animation.
 ///
 /// `NenyrKeyframe` is an enum that allows for different types of keyframe
@@ -48,17 +94,46 @@ pub enum NenyrSubAnimationKind {
 /// - `Halfway`: The midpoint in the animation.
 /// - `To`: The ending point in the animation.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NenyrKeyframe {
     Fraction {
         /// Vector of fractional stops for the keyframe (e.g., 0.0 to 1.0 representing 0% to 100%).
         stops: Vec<f64>,
+        /// Whether each stop was written as a percentage (e.g., `30%`) rather than a bare number,
+        /// in the order of `stops`, which is kept so the keyframe can be written back as it was
+        /// declared.
+        is_percentage: Vec<bool>,
         /// CSS properties for the keyframe at each stop.
-        properties: IndexMap<String, String>,
+        properties: IndexMap<String, Arc<str>>,
     },
-    Progressive(IndexMap<String, String>),
-    From(IndexMap<String, String>),
-    Halfway(IndexMap<String, String>),
-    To(IndexMap<String, String>),
+    Progressive(IndexMap<String, Arc<str>>),
+    From(IndexMap<String, Arc<str>>),
+    Halfway(IndexMap<String, Arc<str>>),
+    To(IndexMap<String, Arc<str>>),
+}
+
+impl NenyrKeyframe {
+    /// Returns the CSS properties declared in the keyframe, regardless of its type.
+    pub fn properties(&self) -> &IndexMap<String, Arc<str>> {
+        match self {
+            NenyrKeyframe::Fraction { properties, .. } => properties,
+            NenyrKeyframe::Progressive(properties)
+            | NenyrKeyframe::From(properties)
+            | NenyrKeyframe::Halfway(properties)
+            | NenyrKeyframe::To(properties) => properties,
+        }
+    }
+
+    /// Returns the CSS properties declared in the keyframe mutably, regardless of its type.
+    pub(crate) fn properties_mut(&mut self) -> &mut IndexMap<String, Arc<str>> {
+        match self {
+            NenyrKeyframe::Fraction { properties, .. } => properties,
+            NenyrKeyframe::Progressive(properties)
+            | NenyrKeyframe::From(properties)
+            | NenyrKeyframe::Halfway(properties)
+            | NenyrKeyframe::To(properties) => properties,
+        }
+    }
 }
 
 /// The main struct representing an animation in Nenyr.
@@ -69,12 +144,17 @@ pub enum NenyrKeyframe {
 ///
 /// ### Fields:
 /// - `animation_name`: The name of the animation.
+/// - `is_keyframes_declaration`: Whether the animation was declared through the `Keyframes`
+///   synonym instead of the `Animation` method, so it can be written back the same way.
 /// - `keyframe`: A vector of `NenyrKeyframe`s representing the various keyframes in the animation.
 /// - `progressive_count`: An optional counter to track progressive keyframes.
 /// - `kind`: The kind of animation, if any, applied to this animation instance.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrAnimation {
     pub animation_name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub is_keyframes_declaration: bool,
     pub kind: Option<NenyrAnimationKind>,
     pub progressive_count: Option<i64>,
     pub keyframe: Vec<NenyrKeyframe>,
@@ -95,6 +175,7 @@ impl NenyrAnimation {
         Self {
             kind: None,
             animation_name,
+            is_keyframes_declaration: false,
             keyframe: Vec::new(),
             progressive_count: None,
         }
@@ -156,18 +237,25 @@ impl NenyrAnimation {
     /// ### Parameters:
     /// - `sub_kind`: The sub-animation kind (`NenyrSubAnimationKind`) determining the keyframe type.
     /// - `stops`: An optional vector of fractional stops, relevant only for `Fraction` keyframes.
-    /// - `keyframe`: A map of CSS properties (`IndexMap<String, String>`) that define the keyframe.
+    /// - `is_percentage`: Whether each stop was written as a percentage, relevant only for
+    ///   `Fraction` keyframes.
+    /// - `keyframe`: A map of CSS properties (`IndexMap<String, Arc<str>>`) that define the keyframe.
     pub(crate) fn add_animation_keyframe(
         &mut self,
         sub_kind: &NenyrSubAnimationKind,
         stops: &Option<Vec<f64>>,
-        keyframe: IndexMap<String, String>,
+        is_percentage: &[bool],
+        keyframe: IndexMap<String, Arc<str>>,
     ) {
         match sub_kind {
             NenyrSubAnimationKind::Fraction => {
-                let stops: Vec<f64> = stops.clone().unwrap_or(vec![0.0]);
+                let (stops, is_percentage) = match stops {
+                    Some(stops) => (stops.clone(), is_percentage.to_vec()),
+                    None => (vec![0.0], vec![false]),
+                };
                 let fraction = NenyrKeyframe::Fraction {
                     stops,
+                    is_percentage,
                     properties: keyframe,
                 };
 
@@ -187,6 +275,104 @@ impl NenyrAnimation {
             }
         }
     }
+
+    /// Iterates over every property-value pair declared across the keyframes of the animation.
+    ///
+    /// ### Returns:
+    /// An iterator of `(property, value)` pairs, in keyframe declaration order.
+    pub fn declarations(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.keyframe.iter().flat_map(|keyframe| {
+            keyframe
+                .properties()
+                .iter()
+                .map(|(property, value)| (property.as_str(), value.as_ref()))
+        })
+    }
+}
+
+/// Retrieves the names of the animations that are not referenced by any of the given declarations.
+///
+/// Animations are referenced by name inside `animation` and `animation-name` values, which may
+/// also hold durations, timing functions or several comma-separated animations, so each value
+/// is split into identifiers before being matched against the animation names. Since an alias
+/// may stand for one of those properties, the values assigned to aliases are scanned as well.
+///
+/// # Parameters
+/// - `animations`: The animations declared in a context.
+/// - `declarations`: The `(property, value)` pairs declared by the classes of the context.
+///
+/// # Returns
+/// - A `Vec<String>` with the unreferenced animation names, in declaration order.
+pub(crate) fn unused_animations<'a>(
+    animations: &IndexMap<String, NenyrAnimation>,
+    declarations: impl Iterator<Item = (&'a str, &'a str)>,
+) -> Vec<String> {
+    let referenced_names: HashSet<&str> = declarations
+        .filter(|(property, _)| {
+            matches!(*property, "animation" | "animation-name") || property.starts_with("nickname;")
+        })
+        .flat_map(|(_, value)| {
+            value.split(|char: char| !(char.is_ascii_alphanumeric() || char == '-' || char == '_'))
+        })
+        .collect();
+
+    animations
+        .keys()
+        .filter(|animation_name| !referenced_names.contains(animation_name.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Renames an animation, updating the `animation` and `animation-name` values of the classes
+/// referencing it, as well as the values of the aliases resolving to these properties.
+/// Aliases that are not declared in the given `aliases` are left untouched.
+///
+/// Nothing is changed if `from` is not declared, or if `to` is not a valid identifier or is
+/// already declared.
+///
+/// # Returns
+/// - The number of property values updated.
+pub(crate) fn rename_animation(
+    animations: &mut IndexMap<String, NenyrAnimation>,
+    classes: Option<&mut IndexMap<String, NenyrStyleClass>>,
+    aliases: Option<&NenyrAliases>,
+    from: &str,
+    to: &str,
+) -> usize {
+    if !can_rename(animations, from, to) {
+        return 0;
+    }
+
+    rename_key(animations, from, to);
+
+    if let Some(animation) = animations.get_mut(to) {
+        animation.animation_name = to.to_string();
+    }
+
+    let mut updated_references = 0;
+
+    for style_class in classes.into_iter().flat_map(|classes| classes.values_mut()) {
+        for (property, value) in style_class.declarations_mut() {
+            let property = match property.strip_prefix("nickname;") {
+                Some(alias) => match aliases.and_then(|aliases| aliases.values.get(alias)) {
+                    Some(property) => property.as_str(),
+                    None => continue,
+                },
+                None => property.as_str(),
+            };
+
+            if !matches!(property, "animation" | "animation-name") {
+                continue;
+            }
+
+            if let Some(replaced) = replace_identifier(value, from, to) {
+                *value = Arc::from(replaced);
+                updated_references += 1;
+            }
+        }
+    }
+
+    updated_references
 }
 
 #[cfg(test)]
@@ -260,12 +446,22 @@ mod tests {
         let mut animation = NenyrAnimation::new("fade".to_string());
         let mut properties = IndexMap::new();
 
-        properties.insert("opacity".to_string(), "0".to_string());
+        properties.insert("opacity".to_string(), "0".into());
 
-        animation.add_animation_keyframe(&NenyrSubAnimationKind::From, &None, properties.clone());
+        animation.add_animation_keyframe(
+            &NenyrSubAnimationKind::From,
+            &None,
+            &[],
+            properties.clone(),
+        );
         assert!(matches!(animation.keyframe[0], NenyrKeyframe::From(_)));
 
-        animation.add_animation_keyframe(&NenyrSubAnimationKind::To, &None, properties.clone());
+        animation.add_animation_keyframe(
+            &NenyrSubAnimationKind::To,
+            &None,
+            &[],
+            properties.clone(),
+        );
         assert!(matches!(animation.keyframe[1], NenyrKeyframe::To(_)));
 
         let stops = vec![0.0, 0.5, 1.0];
@@ -273,6 +469,7 @@ mod tests {
         animation.add_animation_keyframe(
             &NenyrSubAnimationKind::Fraction,
             &Some(stops.clone()),
+            &[false, false, false],
             properties.clone(),
         );
 
@@ -282,4 +479,34 @@ mod tests {
             panic!("NenyrKeyframe::Fraction not added correctly");
         }
     }
+
+    #[test]
+    fn test_keyframe_selector() {
+        assert_eq!(
+            NenyrSubAnimationKind::From.keyframe_selector(None, 0, 0),
+            "0%"
+        );
+        assert_eq!(
+            NenyrSubAnimationKind::Halfway.keyframe_selector(None, 0, 0),
+            "50%"
+        );
+        assert_eq!(
+            NenyrSubAnimationKind::To.keyframe_selector(None, 0, 0),
+            "100%"
+        );
+        assert_eq!(
+            NenyrSubAnimationKind::Fraction.keyframe_selector(Some(&[30.0, 60.5]), 0, 0),
+            "30%, 60.5%"
+        );
+
+        let progressive_selectors: Vec<String> = (0..3)
+            .map(|index| NenyrSubAnimationKind::Progressive.keyframe_selector(None, index, 3))
+            .collect();
+
+        assert_eq!(progressive_selectors, vec!["0%", "50%", "100%"]);
+        assert_eq!(
+            NenyrSubAnimationKind::Progressive.keyframe_selector(None, 1, 4),
+            "33.33%"
+        );
+    }
 }
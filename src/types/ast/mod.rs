@@ -1,4 +1,22 @@
-use super::{central::CentralContext, layout::LayoutContext, module::ModuleContext};
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+use super::{
+    aliases::NenyrAliases, animations::NenyrAnimation, central::CentralContext,
+    class::NenyrStyleClass, declaration::DeclarationCounts, layout::LayoutContext,
+    module::ModuleContext,
+};
+use crate::{
+    error::{NenyrError, NenyrErrorKind, NenyrErrorTracing},
+    NenyrParser, NenyrResult,
+};
+
+/// The `animations` and `classes` maps of a context, handed from one parsing to the next one
+/// by `NenyrParser::parse_into`.
+pub(crate) type NenyrReusableMaps = (
+    Option<IndexMap<String, NenyrAnimation>>,
+    Option<IndexMap<String, NenyrStyleClass>>,
+);
 
 /// An enumeration representing the Abstract Syntax Tree (AST) for the Nenyr framework.
 ///
@@ -14,6 +32,7 @@ use super::{central::CentralContext, layout::LayoutContext, module::ModuleContex
 /// - `ModuleContext`: Represents the context for a module within the Nenyr framework, which can
 ///   include aliases, variables, animations, and style classes specific to that module.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NenyrAst {
     /// Represents the central context of the Nenyr framework.
     ///
@@ -37,3 +56,548 @@ pub enum NenyrAst {
     /// shared or extended across different modules.
     ModuleContext(ModuleContext),
 }
+
+/// The kind of context held by a `NenyrAst`, without its data.
+///
+/// Useful for tools that only need to switch on the type of the parsed context.
+///
+/// # Variants
+/// - `Central`: The central context, declared by `Construct Central`.
+/// - `Layout`: A layout context, declared by `Construct Layout('...')`.
+/// - `Module`: A module context, declared by `Construct Module('...')`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NenyrContextKind {
+    Central,
+    Layout,
+    Module,
+}
+
+impl NenyrAst {
+    /// Retrieves the kind of the parsed context.
+    ///
+    /// # Returns
+    /// The `NenyrContextKind` matching the variant of the AST.
+    pub fn kind(&self) -> NenyrContextKind {
+        match self {
+            NenyrAst::CentralContext(_) => NenyrContextKind::Central,
+            NenyrAst::LayoutContext(_) => NenyrContextKind::Layout,
+            NenyrAst::ModuleContext(_) => NenyrContextKind::Module,
+        }
+    }
+
+    /// Retrieves the name of the parsed context.
+    ///
+    /// # Returns
+    /// - `None` for the central context, which has no name.
+    /// - `Some(&str)` with the `layout_name` or the `module_name` for layout and module contexts.
+    pub fn context_name(&self) -> Option<&str> {
+        match self {
+            NenyrAst::CentralContext(_) => None,
+            NenyrAst::LayoutContext(layout_context) => Some(&layout_context.layout_name),
+            NenyrAst::ModuleContext(module_context) => Some(&module_context.module_name),
+        }
+    }
+
+    /// Counts the declarations of each kind found in the parsed context.
+    ///
+    /// # Returns
+    /// The `DeclarationCounts` of the central, layout or module context.
+    pub fn declaration_counts(&self) -> DeclarationCounts {
+        match self {
+            NenyrAst::CentralContext(context) => context.declaration_counts(),
+            NenyrAst::LayoutContext(context) => context.declaration_counts(),
+            NenyrAst::ModuleContext(context) => context.declaration_counts(),
+        }
+    }
+
+    /// Removes every declaration of the AST, keeping the kind and the name of its context.
+    ///
+    /// The `animations` and `classes` maps are emptied and returned instead of being dropped,
+    /// keeping their capacity so that the next context assembly can refill them.
+    ///
+    /// # Returns
+    /// The emptied `animations` and `classes` maps, or `None` for the ones never created.
+    pub(crate) fn clear(&mut self) -> NenyrReusableMaps {
+        let (mut animations, mut classes) = match self {
+            NenyrAst::CentralContext(context) => {
+                context.imports = None;
+                context.typefaces = None;
+                context.breakpoints = None;
+                context.aliases = None;
+                context.variables = None;
+                context.consts = None;
+                context.themes = None;
+                context.mixins = None;
+
+                (context.animations.take(), context.classes.take())
+            }
+            NenyrAst::LayoutContext(context) => {
+                context.aliases = None;
+                context.variables = None;
+                context.themes = None;
+                context.mixins = None;
+
+                (context.animations.take(), context.classes.take())
+            }
+            NenyrAst::ModuleContext(context) => {
+                context.aliases = None;
+                context.variables = None;
+                context.mixins = None;
+
+                (context.animations.take(), context.classes.take())
+            }
+        };
+
+        animations.iter_mut().for_each(IndexMap::clear);
+        classes.iter_mut().for_each(IndexMap::clear);
+
+        (animations, classes)
+    }
+
+    /// Drops the `animations` and `classes` maps left empty by a context assembly that
+    /// reused the maps of a previous AST, so that the AST matches a freshly parsed one.
+    pub(crate) fn drop_empty_maps(&mut self) {
+        let (animations, classes) = match self {
+            NenyrAst::CentralContext(context) => (&mut context.animations, &mut context.classes),
+            NenyrAst::LayoutContext(context) => (&mut context.animations, &mut context.classes),
+            NenyrAst::ModuleContext(context) => (&mut context.animations, &mut context.classes),
+        };
+
+        if animations.as_ref().is_some_and(IndexMap::is_empty) {
+            *animations = None;
+        }
+
+        if classes.as_ref().is_some_and(IndexMap::is_empty) {
+            *classes = None;
+        }
+    }
+
+    /// Replaces every alias used as a property by the CSS property it stands for, turning
+    /// the `nickname;`-prefixed entries of the mixins, classes and animations into plain
+    /// properties.
+    ///
+    /// Only the aliases declared by the context itself are looked up. Use
+    /// `resolve_aliases_with` to also resolve the aliases declared by other contexts.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` of kind `ValidationError` if an alias is not declared, in
+    /// which case the AST is left unchanged.
+    pub fn resolve_aliases(&mut self) -> NenyrResult<()> {
+        self.resolve_aliases_with(None)
+    }
+
+    /// Replaces every alias used as a property by the CSS property it stands for, looking
+    /// up the aliases declared by the context first and the given global aliases, such as
+    /// the ones of the central context, afterwards.
+    ///
+    /// When an alias resolves to a property already declared in the same block, the value
+    /// declared last is kept, at the position of the first declaration.
+    ///
+    /// # Parameters
+    /// - `global_aliases`: The aliases resolving the properties not found in the context.
+    ///
+    /// # Errors
+    /// Returns a `NenyrError` of kind `ValidationError` if an alias is declared neither in
+    /// the context nor in the global aliases, in which case the AST is left unchanged.
+    pub fn resolve_aliases_with(
+        &mut self,
+        global_aliases: Option<&NenyrAliases>,
+    ) -> NenyrResult<()> {
+        let context_name = self.context_name().map(|name| name.to_string());
+        let (aliases, mixins, animations, classes) = match self {
+            NenyrAst::CentralContext(context) => (
+                &context.aliases,
+                &mut context.mixins,
+                &mut context.animations,
+                &mut context.classes,
+            ),
+            NenyrAst::LayoutContext(context) => (
+                &context.aliases,
+                &mut context.mixins,
+                &mut context.animations,
+                &mut context.classes,
+            ),
+            NenyrAst::ModuleContext(context) => (
+                &context.aliases,
+                &mut context.mixins,
+                &mut context.animations,
+                &mut context.classes,
+            ),
+        };
+        let lookup = |nickname: &str| {
+            [aliases.as_ref(), global_aliases]
+                .into_iter()
+                .flatten()
+                .find_map(|aliases| aliases.values.get(nickname))
+        };
+        let unresolved_error = |nickname: &str, owner: String| {
+            NenyrError::new(
+                Some(format!(
+                    "Declare the `{}` alias in the `Aliases` declaration of the context, or replace it with the CSS property it stands for.",
+                    nickname
+                )),
+                context_name.clone(),
+                "".to_string(),
+                format!(
+                    "The `{}` alias used in the {} could not be resolved, as it is not declared.",
+                    nickname, owner
+                ),
+                NenyrErrorKind::ValidationError,
+                NenyrErrorTracing::new(None, None, None, 0, 0, 0),
+            ).with_code("N0100")
+        };
+        let mut blocks = vec![];
+
+        for (mixin_name, properties) in mixins.iter_mut().flat_map(|mixins| &mut mixins.values) {
+            blocks.push(("mixin", mixin_name, properties));
+        }
+
+        for (class_name, style_class) in classes.iter_mut().flatten() {
+            for properties in style_class.properties_mut() {
+                blocks.push(("class", class_name, properties));
+            }
+        }
+
+        for (animation_name, animation) in animations.iter_mut().flatten() {
+            for keyframe in animation.keyframe.iter_mut() {
+                blocks.push(("animation", animation_name, keyframe.properties_mut()));
+            }
+        }
+
+        // Every block is resolved before any of them is replaced, so an unresolved alias
+        // leaves the whole AST untouched.
+        let resolved_blocks = blocks
+            .iter()
+            .map(|(kind, owner, properties)| {
+                resolve_alias_keys(properties, lookup).map_err(|nickname| {
+                    unresolved_error(&nickname, format!("`{}` {}", owner, kind))
+                })
+            })
+            .collect::<NenyrResult<Vec<_>>>()?;
+
+        for ((_, _, properties), resolved) in blocks.into_iter().zip(resolved_blocks) {
+            if let Some(resolved) = resolved {
+                *properties = resolved;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the name of the context variant, as written after the `Construct` keyword.
+    fn variant_name(&self) -> &'static str {
+        match self.kind() {
+            NenyrContextKind::Central => "Central",
+            NenyrContextKind::Layout => "Layout",
+            NenyrContextKind::Module => "Module",
+        }
+    }
+
+    /// Parses the given Nenyr code with a default `NenyrParser`.
+    fn parse_raw(raw_nenyr: &str) -> Result<Self, NenyrError> {
+        NenyrParser::new().parse(raw_nenyr.to_string(), "".to_string())
+    }
+
+    /// Builds the error returned when the parsed context does not match the requested one.
+    fn mismatched_context_error(self, expected: &str) -> NenyrError {
+        let found = self.variant_name();
+
+        NenyrError::new(
+            Some(format!(
+                "Make sure the Nenyr code declares a `Construct {}` context, or convert it into the `{}Context` type instead.",
+                expected, found
+            )),
+            self.context_name().map(|name| name.to_string()),
+            "".to_string(),
+            format!(
+                "Expected a {} context, but the parsed Nenyr code declares a {} context.",
+                expected, found
+            ),
+            NenyrErrorKind::MissingContext,
+            NenyrErrorTracing::new(None, None, None, 0, 0, 0),
+        ).with_code("N0101")
+    }
+}
+
+/// Builds a copy of a properties map whose `nickname;`-prefixed keys are replaced by the
+/// properties returned by `lookup`, keeping the order of the declarations.
+///
+/// Returns `None` when the map uses no alias, and the first alias that `lookup` could not
+/// resolve as the error. The given map is never modified.
+fn resolve_alias_keys<'a>(
+    properties: &IndexMap<String, Arc<str>>,
+    lookup: impl Fn(&str) -> Option<&'a String>,
+) -> Result<Option<IndexMap<String, Arc<str>>>, String> {
+    if !properties
+        .keys()
+        .any(|property| property.starts_with("nickname;"))
+    {
+        return Ok(None);
+    }
+
+    let mut resolved = IndexMap::with_capacity(properties.len());
+
+    for (property, value) in properties {
+        let property = match property.strip_prefix("nickname;") {
+            Some(nickname) => lookup(nickname).ok_or(nickname.to_string())?.clone(),
+            None => property.clone(),
+        };
+
+        resolved.insert(property, value.clone());
+    }
+
+    Ok(Some(resolved))
+}
+
+impl TryFrom<&str> for CentralContext {
+    type Error = NenyrError;
+
+    /// Parses the given Nenyr code into a `CentralContext`, failing if the code
+    /// cannot be parsed or declares a Layout or Module context.
+    fn try_from(raw_nenyr: &str) -> Result<Self, Self::Error> {
+        match NenyrAst::parse_raw(raw_nenyr)? {
+            NenyrAst::CentralContext(central_context) => Ok(central_context),
+            ast => Err(ast.mismatched_context_error("Central")),
+        }
+    }
+}
+
+impl TryFrom<&str> for LayoutContext {
+    type Error = NenyrError;
+
+    /// Parses the given Nenyr code into a `LayoutContext`, failing if the code
+    /// cannot be parsed or declares a Central or Module context.
+    fn try_from(raw_nenyr: &str) -> Result<Self, Self::Error> {
+        match NenyrAst::parse_raw(raw_nenyr)? {
+            NenyrAst::LayoutContext(layout_context) => Ok(layout_context),
+            ast => Err(ast.mismatched_context_error("Layout")),
+        }
+    }
+}
+
+impl TryFrom<&str> for ModuleContext {
+    type Error = NenyrError;
+
+    /// Parses the given Nenyr code into a `ModuleContext`, failing if the code
+    /// cannot be parsed or declares a Central or Layout context.
+    fn try_from(raw_nenyr: &str) -> Result<Self, Self::Error> {
+        match NenyrAst::parse_raw(raw_nenyr)? {
+            NenyrAst::ModuleContext(module_context) => Ok(module_context),
+            ast => Err(ast.mismatched_context_error("Module")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::NenyrErrorKind,
+        types::{central::CentralContext, module::ModuleContext},
+        NenyrParser,
+    };
+
+    use super::{NenyrAst, NenyrContextKind};
+
+    #[test]
+    fn kind_must_match_the_parsed_context() {
+        let contexts = vec![
+            ("Construct Central { }", NenyrContextKind::Central),
+            (
+                "Construct Layout('mainLayout') { }",
+                NenyrContextKind::Layout,
+            ),
+            (
+                "Construct Module('card') Extending('mainLayout') { }",
+                NenyrContextKind::Module,
+            ),
+        ];
+        let mut parser = NenyrParser::new();
+
+        for (raw_nenyr, kind) in contexts {
+            let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+
+            assert_eq!(ast.kind(), kind);
+        }
+    }
+
+    #[test]
+    fn context_name_must_match_the_parsed_context() {
+        let contexts = vec![
+            ("Construct Central { }", None),
+            ("Construct Layout('mainLayout') { }", Some("mainLayout")),
+            (
+                "Construct Module('card') Extending('mainLayout') { }",
+                Some("card"),
+            ),
+        ];
+        let mut parser = NenyrParser::new();
+
+        for (raw_nenyr, context_name) in contexts {
+            let ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+
+            assert_eq!(ast.context_name(), context_name);
+        }
+    }
+
+    #[test]
+    fn central_context_must_be_converted_from_str() {
+        let central_context = CentralContext::try_from(
+            "Construct Central { Declare Aliases({ bg: backgroundColor }) }",
+        )
+        .unwrap();
+
+        assert_eq!(central_context.unused_aliases(), vec!["bg".to_string()]);
+    }
+
+    #[test]
+    fn layout_context_must_not_be_converted_into_central_context() {
+        let error = CentralContext::try_from("Construct Layout('mainLayout') { }").unwrap_err();
+
+        assert_eq!(error.get_error_kind(), NenyrErrorKind::MissingContext);
+        assert_eq!(error.get_context_name(), Some("mainLayout".to_string()));
+        assert_eq!(
+            error.get_error_message(),
+            "Expected a Central context, but the parsed Nenyr code declares a Layout context."
+        );
+    }
+
+    #[test]
+    fn invalid_nenyr_code_must_return_the_parsing_error() {
+        let error = ModuleContext::try_from("Construct Module('card') {").unwrap_err();
+
+        assert_eq!(error.get_error_kind(), NenyrErrorKind::SyntaxError);
+    }
+
+    #[test]
+    fn aliases_must_be_resolved_into_css_properties() {
+        let raw_nenyr = "Construct Module('card') {
+    Declare Aliases({ bgd: background }),
+    Declare Class('miniatureTrogon') {
+        Stylesheet({ bgd: 'blue', display: 'block' }),
+        PanoramicViewer({ onDeskDesktop({ Hover({ bgd: 'red', pdg: '10px' }) }) })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let mut ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let global_aliases =
+            CentralContext::try_from("Construct Central { Declare Aliases({ pdg: padding }) }")
+                .unwrap()
+                .aliases;
+
+        ast.resolve_aliases_with(global_aliases.as_ref()).unwrap();
+
+        let NenyrAst::ModuleContext(module_context) = ast else {
+            panic!("Expected a module context.");
+        };
+        let style_class = &module_context.classes.unwrap()["miniatureTrogon"];
+
+        assert_eq!(
+            style_class.declarations().collect::<Vec<_>>(),
+            vec![
+                ("background", "blue"),
+                ("display", "block"),
+                ("background", "red"),
+                ("padding", "10px"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unresolvable_aliases_must_return_an_error() {
+        let raw_nenyr = "Construct Module('card') {
+    Declare Class('miniatureTrogon') { Stylesheet({ bgd: 'blue' }) }
+}";
+        let mut parser = NenyrParser::new();
+        let mut ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let error = ast.resolve_aliases().unwrap_err();
+
+        assert_eq!(error.get_error_kind(), NenyrErrorKind::ValidationError);
+        assert_eq!(error.get_context_name(), Some("card".to_string()));
+        assert_eq!(
+            error.get_error_message(),
+            "The `bgd` alias used in the `miniatureTrogon` class could not be resolved, as it is not declared."
+        );
+    }
+
+    #[test]
+    fn unresolvable_aliases_must_leave_the_ast_unchanged() {
+        let raw_nenyr = "Construct Module('card') {
+    Declare Aliases({ pdg: padding }),
+    Declare Class('title') { Stylesheet({ pdg: '10px' }) },
+    Declare Class('miniatureTrogon') {
+        Stylesheet({ color: 'red', bgd: 'blue', width: '1px' })
+    }
+}";
+        let mut parser = NenyrParser::new();
+        let mut ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+        let parsed_ast = ast.clone();
+
+        assert!(ast.resolve_aliases().is_err());
+        assert_eq!(ast, parsed_ast);
+    }
+
+    #[test]
+    fn mixin_aliases_must_be_resolved() {
+        let raw_nenyr = "Construct Layout('cards') {
+    Declare Aliases({ dp: display }),
+    Declare Mixin('flexCenter') { dp: 'flex', justifyContent: 'center' }
+}";
+        let mut parser = NenyrParser::new();
+        let mut ast = parser.parse(raw_nenyr.to_string(), "".to_string()).unwrap();
+
+        ast.resolve_aliases().unwrap();
+
+        let NenyrAst::LayoutContext(layout_context) = ast else {
+            panic!("Expected a layout context.");
+        };
+        let mixin = &layout_context.mixins.unwrap().values["flexCenter"];
+
+        assert_eq!(
+            mixin.keys().collect::<Vec<_>>(),
+            vec!["display", "justify-content"]
+        );
+    }
+
+    #[test]
+    fn contexts_differing_only_in_declaration_order_must_be_equal() {
+        let module_context = ModuleContext::try_from(
+            "Construct Module('card') {
+    Declare Class('title') { Stylesheet({ display: 'block', color: 'red' }) },
+    Declare Class('body') { Stylesheet({ padding: '10px' }) }
+}",
+        )
+        .unwrap();
+        let reordered_module_context = ModuleContext::try_from(
+            "Construct Module('card') {
+    Declare Class('body') { Stylesheet({ padding: '10px' }) },
+    Declare Class('title') { Stylesheet({ color: 'red', display: 'block' }) }
+}",
+        )
+        .unwrap();
+        let changed_module_context = ModuleContext::try_from(
+            "Construct Module('card') {
+    Declare Class('body') { Stylesheet({ padding: '12px' }) },
+    Declare Class('title') { Stylesheet({ color: 'red', display: 'block' }) }
+}",
+        )
+        .unwrap();
+
+        assert_ne!(
+            module_context
+                .classes
+                .as_ref()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            reordered_module_context
+                .classes
+                .as_ref()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(module_context, reordered_module_context);
+        assert_ne!(module_context, changed_module_context);
+    }
+}
@@ -6,6 +6,7 @@ use indexmap::IndexMap;
 /// approach to apply within the framework. Each variant represents
 /// a different method for scaling styles based on device screen sizes.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NenyrBreakpointKind {
     MobileFirst,
     DesktopFirst,
@@ -25,6 +26,7 @@ pub enum NenyrBreakpointKind {
 /// - `desktop_first`: An `Option<IndexMap<String, String>>` containing breakpoint
 ///   properties specific to desktop-first designs. Defaults to `None` until set.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrBreakpoints {
     pub mobile_first: Option<IndexMap<String, String>>,
     pub desktop_first: Option<IndexMap<String, String>>,
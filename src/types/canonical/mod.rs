@@ -0,0 +1,596 @@
+use indexmap::IndexMap;
+use std::{collections::BTreeMap, sync::Arc};
+
+use super::{
+    aliases::NenyrAliases,
+    animations::{NenyrAnimation, NenyrKeyframe},
+    ast::NenyrContextKind,
+    breakpoints::NenyrBreakpoints,
+    central::CentralContext,
+    class::NenyrStyleClass,
+    consts::NenyrConsts,
+    imports::NenyrImports,
+    layout::LayoutContext,
+    mixins::NenyrMixins,
+    module::ModuleContext,
+    themes::NenyrThemes,
+    typefaces::NenyrTypefaces,
+    variables::{NenyrVariableValue, NenyrVariables},
+};
+
+/// A node of a `CanonicalContext`.
+///
+/// # Variants
+/// - `Value`: A textual value, such as a property value or a class name.
+/// - `List`: A sequence whose order is meaningful, such as the keyframes of an animation or
+///   the properties of a block.
+/// - `Map`: A mapping whose entries are sorted by key, regardless of their declaration order.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum CanonicalNode {
+    Value(String),
+    List(Vec<CanonicalNode>),
+    Map(BTreeMap<String, CanonicalNode>),
+}
+
+/// A deterministic representation of a Nenyr context, suitable for hashing.
+///
+/// Every insertion-ordered map of the context is converted into a sorted `BTreeMap`, so
+/// two contexts declaring the same entries in different orders produce identical canonical
+/// forms. The sequences whose order changes the produced CSS keep their order, namely the
+/// imports, the keyframes of an animation and the properties of every block, since a
+/// property may override a shorthand declared before it. The typed forms of the variables
+/// are represented by their textual form, except the arrays, which are kept as lists.
+///
+/// # Fields
+/// - `kind`: The kind of the canonicalized context.
+/// - `entries`: The declarations of the context, keyed by the name of the context field.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct CanonicalContext {
+    pub kind: NenyrContextKind,
+    pub entries: BTreeMap<String, CanonicalNode>,
+}
+
+impl CentralContext {
+    /// Converts the context into its deterministic `CanonicalContext` form.
+    ///
+    /// # Returns
+    /// A `CanonicalContext` with every map sorted by key.
+    pub fn canonicalized(&self) -> CanonicalContext {
+        let mut entries = BTreeMap::new();
+
+        insert_entry(
+            &mut entries,
+            "imports",
+            self.imports.as_ref().map(canonical_imports),
+        );
+        insert_entry(
+            &mut entries,
+            "typefaces",
+            self.typefaces.as_ref().map(canonical_typefaces),
+        );
+        insert_entry(
+            &mut entries,
+            "breakpoints",
+            self.breakpoints.as_ref().map(canonical_breakpoints),
+        );
+        insert_entry(
+            &mut entries,
+            "aliases",
+            self.aliases.as_ref().map(canonical_aliases),
+        );
+        insert_entry(
+            &mut entries,
+            "variables",
+            self.variables.as_ref().map(canonical_variables),
+        );
+        insert_entry(
+            &mut entries,
+            "consts",
+            self.consts.as_ref().map(canonical_consts),
+        );
+        insert_entry(
+            &mut entries,
+            "themes",
+            self.themes.as_ref().map(canonical_themes),
+        );
+        insert_entry(
+            &mut entries,
+            "mixins",
+            self.mixins.as_ref().map(canonical_mixins),
+        );
+        insert_entry(
+            &mut entries,
+            "animations",
+            self.animations.as_ref().map(canonical_animations),
+        );
+        insert_entry(
+            &mut entries,
+            "classes",
+            self.classes.as_ref().map(canonical_classes),
+        );
+
+        CanonicalContext {
+            kind: NenyrContextKind::Central,
+            entries,
+        }
+    }
+}
+
+impl LayoutContext {
+    /// Converts the context into its deterministic `CanonicalContext` form.
+    ///
+    /// # Returns
+    /// A `CanonicalContext` with every map sorted by key.
+    pub fn canonicalized(&self) -> CanonicalContext {
+        let mut entries = BTreeMap::new();
+
+        insert_entry(&mut entries, "layout_name", Some(value(&self.layout_name)));
+        insert_entry(
+            &mut entries,
+            "aliases",
+            self.aliases.as_ref().map(canonical_aliases),
+        );
+        insert_entry(
+            &mut entries,
+            "variables",
+            self.variables.as_ref().map(canonical_variables),
+        );
+        insert_entry(
+            &mut entries,
+            "themes",
+            self.themes.as_ref().map(canonical_themes),
+        );
+        insert_entry(
+            &mut entries,
+            "mixins",
+            self.mixins.as_ref().map(canonical_mixins),
+        );
+        insert_entry(
+            &mut entries,
+            "animations",
+            self.animations.as_ref().map(canonical_animations),
+        );
+        insert_entry(
+            &mut entries,
+            "classes",
+            self.classes.as_ref().map(canonical_classes),
+        );
+
+        CanonicalContext {
+            kind: NenyrContextKind::Layout,
+            entries,
+        }
+    }
+}
+
+impl ModuleContext {
+    /// Converts the context into its deterministic `CanonicalContext` form.
+    ///
+    /// # Returns
+    /// A `CanonicalContext` with every map sorted by key.
+    pub fn canonicalized(&self) -> CanonicalContext {
+        let mut entries = BTreeMap::new();
+
+        insert_entry(&mut entries, "module_name", Some(value(&self.module_name)));
+        insert_entry(
+            &mut entries,
+            "extending_from",
+            self.extending_from.as_deref().map(value),
+        );
+        insert_entry(
+            &mut entries,
+            "aliases",
+            self.aliases.as_ref().map(canonical_aliases),
+        );
+        insert_entry(
+            &mut entries,
+            "variables",
+            self.variables.as_ref().map(canonical_variables),
+        );
+        insert_entry(
+            &mut entries,
+            "mixins",
+            self.mixins.as_ref().map(canonical_mixins),
+        );
+        insert_entry(
+            &mut entries,
+            "animations",
+            self.animations.as_ref().map(canonical_animations),
+        );
+        insert_entry(
+            &mut entries,
+            "classes",
+            self.classes.as_ref().map(canonical_classes),
+        );
+
+        CanonicalContext {
+            kind: NenyrContextKind::Module,
+            entries,
+        }
+    }
+}
+
+fn insert_entry(
+    entries: &mut BTreeMap<String, CanonicalNode>,
+    key: &str,
+    node: Option<CanonicalNode>,
+) {
+    if let Some(node) = node {
+        entries.insert(key.to_string(), node);
+    }
+}
+
+fn value(value: &str) -> CanonicalNode {
+    CanonicalNode::Value(value.to_string())
+}
+
+fn map<'a, V: 'a>(
+    values: impl IntoIterator<Item = (&'a String, V)>,
+    to_node: impl Fn(V) -> CanonicalNode,
+) -> CanonicalNode {
+    CanonicalNode::Map(
+        values
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), to_node(value)))
+            .collect(),
+    )
+}
+
+fn canonical_properties(properties: &IndexMap<String, Arc<str>>) -> CanonicalNode {
+    // The properties keep their order, as a later property overrides an earlier one,
+    // e.g. `margin-top` declared after `margin`.
+    let properties = properties
+        .iter()
+        .map(|(property, property_value)| {
+            CanonicalNode::Map(BTreeMap::from([(
+                property.to_string(),
+                value(property_value),
+            )]))
+        })
+        .collect();
+
+    CanonicalNode::List(properties)
+}
+
+fn canonical_patterns(patterns: &IndexMap<String, IndexMap<String, Arc<str>>>) -> CanonicalNode {
+    map(patterns, canonical_properties)
+}
+
+fn canonical_imports(imports: &NenyrImports) -> CanonicalNode {
+    // The imports keep their order, as it defines the cascade of the imported stylesheets.
+    let imports = imports
+        .values
+        .iter()
+        .map(|(import, layer)| {
+            let mut entries = BTreeMap::new();
+
+            insert_entry(&mut entries, "import", Some(value(import)));
+            insert_entry(&mut entries, "layer", layer.as_deref().map(value));
+
+            CanonicalNode::Map(entries)
+        })
+        .collect();
+
+    CanonicalNode::List(imports)
+}
+
+fn canonical_typefaces(typefaces: &NenyrTypefaces) -> CanonicalNode {
+    map(&typefaces.values, |typeface| value(typeface))
+}
+
+fn canonical_breakpoints(breakpoints: &NenyrBreakpoints) -> CanonicalNode {
+    let mut entries = BTreeMap::new();
+
+    for (key, breakpoints) in [
+        ("mobile_first", &breakpoints.mobile_first),
+        ("desktop_first", &breakpoints.desktop_first),
+    ] {
+        insert_entry(
+            &mut entries,
+            key,
+            breakpoints
+                .as_ref()
+                .map(|breakpoints| map(breakpoints, |breakpoint| value(breakpoint))),
+        );
+    }
+
+    CanonicalNode::Map(entries)
+}
+
+fn canonical_aliases(aliases: &NenyrAliases) -> CanonicalNode {
+    map(&aliases.values, |property| value(property))
+}
+
+fn canonical_variables(variables: &NenyrVariables) -> CanonicalNode {
+    CanonicalNode::Map(
+        variables
+            .values
+            .iter()
+            .map(|(identifier, variable_value)| {
+                let node = match variable_value {
+                    NenyrVariableValue::Text(variable_value) => value(variable_value),
+                    NenyrVariableValue::List(elements) => {
+                        CanonicalNode::List(elements.iter().map(|element| value(element)).collect())
+                    }
+                };
+
+                (identifier.to_string(), node)
+            })
+            .collect(),
+    )
+}
+
+fn canonical_consts(consts: &NenyrConsts) -> CanonicalNode {
+    map(&consts.values, |const_value| value(const_value))
+}
+
+fn canonical_themes(themes: &NenyrThemes) -> CanonicalNode {
+    let mut entries = BTreeMap::new();
+
+    insert_entry(
+        &mut entries,
+        "light_schema",
+        themes.light_schema.as_ref().map(canonical_variables),
+    );
+    insert_entry(
+        &mut entries,
+        "dark_schema",
+        themes.dark_schema.as_ref().map(canonical_variables),
+    );
+    insert_entry(
+        &mut entries,
+        "light_aliases",
+        themes.light_aliases.as_ref().map(canonical_aliases),
+    );
+    insert_entry(
+        &mut entries,
+        "dark_aliases",
+        themes.dark_aliases.as_ref().map(canonical_aliases),
+    );
+    insert_entry(
+        &mut entries,
+        "light_typefaces",
+        themes.light_typefaces.as_ref().map(canonical_typefaces),
+    );
+    insert_entry(
+        &mut entries,
+        "dark_typefaces",
+        themes.dark_typefaces.as_ref().map(canonical_typefaces),
+    );
+
+    CanonicalNode::Map(entries)
+}
+
+fn canonical_mixins(mixins: &NenyrMixins) -> CanonicalNode {
+    map(&mixins.values, canonical_properties)
+}
+
+fn canonical_animations(animations: &IndexMap<String, NenyrAnimation>) -> CanonicalNode {
+    map(animations, |animation| {
+        let mut entries = BTreeMap::new();
+
+        insert_entry(
+            &mut entries,
+            "animation_name",
+            Some(value(&animation.animation_name)),
+        );
+        insert_entry(
+            &mut entries,
+            "kind",
+            animation
+                .kind
+                .as_ref()
+                .map(|kind| value(&format!("{:?}", kind))),
+        );
+        insert_entry(
+            &mut entries,
+            "progressive_count",
+            animation
+                .progressive_count
+                .map(|count| value(&count.to_string())),
+        );
+
+        // The keyframes keep their order, as it defines the progression of the animation.
+        let keyframes = animation
+            .keyframe
+            .iter()
+            .map(|keyframe| {
+                let mut entries = BTreeMap::new();
+                let (kind, properties) = match keyframe {
+                    // The notation of the stops does not change their meaning, so it is left out.
+                    NenyrKeyframe::Fraction {
+                        stops, properties, ..
+                    } => {
+                        let stops = stops.iter().map(|stop| value(&stop.to_string())).collect();
+
+                        entries.insert("stops".to_string(), CanonicalNode::List(stops));
+
+                        ("Fraction", properties)
+                    }
+                    NenyrKeyframe::Progressive(properties) => ("Progressive", properties),
+                    NenyrKeyframe::From(properties) => ("From", properties),
+                    NenyrKeyframe::Halfway(properties) => ("Halfway", properties),
+                    NenyrKeyframe::To(properties) => ("To", properties),
+                };
+
+                entries.insert("kind".to_string(), value(kind));
+                entries.insert("properties".to_string(), canonical_properties(properties));
+
+                CanonicalNode::Map(entries)
+            })
+            .collect();
+
+        entries.insert("keyframe".to_string(), CanonicalNode::List(keyframes));
+
+        CanonicalNode::Map(entries)
+    })
+}
+
+fn canonical_classes(classes: &IndexMap<String, NenyrStyleClass>) -> CanonicalNode {
+    map(classes, |style_class| {
+        let mut entries = BTreeMap::new();
+
+        insert_entry(
+            &mut entries,
+            "class_name",
+            Some(value(&style_class.class_name)),
+        );
+        insert_entry(
+            &mut entries,
+            "deriving_from",
+            style_class.deriving_from.as_deref().map(value),
+        );
+        insert_entry(
+            &mut entries,
+            "is_important",
+            style_class
+                .is_important
+                .map(|is_important| value(&is_important.to_string())),
+        );
+        insert_entry(
+            &mut entries,
+            "style_patterns",
+            style_class.style_patterns.as_ref().map(canonical_patterns),
+        );
+        insert_entry(
+            &mut entries,
+            "supports_patterns",
+            style_class
+                .supports_patterns
+                .as_ref()
+                .map(canonical_patterns),
+        );
+        insert_entry(
+            &mut entries,
+            "container_patterns",
+            style_class
+                .container_patterns
+                .as_ref()
+                .map(canonical_patterns),
+        );
+        insert_entry(
+            &mut entries,
+            "responsive_patterns",
+            style_class
+                .responsive_patterns
+                .as_ref()
+                .map(|patterns| map(patterns, canonical_patterns)),
+        );
+        insert_entry(
+            &mut entries,
+            "media_patterns",
+            style_class
+                .media_patterns
+                .as_ref()
+                .map(|patterns| map(patterns, canonical_patterns)),
+        );
+
+        CanonicalNode::Map(entries)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    use crate::{types::ast::NenyrAst, NenyrParser};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    #[test]
+    fn contexts_declared_in_different_orders_must_share_the_canonical_form() {
+        let mut parser = NenyrParser::new();
+        let ast = parser
+            .parse(
+                "Construct Layout('mainLayout') {
+    Declare Variables({ primary: '#333', spacing: 8px }),
+    Declare Class('header') { Stylesheet({ color: 'red', display: 'flex' }), Hover({ color: 'blue' }) },
+    Declare Class('footer') { Stylesheet({ padding: '10px' }) }
+}"
+                .to_string(),
+                "".to_string(),
+            )
+            .unwrap();
+        let reordered_ast = parser
+            .parse(
+                "Construct Layout('mainLayout') {
+    Declare Class('footer') { Stylesheet({ padding: '10px' }) },
+    Declare Class('header') { Hover({ color: 'blue' }), Stylesheet({ color: 'red', display: 'flex' }) },
+    Declare Variables({ spacing: 8px, primary: '#333' })
+}"
+                .to_string(),
+                "".to_string(),
+            )
+            .unwrap();
+
+        let (NenyrAst::LayoutContext(context), NenyrAst::LayoutContext(reordered_context)) =
+            (ast, reordered_ast)
+        else {
+            panic!("Both inputs must be parsed as layout contexts.");
+        };
+
+        assert_eq!(context.canonicalized(), reordered_context.canonicalized());
+        assert_eq!(
+            hash_of(&context.canonicalized()),
+            hash_of(&reordered_context.canonicalized())
+        );
+    }
+
+    #[test]
+    fn contexts_with_different_values_must_not_share_the_canonical_form() {
+        let mut parser = NenyrParser::new();
+        let parse_layout = |parser: &mut NenyrParser, color: &str| {
+            match parser
+            .parse(
+                format!(
+                    "Construct Layout('mainLayout') {{ Declare Class('header') {{ Stylesheet({{ color: '{}' }}) }} }}",
+                    color
+                ),
+                "".to_string(),
+            )
+            .unwrap()
+        {
+            NenyrAst::LayoutContext(context) => context,
+            _ => panic!("The input must be parsed as a layout context."),
+        }
+        };
+
+        let context = parse_layout(&mut parser, "red");
+        let other_context = parse_layout(&mut parser, "blue");
+
+        assert_ne!(context.canonicalized(), other_context.canonicalized());
+    }
+
+    #[test]
+    fn contexts_with_reordered_properties_must_not_share_the_canonical_form() {
+        let mut parser = NenyrParser::new();
+        let parse_layout = |parser: &mut NenyrParser, properties: &str| {
+            match parser
+                .parse(
+                    format!(
+                        "Construct Layout('mainLayout') {{ Declare Class('header') {{ Stylesheet({{ {} }}) }} }}",
+                        properties
+                    ),
+                    "".to_string(),
+                )
+                .unwrap()
+            {
+                NenyrAst::LayoutContext(context) => context,
+                _ => panic!("The input must be parsed as a layout context."),
+            }
+        };
+
+        let context = parse_layout(&mut parser, "margin: '0', marginTop: '10px'");
+        let reordered_context = parse_layout(&mut parser, "marginTop: '10px', margin: '0'");
+
+        assert_ne!(context.canonicalized(), reordered_context.canonicalized());
+    }
+}
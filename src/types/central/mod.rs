@@ -1,8 +1,20 @@
 use indexmap::IndexMap;
+use std::sync::Arc;
+
+use crate::tokens::NenyrTokens;
 
 use super::{
-    aliases::NenyrAliases, animations::NenyrAnimation, breakpoints::NenyrBreakpoints,
-    class::NenyrStyleClass, imports::NenyrImports, themes::NenyrThemes, typefaces::NenyrTypefaces,
+    aliases::NenyrAliases,
+    animations::{rename_animation, unused_animations, NenyrAnimation},
+    breakpoints::NenyrBreakpoints,
+    class::{classes_with_pattern, rename_class, NenyrStyleClass},
+    collection::NenyrCollection,
+    consts::NenyrConsts,
+    declaration::{DeclarationCounts, NenyrDeclaration},
+    imports::NenyrImports,
+    mixins::NenyrMixins,
+    themes::NenyrThemes,
+    typefaces::NenyrTypefaces,
     variables::NenyrVariables,
 };
 
@@ -10,18 +22,21 @@ use super::{
 ///
 /// The `CentralContext` struct aggregates various elements essential for
 /// styling in the Nenyr framework. It encompasses imports, typefaces,
-/// breakpoints, aliases, variables, themes, animations, and style classes.
+/// breakpoints, aliases, variables, constants, themes, mixins, animations, and style classes.
 ///
 /// Each field within the struct is optional, indicating that the context
 /// can be incrementally built up as needed during the styling process.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CentralContext {
     pub imports: Option<NenyrImports>,
     pub typefaces: Option<NenyrTypefaces>,
     pub breakpoints: Option<NenyrBreakpoints>,
     pub aliases: Option<NenyrAliases>,
     pub variables: Option<NenyrVariables>,
+    pub consts: Option<NenyrConsts>,
     pub themes: Option<NenyrThemes>,
+    pub mixins: Option<NenyrMixins>,
     pub animations: Option<IndexMap<String, NenyrAnimation>>,
     pub classes: Option<IndexMap<String, NenyrStyleClass>>,
 }
@@ -42,7 +57,9 @@ impl CentralContext {
             breakpoints: None,
             aliases: None,
             variables: None,
+            consts: None,
             themes: None,
+            mixins: None,
             animations: None,
             classes: None,
         }
@@ -97,6 +114,25 @@ impl CentralContext {
         }
     }
 
+    /// Adds a mixin to the context.
+    ///
+    /// This method inserts the properties of a mixin into the `mixins`
+    /// field, creating the `NenyrMixins` if it does not already exist.
+    ///
+    /// # Parameters
+    ///
+    /// - `mixin_name`: A `String` representing the name of the mixin.
+    /// - `properties`: The properties declared by the mixin.
+    pub(crate) fn add_mixin_to_context(
+        &mut self,
+        mixin_name: String,
+        properties: IndexMap<String, Arc<str>>,
+    ) {
+        self.mixins
+            .get_or_insert_with(NenyrMixins::new)
+            .add_mixin(mixin_name, properties);
+    }
+
     /// Adds variables to the context.
     ///
     /// This method sets the `variables` field in the `CentralContext`
@@ -109,6 +145,22 @@ impl CentralContext {
         self.variables = Some(variables);
     }
 
+    /// Adds constants to the context.
+    ///
+    /// This method sets the `consts` field in the `CentralContext`
+    /// with the provided `NenyrConsts`. As constants cannot be redeclared, the
+    /// constants of a further `Const` declaration are appended to the existing ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `consts`: The `NenyrConsts` instance to be added to the context.
+    pub(crate) fn add_consts_to_context(&mut self, consts: NenyrConsts) {
+        match &mut self.consts {
+            Some(existing) => existing.values.extend(consts.values),
+            None => self.consts = Some(consts),
+        }
+    }
+
     /// Adds aliases to the context.
     ///
     /// This method sets the `aliases` field in the `CentralContext`
@@ -168,6 +220,234 @@ impl CentralContext {
     pub(crate) fn add_breakpoints_to_context(&mut self, breakpoints: NenyrBreakpoints) {
         self.breakpoints = Some(breakpoints);
     }
+
+    /// Adds a parsed declaration to the context.
+    ///
+    /// This method dispatches the declaration to the matching `add_*_to_context` method.
+    ///
+    /// # Parameters
+    ///
+    /// - `declaration`: The `NenyrDeclaration` to be added to the context.
+    pub(crate) fn add_declaration_to_context(&mut self, declaration: NenyrDeclaration) {
+        match declaration {
+            NenyrDeclaration::Imports(imports) => self.add_imports_to_context(imports),
+            NenyrDeclaration::Typefaces(typefaces) => self.add_typefaces_to_context(typefaces),
+            NenyrDeclaration::Breakpoints(breakpoints) => {
+                self.add_breakpoints_to_context(breakpoints)
+            }
+            NenyrDeclaration::Aliases(aliases) => self.add_aliases_to_context(aliases),
+            NenyrDeclaration::Variables(variables) => self.add_variables_to_context(variables),
+            NenyrDeclaration::Consts(consts) => self.add_consts_to_context(consts),
+            NenyrDeclaration::Themes(themes) => self.add_themes_to_context(*themes),
+            NenyrDeclaration::Mixin(mixin_name, properties) => {
+                self.add_mixin_to_context(mixin_name, properties)
+            }
+            NenyrDeclaration::Animation(animation_name, animation) => {
+                self.add_animation_to_context(animation_name, animation)
+            }
+            NenyrDeclaration::Class(class_name, style_class) => {
+                self.add_style_class_to_context(class_name, *style_class)
+            }
+        }
+    }
+
+    /// Iterates over every property-value pair declared in the animations and classes of the context.
+    ///
+    /// Animation keyframes are flattened first, followed by the class style patterns, `Supports`
+    /// and `Container` patterns and responsive patterns. It is meant for audits, such as finding
+    /// every `outline: none` declaration.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of `(owner, property, value)` tuples, where the owner is the name of the
+    /// animation or class declaring the pair.
+    pub fn all_declarations(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+        let animations = self
+            .animations
+            .iter()
+            .flatten()
+            .flat_map(|(name, animation)| {
+                animation
+                    .declarations()
+                    .map(move |(property, value)| (name.as_str(), property, value))
+            });
+        let classes = self
+            .classes
+            .iter()
+            .flatten()
+            .flat_map(|(name, style_class)| {
+                style_class
+                    .declarations()
+                    .map(move |(property, value)| (name.as_str(), property, value))
+            });
+
+        animations.chain(classes)
+    }
+
+    /// Retrieves the names of the breakpoints declared in the context.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` with the `MobileFirst` breakpoint names followed by the `DesktopFirst`
+    /// ones, each in declaration order.
+    pub fn breakpoint_names(&self) -> Vec<String> {
+        match &self.breakpoints {
+            Some(breakpoints) => breakpoints
+                .mobile_first
+                .iter()
+                .chain(breakpoints.desktop_first.iter())
+                .flat_map(|schema| schema.keys().cloned())
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Retrieves the aliases declared in the context that are never used by its classes
+    /// or animations.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` with the unused alias identifiers, in declaration order.
+    pub fn unused_aliases(&self) -> Vec<String> {
+        match &self.aliases {
+            Some(aliases) => {
+                aliases.unused_aliases(self.all_declarations().map(|(_, property, _)| property))
+            }
+            None => vec![],
+        }
+    }
+
+    /// Retrieves the animations declared in the context that are never referenced by the
+    /// `animation` or `animation-name` values of its classes.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` with the unused animation names, in declaration order.
+    pub fn unused_animations(&self) -> Vec<String> {
+        match &self.animations {
+            Some(animations) => unused_animations(
+                animations,
+                self.classes()
+                    .iter()
+                    .flat_map(|(_, style_class)| style_class.declarations()),
+            ),
+            None => vec![],
+        }
+    }
+
+    /// Renames an animation declared in the context, updating the `animation` and
+    /// `animation-name` values of the classes referencing it.
+    ///
+    /// Nothing is changed if `from` is not declared, or if `to` is not a valid identifier
+    /// or is already declared by another animation.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: The current name of the animation.
+    /// - `to`: The new name of the animation.
+    ///
+    /// # Returns
+    ///
+    /// The number of property values updated to reference the new name.
+    pub fn rename_animation(&mut self, from: &str, to: &str) -> usize {
+        match &mut self.animations {
+            Some(animations) => rename_animation(
+                animations,
+                self.classes.as_mut(),
+                self.aliases.as_ref(),
+                from,
+                to,
+            ),
+            None => 0,
+        }
+    }
+
+    /// Renames a style class declared in the context, updating the `Deriving` references
+    /// of the other classes.
+    ///
+    /// Nothing is changed if `from` is not declared, or if `to` is not a valid identifier
+    /// or is already declared by another class.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: The current name of the class.
+    /// - `to`: The new name of the class.
+    ///
+    /// # Returns
+    ///
+    /// The number of classes updated to derive from the new name.
+    pub fn rename_class(&mut self, from: &str, to: &str) -> usize {
+        match &mut self.classes {
+            Some(classes) => rename_class(classes, from, to),
+            None => 0,
+        }
+    }
+
+    /// Retrieves the classes defining a style for the given pattern, such as the classes
+    /// declaring a `Hover` pattern, either directly or inside a `PanoramicViewer` breakpoint
+    /// or media type. The `Supports` and `Container` tokens match the classes declaring
+    /// these patterns.
+    ///
+    /// # Parameters
+    ///
+    /// - `pattern`: The style pattern token, e.g. `NenyrTokens::Hover`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<&str>` with the matching class names, in declaration order.
+    pub fn classes_with_pattern(&self, pattern: &NenyrTokens) -> Vec<&str> {
+        match &self.classes {
+            Some(classes) => classes_with_pattern(classes, pattern),
+            None => vec![],
+        }
+    }
+
+    /// Counts the declarations of each kind found in the context.
+    ///
+    /// # Returns
+    ///
+    /// A `DeclarationCounts` with the number of classes, animations, variables, aliases,
+    /// themes, imports, typefaces and breakpoints declared.
+    pub fn declaration_counts(&self) -> DeclarationCounts {
+        DeclarationCounts {
+            classes: self.classes.as_ref().map_or(0, |classes| classes.len()),
+            animations: self
+                .animations
+                .as_ref()
+                .map_or(0, |animations| animations.len()),
+            variables: self
+                .variables
+                .as_ref()
+                .map_or(0, |variables| variables.values.len()),
+            aliases: self
+                .aliases
+                .as_ref()
+                .map_or(0, |aliases| aliases.values.len()),
+            themes: self
+                .themes
+                .as_ref()
+                .map_or(0, |themes| themes.count_themes()),
+            imports: self
+                .imports
+                .as_ref()
+                .map_or(0, |imports| imports.values.len()),
+            typefaces: self
+                .typefaces
+                .as_ref()
+                .map_or(0, |typefaces| typefaces.values.len()),
+            breakpoints: self.breakpoint_names().len(),
+        }
+    }
+
+    /// Retrieves the animations declared in the context, in declaration order.
+    pub fn animations(&self) -> NenyrCollection<'_, NenyrAnimation> {
+        NenyrCollection::new(self.animations.as_ref())
+    }
+
+    /// Retrieves the classes declared in the context, in declaration order.
+    pub fn classes(&self) -> NenyrCollection<'_, NenyrStyleClass> {
+        NenyrCollection::new(self.classes.as_ref())
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,16 @@
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
+use std::sync::Arc;
+
+use crate::{
+    converters::{specificity::selector_specificity, style_pattern::NenyrStylePatternConverter},
+    error::{NenyrError, NenyrErrorKind, NenyrErrorTracing},
+    tokens::NenyrTokens,
+    validators::identifier::NenyrIdentifierValidator,
+    NenyrResult,
+};
+
+/// Style patterns organized by panoramic breakpoint or media type, then by pattern name.
+pub type NenyrPanoramicPatterns = IndexMap<String, IndexMap<String, IndexMap<String, Arc<str>>>>;
 
 /// Represents a style class in the Nenyr DSL.
 ///
@@ -13,15 +25,25 @@ use indexmap::IndexMap;
 /// - `deriving_from`: An optional field representing the class this style class derives from.
 /// - `is_important`: An optional boolean indicating whether the styles in this class are marked as important.
 /// - `style_patterns`: An optional map of style patterns associated with this class.
+/// - `supports_patterns`: An optional map of style rules guarded by feature queries, organized by
+///   the feature query condition (e.g., `(display: grid)`).
+/// - `container_patterns`: An optional map of style rules guarded by container queries, organized by
+///   the container query prelude, optionally prefixed by the container name (e.g., `sidebar (min-width: 400px)`).
 /// - `responsive_patterns`: An optional map of responsive style patterns, organized by panoramic names.
+/// - `media_patterns`: An optional map of style patterns guarded by a media type, organized by the
+///   media type declared within the panoramic pattern (e.g., `print`).
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrStyleClass {
     pub class_name: String,
     pub deriving_from: Option<String>,
     pub is_important: Option<bool>,
 
-    pub style_patterns: Option<IndexMap<String, IndexMap<String, String>>>,
-    pub responsive_patterns: Option<IndexMap<String, IndexMap<String, IndexMap<String, String>>>>,
+    pub style_patterns: Option<IndexMap<String, IndexMap<String, Arc<str>>>>,
+    pub supports_patterns: Option<IndexMap<String, IndexMap<String, Arc<str>>>>,
+    pub container_patterns: Option<IndexMap<String, IndexMap<String, Arc<str>>>>,
+    pub responsive_patterns: Option<NenyrPanoramicPatterns>,
+    pub media_patterns: Option<NenyrPanoramicPatterns>,
 }
 
 impl NenyrStyleClass {
@@ -41,7 +63,10 @@ impl NenyrStyleClass {
             deriving_from,
             is_important: None,
             style_patterns: None,
+            supports_patterns: None,
+            container_patterns: None,
             responsive_patterns: None,
+            media_patterns: None,
         }
     }
 
@@ -85,7 +110,12 @@ impl NenyrStyleClass {
     /// - `pattern_name`: The name of the pattern to which the style rule is to be added.
     /// - `property`: The property name to be set.
     /// - `value`: The value of the property.
-    pub(crate) fn add_style_rule(&mut self, pattern_name: String, property: String, value: String) {
+    pub(crate) fn add_style_rule(
+        &mut self,
+        pattern_name: String,
+        property: String,
+        value: Arc<str>,
+    ) {
         if self.style_patterns == None {
             self.style_patterns = Some(IndexMap::new());
         }
@@ -101,6 +131,88 @@ impl NenyrStyleClass {
         }
     }
 
+    /// Adds the style rules guarded by a feature query.
+    ///
+    /// This method stores the property-value pairs declared inside a `Supports` pattern under
+    /// the given feature query. If the feature query was already declared, its properties are
+    /// merged into the existing ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `feature_query`: The feature query condition guarding the style rules.
+    /// - `properties`: The property-value pairs declared inside the `Supports` pattern.
+    pub(crate) fn add_supports_pattern(
+        &mut self,
+        feature_query: String,
+        properties: IndexMap<String, Arc<str>>,
+    ) {
+        if self.supports_patterns.is_none() {
+            self.supports_patterns = Some(IndexMap::new());
+        }
+
+        if let Some(supports_patterns) = &mut self.supports_patterns {
+            if let Some(existing_pattern) = supports_patterns.get_mut(&feature_query) {
+                existing_pattern.extend(properties);
+            } else {
+                supports_patterns.insert(feature_query, properties);
+            }
+        }
+    }
+
+    /// Adds the style rules guarded by a container query.
+    ///
+    /// This method stores the property-value pairs declared inside a `Container` pattern under
+    /// the container query prelude, which is the condition optionally prefixed by the container
+    /// name (e.g., `sidebar (min-width: 400px)`). If the same prelude was already declared, its
+    /// properties are merged into the existing ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `container_query`: The container query prelude guarding the style rules.
+    /// - `properties`: The property-value pairs declared inside the `Container` pattern.
+    pub(crate) fn add_container_pattern(
+        &mut self,
+        container_query: String,
+        properties: IndexMap<String, Arc<str>>,
+    ) {
+        if self.container_patterns.is_none() {
+            self.container_patterns = Some(IndexMap::new());
+        }
+
+        if let Some(container_patterns) = &mut self.container_patterns {
+            if let Some(existing_pattern) = container_patterns.get_mut(&container_query) {
+                existing_pattern.extend(properties);
+            } else {
+                container_patterns.insert(container_query, properties);
+            }
+        }
+    }
+
+    /// Checks whether a pattern node was already created, either at the root of the class or
+    /// within the given panoramic node.
+    ///
+    /// # Parameters
+    ///
+    /// - `breakpoint_name`: The name of the panoramic containing the pattern, if any.
+    /// - `pattern_name`: The name of the pattern to look for.
+    pub(crate) fn has_pattern_node(
+        &self,
+        breakpoint_name: Option<&str>,
+        pattern_name: &str,
+    ) -> bool {
+        match breakpoint_name {
+            Some(breakpoint_name) => self
+                .responsive_patterns
+                .as_ref()
+                .and_then(|responsive_patterns| responsive_patterns.get(breakpoint_name))
+                .is_some_and(|panoramic_patterns| panoramic_patterns.contains_key(pattern_name)),
+            None => self
+                .style_patterns
+                .as_ref()
+                .is_some_and(|style_patterns| style_patterns.contains_key(pattern_name)),
+        }
+    }
+
     /// Resets a panoramic node for the specified panoramic name.
     ///
     /// This method initializes or resets the responsive patterns for a given panoramic name,
@@ -144,6 +256,93 @@ impl NenyrStyleClass {
         }
     }
 
+    /// Copies the style rules of a pattern node into another pattern node, replacing it.
+    ///
+    /// # Parameters
+    ///
+    /// - `breakpoint_name`: The name of the panoramic containing both patterns, or `None`
+    ///   for the patterns declared directly in the class.
+    /// - `source_pattern`: The name of the pattern whose rules are copied.
+    /// - `target_pattern`: The name of the pattern receiving the rules.
+    pub(crate) fn copy_pattern_node(
+        &mut self,
+        breakpoint_name: Option<&str>,
+        source_pattern: &str,
+        target_pattern: &str,
+    ) {
+        let patterns = match breakpoint_name {
+            Some(breakpoint_name) => self
+                .responsive_patterns
+                .as_mut()
+                .and_then(|responsive_patterns| responsive_patterns.get_mut(breakpoint_name)),
+            None => self.style_patterns.as_mut(),
+        };
+
+        if let Some(patterns) = patterns {
+            if let Some(properties) = patterns.get(source_pattern).cloned() {
+                patterns.insert(target_pattern.to_string(), properties);
+            }
+        }
+    }
+
+    /// Includes the properties of a mixin into the stylesheet of the class.
+    ///
+    /// The mixin properties are placed before the ones already declared in the stylesheet,
+    /// so a property declared by the class always takes precedence over the mixin one.
+    ///
+    /// # Parameters
+    ///
+    /// - `breakpoint_name`: The name of the panoramic receiving the properties, or `None`
+    ///   for the stylesheet declared directly in the class.
+    /// - `properties`: The properties declared by the mixin.
+    ///
+    /// # Returns
+    ///
+    /// The number of properties of the stylesheet once the mixin properties are included.
+    pub(crate) fn include_style_rules(
+        &mut self,
+        breakpoint_name: Option<&str>,
+        properties: &IndexMap<String, Arc<str>>,
+    ) -> usize {
+        let patterns = match breakpoint_name {
+            Some(breakpoint_name) => self
+                .responsive_patterns
+                .get_or_insert_with(IndexMap::new)
+                .entry(breakpoint_name.to_string())
+                .or_default(),
+            None => self.style_patterns.get_or_insert_with(IndexMap::new),
+        };
+
+        let stylesheet = patterns.entry("_stylesheet".to_string()).or_default();
+        let mut included_properties: IndexMap<String, Arc<str>> = properties
+            .iter()
+            .filter(|(property, _)| !stylesheet.contains_key(*property))
+            .map(|(property, value)| (property.clone(), value.clone()))
+            .collect();
+
+        included_properties.extend(stylesheet.drain(..));
+        *stylesheet = included_properties;
+
+        stylesheet.len()
+    }
+
+    /// Adds the style patterns guarded by a media type, replacing the patterns previously
+    /// added for the same media type.
+    ///
+    /// # Parameters
+    ///
+    /// - `media_type`: The media type guarding the patterns, such as `print`.
+    /// - `patterns`: The style patterns, mapped from the pattern name to its properties.
+    pub(crate) fn add_media_patterns(
+        &mut self,
+        media_type: String,
+        patterns: IndexMap<String, IndexMap<String, Arc<str>>>,
+    ) {
+        self.media_patterns
+            .get_or_insert_with(IndexMap::new)
+            .insert(media_type, patterns);
+    }
+
     /// Adds a responsive style rule to a specified panoramic pattern.
     ///
     /// This method adds a property-value pair to the specified pattern's responsive style rules.
@@ -160,7 +359,7 @@ impl NenyrStyleClass {
         breakpoint_name: String,
         pattern_name: String,
         property: String,
-        value: String,
+        value: Arc<str>,
     ) {
         if self.responsive_patterns == None {
             self.responsive_patterns = Some(IndexMap::new());
@@ -183,6 +382,492 @@ impl NenyrStyleClass {
             }
         }
     }
+
+    /// Lists every CSS selector produced by the class.
+    ///
+    /// The style pattern keys are already stored as the pseudo-selectors returned by the
+    /// `NenyrStylePatternConverter`, so each one is appended to the class selector, with the
+    /// `_stylesheet` pattern producing the bare class selector. The `Supports` and `Container`
+    /// patterns are scoped by their rule, as `@supports (display: grid) .className` and
+    /// `@container (min-width: 400px) .className`. Responsive patterns are scoped by the name
+    /// of their breakpoint, as `@breakpointName .className:pseudo`, since the class does not
+    /// know the breakpoint values, and the media type patterns by their media type, as
+    /// `@print .className`.
+    ///
+    /// # Parameters
+    ///
+    /// - `class_prefix`: A prefix to be placed between the `.` and the class name.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the selectors in declaration order, responsive ones placed last.
+    pub fn produced_selectors(&self, class_prefix: &str) -> Vec<String> {
+        self.scoped_selectors(class_prefix)
+            .into_iter()
+            .map(|(scope, selector)| match scope {
+                Some(scope) => format!("{} {}", scope, selector),
+                None => selector,
+            })
+            .collect()
+    }
+
+    /// Lists every CSS selector produced by the class along with its specificity.
+    ///
+    /// The selectors are the ones returned by `produced_selectors`, and the at-rule scope of
+    /// the scoped ones is left out of the specificity, since it is not part of the selector
+    /// itself.
+    ///
+    /// # Parameters
+    ///
+    /// - `class_prefix`: A prefix to be placed between the `.` and the class name.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(selector, (ids, classes, elements))` pairs, in the order of `produced_selectors`.
+    pub fn selector_specificities(&self, class_prefix: &str) -> Vec<(String, (u32, u32, u32))> {
+        self.scoped_selectors(class_prefix)
+            .into_iter()
+            .map(|(scope, selector)| {
+                let specificity = selector_specificity(&selector);
+
+                match scope {
+                    Some(scope) => (format!("{} {}", scope, selector), specificity),
+                    None => (selector, specificity),
+                }
+            })
+            .collect()
+    }
+
+    /// Lists every CSS selector produced by the class, paired with the at-rule scoping it.
+    ///
+    /// # Parameters
+    ///
+    /// - `class_prefix`: A prefix to be placed between the `.` and the class name.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(scope, selector)` pairs in the order of `produced_selectors`, where the
+    /// scope is `None` for the selectors declared at the root of the class.
+    fn scoped_selectors(&self, class_prefix: &str) -> Vec<(Option<String>, String)> {
+        let class_selector = format!(".{}{}", class_prefix, self.class_name);
+        let to_selector = |pattern_name: &str| match pattern_name {
+            "_stylesheet" => class_selector.clone(),
+            pseudo_selector => format!("{}{}", class_selector, pseudo_selector),
+        };
+        let mut selectors: Vec<(Option<String>, String)> = Vec::new();
+
+        if let Some(style_patterns) = &self.style_patterns {
+            selectors.extend(
+                style_patterns
+                    .keys()
+                    .map(|pattern_name| (None, to_selector(pattern_name))),
+            );
+        }
+
+        for (at_rule, query_patterns) in [
+            ("@supports", &self.supports_patterns),
+            ("@container", &self.container_patterns),
+        ] {
+            selectors.extend(query_patterns.iter().flatten().map(|(query, _)| {
+                (
+                    Some(format!("{} {}", at_rule, query)),
+                    class_selector.clone(),
+                )
+            }));
+        }
+
+        for responsive_patterns in [&self.responsive_patterns, &self.media_patterns] {
+            for (breakpoint_name, panoramic_patterns) in responsive_patterns.iter().flatten() {
+                selectors.extend(panoramic_patterns.keys().map(|pattern_name| {
+                    (
+                        Some(format!("@{}", breakpoint_name)),
+                        to_selector(pattern_name),
+                    )
+                }));
+            }
+        }
+
+        selectors
+    }
+
+    /// Iterates over every property-value pair declared within the class.
+    ///
+    /// The pairs are flattened from the style patterns, the `Supports` and `Container`
+    /// patterns, the responsive patterns and the media type patterns, in this order. Aliases keep their
+    /// `nickname;` prefix, as they are stored in the class.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of `(property, value)` pairs.
+    pub fn declarations(&self) -> impl Iterator<Item = (&str, &str)> {
+        let patterns = self
+            .style_patterns
+            .iter()
+            .chain(self.supports_patterns.iter())
+            .chain(self.container_patterns.iter())
+            .flat_map(|patterns| patterns.values());
+        let responsive_patterns = self
+            .responsive_patterns
+            .iter()
+            .chain(self.media_patterns.iter())
+            .flat_map(|responsive_patterns| responsive_patterns.values())
+            .flat_map(|panoramic_patterns| panoramic_patterns.values());
+
+        patterns
+            .chain(responsive_patterns)
+            .flat_map(|properties| properties.iter())
+            .map(|(property, value)| (property.as_str(), value.as_ref()))
+    }
+
+    /// Iterates over every property-value pair declared within the responsive patterns of
+    /// the class, along with the panoramic breakpoint and the pattern holding it.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of `(breakpoint, pattern, property, value)` tuples, in declaration order.
+    pub fn responsive_declarations(&self) -> impl Iterator<Item = (&str, &str, &str, &str)> {
+        self.responsive_patterns
+            .iter()
+            .flat_map(|responsive_patterns| responsive_patterns.iter())
+            .flat_map(|(breakpoint, panoramic_patterns)| {
+                panoramic_patterns
+                    .iter()
+                    .map(move |(pattern, properties)| (breakpoint, pattern, properties))
+            })
+            .flat_map(|(breakpoint, pattern, properties)| {
+                properties.iter().map(move |(property, value)| {
+                    (
+                        breakpoint.as_str(),
+                        pattern.as_str(),
+                        property.as_str(),
+                        value.as_ref(),
+                    )
+                })
+            })
+    }
+
+    /// Iterates mutably over every property-value pair declared within the class, in the
+    /// same order as `declarations`.
+    pub(crate) fn declarations_mut(&mut self) -> impl Iterator<Item = (&String, &mut Arc<str>)> {
+        self.properties_mut()
+            .flat_map(|properties| properties.iter_mut())
+    }
+
+    /// Iterates mutably over every properties map of the class, in the same order as
+    /// `declarations`.
+    pub(crate) fn properties_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut IndexMap<String, Arc<str>>> {
+        let patterns = self
+            .style_patterns
+            .iter_mut()
+            .chain(self.supports_patterns.iter_mut())
+            .chain(self.container_patterns.iter_mut())
+            .flat_map(|patterns| patterns.values_mut());
+        let responsive_patterns = self
+            .responsive_patterns
+            .iter_mut()
+            .chain(self.media_patterns.iter_mut())
+            .flat_map(|responsive_patterns| responsive_patterns.values_mut())
+            .flat_map(|panoramic_patterns| panoramic_patterns.values_mut());
+
+        patterns.chain(responsive_patterns)
+    }
+}
+
+/// Validates the identifiers given to the rename operations.
+struct RenameValidator;
+
+impl NenyrIdentifierValidator for RenameValidator {}
+
+/// Checks whether a declaration named `from` can be renamed to `to` within a map.
+///
+/// The new name must be a valid identifier not used by any other declaration, and the
+/// old name must be declared.
+pub(crate) fn can_rename<T>(declarations: &IndexMap<String, T>, from: &str, to: &str) -> bool {
+    from != to
+        && RenameValidator.is_valid_identifier(to)
+        && declarations.contains_key(from)
+        && !declarations.contains_key(to)
+}
+
+/// Renames the `from` key of a map to `to`, keeping its position.
+pub(crate) fn rename_key<T>(declarations: &mut IndexMap<String, T>, from: &str, to: &str) {
+    *declarations = declarations
+        .drain(..)
+        .map(|(name, declaration)| {
+            if name == from {
+                (to.to_string(), declaration)
+            } else {
+                (name, declaration)
+            }
+        })
+        .collect();
+}
+
+/// Replaces every whole-word occurrence of `from` in a value with `to`.
+///
+/// Words are delimited by any character other than ASCII alphanumerics, `-` and `_`,
+/// so `fadeIn` is replaced in `fadeIn 2s ease` but not in `fadeInUp 2s`.
+///
+/// # Returns
+/// - `Some(String)` with the rewritten value, if any occurrence was replaced.
+/// - `None` if the value does not reference `from`.
+pub(crate) fn replace_identifier(value: &str, from: &str, to: &str) -> Option<String> {
+    let is_word_char = |char: char| char.is_ascii_alphanumeric() || char == '-' || char == '_';
+    let mut replaced = String::with_capacity(value.len());
+    let mut word_start: Option<usize> = None;
+    let mut has_replaced = false;
+
+    for (idx, char) in value.char_indices().chain([(value.len(), ' ')]) {
+        if is_word_char(char) && idx < value.len() {
+            word_start.get_or_insert(idx);
+
+            continue;
+        }
+
+        if let Some(start) = word_start.take() {
+            if &value[start..idx] == from {
+                replaced.push_str(to);
+                has_replaced = true;
+            } else {
+                replaced.push_str(&value[start..idx]);
+            }
+        }
+
+        if idx < value.len() {
+            replaced.push(char);
+        }
+    }
+
+    has_replaced.then_some(replaced)
+}
+
+/// Renames a style class, updating the `Deriving` references of the other classes.
+///
+/// Nothing is changed if `from` is not declared, or if `to` is not a valid identifier or
+/// is already declared.
+///
+/// # Returns
+/// - The number of classes whose `Deriving` reference was updated.
+pub(crate) fn rename_class(
+    classes: &mut IndexMap<String, NenyrStyleClass>,
+    from: &str,
+    to: &str,
+) -> usize {
+    if !can_rename(classes, from, to) {
+        return 0;
+    }
+
+    rename_key(classes, from, to);
+
+    let mut updated_references = 0;
+
+    for (class_name, style_class) in classes.iter_mut() {
+        if class_name == to {
+            style_class.class_name = to.to_string();
+        }
+
+        if style_class.deriving_from.as_deref() == Some(from) {
+            style_class.deriving_from = Some(to.to_string());
+            updated_references += 1;
+        }
+    }
+
+    updated_references
+}
+
+/// Converts the style pattern tokens into the selector keys used by the classes.
+struct PatternMatcher;
+
+impl NenyrStylePatternConverter for PatternMatcher {}
+
+/// Retrieves the names of the classes defining a style for the given pattern, either
+/// directly or inside one of their `PanoramicViewer` breakpoints or media types. The
+/// `Supports` and `Container` tokens match the classes declaring at least one pattern of
+/// that kind.
+///
+/// # Parameters
+/// - `classes`: Every style class declared in the context, indexed by class name.
+/// - `pattern`: The style pattern token, e.g. `NenyrTokens::Hover`.
+///
+/// # Returns
+/// - The matching class names, in declaration order. Empty if the token is not a style pattern.
+pub(crate) fn classes_with_pattern<'a>(
+    classes: &'a IndexMap<String, NenyrStyleClass>,
+    pattern: &NenyrTokens,
+) -> Vec<&'a str> {
+    let selector = PatternMatcher.convert_nenyr_style_pattern_to_pseudo_selector(pattern);
+    let has_query_patterns = |query_patterns: &Option<IndexMap<String, _>>| {
+        query_patterns
+            .as_ref()
+            .is_some_and(|query_patterns| !query_patterns.is_empty())
+    };
+    let has_pattern = |style_class: &NenyrStyleClass, selector: &String| {
+        style_class
+            .style_patterns
+            .iter()
+            .chain(
+                style_class
+                    .responsive_patterns
+                    .iter()
+                    .chain(style_class.media_patterns.iter())
+                    .flat_map(|responsive_patterns| responsive_patterns.values()),
+            )
+            .any(|patterns| patterns.contains_key(selector))
+    };
+
+    classes
+        .iter()
+        .filter(|(_, style_class)| match (pattern, &selector) {
+            (NenyrTokens::Supports, _) => has_query_patterns(&style_class.supports_patterns),
+            (NenyrTokens::Container, _) => has_query_patterns(&style_class.container_patterns),
+            (_, Some(selector)) => has_pattern(style_class, selector),
+            (_, None) => false,
+        })
+        .map(|(class_name, _)| class_name.as_str())
+        .collect()
+}
+
+/// Computes the effective style class after resolving its `Deriving` chain.
+///
+/// The bases are resolved recursively and merged pattern by pattern, so that a property
+/// declared by the derived class replaces the same property declared by its base, while
+/// the properties only declared by the base are kept. The importance of the class is kept
+/// when set, otherwise it is inherited from the base. The resolved class no longer derives
+/// from any other class.
+///
+/// # Parameters
+/// - `class`: The style class to be resolved.
+/// - `all`: Every style class declared in the context, indexed by class name.
+///
+/// # Returns
+/// - `Ok(NenyrStyleClass)` with the fully resolved style class.
+/// - `Err(NenyrError)` if a base class is not declared in `all`, or if the derivation is cyclic.
+pub fn resolve_derived(
+    class: &NenyrStyleClass,
+    all: &IndexMap<String, NenyrStyleClass>,
+) -> NenyrResult<NenyrStyleClass> {
+    let mut visited_classes: IndexSet<String> = IndexSet::new();
+
+    resolve_derived_chain(class, all, &mut visited_classes)
+}
+
+fn resolve_derived_chain(
+    class: &NenyrStyleClass,
+    all: &IndexMap<String, NenyrStyleClass>,
+    visited_classes: &mut IndexSet<String>,
+) -> NenyrResult<NenyrStyleClass> {
+    if !visited_classes.insert(class.class_name.clone()) {
+        let derivation_chain: Vec<&str> = visited_classes
+            .iter()
+            .map(|class_name| class_name.as_str())
+            .chain(std::iter::once(class.class_name.as_str()))
+            .collect();
+
+        return Err(throw_derivation_error(
+            "Remove the `Deriving` declaration that closes the cycle, so that every class derives from a class which does not derive back from it.".to_string(),
+            format!(
+                "The `{}` class has a cyclic derivation: {}.",
+                class.class_name,
+                derivation_chain.join(" -> ")
+            ),
+        ));
+    }
+
+    let base_name = match &class.deriving_from {
+        Some(base_name) => base_name,
+        None => {
+            let mut resolved_class = class.clone();
+
+            resolved_class.deriving_from = None;
+
+            return Ok(resolved_class);
+        }
+    };
+
+    let base_class = all.get(base_name).ok_or_else(|| {
+        throw_derivation_error(
+            format!("Declare the `{}` class in the same context, or remove the `Deriving('{}')` declaration from the `{}` class.", base_name, base_name, class.class_name),
+            format!(
+                "The `{}` class derives from the `{}` class, which was not found.",
+                class.class_name, base_name
+            ),
+        )
+    })?;
+
+    let mut resolved_class = resolve_derived_chain(base_class, all, visited_classes)?;
+
+    resolved_class.class_name = class.class_name.clone();
+    resolved_class.is_important = class.is_important.or(resolved_class.is_important);
+
+    merge_patterns(&mut resolved_class.style_patterns, &class.style_patterns);
+    merge_patterns(
+        &mut resolved_class.supports_patterns,
+        &class.supports_patterns,
+    );
+    merge_patterns(
+        &mut resolved_class.container_patterns,
+        &class.container_patterns,
+    );
+
+    merge_panoramic_patterns(
+        &mut resolved_class.responsive_patterns,
+        &class.responsive_patterns,
+    );
+    merge_panoramic_patterns(&mut resolved_class.media_patterns, &class.media_patterns);
+
+    Ok(resolved_class)
+}
+
+fn merge_panoramic_patterns(
+    base_patterns: &mut Option<NenyrPanoramicPatterns>,
+    patterns: &Option<NenyrPanoramicPatterns>,
+) {
+    if let Some(patterns) = patterns {
+        let base_patterns = base_patterns.get_or_insert_with(IndexMap::new);
+
+        for (panoramic_name, panoramic_patterns) in patterns {
+            let base_panoramic_patterns =
+                base_patterns.entry(panoramic_name.to_string()).or_default();
+
+            merge_properties(base_panoramic_patterns, panoramic_patterns);
+        }
+    }
+}
+
+fn merge_patterns(
+    base_patterns: &mut Option<IndexMap<String, IndexMap<String, Arc<str>>>>,
+    patterns: &Option<IndexMap<String, IndexMap<String, Arc<str>>>>,
+) {
+    if let Some(patterns) = patterns {
+        merge_properties(base_patterns.get_or_insert_with(IndexMap::new), patterns);
+    }
+}
+
+fn merge_properties(
+    base_patterns: &mut IndexMap<String, IndexMap<String, Arc<str>>>,
+    patterns: &IndexMap<String, IndexMap<String, Arc<str>>>,
+) {
+    for (pattern_name, properties) in patterns {
+        let base_properties = base_patterns.entry(pattern_name.to_string()).or_default();
+
+        for (property, value) in properties {
+            base_properties.insert(property.to_string(), value.clone());
+        }
+    }
+}
+
+fn throw_derivation_error(suggestion: String, error_message: String) -> NenyrError {
+    NenyrError::new(
+        Some(suggestion),
+        None,
+        "".to_string(),
+        error_message,
+        NenyrErrorKind::ValidationError,
+        NenyrErrorTracing::new(None, None, None, 0, 0, 0),
+    )
+    .with_code("N0102")
 }
 
 #[cfg(test)]
@@ -198,6 +883,8 @@ mod tests {
         assert_eq!(class.deriving_from, None);
         assert_eq!(class.is_important, None);
         assert!(class.style_patterns.is_none());
+        assert!(class.supports_patterns.is_none());
+        assert!(class.container_patterns.is_none());
         assert!(class.responsive_patterns.is_none());
     }
 
@@ -226,11 +913,11 @@ mod tests {
         class.add_style_rule(
             "base-pattern".to_string(),
             "color".to_string(),
-            "red".to_string(),
+            "red".into(),
         );
 
         let mut expected_property = IndexMap::new();
-        expected_property.insert("color".to_string(), "red".to_string());
+        expected_property.insert("color".to_string(), "red".into());
 
         let mut expected_patterns = IndexMap::new();
         expected_patterns.insert("base-pattern".to_string(), expected_property);
@@ -238,6 +925,52 @@ mod tests {
         assert_eq!(class.style_patterns, Some(expected_patterns));
     }
 
+    #[test]
+    fn test_add_supports_pattern() {
+        let mut class = NenyrStyleClass::new("test-class".to_string(), None);
+
+        class.add_supports_pattern(
+            "(display: grid)".to_string(),
+            IndexMap::from([("display".to_string(), "grid".into())]),
+        );
+        class.add_supports_pattern(
+            "(display: grid)".to_string(),
+            IndexMap::from([("gap".to_string(), "1rem".into())]),
+        );
+
+        let mut expected_property = IndexMap::new();
+        expected_property.insert("display".to_string(), "grid".into());
+        expected_property.insert("gap".to_string(), "1rem".into());
+
+        let mut expected_patterns = IndexMap::new();
+        expected_patterns.insert("(display: grid)".to_string(), expected_property);
+
+        assert_eq!(class.supports_patterns, Some(expected_patterns));
+    }
+
+    #[test]
+    fn test_add_container_pattern() {
+        let mut class = NenyrStyleClass::new("test-class".to_string(), None);
+
+        class.add_container_pattern(
+            "sidebar (min-width: 400px)".to_string(),
+            IndexMap::from([("display".to_string(), "flex".into())]),
+        );
+        class.add_container_pattern(
+            "sidebar (min-width: 400px)".to_string(),
+            IndexMap::from([("gap".to_string(), "1rem".into())]),
+        );
+
+        let mut expected_property = IndexMap::new();
+        expected_property.insert("display".to_string(), "flex".into());
+        expected_property.insert("gap".to_string(), "1rem".into());
+
+        let mut expected_patterns = IndexMap::new();
+        expected_patterns.insert("sidebar (min-width: 400px)".to_string(), expected_property);
+
+        assert_eq!(class.container_patterns, Some(expected_patterns));
+    }
+
     #[test]
     fn test_reset_panoramic_node() {
         let mut class = NenyrStyleClass::new("test-class".to_string(), None);
@@ -260,11 +993,11 @@ mod tests {
             "lg".to_string(),
             "base-pattern".to_string(),
             "width".to_string(),
-            "100%".to_string(),
+            "100%".into(),
         );
 
         let mut expected_property = IndexMap::new();
-        expected_property.insert("width".to_string(), "100%".to_string());
+        expected_property.insert("width".to_string(), "100%".into());
 
         let mut expected_pattern = IndexMap::new();
         expected_pattern.insert("base-pattern".to_string(), expected_property);
@@ -277,4 +1010,141 @@ mod tests {
             Some(expected_responsive_patterns)
         );
     }
+
+    #[test]
+    fn classes_must_be_found_by_pattern_in_every_scope() {
+        let mut hovered = NenyrStyleClass::new("hovered".to_string(), None);
+        let mut printed = NenyrStyleClass::new("printed".to_string(), None);
+        let mut supported = NenyrStyleClass::new("supported".to_string(), None);
+        let mut contained = NenyrStyleClass::new("contained".to_string(), None);
+
+        hovered.add_style_rule(":hover".to_string(), "color".to_string(), "red".into());
+        printed.add_media_patterns(
+            "print".to_string(),
+            IndexMap::from([(
+                ":hover".to_string(),
+                IndexMap::from([("color".to_string(), "black".into())]),
+            )]),
+        );
+        supported.add_supports_pattern(
+            "(display: grid)".to_string(),
+            IndexMap::from([("display".to_string(), "grid".into())]),
+        );
+        contained.add_container_pattern(
+            "(min-width: 400px)".to_string(),
+            IndexMap::from([("display".to_string(), "flex".into())]),
+        );
+
+        let classes: IndexMap<String, NenyrStyleClass> = [hovered, printed, supported, contained]
+            .into_iter()
+            .map(|style_class| (style_class.class_name.clone(), style_class))
+            .collect();
+
+        assert_eq!(
+            classes_with_pattern(&classes, &NenyrTokens::Hover),
+            vec!["hovered", "printed"]
+        );
+        assert_eq!(
+            classes_with_pattern(&classes, &NenyrTokens::Supports),
+            vec!["supported"]
+        );
+        assert_eq!(
+            classes_with_pattern(&classes, &NenyrTokens::Container),
+            vec!["contained"]
+        );
+        assert!(classes_with_pattern(&classes, &NenyrTokens::Focus).is_empty());
+    }
+
+    fn derived_class(class_name: &str, deriving_from: Option<&str>) -> NenyrStyleClass {
+        NenyrStyleClass::new(
+            class_name.to_string(),
+            deriving_from.map(|base_name| base_name.to_string()),
+        )
+    }
+
+    #[test]
+    fn derived_class_must_be_merged_with_its_base() {
+        let mut base = derived_class("button", None);
+        let mut child = derived_class("dangerButton", Some("button"));
+
+        base.set_importance(true);
+        base.add_style_rule(
+            "_stylesheet".to_string(),
+            "color".to_string(),
+            "black".into(),
+        );
+        base.add_style_rule(
+            "_stylesheet".to_string(),
+            "padding".to_string(),
+            "10px".into(),
+        );
+        base.add_style_rule(":hover".to_string(), "color".to_string(), "gray".into());
+        base.add_responsive_style_rule(
+            "onMobile".to_string(),
+            "_stylesheet".to_string(),
+            "padding".to_string(),
+            "5px".into(),
+        );
+        child.add_style_rule("_stylesheet".to_string(), "color".to_string(), "red".into());
+        child.add_responsive_style_rule(
+            "onMobile".to_string(),
+            "_stylesheet".to_string(),
+            "color".to_string(),
+            "darkred".into(),
+        );
+
+        let mut all = IndexMap::new();
+
+        all.insert("button".to_string(), base);
+        all.insert("dangerButton".to_string(), child.clone());
+
+        let resolved = resolve_derived(&child, &all).unwrap();
+
+        assert_eq!(resolved.class_name, "dangerButton");
+        assert_eq!(resolved.deriving_from, None);
+        assert_eq!(resolved.is_important, Some(true));
+        assert_eq!(
+            resolved.declarations().collect::<Vec<_>>(),
+            vec![
+                ("color", "red"),
+                ("padding", "10px"),
+                ("color", "gray"),
+                ("padding", "5px"),
+                ("color", "darkred"),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_base_class_must_not_be_resolved() {
+        let child = derived_class("dangerButton", Some("button"));
+        let mut all = IndexMap::new();
+
+        all.insert("dangerButton".to_string(), child.clone());
+
+        let err = resolve_derived(&child, &all).unwrap_err();
+
+        assert_eq!(err.get_error_kind(), NenyrErrorKind::ValidationError);
+        assert_eq!(
+            err.get_error_message(),
+            "The `dangerButton` class derives from the `button` class, which was not found."
+        );
+    }
+
+    #[test]
+    fn cyclic_derivation_must_not_be_resolved() {
+        let mut all = IndexMap::new();
+
+        all.insert("first".to_string(), derived_class("first", Some("second")));
+        all.insert("second".to_string(), derived_class("second", Some("third")));
+        all.insert("third".to_string(), derived_class("third", Some("first")));
+
+        let err = resolve_derived(&all["first"], &all).unwrap_err();
+
+        assert_eq!(err.get_error_kind(), NenyrErrorKind::ValidationError);
+        assert_eq!(
+            err.get_error_message(),
+            "The `first` class has a cyclic derivation: first -> second -> third -> first."
+        );
+    }
 }
@@ -0,0 +1,131 @@
+use indexmap::IndexMap;
+
+/// A read-only view over the named animations or classes of a Nenyr context.
+///
+/// The `NenyrCollection` wraps the optional `IndexMap` stored by the contexts, hiding it from
+/// the public API. The items are yielded in declaration order, and a context without any
+/// declaration of the requested kind is seen as an empty collection.
+///
+/// ```ignore
+/// for (animation_name, animation) in central_context.animations() {
+///     // ...
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NenyrCollection<'a, T> {
+    items: Option<&'a IndexMap<String, T>>,
+}
+
+impl<'a, T> NenyrCollection<'a, T> {
+    /// Creates a new `NenyrCollection` over the given items.
+    pub(crate) fn new(items: Option<&'a IndexMap<String, T>>) -> Self {
+        Self { items }
+    }
+
+    /// Retrieves an item by its name.
+    pub fn get(&self, name: &str) -> Option<&'a T> {
+        self.items.and_then(|items| items.get(name))
+    }
+
+    /// Retrieves the number of items in the collection.
+    pub fn len(&self) -> usize {
+        self.items.map_or(0, |items| items.len())
+    }
+
+    /// Checks if the collection has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the `(name, item)` pairs of the collection in declaration order.
+    pub fn iter(&self) -> NenyrCollectionIter<'a, T> {
+        NenyrCollectionIter {
+            inner: self.items.map(|items| items.iter()),
+        }
+    }
+
+    /// Iterates over the item names of the collection in declaration order.
+    pub fn names(&self) -> impl Iterator<Item = &'a str> {
+        self.iter().map(|(name, _)| name)
+    }
+}
+
+impl<'a, T> IntoIterator for NenyrCollection<'a, T> {
+    type Item = (&'a str, &'a T);
+    type IntoIter = NenyrCollectionIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &NenyrCollection<'a, T> {
+    type Item = (&'a str, &'a T);
+    type IntoIter = NenyrCollectionIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The iterator over the `(name, item)` pairs of a `NenyrCollection`.
+#[derive(Debug, Clone)]
+pub struct NenyrCollectionIter<'a, T> {
+    inner: Option<indexmap::map::Iter<'a, String, T>>,
+}
+
+impl<'a, T> Iterator for NenyrCollectionIter<'a, T> {
+    type Item = (&'a str, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .as_mut()?
+            .next()
+            .map(|(name, item)| (name.as_str(), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner
+            .as_ref()
+            .map_or((0, Some(0)), |inner| inner.size_hint())
+    }
+}
+
+impl<T> ExactSizeIterator for NenyrCollectionIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::NenyrCollection;
+
+    #[test]
+    fn collection_must_iterate_in_declaration_order() {
+        let mut items = IndexMap::new();
+
+        items.insert("second".to_string(), 2);
+        items.insert("first".to_string(), 1);
+
+        let collection = NenyrCollection::new(Some(&items));
+
+        assert_eq!(collection.len(), 2);
+        assert_eq!(collection.get("first"), Some(&1));
+        assert_eq!(
+            collection.into_iter().collect::<Vec<_>>(),
+            vec![("second", &2), ("first", &1)]
+        );
+        assert_eq!(
+            collection.names().collect::<Vec<_>>(),
+            vec!["second", "first"]
+        );
+    }
+
+    #[test]
+    fn missing_collection_must_be_empty() {
+        let collection: NenyrCollection<'_, usize> = NenyrCollection::new(None);
+
+        assert!(collection.is_empty());
+        assert_eq!(collection.get("first"), None);
+        assert_eq!(collection.iter().next(), None);
+    }
+}
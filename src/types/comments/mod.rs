@@ -0,0 +1,96 @@
+use indexmap::IndexMap;
+
+/// Holds the comments found in a Nenyr context, collected aside from the AST so that the
+/// formatter can write them back.
+///
+/// Each comment keeps its markers, such as `// comment` or `/* comment */`. The comments
+/// found before or inside a declaration are attached to that declaration, so the comments
+/// inside a declaration are written back before it.
+///
+/// # Fields
+/// - `leading`: The comments found before the `Construct` keyword.
+/// - `declarations`: The comments attached to each declaration, keyed by the declared method
+///   followed by the declared name, if any, e.g. `Aliases` or `Class('button')`.
+/// - `trailing`: The comments found after the last declaration.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NenyrComments {
+    pub leading: Vec<String>,
+    pub declarations: IndexMap<String, Vec<String>>,
+    pub trailing: Vec<String>,
+}
+
+impl NenyrComments {
+    /// Creates an empty `NenyrComments`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches comments to a declaration, after the ones already attached to it.
+    ///
+    /// # Parameters
+    /// - `declaration_key`: The key of the declaration, built by `declaration_key`.
+    /// - `comments`: The comments found before or inside the declaration.
+    pub(crate) fn add_declaration_comments(
+        &mut self,
+        declaration_key: String,
+        comments: Vec<String>,
+    ) {
+        self.declarations
+            .entry(declaration_key)
+            .or_default()
+            .extend(comments);
+    }
+
+    /// Retrieves the comments attached to a declaration.
+    ///
+    /// # Parameters
+    /// - `method`: The declared method, such as `Aliases` or `Class`.
+    /// - `name`: The declared name, for the mixins, animations and classes.
+    ///
+    /// # Returns
+    /// The comments attached to the declaration, or an empty slice when there are none.
+    pub fn get_declaration_comments(&self, method: &str, name: Option<&str>) -> &[String] {
+        self.declarations
+            .get(&declaration_key(method, name))
+            .map(|comments| comments.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Builds the key of a declaration, such as `Aliases` or `Class('button')`.
+///
+/// # Parameters
+/// - `method`: The declared method. The `Keyframes` declarations use the `Animation` method.
+/// - `name`: The declared name, for the mixins, animations and classes.
+pub(crate) fn declaration_key(method: &str, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{}('{}')", method, name),
+        None => method.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NenyrComments;
+
+    #[test]
+    fn declaration_comments_must_be_appended() {
+        let mut comments = NenyrComments::new();
+
+        comments
+            .add_declaration_comments("Class('button')".to_string(), vec!["// first".to_string()]);
+        comments.add_declaration_comments(
+            "Class('button')".to_string(),
+            vec!["/* second */".to_string()],
+        );
+
+        assert_eq!(
+            comments.get_declaration_comments("Class", Some("button")),
+            ["// first", "/* second */"]
+        );
+        assert!(comments
+            .get_declaration_comments("Aliases", None)
+            .is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+use indexmap::IndexMap;
+
+/// `NenyrConsts` represents the constants declared through `Declare Const({ ... })` in the central
+/// context. Unlike `NenyrVariables`, constants are not themeable: they cannot be overridden by
+/// themes or by the contexts extending the central one, and each name can only be declared once.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NenyrConsts {
+    /// Holds the mapping of constant identifiers to their values, preserving insertion order.
+    pub values: IndexMap<String, String>,
+}
+
+impl NenyrConsts {
+    /// Creates a new, empty `NenyrConsts` instance.
+    ///
+    /// # Returns
+    /// - A new instance of `NenyrConsts` with an empty `values` map.
+    pub fn new() -> Self {
+        Self {
+            values: IndexMap::new(),
+        }
+    }
+
+    /// Adds a constant to the `NenyrConsts` map, unless the identifier was already declared.
+    ///
+    /// # Parameters
+    /// - `identifier`: A `String` that uniquely represents the constant name.
+    /// - `value`: A `String` containing the value to be assigned to the constant.
+    ///
+    /// # Returns
+    /// - `true` if the constant was added.
+    /// - `false` if a constant with the same identifier already exists, in which case its value
+    ///   is kept untouched.
+    pub(crate) fn add_const(&mut self, identifier: String, value: String) -> bool {
+        if self.values.contains_key(&identifier) {
+            return false;
+        }
+
+        self.values.insert(identifier, value);
+
+        true
+    }
+}
+
+impl Default for NenyrConsts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NenyrConsts;
+
+    #[test]
+    fn consts_cannot_be_redeclared() {
+        let mut consts = NenyrConsts::new();
+
+        assert!(consts.add_const("siteWidth".to_string(), "1200px".to_string()));
+        assert!(!consts.add_const("siteWidth".to_string(), "960px".to_string()));
+        assert_eq!(consts.values.get("siteWidth"), Some(&"1200px".to_string()));
+    }
+}
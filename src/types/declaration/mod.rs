@@ -0,0 +1,85 @@
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+use super::{
+    aliases::NenyrAliases, animations::NenyrAnimation, breakpoints::NenyrBreakpoints,
+    class::NenyrStyleClass, comments::declaration_key, consts::NenyrConsts, imports::NenyrImports,
+    themes::NenyrThemes, typefaces::NenyrTypefaces, variables::NenyrVariables,
+};
+
+/// Represents a single `Declare` block parsed from a Nenyr context.
+///
+/// Each variant holds the result of one declaration, exactly as it would be stored in the
+/// context it belongs to. Declarations are handed to the declaration sink registered through
+/// `NenyrParser::with_declaration_sink` as soon as their block finishes parsing, allowing
+/// large contexts to be consumed without holding the whole AST in memory.
+///
+/// The themes and classes are boxed, as they are far larger than the other declarations.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NenyrDeclaration {
+    /// A `Declare Imports([ ... ])` block.
+    Imports(NenyrImports),
+    /// A `Declare Typefaces({ ... })` block.
+    Typefaces(NenyrTypefaces),
+    /// A `Declare Breakpoints({ ... })` block.
+    Breakpoints(NenyrBreakpoints),
+    /// A `Declare Aliases({ ... })` block.
+    Aliases(NenyrAliases),
+    /// A `Declare Variables({ ... })` block.
+    Variables(NenyrVariables),
+    /// A `Declare Const({ ... })` block.
+    Consts(NenyrConsts),
+    /// A `Declare Themes({ ... })` block.
+    Themes(Box<NenyrThemes>),
+    /// A `Declare Mixin('name') { ... }` block, along with the mixin name.
+    Mixin(String, IndexMap<String, Arc<str>>),
+    /// A `Declare Animation('name') { ... }` block, along with the animation name.
+    Animation(String, NenyrAnimation),
+    /// A `Declare Class('name') { ... }` block, along with the class name.
+    Class(String, Box<NenyrStyleClass>),
+}
+
+impl NenyrDeclaration {
+    /// Builds the key the comments of the declaration are attached to in `NenyrComments`.
+    pub(crate) fn comment_key(&self) -> String {
+        match self {
+            NenyrDeclaration::Imports(_) => declaration_key("Imports", None),
+            NenyrDeclaration::Typefaces(_) => declaration_key("Typefaces", None),
+            NenyrDeclaration::Breakpoints(_) => declaration_key("Breakpoints", None),
+            NenyrDeclaration::Aliases(_) => declaration_key("Aliases", None),
+            NenyrDeclaration::Variables(_) => declaration_key("Variables", None),
+            NenyrDeclaration::Consts(_) => declaration_key("Const", None),
+            NenyrDeclaration::Themes(_) => declaration_key("Themes", None),
+            NenyrDeclaration::Mixin(name, _) => declaration_key("Mixin", Some(name)),
+            NenyrDeclaration::Animation(name, _) => declaration_key("Animation", Some(name)),
+            NenyrDeclaration::Class(name, _) => declaration_key("Class", Some(name)),
+        }
+    }
+}
+
+/// Holds the number of declarations of each kind found in a context, such as the number of
+/// classes or variables, e.g. to feed dashboards and metrics.
+///
+/// The kinds a context cannot declare, such as the imports of a layout context, are always
+/// counted as zero.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeclarationCounts {
+    /// The number of style classes.
+    pub classes: usize,
+    /// The number of animations.
+    pub animations: usize,
+    /// The number of variables.
+    pub variables: usize,
+    /// The number of aliases.
+    pub aliases: usize,
+    /// The number of themes, `Light` and `Dark`, declared.
+    pub themes: usize,
+    /// The number of imports.
+    pub imports: usize,
+    /// The number of typefaces.
+    pub typefaces: usize,
+    /// The number of `MobileFirst` and `DesktopFirst` breakpoints.
+    pub breakpoints: usize,
+}
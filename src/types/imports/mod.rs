@@ -5,10 +5,13 @@ use indexmap::IndexMap;
 /// or other external CSS resources necessary for the styling of an application.
 /// By using a `IndexMap`, `NenyrImports` ensures that each import is unique, preventing duplicate entries.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrImports {
     /// A collection of unique import statements used within an application. This `IndexMap` holds
-    /// external resource URLs or paths, ensuring each import is stored only once.
-    pub values: IndexMap<String, ()>,
+    /// external resource URLs or paths, ensuring each import is stored only once, along with the
+    /// name of the cascade layer assigned through `Layer('name')`, if any. The declaration order
+    /// of the imports is preserved.
+    pub values: IndexMap<String, Option<String>>,
 }
 
 impl NenyrImports {
@@ -29,8 +32,60 @@ impl NenyrImports {
     ///
     /// # Parameters
     /// - `value`: A `String` representing the external CSS resource URL or path to be added to the import set.
-    pub(crate) fn add_import(&mut self, value: String) {
-        self.values.insert(value, ());
+    /// - `layer`: The name of the cascade layer the import belongs to, if any.
+    pub(crate) fn add_import(&mut self, value: String, layer: Option<String>) {
+        self.values.insert(value, layer);
+    }
+
+    /// Resolves the relative import paths against the given base directory, in declaration order.
+    ///
+    /// The paths are joined and their `.` and `..` segments collapsed lexically, without
+    /// touching the file system. URLs such as `https://...` and absolute paths are kept as they are.
+    ///
+    /// # Parameters
+    /// - `base_path`: The directory the relative imports are relative to, usually the directory
+    ///   of the parsed context.
+    ///
+    /// # Returns
+    /// - A `Vec<String>` holding every import, with the relative paths resolved.
+    pub fn resolve_against(&self, base_path: &str) -> Vec<String> {
+        self.values
+            .keys()
+            .map(
+                |import| match import.contains("://") || import.starts_with('/') {
+                    true => import.clone(),
+                    false => join_path(base_path, import),
+                },
+            )
+            .collect()
+    }
+}
+
+/// Joins a relative path to a base directory, collapsing the `.` and `..` segments.
+///
+/// The `..` segments never climb above the root of an absolute base, while the ones
+/// climbing above a relative base are kept.
+fn join_path(base_path: &str, relative_path: &str) -> String {
+    let is_absolute = base_path.starts_with('/');
+    let mut segments: Vec<&str> = vec![];
+
+    for segment in base_path.split('/').chain(relative_path.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => match segments.last() {
+                Some(&last) if last != ".." => {
+                    segments.pop();
+                }
+                _ if is_absolute => {}
+                _ => segments.push(segment),
+            },
+            _ => segments.push(segment),
+        }
+    }
+
+    match is_absolute {
+        true => format!("/{}", segments.join("/")),
+        false => segments.join("/"),
     }
 }
 
@@ -51,7 +106,7 @@ mod tests {
         fn test_add_imports_inserts_new_typeface() {
             let mut imports = NenyrImports::new();
 
-            imports.add_import("../../mocks/imports/another_external.css".to_string());
+            imports.add_import("../../mocks/imports/another_external.css".to_string(), None);
             assert_eq!(
                 imports
                     .values
@@ -61,6 +116,7 @@ mod tests {
 
             imports.add_import(
                 "https://fonts.googleapis.com/css2?family=Matemasie&display=swap".to_string(),
+                Some("base".to_string()),
             );
             assert_eq!(
                 imports.values.contains_key(
@@ -68,6 +124,40 @@ mod tests {
                 ),
                 true
             );
+            assert_eq!(
+                imports
+                    .values
+                    .get("https://fonts.googleapis.com/css2?family=Matemasie&display=swap"),
+                Some(&Some("base".to_string()))
+            );
+        }
+
+        #[test]
+        fn relative_imports_are_resolved_against_the_base_path() {
+            let mut imports = NenyrImports::new();
+
+            imports.add_import("../imports/styles.css".to_string(), None);
+            imports.add_import(
+                "https://fonts.googleapis.com/css2?family=Matemasie&display=swap".to_string(),
+                None,
+            );
+            imports.add_import("./reset.css".to_string(), None);
+            imports.add_import("/assets/theme.css".to_string(), None);
+
+            assert_eq!(
+                imports.resolve_against("mocks/nenyr/"),
+                vec![
+                    "mocks/imports/styles.css".to_string(),
+                    "https://fonts.googleapis.com/css2?family=Matemasie&display=swap".to_string(),
+                    "mocks/nenyr/reset.css".to_string(),
+                    "/assets/theme.css".to_string(),
+                ]
+            );
+            assert_eq!(
+                imports.resolve_against("/srv/app")[0],
+                "/srv/imports/styles.css"
+            );
+            assert_eq!(imports.resolve_against("")[0], "../imports/styles.css");
         }
     }
 }
@@ -1,14 +1,23 @@
 use indexmap::IndexMap;
+use std::sync::Arc;
+
+use crate::tokens::NenyrTokens;
 
 use super::{
-    aliases::NenyrAliases, animations::NenyrAnimation, class::NenyrStyleClass, themes::NenyrThemes,
+    aliases::NenyrAliases,
+    animations::{rename_animation, unused_animations, NenyrAnimation},
+    class::{classes_with_pattern, rename_class, NenyrStyleClass},
+    collection::NenyrCollection,
+    declaration::{DeclarationCounts, NenyrDeclaration},
+    mixins::NenyrMixins,
+    themes::NenyrThemes,
     variables::NenyrVariables,
 };
 
 /// Represents the context for a layout within the Nenyr framework.
 ///
 /// The `LayoutContext` struct encapsulates all the styling elements relevant to a specific layout,
-/// such as aliases, variables, themes, animations, and style classes. It allows for the dynamic
+/// such as aliases, variables, themes, mixins, animations, and style classes. It allows for the dynamic
 /// addition of styles and related properties as needed.
 ///
 /// # Fields
@@ -16,14 +25,17 @@ use super::{
 /// - `aliases`: An optional collection of style aliases specific to this layout context.
 /// - `variables`: An optional collection of style variables specific to this layout context.
 /// - `themes`: An optional collection of themes associated with this layout context.
+/// - `mixins`: An optional collection of the reusable property bundles of this layout context.
 /// - `animations`: An optional `IndexMap` that maps animation names to `NenyrAnimation` instances.
 /// - `classes`: An optional `IndexMap` that maps class names to `NenyrStyleClass` instances.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LayoutContext {
     pub layout_name: String,
     pub aliases: Option<NenyrAliases>,
     pub variables: Option<NenyrVariables>,
     pub themes: Option<NenyrThemes>,
+    pub mixins: Option<NenyrMixins>,
     pub animations: Option<IndexMap<String, NenyrAnimation>>,
     pub classes: Option<IndexMap<String, NenyrStyleClass>>,
 }
@@ -43,6 +55,7 @@ impl LayoutContext {
             aliases: None,
             variables: None,
             themes: None,
+            mixins: None,
             animations: None,
             classes: None,
         }
@@ -70,6 +83,25 @@ impl LayoutContext {
         }
     }
 
+    /// Adds a mixin to the layout context.
+    ///
+    /// This method inserts the properties of a mixin into the `mixins`
+    /// field, creating the `NenyrMixins` if it does not already exist.
+    ///
+    /// # Parameters
+    ///
+    /// - `mixin_name`: A `String` representing the name of the mixin.
+    /// - `properties`: The properties declared by the mixin.
+    pub(crate) fn add_mixin_to_context(
+        &mut self,
+        mixin_name: String,
+        properties: IndexMap<String, Arc<str>>,
+    ) {
+        self.mixins
+            .get_or_insert_with(NenyrMixins::new)
+            .add_mixin(mixin_name, properties);
+    }
+
     /// Adds an animation to the layout context.
     ///
     /// This method will insert a `NenyrAnimation` into the `animations` field,
@@ -121,6 +153,203 @@ impl LayoutContext {
     pub(crate) fn add_themes_to_context(&mut self, themes: NenyrThemes) {
         self.themes = Some(themes);
     }
+
+    /// Adds a parsed declaration to the context.
+    ///
+    /// This method dispatches the declaration to the matching `add_*_to_context` method.
+    ///
+    /// # Parameters
+    ///
+    /// - `declaration`: The `NenyrDeclaration` to be added to the context.
+    pub(crate) fn add_declaration_to_context(&mut self, declaration: NenyrDeclaration) {
+        match declaration {
+            NenyrDeclaration::Aliases(aliases) => self.add_aliases_to_context(aliases),
+            NenyrDeclaration::Variables(variables) => self.add_variables_to_context(variables),
+            NenyrDeclaration::Themes(themes) => self.add_themes_to_context(*themes),
+            NenyrDeclaration::Mixin(mixin_name, properties) => {
+                self.add_mixin_to_context(mixin_name, properties)
+            }
+            NenyrDeclaration::Animation(animation_name, animation) => {
+                self.add_animation_to_context(animation_name, animation)
+            }
+            NenyrDeclaration::Class(class_name, style_class) => {
+                self.add_style_class_to_context(class_name, *style_class)
+            }
+            // The parser only accepts the declarations supported by the layout context.
+            _ => {}
+        }
+    }
+
+    /// Iterates over every property-value pair declared in the animations and classes of the context.
+    ///
+    /// Animation keyframes are flattened first, followed by the class style patterns, `Supports`
+    /// and `Container` patterns and responsive patterns. It is meant for audits, such as finding
+    /// every `outline: none` declaration.
+    ///
+    /// # Returns
+    /// An iterator of `(owner, property, value)` tuples, where the owner is the name of the
+    /// animation or class declaring the pair.
+    pub fn all_declarations(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+        let animations = self
+            .animations
+            .iter()
+            .flatten()
+            .flat_map(|(name, animation)| {
+                animation
+                    .declarations()
+                    .map(move |(property, value)| (name.as_str(), property, value))
+            });
+        let classes = self
+            .classes
+            .iter()
+            .flatten()
+            .flat_map(|(name, style_class)| {
+                style_class
+                    .declarations()
+                    .map(move |(property, value)| (name.as_str(), property, value))
+            });
+
+        animations.chain(classes)
+    }
+
+    /// Retrieves the aliases declared in the context that are never used by its classes
+    /// or animations.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` with the unused alias identifiers, in declaration order.
+    pub fn unused_aliases(&self) -> Vec<String> {
+        match &self.aliases {
+            Some(aliases) => {
+                aliases.unused_aliases(self.all_declarations().map(|(_, property, _)| property))
+            }
+            None => vec![],
+        }
+    }
+
+    /// Retrieves the animations declared in the context that are never referenced by the
+    /// `animation` or `animation-name` values of its classes.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` with the unused animation names, in declaration order.
+    pub fn unused_animations(&self) -> Vec<String> {
+        match &self.animations {
+            Some(animations) => unused_animations(
+                animations,
+                self.classes()
+                    .iter()
+                    .flat_map(|(_, style_class)| style_class.declarations()),
+            ),
+            None => vec![],
+        }
+    }
+
+    /// Renames an animation declared in the context, updating the `animation` and
+    /// `animation-name` values of the classes referencing it.
+    ///
+    /// Nothing is changed if `from` is not declared, or if `to` is not a valid identifier
+    /// or is already declared by another animation.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: The current name of the animation.
+    /// - `to`: The new name of the animation.
+    ///
+    /// # Returns
+    ///
+    /// The number of property values updated to reference the new name.
+    pub fn rename_animation(&mut self, from: &str, to: &str) -> usize {
+        match &mut self.animations {
+            Some(animations) => rename_animation(
+                animations,
+                self.classes.as_mut(),
+                self.aliases.as_ref(),
+                from,
+                to,
+            ),
+            None => 0,
+        }
+    }
+
+    /// Renames a style class declared in the context, updating the `Deriving` references
+    /// of the other classes.
+    ///
+    /// Nothing is changed if `from` is not declared, or if `to` is not a valid identifier
+    /// or is already declared by another class.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: The current name of the class.
+    /// - `to`: The new name of the class.
+    ///
+    /// # Returns
+    ///
+    /// The number of classes updated to derive from the new name.
+    pub fn rename_class(&mut self, from: &str, to: &str) -> usize {
+        match &mut self.classes {
+            Some(classes) => rename_class(classes, from, to),
+            None => 0,
+        }
+    }
+
+    /// Retrieves the classes defining a style for the given pattern, such as the classes
+    /// declaring a `Hover` pattern, either directly or inside a `PanoramicViewer` breakpoint
+    /// or media type. The `Supports` and `Container` tokens match the classes declaring
+    /// these patterns.
+    ///
+    /// # Parameters
+    ///
+    /// - `pattern`: The style pattern token, e.g. `NenyrTokens::Hover`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<&str>` with the matching class names, in declaration order.
+    pub fn classes_with_pattern(&self, pattern: &NenyrTokens) -> Vec<&str> {
+        match &self.classes {
+            Some(classes) => classes_with_pattern(classes, pattern),
+            None => vec![],
+        }
+    }
+
+    /// Counts the declarations of each kind found in the context.
+    ///
+    /// # Returns
+    ///
+    /// A `DeclarationCounts` with the number of classes, animations, variables, aliases,
+    /// themes, imports, typefaces and breakpoints declared.
+    pub fn declaration_counts(&self) -> DeclarationCounts {
+        DeclarationCounts {
+            classes: self.classes.as_ref().map_or(0, |classes| classes.len()),
+            animations: self
+                .animations
+                .as_ref()
+                .map_or(0, |animations| animations.len()),
+            variables: self
+                .variables
+                .as_ref()
+                .map_or(0, |variables| variables.values.len()),
+            aliases: self
+                .aliases
+                .as_ref()
+                .map_or(0, |aliases| aliases.values.len()),
+            themes: self
+                .themes
+                .as_ref()
+                .map_or(0, |themes| themes.count_themes()),
+            ..Default::default()
+        }
+    }
+
+    /// Retrieves the animations declared in the context, in declaration order.
+    pub fn animations(&self) -> NenyrCollection<'_, NenyrAnimation> {
+        NenyrCollection::new(self.animations.as_ref())
+    }
+
+    /// Retrieves the classes declared in the context, in declaration order.
+    pub fn classes(&self) -> NenyrCollection<'_, NenyrStyleClass> {
+        NenyrCollection::new(self.classes.as_ref())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,58 @@
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+/// `NenyrMixins` stores the reusable property bundles declared through `Declare Mixin('name') { ... }`.
+/// Each mixin maps its name to the properties it declares, which are expanded into the stylesheet of
+/// every class including it through the `Include('name')` pattern.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NenyrMixins {
+    /// Holds the mapping of mixin names to their properties, preserving insertion order.
+    pub values: IndexMap<String, IndexMap<String, Arc<str>>>,
+}
+
+impl NenyrMixins {
+    /// Creates a new, empty `NenyrMixins` instance.
+    ///
+    /// # Returns
+    /// - A new instance of `NenyrMixins` with an empty `values` map.
+    pub fn new() -> Self {
+        Self {
+            values: IndexMap::new(),
+        }
+    }
+
+    /// Adds a mixin to the `NenyrMixins` map. If the mixin name already exists, its
+    /// properties are replaced with the new ones provided.
+    ///
+    /// # Parameters
+    /// - `mixin_name`: A `String` that uniquely represents the mixin name.
+    /// - `properties`: The properties declared by the mixin.
+    pub(crate) fn add_mixin(&mut self, mixin_name: String, properties: IndexMap<String, Arc<str>>) {
+        self.values.insert(mixin_name, properties);
+    }
+}
+
+impl Default for NenyrMixins {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use std::sync::Arc;
+
+    use super::NenyrMixins;
+
+    #[test]
+    fn mixins_are_stored_by_name() {
+        let mut mixins = NenyrMixins::new();
+        let properties = IndexMap::from([("display".to_string(), Arc::from("flex"))]);
+
+        mixins.add_mixin("flexCenter".to_string(), properties.clone());
+
+        assert_eq!(mixins.values.get("flexCenter"), Some(&properties));
+    }
+}
@@ -1,4 +1,4 @@
-use super::variables::NenyrVariables;
+use super::{aliases::NenyrAliases, typefaces::NenyrTypefaces, variables::NenyrVariables};
 
 /// Enum representing different theme kinds in the Nenyr styling framework.
 ///
@@ -10,6 +10,7 @@ use super::variables::NenyrVariables;
 /// - `Light`: Represents a light theme configuration.
 /// - `Dark`: Represents a dark theme configuration.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NenyrThemesKind {
     Light,
     Dark,
@@ -27,10 +28,25 @@ pub enum NenyrThemesKind {
 ///   specific to the light theme. Defaults to `None` until set.
 /// - `dark_schema`: An optional `NenyrVariables` struct containing variables
 ///   specific to the dark theme. Defaults to `None` until set.
+/// - `light_aliases` and `dark_aliases`: The optional aliases scoped to each theme,
+///   declared through `Aliases({ ... })` alongside the theme variables. They can be used as
+///   properties by the classes, and must refer to the same property as the context alias of
+///   the same name, if any. As the property of an alias cannot depend on the active theme,
+///   they are not used to resolve the aliases of the classes, which only rely on the context
+///   aliases.
+/// - `light_typefaces` and `dark_typefaces`: The optional typefaces scoped to each theme,
+///   declared through `Typefaces({ ... })`, e.g. to use different fonts per theme. They are
+///   validated like the context typefaces, but are otherwise kept as opaque data for the
+///   consumers of the AST.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrThemes {
     pub light_schema: Option<NenyrVariables>,
     pub dark_schema: Option<NenyrVariables>,
+    pub light_aliases: Option<NenyrAliases>,
+    pub dark_aliases: Option<NenyrAliases>,
+    pub light_typefaces: Option<NenyrTypefaces>,
+    pub dark_typefaces: Option<NenyrTypefaces>,
 }
 
 impl NenyrThemes {
@@ -46,6 +62,10 @@ impl NenyrThemes {
         Self {
             light_schema: None,
             dark_schema: None,
+            light_aliases: None,
+            dark_aliases: None,
+            light_typefaces: None,
+            dark_typefaces: None,
         }
     }
 
@@ -75,6 +95,51 @@ impl NenyrThemes {
             }
         }
     }
+
+    /// Adds theme-specific aliases to the appropriate theme, replacing the ones
+    /// previously declared for it.
+    ///
+    /// # Parameters
+    /// - `schema_kind`: A reference to `NenyrThemesKind` indicating whether the
+    ///   aliases apply to the light or dark theme.
+    /// - `aliases`: The `NenyrAliases` scoped to the specified theme.
+    pub(crate) fn add_aliases(&mut self, schema_kind: &NenyrThemesKind, aliases: NenyrAliases) {
+        match schema_kind {
+            NenyrThemesKind::Light => self.light_aliases = Some(aliases),
+            NenyrThemesKind::Dark => self.dark_aliases = Some(aliases),
+        }
+    }
+
+    /// Adds theme-specific typefaces to the appropriate theme, replacing the ones
+    /// previously declared for it.
+    ///
+    /// # Parameters
+    /// - `schema_kind`: A reference to `NenyrThemesKind` indicating whether the
+    ///   typefaces apply to the light or dark theme.
+    /// - `typefaces`: The `NenyrTypefaces` scoped to the specified theme.
+    pub(crate) fn add_typefaces(
+        &mut self,
+        schema_kind: &NenyrThemesKind,
+        typefaces: NenyrTypefaces,
+    ) {
+        match schema_kind {
+            NenyrThemesKind::Light => self.light_typefaces = Some(typefaces),
+            NenyrThemesKind::Dark => self.dark_typefaces = Some(typefaces),
+        }
+    }
+
+    /// Counts the themes declared, i.e. `Light` and `Dark` when they declare variables,
+    /// aliases or typefaces.
+    pub(crate) fn count_themes(&self) -> usize {
+        let is_light_declared = self.light_schema.is_some()
+            || self.light_aliases.is_some()
+            || self.light_typefaces.is_some();
+        let is_dark_declared = self.dark_schema.is_some()
+            || self.dark_aliases.is_some()
+            || self.dark_typefaces.is_some();
+
+        usize::from(is_light_declared) + usize::from(is_dark_declared)
+    }
 }
 
 #[cfg(test)]
@@ -88,6 +153,8 @@ mod tests {
         // Assert that both schemas are None when a new instance is created
         assert_eq!(themes.light_schema, None);
         assert_eq!(themes.dark_schema, None);
+        assert_eq!(themes.light_aliases, None);
+        assert_eq!(themes.dark_typefaces, None);
     }
 
     #[test]
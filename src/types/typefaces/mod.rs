@@ -6,6 +6,7 @@ use indexmap::IndexMap;
 /// the Nenyr context of Galadriel CSS to easily reference specific fonts, facilitating efficient styling and
 /// consistent typography across the application.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrTypefaces {
     /// A mapping of typeface identifiers to their respective font-family definitions, maintaining
     /// the order of insertion.
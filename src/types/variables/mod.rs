@@ -1,13 +1,193 @@
 use indexmap::IndexMap;
+use std::fmt;
+
+/// `NenyrDimension` is the typed form of an unquoted `number+unit` variable value, such as `8px`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NenyrDimension {
+    /// The numeric part of the value.
+    pub value: f64,
+    /// The CSS unit following the number, such as `px` or `%`.
+    pub unit: String,
+}
+
+impl fmt::Display for NenyrDimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+/// `NenyrOperator` is one of the arithmetic operators allowed in a computed variable value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NenyrOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl NenyrOperator {
+    /// Retrieves the binding power of the operator, multiplication and division binding
+    /// tighter than addition and subtraction.
+    fn precedence(&self) -> u8 {
+        match self {
+            NenyrOperator::Add | NenyrOperator::Subtract => 1,
+            NenyrOperator::Multiply | NenyrOperator::Divide => 2,
+        }
+    }
+}
+
+impl fmt::Display for NenyrOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self {
+            NenyrOperator::Add => "+",
+            NenyrOperator::Subtract => "-",
+            NenyrOperator::Multiply => "*",
+            NenyrOperator::Divide => "/",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
+
+/// `NenyrExpression` is the parsed form of a computed variable value, such as
+/// `calc('${spacing}' * 2)`, which applies arithmetic over numbers, dimensions and
+/// references to other variables.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NenyrExpression {
+    /// A unitless number, such as `2`.
+    Number(f64),
+    /// A `number+unit` value, such as `8px`.
+    Dimension(NenyrDimension),
+    /// A reference to another variable, holding the variable name.
+    Reference(String),
+    /// An arithmetic operation between two expressions.
+    Binary {
+        operator: NenyrOperator,
+        left: Box<NenyrExpression>,
+        right: Box<NenyrExpression>,
+    },
+}
+
+impl NenyrExpression {
+    /// Writes the expression, wrapping the operands that would otherwise be evaluated in a
+    /// different order in parentheses. References are written by the given closure.
+    fn write_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        write_reference: fn(&mut fmt::Formatter<'_>, &str) -> fmt::Result,
+    ) -> fmt::Result {
+        match self {
+            NenyrExpression::Number(value) => write!(f, "{}", value),
+            NenyrExpression::Dimension(dimension) => write!(f, "{}", dimension),
+            NenyrExpression::Reference(name) => write_reference(f, name),
+            NenyrExpression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let needs_parentheses = |operand: &NenyrExpression, is_right: bool| match operand {
+                    NenyrExpression::Binary {
+                        operator: inner, ..
+                    } => {
+                        inner.precedence() < operator.precedence()
+                            || (is_right
+                                && inner.precedence() == operator.precedence()
+                                && matches!(
+                                    operator,
+                                    NenyrOperator::Subtract | NenyrOperator::Divide
+                                ))
+                    }
+                    _ => false,
+                };
+
+                for (operand, is_right) in [(left, false), (right, true)] {
+                    if is_right {
+                        write!(f, " {} ", operator)?;
+                    }
+
+                    if needs_parentheses(operand, is_right) {
+                        write!(f, "(")?;
+                        operand.write_with(f, write_reference)?;
+                        write!(f, ")")?;
+                    } else {
+                        operand.write_with(f, write_reference)?;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Retrieves the Nenyr source form of the expression, e.g. `calc("${spacing}" * 2)`,
+    /// as written in a `Variables` declaration.
+    pub fn to_nenyr(&self) -> String {
+        struct NenyrForm<'a>(&'a NenyrExpression);
+
+        impl fmt::Display for NenyrForm<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "calc(")?;
+                self.0
+                    .write_with(f, |f, name| write!(f, "\"${{{}}}\"", name))?;
+                write!(f, ")")
+            }
+        }
+
+        NenyrForm(self).to_string()
+    }
+}
+
+/// Writes the textual form of the expression, e.g. `calc(${spacing} * 2)`, which is the
+/// value stored for the variable.
+impl fmt::Display for NenyrExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "calc(")?;
+        self.write_with(f, |f, name| write!(f, "${{{}}}", name))?;
+        write!(f, ")")
+    }
+}
+
+/// `NenyrVariableValue` is the value assigned to a Nenyr variable, either a single value or
+/// the elements of an array literal.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum NenyrVariableValue {
+    /// A single value, such as `#FF6677` or `8px`.
+    Text(String),
+    /// The elements of a variable declared as an array literal, such as a stack of shadows.
+    List(Vec<String>),
+}
+
+/// Writes the value as emitted in CSS, the elements of a list being joined by commas,
+/// e.g. `0 1px 2px #000, 0 2px 4px #333`.
+impl fmt::Display for NenyrVariableValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NenyrVariableValue::Text(value) => write!(f, "{}", value),
+            NenyrVariableValue::List(elements) => write!(f, "{}", elements.join(", ")),
+        }
+    }
+}
 
 /// `NenyrVariables` represents a collection of key-value pairs where each key is a variable identifier,
 /// and each value is the associated variable's string representation. This struct is utilized within the
 /// Nenyr context of Galadriel Nenyr to store and manage Nenyr variables, offering efficient retrieval and
 /// modification of stored values.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NenyrVariables {
     /// Holds the mapping of variable identifiers to their values, preserving insertion order.
-    pub values: IndexMap<String, String>,
+    pub values: IndexMap<String, NenyrVariableValue>,
+    /// Holds the typed form of the variables declared as unquoted `number+unit` values. Their
+    /// string representation is still stored in `values`.
+    pub dimensions: Option<IndexMap<String, NenyrDimension>>,
+    /// Holds the parsed expressions of the variables declared as computed values, such as
+    /// `calc('${spacing}' * 2)`. Their textual form is still stored in `values`.
+    pub expressions: Option<IndexMap<String, NenyrExpression>>,
 }
 
 impl NenyrVariables {
@@ -20,6 +200,8 @@ impl NenyrVariables {
     pub fn new() -> Self {
         Self {
             values: IndexMap::new(),
+            dimensions: None,
+            expressions: None,
         }
     }
 
@@ -29,13 +211,81 @@ impl NenyrVariables {
     /// - `identifier`: A `String` that uniquely represents the variable name.
     /// - `value`: A `String` containing the value to be assigned to the variable.
     pub(crate) fn add_variable(&mut self, identifier: String, value: String) {
-        self.values.insert(identifier, value);
+        self.remove_typed_forms(&identifier);
+        self.values
+            .insert(identifier, NenyrVariableValue::Text(value));
+    }
+
+    /// Adds a variable declared as an unquoted `number+unit` value, keeping both its typed
+    /// form and its string representation.
+    ///
+    /// # Parameters
+    /// - `identifier`: A `String` that uniquely represents the variable name.
+    /// - `dimension`: The `NenyrDimension` assigned to the variable.
+    pub(crate) fn add_dimension_variable(&mut self, identifier: String, dimension: NenyrDimension) {
+        self.remove_typed_forms(&identifier);
+        self.values.insert(
+            identifier.clone(),
+            NenyrVariableValue::Text(dimension.to_string()),
+        );
+
+        if self.dimensions.is_none() {
+            self.dimensions = Some(IndexMap::new());
+        }
+
+        if let Some(dimensions) = &mut self.dimensions {
+            dimensions.insert(identifier, dimension);
+        }
+    }
+
+    /// Adds a variable declared as an array literal, keeping its elements.
+    ///
+    /// # Parameters
+    /// - `identifier`: A `String` that uniquely represents the variable name.
+    /// - `elements`: The elements of the array assigned to the variable.
+    pub(crate) fn add_list_variable(&mut self, identifier: String, elements: Vec<String>) {
+        self.remove_typed_forms(&identifier);
+        self.values
+            .insert(identifier, NenyrVariableValue::List(elements));
+    }
+
+    /// Adds a variable declared as a computed value, keeping both its parsed expression
+    /// and its textual form, e.g. `calc(${spacing} * 2)`.
+    ///
+    /// # Parameters
+    /// - `identifier`: A `String` that uniquely represents the variable name.
+    /// - `expression`: The `NenyrExpression` assigned to the variable.
+    pub(crate) fn add_expression_variable(
+        &mut self,
+        identifier: String,
+        expression: NenyrExpression,
+    ) {
+        self.remove_typed_forms(&identifier);
+        self.values.insert(
+            identifier.clone(),
+            NenyrVariableValue::Text(expression.to_string()),
+        );
+
+        self.expressions
+            .get_or_insert_with(IndexMap::new)
+            .insert(identifier, expression);
+    }
+
+    /// Removes the typed forms previously kept for a variable that is being redeclared.
+    fn remove_typed_forms(&mut self, identifier: &str) {
+        if let Some(dimensions) = &mut self.dimensions {
+            dimensions.shift_remove(identifier);
+        }
+
+        if let Some(expressions) = &mut self.expressions {
+            expressions.shift_remove(identifier);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::types::variables::NenyrVariables;
+    use crate::types::variables::{NenyrDimension, NenyrVariableValue, NenyrVariables};
 
     #[test]
     fn test_new_creates_empty_instance() {
@@ -49,7 +299,10 @@ mod tests {
         let mut variables = NenyrVariables::new();
 
         variables.add_variable("var1".to_string(), "value1".to_string());
-        assert_eq!(variables.values.get("var1"), Some(&"value1".to_string()));
+        assert_eq!(
+            variables.values.get("var1"),
+            Some(&NenyrVariableValue::Text("value1".to_string()))
+        );
     }
 
     #[test]
@@ -60,7 +313,7 @@ mod tests {
         variables.add_variable("var1".to_string(), "updated_value".to_string());
         assert_eq!(
             variables.values.get("var1"),
-            Some(&"updated_value".to_string())
+            Some(&NenyrVariableValue::Text("updated_value".to_string()))
         );
     }
 
@@ -71,7 +324,55 @@ mod tests {
         variables.add_variable("spécial_ñame".to_string(), "valüe_@_1".to_string());
         assert_eq!(
             variables.values.get("spécial_ñame"),
-            Some(&"valüe_@_1".to_string())
+            Some(&NenyrVariableValue::Text("valüe_@_1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_add_dimension_variable_keeps_string_representation() {
+        let mut variables = NenyrVariables::new();
+        let dimension = NenyrDimension {
+            value: 8.0,
+            unit: "px".to_string(),
+        };
+
+        variables.add_dimension_variable("spacing".to_string(), dimension.clone());
+        assert_eq!(
+            variables.values.get("spacing"),
+            Some(&NenyrVariableValue::Text("8px".to_string()))
+        );
+        assert_eq!(
+            variables.dimensions.as_ref().unwrap().get("spacing"),
+            Some(&dimension)
+        );
+
+        variables.add_variable("spacing".to_string(), "1rem".to_string());
+        assert_eq!(
+            variables.values.get("spacing"),
+            Some(&NenyrVariableValue::Text("1rem".to_string()))
+        );
+        assert!(variables.dimensions.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_add_list_variable_keeps_elements() {
+        let mut variables = NenyrVariables::new();
+        let elements = vec!["0 1px 2px #000".to_string(), "0 2px 4px #333".to_string()];
+
+        variables.add_list_variable("shadows".to_string(), elements.clone());
+        assert_eq!(
+            variables.values.get("shadows"),
+            Some(&NenyrVariableValue::List(elements))
+        );
+        assert_eq!(
+            variables.values["shadows"].to_string(),
+            "0 1px 2px #000, 0 2px 4px #333"
+        );
+
+        variables.add_variable("shadows".to_string(), "none".to_string());
+        assert_eq!(
+            variables.values.get("shadows"),
+            Some(&NenyrVariableValue::Text("none".to_string()))
         );
     }
 }
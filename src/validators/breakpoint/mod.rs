@@ -42,6 +42,18 @@ pub trait NenyrBreakpointValidator {
     fn is_valid_breakpoint(&self, breakpoint: &str) -> bool {
         RE.is_match(breakpoint)
     }
+
+    /// Validates a media type, such as the `print` of `@media print`.
+    ///
+    /// # Parameters
+    /// - `media_type`: A string slice representing the media type to validate.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the media type is one of `all`, `print`, `screen` or `speech`;
+    ///   `false` otherwise.
+    fn is_valid_media_type(&self, media_type: &str) -> bool {
+        matches!(media_type, "all" | "print" | "screen" | "speech")
+    }
 }
 
 #[cfg(test)]
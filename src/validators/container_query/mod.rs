@@ -0,0 +1,117 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::validators::feature_query::is_valid_condition;
+
+lazy_static! {
+    static ref SIZE_FEATURE: Regex =
+        Regex::new(r"^\s*[a-zA-Z][a-zA-Z0-9-]*\s*:\s*[^\s:;{}@!<>=][^:;{}@!<>=]*$").unwrap();
+    static ref RANGE_FEATURE: Regex = Regex::new(
+        r"^\s*(?:(?P<start>[^\s:;{}@!<>=()]+)\s*(?:<=|>=|<|>|=)\s*)?(?P<name>[a-zA-Z][a-zA-Z0-9-]*)(?:\s*(?:<=|>=|<|>|=)\s*(?P<end>[^\s:;{}@!<>=()]+))?\s*$"
+    )
+    .unwrap();
+}
+
+/// A trait responsible for validating CSS container queries.
+///
+/// The `NenyrContainerQueryValidator` trait checks the conditions received by the
+/// `Container('(min-width: 400px)', { ... })` pattern, which is emitted as a CSS `@container`
+/// rule. A container query follows the same structure as a feature query: parenthesized groups,
+/// optionally combined through the `and`, `or` and `not` operators, with balanced parentheses.
+/// Besides `(name: value)` size features, each group may also be a range comparison.
+///
+/// # Examples of valid container queries
+///
+/// - `(min-width: 400px)`
+/// - `(width > 400px)`
+/// - `(400px <= width <= 800px)`
+/// - `(orientation: landscape) and (min-width: 600px)`
+pub trait NenyrContainerQueryValidator {
+    /// Validates a container query condition.
+    ///
+    /// # Parameters
+    /// - `container_query`: A string slice representing the container query to validate.
+    ///
+    /// # Returns
+    /// - `true` if the container query has balanced parentheses and every group is either a
+    ///   `(name: value)` size feature, a range comparison or a nested condition.
+    /// - `false` otherwise.
+    fn is_valid_container_query(&self, container_query: &str) -> bool {
+        is_valid_condition(container_query.trim(), &is_valid_size_feature)
+    }
+}
+
+/// Validates a single size feature, written either as `name: value` or as a range comparison.
+fn is_valid_size_feature(feature: &str) -> bool {
+    if SIZE_FEATURE.is_match(feature) {
+        return true;
+    }
+
+    match RANGE_FEATURE.captures(feature) {
+        Some(captures) => captures.name("start").is_some() || captures.name("end").is_some(),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NenyrContainerQueryValidator;
+
+    struct ContainerQuery {}
+
+    impl ContainerQuery {
+        pub fn new() -> Self {
+            Self {}
+        }
+    }
+
+    impl NenyrContainerQueryValidator for ContainerQuery {}
+
+    #[test]
+    fn all_container_queries_are_valid() {
+        let container_query = ContainerQuery::new();
+        let valid_container_queries = vec![
+            "(min-width: 400px)",
+            "  (min-width:400px)  ",
+            "not (orientation: portrait)",
+            "(width > 400px)",
+            "(400px <= width <= 800px)",
+            "(inline-size >= 30em) and (block-size < 50em)",
+            "(min-width: 400px) or (aspect-ratio: 16 / 9)",
+            "((width > 400px) and (not (height < 200px)))",
+        ];
+
+        for valid_container_query in valid_container_queries {
+            assert!(
+                container_query.is_valid_container_query(valid_container_query),
+                "Container query '{}' should be valid.",
+                valid_container_query
+            );
+        }
+    }
+
+    #[test]
+    fn all_container_queries_are_not_valid() {
+        let container_query = ContainerQuery::new();
+        let invalid_container_queries = vec![
+            "",
+            "min-width: 400px",
+            "(min-width: 400px",
+            "(width)",
+            "(width >)",
+            "(> 400px)",
+            "(width >> 400px)",
+            "(min-width: 400px) (max-width: 800px)",
+            "(min-width: 400px) xor (max-width: 800px)",
+            "(min-width: 400px;)",
+        ];
+
+        for invalid_container_query in invalid_container_queries {
+            assert!(
+                !container_query.is_valid_container_query(invalid_container_query),
+                "Container query '{}' should be invalid.",
+                invalid_container_query
+            );
+        }
+    }
+}
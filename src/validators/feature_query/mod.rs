@@ -0,0 +1,195 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref DECLARATION: Regex =
+        Regex::new(r"^\s*(--)?[a-zA-Z][a-zA-Z0-9-]*\s*:\s*[^\s:;{}@!][^:;{}@!]*$").unwrap();
+}
+
+/// A trait responsible for validating CSS feature queries.
+///
+/// The `NenyrFeatureQueryValidator` trait checks the conditions received by patterns such as
+/// `Supports('(display: grid)', { ... })`, which are emitted as CSS `@supports` rules. A feature
+/// query is considered valid when it is composed of parenthesized `(property: value)` declarations,
+/// optionally combined through the `and`, `or` and `not` operators, and when all of its
+/// parentheses are balanced.
+///
+/// # Examples of valid feature queries
+///
+/// - `(display: grid)`
+/// - `not (display: grid)`
+/// - `(display: flex) and (gap: 1rem)`
+/// - `((position: sticky) or (position: -webkit-sticky))`
+pub trait NenyrFeatureQueryValidator {
+    /// Validates a feature query condition.
+    ///
+    /// # Parameters
+    /// - `feature_query`: A string slice representing the feature query to validate.
+    ///
+    /// # Returns
+    /// - `true` if the feature query has balanced parentheses and every group is either a
+    ///   `(property: value)` declaration or a nested condition.
+    /// - `false` otherwise.
+    fn is_valid_feature_query(&self, feature_query: &str) -> bool {
+        is_valid_condition(feature_query.trim(), &|declaration| {
+            DECLARATION.is_match(declaration)
+        })
+    }
+}
+
+/// Validates a condition made of parenthesized groups joined by `and`/`or`, or negated by `not`.
+///
+/// The innermost groups, which are not nested conditions themselves, are checked by `is_valid_leaf`,
+/// allowing the same walker to validate both feature queries and container queries.
+pub(crate) fn is_valid_condition(condition: &str, is_valid_leaf: &dyn Fn(&str) -> bool) -> bool {
+    if let Some(negated) = condition.strip_prefix("not") {
+        if negated.starts_with(char::is_whitespace) || negated.starts_with('(') {
+            return is_valid_group_sequence(negated.trim_start(), false, is_valid_leaf);
+        }
+    }
+
+    is_valid_group_sequence(condition, true, is_valid_leaf)
+}
+
+/// Walks through the top level of a condition, validating each parenthesized group and the
+/// operators placed between them.
+fn is_valid_group_sequence(
+    condition: &str,
+    allow_operators: bool,
+    is_valid_leaf: &dyn Fn(&str) -> bool,
+) -> bool {
+    let mut groups_count = 0;
+    let mut remaining = condition;
+
+    while !remaining.is_empty() {
+        if groups_count > 0 {
+            let operator_end = remaining
+                .find(|c: char| c.is_whitespace() || c == '(')
+                .unwrap_or(remaining.len());
+            let operator = &remaining[..operator_end];
+
+            if !allow_operators || (operator != "and" && operator != "or") {
+                return false;
+            }
+
+            remaining = remaining[operator_end..].trim_start();
+        }
+
+        match retrieve_group_end(remaining) {
+            Some(group_end) => {
+                let inner = remaining[1..group_end].trim();
+                let is_nested = inner.starts_with('(')
+                    || inner.strip_prefix("not").is_some_and(|rest| {
+                        rest.starts_with(char::is_whitespace) || rest.starts_with('(')
+                    });
+
+                if is_nested && !is_valid_condition(inner, is_valid_leaf) {
+                    return false;
+                }
+
+                if !is_nested && !is_valid_leaf(inner) {
+                    return false;
+                }
+
+                groups_count += 1;
+                remaining = remaining[group_end + 1..].trim_start();
+            }
+            None => return false,
+        }
+    }
+
+    groups_count > 0
+}
+
+/// Returns the byte index of the parenthesis closing the group opened at the start of `group`.
+fn retrieve_group_end(group: &str) -> Option<usize> {
+    if !group.starts_with('(') {
+        return None;
+    }
+
+    let mut depth = 0;
+
+    for (idx, char) in group.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NenyrFeatureQueryValidator;
+
+    struct FeatureQuery {}
+
+    impl FeatureQuery {
+        pub fn new() -> Self {
+            Self {}
+        }
+    }
+
+    impl NenyrFeatureQueryValidator for FeatureQuery {}
+
+    #[test]
+    fn all_feature_queries_are_valid() {
+        let feature_query = FeatureQuery::new();
+        let valid_feature_queries = vec![
+            "(display: grid)",
+            "  (display:grid)  ",
+            "not (display: grid)",
+            "(display: flex) and (gap: 1rem)",
+            "(position: sticky) or (position: -webkit-sticky)",
+            "((display: grid) and (not (display: inline-grid)))",
+            "(transform: translate(10px, 20px))",
+            "(--custom-property: blue)",
+            "(aspect-ratio: 16 / 9)",
+        ];
+
+        for valid_feature_query in valid_feature_queries {
+            assert!(
+                feature_query.is_valid_feature_query(valid_feature_query),
+                "Feature query '{}' should be valid.",
+                valid_feature_query
+            );
+        }
+    }
+
+    #[test]
+    fn all_feature_queries_are_not_valid() {
+        let feature_query = FeatureQuery::new();
+        let invalid_feature_queries = vec![
+            "",
+            "display: grid",
+            "(display: grid",
+            "display: grid)",
+            "(display grid)",
+            "(display:)",
+            "(: grid)",
+            "()",
+            "(display: grid) (gap: 1rem)",
+            "(display: grid) xor (gap: 1rem)",
+            "not not (display: grid)",
+            "(display: grid) and",
+            "(display: grid;)",
+            "(display: grid) and (gap: 1rem))",
+        ];
+
+        for invalid_feature_query in invalid_feature_queries {
+            assert!(
+                !feature_query.is_valid_feature_query(invalid_feature_query),
+                "Feature query '{}' should be invalid.",
+                invalid_feature_query
+            );
+        }
+    }
+}
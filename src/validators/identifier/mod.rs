@@ -12,7 +12,7 @@
 /// This allows identifiers like `camelCase` and `PascalCase` but disallows strings that start with numbers
 /// or include non-alphanumeric characters like symbols (`@`, `#`, `_`).
 ///
-/// ## Method
+/// ## Methods
 ///
 /// ### `is_valid_identifier`
 ///
@@ -24,6 +24,10 @@
 /// - **Returns**:
 ///     - `true` if the identifier is valid according to the rules above.
 ///     - `false` if the identifier is empty, starts with a non-alphabetic character, or contains invalid characters.
+///
+/// ### `find_invalid_identifier_char`
+///
+/// Locates the byte index of the first character breaking the rules above, if any.
 pub trait NenyrIdentifierValidator {
     /// Validates if the given identifier follows the specified rules.
     ///
@@ -40,19 +44,36 @@ pub trait NenyrIdentifierValidator {
     /// - `true` if the identifier is valid.
     /// - `false` if the identifier is empty, starts with a non-alphabetic character, or contains invalid characters.
     fn is_valid_identifier(&self, identifier: &str) -> bool {
-        if identifier.is_empty() {
-            return false;
-        }
-
-        let mut chars = identifier.chars();
+        self.find_invalid_identifier_char(identifier).is_none()
+    }
 
-        if let Some(first_char) = chars.next() {
-            if !first_char.is_ascii_alphabetic() {
-                return false;
-            }
+    /// Locates the first character breaking the identifier rules, so the error raised
+    /// for an invalid identifier can point at the exact offending character.
+    ///
+    /// # Parameters
+    ///
+    /// - `identifier`: A string slice reference (`&str`) representing the identifier to validate.
+    ///
+    /// # Returns
+    ///
+    /// - `None` if the identifier is valid.
+    /// - `Some(index)` holding the byte index of the first offending character. An empty
+    ///   identifier is reported at index `0`.
+    fn find_invalid_identifier_char(&self, identifier: &str) -> Option<usize> {
+        if identifier.is_empty() {
+            return Some(0);
         }
 
-        chars.all(|c| c.is_ascii_alphanumeric())
+        identifier
+            .char_indices()
+            .find(|(idx, c)| {
+                if *idx == 0 {
+                    !c.is_ascii_alphabetic()
+                } else {
+                    !c.is_ascii_alphanumeric()
+                }
+            })
+            .map(|(idx, _)| idx)
     }
 }
 
@@ -152,6 +173,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn first_invalid_character_must_be_located() {
+        let identifier = Identifier::new();
+
+        assert_eq!(identifier.find_invalid_identifier_char("my Name"), Some(2));
+        assert_eq!(identifier.find_invalid_identifier_char("1myName"), Some(0));
+        assert_eq!(identifier.find_invalid_identifier_char("myNaçme"), Some(4));
+        assert_eq!(identifier.find_invalid_identifier_char(""), Some(0));
+        assert_eq!(identifier.find_invalid_identifier_char("myName"), None);
+    }
+
     #[test]
     fn performance_test_large_identifier_valid_vector() {
         let identifier = Identifier::new();
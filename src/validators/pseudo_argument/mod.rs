@@ -0,0 +1,220 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref SELECTOR: Regex = Regex::new(r#"^[a-zA-Z0-9_\-.#*:\[\]=~^$|"'()\s,>+]+$"#).unwrap();
+    static ref NTH_EXPRESSION: Regex =
+        Regex::new(r"^(?:odd|even|[+-]?\d*n(?:\s*[+-]\s*\d+)?|[+-]?\d+)$").unwrap();
+    static ref ATTRIBUTE_SELECTOR: Regex = Regex::new(
+        r#"^(?:\[\s*[a-zA-Z_\-][a-zA-Z0-9_\-]*\s*(?:[~|^$*]?=\s*(?:"[^"]*"|'[^']*'|[a-zA-Z0-9_\-]+)\s*(?:[iIsS]\s*)?)?\])+$"#
+    )
+    .unwrap();
+}
+
+/// A trait responsible for validating the arguments of functional pseudo-class patterns.
+///
+/// The `NenyrPseudoArgumentValidator` trait checks the arguments received by patterns such as
+/// `Not('.disabled', { ... })` and `NthChild('2n + 1', { ... })`, which are emitted as the
+/// `:not(...)` and `:nth-child(...)` CSS pseudo-classes, along with the attribute selectors
+/// received by `Attribute('[data-state="open"]', { ... })`.
+///
+/// # Examples of valid arguments
+///
+/// - Selectors: `.disabled`, `#main`, `[type="text"]`, `.active, :focus`
+/// - Nth expressions: `odd`, `even`, `3`, `2n`, `-n + 3`, `2n+1`
+/// - Attribute selectors: `[disabled]`, `[data-state="open"]`, `[lang|=en]`, `[href$=".pdf" i]`
+pub trait NenyrPseudoArgumentValidator {
+    /// Validates the selector list received by the `Not` pattern.
+    ///
+    /// # Parameters
+    /// - `selector`: A string slice representing the selector list to validate.
+    ///
+    /// # Returns
+    /// - `true` if the selector is not empty, contains only selector characters and has
+    ///   balanced parentheses and square brackets.
+    /// - `false` otherwise.
+    fn is_valid_selector_argument(&self, selector: &str) -> bool {
+        let selector = selector.trim();
+
+        if selector.is_empty()
+            || !SELECTOR.is_match(selector)
+            || selector.starts_with(',')
+            || selector.ends_with(',')
+        {
+            return false;
+        }
+
+        has_balanced_delimiters(selector)
+    }
+
+    /// Validates the `An+B` expression received by the `NthChild` pattern.
+    ///
+    /// # Parameters
+    /// - `expression`: A string slice representing the expression to validate.
+    ///
+    /// # Returns
+    /// - `true` if the expression is `odd`, `even`, an integer or an `An+B` expression.
+    /// - `false` otherwise.
+    fn is_valid_nth_argument(&self, expression: &str) -> bool {
+        NTH_EXPRESSION.is_match(expression.trim())
+    }
+
+    /// Validates the attribute selector received by the `Attribute` pattern.
+    ///
+    /// # Parameters
+    /// - `selector`: A string slice representing the attribute selector to validate.
+    ///
+    /// # Returns
+    /// - `true` if the selector is one or more balanced, non-empty square-bracketed attribute
+    ///   selectors, each naming an attribute optionally followed by a matcher and a value.
+    /// - `false` otherwise.
+    fn is_valid_attribute_argument(&self, selector: &str) -> bool {
+        ATTRIBUTE_SELECTOR.is_match(selector.trim())
+    }
+}
+
+/// Checks that every parenthesis and square bracket of the selector is properly closed.
+fn has_balanced_delimiters(selector: &str) -> bool {
+    let mut stack = vec![];
+
+    for char in selector.chars() {
+        match char {
+            '(' | '[' => stack.push(char),
+            ')' if stack.pop() != Some('(') => return false,
+            ']' if stack.pop() != Some('[') => return false,
+            _ => {}
+        }
+    }
+
+    stack.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NenyrPseudoArgumentValidator;
+
+    struct PseudoArgument {}
+
+    impl NenyrPseudoArgumentValidator for PseudoArgument {}
+
+    #[test]
+    fn all_selector_arguments_are_valid() {
+        let validator = PseudoArgument {};
+        let selectors = vec![
+            ".disabled",
+            "#main",
+            "[type=\"text\"]",
+            ".active, :focus",
+            "div > .item",
+            ":first-child",
+            "*",
+        ];
+
+        for selector in selectors {
+            assert!(
+                validator.is_valid_selector_argument(selector),
+                "{}",
+                selector
+            );
+        }
+    }
+
+    #[test]
+    fn all_selector_arguments_are_not_valid() {
+        let validator = PseudoArgument {};
+        let selectors = vec![
+            "",
+            "   ",
+            ".disabled {",
+            ".a; .b",
+            "[type=\"text\"",
+            ":is(.a",
+            ".a,",
+            "@media",
+        ];
+
+        for selector in selectors {
+            assert!(
+                !validator.is_valid_selector_argument(selector),
+                "{}",
+                selector
+            );
+        }
+    }
+
+    #[test]
+    fn all_nth_arguments_are_valid() {
+        let validator = PseudoArgument {};
+        let expressions = vec![
+            "odd", "even", "3", "+3", "2n", "n", "-n + 3", "2n+1", " 3n - 2 ",
+        ];
+
+        for expression in expressions {
+            assert!(
+                validator.is_valid_nth_argument(expression),
+                "{}",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn all_nth_arguments_are_not_valid() {
+        let validator = PseudoArgument {};
+        let expressions = vec!["", "odds", "2x", "n +", "2n + 1 + 1", ".disabled", "1.5n"];
+
+        for expression in expressions {
+            assert!(
+                !validator.is_valid_nth_argument(expression),
+                "{}",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn all_attribute_arguments_are_valid() {
+        let validator = PseudoArgument {};
+        let selectors = vec![
+            "[disabled]",
+            "[data-state=\"open\"]",
+            "[data-state='open']",
+            "[lang|=en]",
+            "[href$=\".pdf\" i]",
+            " [type=\"text\"][required] ",
+            "[title=\"a]b\"]",
+        ];
+
+        for selector in selectors {
+            assert!(
+                validator.is_valid_attribute_argument(selector),
+                "{}",
+                selector
+            );
+        }
+    }
+
+    #[test]
+    fn all_attribute_arguments_are_not_valid() {
+        let validator = PseudoArgument {};
+        let selectors = vec![
+            "",
+            "[]",
+            "[ ]",
+            "[data-state=\"open\"",
+            "data-state=\"open\"]",
+            "[[data-state]]",
+            "[data-state=\"open]",
+            ".active[disabled]",
+            "[data-state==open]",
+        ];
+
+        for selector in selectors {
+            assert!(
+                !validator.is_valid_attribute_argument(selector),
+                "{}",
+                selector
+            );
+        }
+    }
+}
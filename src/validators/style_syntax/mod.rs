@@ -3,8 +3,74 @@ use regex::Regex;
 
 lazy_static! {
     static ref INVALID_CHARS: Regex = Regex::new(r"[@!;:]").unwrap();
+    static ref HEX_COLOR: Regex =
+        Regex::new(r"#(?:[0-9a-fA-F]{3,4}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})\b").unwrap();
+    static ref NUMERIC_TOKEN: Regex =
+        Regex::new(r"^[+-]?(?:\d+(?:\.\d+)?|\.\d+)(?:[eE][+-]?\d+)?([a-zA-Z]+|%)?$").unwrap();
 }
 
+/// The CSS-wide keywords, which are valid values for any property.
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// The units accepted after a number, such as the `px` of `10px`, which are case-insensitive.
+const CSS_UNITS: [&str; 54] = [
+    "px", "cm", "mm", "q", "in", "pt", "pc", "em", "rem", "ex", "rex", "cap", "rcap", "ch", "rch",
+    "ic", "ric", "lh", "rlh", "vw", "vh", "vi", "vb", "vmin", "vmax", "svw", "svh", "lvw", "lvh",
+    "dvw", "dvh", "cqw", "cqh", "cqi", "cqb", "cqmin", "cqmax", "fr", "deg", "grad", "rad", "turn",
+    "s", "ms", "hz", "khz", "dpi", "dpcm", "dppx", "x", "svi", "svb", "dvi", "dvb",
+];
+
+/// The keywords accepted by the `content` property without being quoted, besides the
+/// CSS-wide keywords.
+const CONTENT_KEYWORDS: [&str; 6] = [
+    "none",
+    "normal",
+    "open-quote",
+    "close-quote",
+    "no-open-quote",
+    "no-close-quote",
+];
+
+/// The functions accepted by the `content` property.
+const CONTENT_FUNCTIONS: [&str; 11] = [
+    "attr",
+    "counter",
+    "counters",
+    "url",
+    "var",
+    "image-set",
+    "linear-gradient",
+    "radial-gradient",
+    "conic-gradient",
+    "repeating-linear-gradient",
+    "repeating-radial-gradient",
+];
+
+/// The accepted number of arguments, as an inclusive range, for each `transform` function.
+const TRANSFORM_ARITIES: [(&str, usize, usize); 21] = [
+    ("matrix", 6, 6),
+    ("matrix3d", 16, 16),
+    ("perspective", 1, 1),
+    ("rotate", 1, 1),
+    ("rotate3d", 4, 4),
+    ("rotateX", 1, 1),
+    ("rotateY", 1, 1),
+    ("rotateZ", 1, 1),
+    ("scale", 1, 2),
+    ("scale3d", 3, 3),
+    ("scaleX", 1, 1),
+    ("scaleY", 1, 1),
+    ("scaleZ", 1, 1),
+    ("skew", 1, 2),
+    ("skewX", 1, 1),
+    ("skewY", 1, 1),
+    ("translate", 1, 2),
+    ("translate3d", 3, 3),
+    ("translateX", 1, 1),
+    ("translateY", 1, 1),
+    ("translateZ", 1, 1),
+];
+
 /// A trait responsible for validating the syntax of style rules.
 ///
 /// This trait provides a method to check if a given style rule
@@ -32,17 +98,310 @@ pub trait NenyrStyleSyntaxValidator {
     /// invalid characters. If it does, the method returns `false`;
     /// otherwise, it returns `true`.
     ///
+    /// Every top-level token shaped like a number is checked as well. Signed numbers
+    /// (`-10px`), unitless numbers (`0`) and leading-dot decimals (`.5em`) are accepted,
+    /// while malformed ones, such as `--10px` or `10pxpx`, are rejected.
+    ///
+    /// The CSS-wide keywords (`inherit`, `initial`, `unset`, `revert` and `revert-layer`)
+    /// are valid for any property, so they are always accepted when used as the whole value.
+    ///
     /// # Parameters
     /// - `rule`: A string slice that represents the style rule to validate.
     ///
     /// # Returns
-    /// - `true` if the syntax of the style rule is valid (i.e., does
-    ///   not contain any invalid characters).
+    /// - `true` if the style rule is a CSS-wide keyword or if its syntax is valid
+    ///   (i.e., does not contain any invalid characters).
     /// - `false` if the syntax is invalid (i.e., contains one or
-    ///   more of the invalid characters defined).
+    ///   more of the invalid characters defined, or a malformed number).
     fn is_valid_style_syntax(&self, rule: &str) -> bool {
-        !INVALID_CHARS.is_match(rule)
+        if is_css_wide_keyword(rule) {
+            return true;
+        }
+
+        !INVALID_CHARS.is_match(rule) && has_valid_numeric_tokens(rule)
+    }
+
+    /// Lowercases every hex color found in a value, e.g. `#FF5733` into `#ff5733`.
+    ///
+    /// # Parameters
+    /// - `value`: The value that may contain hex colors.
+    ///
+    /// # Returns
+    /// - The value with its hex colors lowercased, any other part kept untouched.
+    fn lowercase_hex_colors(&self, value: String) -> String {
+        if !HEX_COLOR.is_match(&value) {
+            return value;
+        }
+
+        HEX_COLOR
+            .replace_all(&value, |captures: &regex::Captures| {
+                captures[0].to_ascii_lowercase()
+            })
+            .to_string()
+    }
+
+    /// Collapses every run of whitespace of a value into a single space, e.g.
+    /// `10px  solid   red` into `10px solid red`, trimming the value as well.
+    ///
+    /// The content of quoted strings and of parentheses is kept untouched, so the spaces
+    /// required by functions such as `calc(100% - 10px)` are preserved exactly as written.
+    ///
+    /// # Parameters
+    /// - `value`: The value to be normalized.
+    ///
+    /// # Returns
+    /// - The value with its top-level whitespace collapsed.
+    fn collapse_whitespace(&self, value: String) -> String {
+        let mut collapsed = String::with_capacity(value.len());
+        let mut quote: Option<char> = None;
+        let mut is_escaped = false;
+        let mut depth = 0usize;
+        let mut has_pending_space = false;
+
+        for char in value.trim().chars() {
+            if quote.is_none() && depth == 0 && char.is_whitespace() {
+                has_pending_space = true;
+
+                continue;
+            }
+
+            if has_pending_space {
+                collapsed.push(' ');
+                has_pending_space = false;
+            }
+
+            match quote {
+                Some(_) if is_escaped => is_escaped = false,
+                Some(_) if char == '\\' => is_escaped = true,
+                Some(quote_char) if char == quote_char => quote = None,
+                Some(_) => {}
+                None => match char {
+                    '\'' | '"' => quote = Some(char),
+                    '(' => depth += 1,
+                    ')' => depth = depth.saturating_sub(1),
+                    _ => {}
+                },
+            }
+
+            collapsed.push(char);
+        }
+
+        collapsed
     }
+
+    /// Normalizes the value of a style property according to the property-specific rules.
+    ///
+    /// The `content` property requires its value to be a quoted string, one of its keywords
+    /// (e.g. `none` or `normal`), or one of the functions it accepts (e.g. `attr(...)` or
+    /// `counter(...)`). Plain text, such as the `hello` received from `content: 'hello'`, is
+    /// wrapped in double quotes. The `transform` property has the number of arguments of each
+    /// known function checked, e.g. `translate()` takes one or two arguments while `rotate()`
+    /// takes exactly one. Any other property keeps its value untouched.
+    ///
+    /// Aliases must be resolved into the CSS property they refer to before calling it, so
+    /// that `txt: 'hello'` is quoted when `txt` is an alias of `content`.
+    ///
+    /// # Parameters
+    /// - `property`: The CSS name of the property receiving the value.
+    /// - `value`: The value assigned to the property.
+    ///
+    /// # Returns
+    /// - `Some(String)` with the value to be emitted.
+    /// - `None` if the value is not valid for the property, such as a `content` value
+    ///   containing an unsupported function or a `transform` function receiving the
+    ///   wrong number of arguments.
+    fn normalize_property_value(&self, property: &str, value: String) -> Option<String> {
+        if property == "transform" {
+            return has_valid_transform_arities(&value).then_some(value);
+        }
+
+        if property != "content" {
+            return Some(value);
+        }
+
+        let value = value.trim();
+
+        if split_content_tokens(value)
+            .iter()
+            .all(|token| is_valid_content_token(token))
+        {
+            return Some(value.to_string());
+        }
+
+        if value.contains(['(', ')', '\'', '"']) {
+            return None;
+        }
+
+        Some(format!("\"{}\"", value))
+    }
+}
+
+/// Splits a `content` value into its whitespace separated tokens, keeping quoted strings
+/// and function arguments together.
+fn split_content_tokens(value: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut token_start: Option<usize> = None;
+    let mut quote: Option<char> = None;
+    let mut depth = 0;
+
+    for (idx, char) in value.char_indices() {
+        match (quote, char) {
+            (Some(opened_quote), _) if char == opened_quote => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(char),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, _) if char.is_whitespace() && depth == 0 => {
+                if let Some(start) = token_start.take() {
+                    tokens.push(&value[start..idx]);
+                }
+
+                continue;
+            }
+            _ => {}
+        }
+
+        if token_start.is_none() {
+            token_start = Some(idx);
+        }
+    }
+
+    if let Some(start) = token_start {
+        tokens.push(&value[start..]);
+    }
+
+    tokens
+}
+
+/// Checks whether every top-level token shaped like a number, such as `-10px` or `.5em`, is a
+/// well-formed number followed by an optional known unit. Functions, quoted strings and
+/// placeholders are left untouched.
+fn has_valid_numeric_tokens(value: &str) -> bool {
+    split_content_tokens(value.trim())
+        .iter()
+        .flat_map(|token| split_top_level_separators(token))
+        .filter(|token| !token.contains(['(', ')', '\'', '"', '$']) && is_numeric_like(token))
+        .all(|token| match NUMERIC_TOKEN.captures(token) {
+            Some(captures) => captures.get(1).is_none_or(|unit| {
+                unit.as_str() == "%"
+                    || CSS_UNITS
+                        .iter()
+                        .any(|known_unit| known_unit.eq_ignore_ascii_case(unit.as_str()))
+            }),
+            None => false,
+        })
+}
+
+/// Splits a token on the commas and slashes found outside of parentheses and quotes, so the
+/// arguments of a function such as `rgba(0,0,0,0.5)` are kept together.
+fn split_top_level_separators(token: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0usize;
+    let mut part_start = 0;
+
+    for (idx, char) in token.char_indices() {
+        match (quote, char) {
+            (Some(opened_quote), _) if char == opened_quote => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(char),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ',' | '/') if depth == 0 => {
+                parts.push(&token[part_start..idx]);
+                part_start = idx + char.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&token[part_start..]);
+
+    parts
+}
+
+/// Checks whether a token starts like a number, i.e. a digit or a dot followed by a digit,
+/// once its leading signs are skipped. A custom property name such as `--gap` does not.
+fn is_numeric_like(token: &str) -> bool {
+    let unsigned = token.trim_start_matches(['+', '-']);
+    let unsigned = unsigned.strip_prefix('.').unwrap_or(unsigned);
+
+    unsigned.starts_with(|char: char| char.is_ascii_digit())
+}
+
+/// Checks whether every known function of a `transform` value receives an accepted number
+/// of arguments. Unknown functions and placeholders are left for the other validations.
+fn has_valid_transform_arities(value: &str) -> bool {
+    split_content_tokens(value.trim()).iter().all(|token| {
+        let Some((name, arguments)) = token
+            .strip_suffix(')')
+            .and_then(|function| function.split_once('('))
+        else {
+            return true;
+        };
+
+        match TRANSFORM_ARITIES
+            .iter()
+            .find(|(function_name, _, _)| *function_name == name)
+        {
+            Some((_, min, max)) => {
+                let arity = count_function_arguments(arguments);
+
+                arity >= *min && arity <= *max
+            }
+            None => true,
+        }
+    })
+}
+
+/// Counts the top-level comma separated arguments of a function, ignoring the commas
+/// found inside nested functions such as `calc(...)` or `var(...)`.
+fn count_function_arguments(arguments: &str) -> usize {
+    if arguments.trim().is_empty() {
+        return 0;
+    }
+
+    let mut depth = 0;
+    let mut count = 1;
+
+    for char in arguments.chars() {
+        match char {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => count += 1,
+            _ => {}
+        }
+    }
+
+    count
+}
+
+/// Checks whether a value is one of the CSS-wide keywords, which are case-insensitive.
+pub(crate) fn is_css_wide_keyword(value: &str) -> bool {
+    let value = value.trim();
+
+    CSS_WIDE_KEYWORDS
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(value))
+}
+
+/// Checks whether a single token is accepted by the `content` property.
+fn is_valid_content_token(token: &str) -> bool {
+    let is_quoted = token.len() >= 2
+        && ((token.starts_with('"') && token.ends_with('"'))
+            || (token.starts_with('\'') && token.ends_with('\'')));
+    let is_placeholder = token.starts_with("${") && token.ends_with('}');
+    let is_function = token.ends_with(')')
+        && token
+            .split_once('(')
+            .is_some_and(|(name, _)| CONTENT_FUNCTIONS.contains(&name));
+
+    is_quoted
+        || is_placeholder
+        || is_function
+        || token == "/"
+        || CONTENT_KEYWORDS.contains(&token)
+        || is_css_wide_keyword(token)
 }
 
 #[cfg(test)]
@@ -119,4 +478,184 @@ mod tests {
             assert!(!styles_syntax.is_valid_style_syntax(rule));
         }
     }
+
+    #[test]
+    fn signed_zero_and_leading_dot_numbers_are_valid() {
+        let styles_syntax = StyleSyntax::new();
+        let values = [
+            "-10px",
+            "+10px",
+            "0",
+            ".5em",
+            "-.5em",
+            "-0.25rem 0 1.5E2PX",
+            "1.5",
+            ".5",
+            "50%",
+            "16/9",
+            "1s, 200ms",
+            "-webkit-box",
+            "var(--gap)",
+            "calc(-10px + .5em)",
+            "rgba(0,0,0,0.5)",
+            "translate(10px,20px)",
+            "cubic-bezier(0.1,0.7,1.0,0.1)",
+            "10px,rgba(0,0,0,0.5)",
+        ];
+
+        for value in values {
+            assert!(styles_syntax.is_valid_style_syntax(value), "{}", value);
+        }
+    }
+
+    #[test]
+    fn malformed_numbers_are_not_valid() {
+        let styles_syntax = StyleSyntax::new();
+        let values = ["10pxpx", "--10px", "+-10px", "1.5.5em", "10px 10pxpx"];
+
+        for value in values {
+            assert!(!styles_syntax.is_valid_style_syntax(value), "{}", value);
+        }
+    }
+
+    #[test]
+    fn content_values_are_normalized() {
+        let styles_syntax = StyleSyntax::new();
+
+        assert_eq!(
+            styles_syntax.normalize_property_value("content", "hello".to_string()),
+            Some("\"hello\"".to_string())
+        );
+        assert_eq!(
+            styles_syntax.normalize_property_value("content", "none".to_string()),
+            Some("none".to_string())
+        );
+        assert_eq!(
+            styles_syntax.normalize_property_value("content", "\"' '\"".to_string()),
+            Some("\"' '\"".to_string())
+        );
+        assert_eq!(
+            styles_syntax
+                .normalize_property_value("content", "'(' attr(data-label) ')'".to_string()),
+            Some("'(' attr(data-label) ')'".to_string())
+        );
+        assert_eq!(
+            styles_syntax.normalize_property_value("content", "rotate(45deg)".to_string()),
+            None
+        );
+        assert_eq!(
+            styles_syntax.normalize_property_value("display", "hello".to_string()),
+            Some("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_functions_with_correct_arities_are_valid() {
+        let styles_syntax = StyleSyntax::new();
+        let values = [
+            "translate(10px)",
+            "translate(10px, 20px)",
+            "translate3d(1px, 2px, 3px)",
+            "scale(2)",
+            "scale(2, 0.5)",
+            "rotate(45deg)",
+            "translate(calc(100% - 10px), 5px) rotate(90deg) scale(1.5)",
+            "none",
+        ];
+
+        for value in values {
+            assert_eq!(
+                styles_syntax.normalize_property_value("transform", value.to_string()),
+                Some(value.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn transform_functions_with_wrong_arities_are_not_valid() {
+        let styles_syntax = StyleSyntax::new();
+        let values = [
+            "translate(1px, 2px, 3px)",
+            "translate()",
+            "translate3d(1px, 2px)",
+            "translate3d(1px, 2px, 3px, 4px)",
+            "scale(1, 2, 3)",
+            "scale()",
+            "rotate(45deg, 90deg)",
+            "rotate()",
+            "scale(2) rotate(1deg, 2deg)",
+        ];
+
+        for value in values {
+            assert_eq!(
+                styles_syntax.normalize_property_value("transform", value.to_string()),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn css_wide_keywords_are_valid_for_any_property() {
+        let styles_syntax = StyleSyntax::new();
+
+        for (property, value) in [("display", "inherit"), ("color", "unset")] {
+            assert!(styles_syntax.is_valid_style_syntax(value));
+            assert_eq!(
+                styles_syntax.normalize_property_value(property, value.to_string()),
+                Some(value.to_string())
+            );
+        }
+
+        assert!(styles_syntax.is_valid_style_syntax("Revert-Layer"));
+        assert_eq!(
+            styles_syntax.normalize_property_value("content", "INITIAL".to_string()),
+            Some("INITIAL".to_string())
+        );
+        assert_eq!(
+            styles_syntax.normalize_property_value("content", "inheritt".to_string()),
+            Some("\"inheritt\"".to_string())
+        );
+    }
+
+    #[test]
+    fn hex_colors_are_lowercased() {
+        let styles_syntax = StyleSyntax::new();
+
+        assert_eq!(
+            styles_syntax.lowercase_hex_colors("#FF5733".to_string()),
+            "#ff5733".to_string()
+        );
+        assert_eq!(
+            styles_syntax.lowercase_hex_colors(
+                "1px solid #ABC, linear-gradient(#FFFFFF80, Red)".to_string()
+            ),
+            "1px solid #abc, linear-gradient(#ffffff80, Red)".to_string()
+        );
+        assert_eq!(
+            styles_syntax.lowercase_hex_colors("#ABCDE ${AccentColor} #GGG".to_string()),
+            "#ABCDE ${AccentColor} #GGG".to_string()
+        );
+    }
+
+    #[test]
+    fn whitespace_is_collapsed_outside_quotes_and_parentheses() {
+        let styles_syntax = StyleSyntax::new();
+
+        assert_eq!(
+            styles_syntax.collapse_whitespace("  10px  solid \t  red ".to_string()),
+            "10px solid red".to_string()
+        );
+        assert_eq!(
+            styles_syntax.collapse_whitespace("0  2px   rgba(0,  0, 0,   0.2)".to_string()),
+            "0 2px rgba(0,  0, 0,   0.2)".to_string()
+        );
+        assert_eq!(
+            styles_syntax.collapse_whitespace("calc(100%  -  10px)".to_string()),
+            "calc(100%  -  10px)".to_string()
+        );
+        assert_eq!(
+            styles_syntax.collapse_whitespace("'a  \\'  b'   \"c  d\"".to_string()),
+            "'a  \\'  b' \"c  d\"".to_string()
+        );
+    }
 }
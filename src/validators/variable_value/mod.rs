@@ -11,6 +11,10 @@ lazy_static! {
     static ref INVALID_KEYWORD_REGEX: Regex = Regex::new(r"^(undefined|invalid|NaN)$").unwrap();
     static ref INVALID_URL_REGEX: Regex = Regex::new(r#"url\(\s*\)"#).unwrap();
     static ref INVALID_PROPERTY_REGEX: Regex = Regex::new(r"^\w+\s*:\s*\w+$").unwrap();
+    static ref VALID_UNIT_REGEX: Regex = Regex::new(
+        r"^(%|px|cm|mm|Q|in|pt|pc|em|rem|ex|rex|cap|rcap|ch|rch|ic|ric|lh|rlh|vw|vh|vi|vb|vmin|vmax|svw|svh|svi|svb|svmin|svmax|lvw|lvh|lvi|lvb|lvmin|lvmax|dvw|dvh|dvi|dvb|dvmin|dvmax|cqw|cqh|cqi|cqb|cqmin|cqmax|deg|grad|rad|turn|s|ms|Hz|kHz|dpi|dpcm|dppx|x|fr)$"
+    )
+    .unwrap();
 
     //static ref NUMBER_WITHOUT_UNIT_REGEX: Regex = Regex::new(r"^\d+$").unwrap();
     //static ref INVALID_NUMBER_UNIT_REGEX: Regex = Regex::new(r"^\d+\s+[a-zA-Z]+$").unwrap();
@@ -60,6 +64,18 @@ pub trait NenyrVariableValueValidator {
             && !INVALID_URL_REGEX.is_match(variable_value)
             && !INVALID_PROPERTY_REGEX.is_match(variable_value)
     }
+
+    /// Validates the unit of an unquoted `number+unit` variable value, such as the `px` in `8px`.
+    ///
+    /// Returns `true` if the unit is a CSS length, angle, time, frequency, resolution,
+    /// flex or percentage unit, and `false` otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// - `unit`: A string slice representing the unit to validate.
+    fn is_valid_unit(&self, unit: &str) -> bool {
+        VALID_UNIT_REGEX.is_match(unit)
+    }
 }
 
 #[cfg(test)]
@@ -146,4 +162,34 @@ mod tests {
             assert!(!variable.is_valid_variable_value(&valid_variable));
         }
     }
+
+    #[test]
+    fn all_units_are_valid() {
+        let variable = Variable::new();
+        let valid_units = vec![
+            "px", "rem", "%", "vh", "dvw", "cqi", "deg", "ms", "fr", "dppx",
+        ];
+
+        for valid_unit in valid_units {
+            assert!(
+                variable.is_valid_unit(valid_unit),
+                "Unit '{}' should be valid.",
+                valid_unit
+            );
+        }
+    }
+
+    #[test]
+    fn all_units_are_not_valid() {
+        let variable = Variable::new();
+        let invalid_units = vec!["", "PX", "pxx", "foo", "%%", "e"];
+
+        for invalid_unit in invalid_units {
+            assert!(
+                !variable.is_valid_unit(invalid_unit),
+                "Unit '{}' should be invalid.",
+                invalid_unit
+            );
+        }
+    }
 }
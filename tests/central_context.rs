@@ -1,4 +1,9 @@
-use nenyr::NenyrParser;
+use nenyr::{
+    converters::css_emitter::{emit_css, emit_css_with, EmitOptions},
+    tokens::NenyrTokens,
+    types::declaration::DeclarationCounts,
+    NenyrParser,
+};
 
 #[test]
 fn central_context_is_valid() {
@@ -11,7 +16,7 @@ fn central_context_is_valid() {
 
             assert_eq!(
                 format!("{:?}", central_ast),
-                "Ok(CentralContext(CentralContext { imports: Some(NenyrImports { values: {\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": (), \"https://fonts.googleapis.com/css2?family=Roboto:ital,wght@0,100;0,300;0,400;0,500;0,700;0,900;1,100;1,300;1,400;1,500;1,700;1,900&display=swap\": (), \"https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap\": (), \"../../mocks/imports/another_external.css\": (), \"../../mocks/imports/external_styles.css\": (), \"../../mocks/imports/styles.css\": ()} }), typefaces: Some(NenyrTypefaces { values: {\"roseMartin\": \"../../mocks/typefaces/rosemartin.regular.otf\", \"regularEot\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.eot\", \"regularSvg\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.svg\", \"regularTtf\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.ttf\", \"regularWoff\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.woff\", \"regularWoff2\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.woff2\"} }), breakpoints: Some(NenyrBreakpoints { mobile_first: Some({\"onMobXs\": \"360px\", \"onMobSmall\": \"480px\", \"onMobMedium\": \"640px\", \"onMobTablet\": \"768px\", \"onMobLarge\": \"1024px\", \"onMobDesktop\": \"1280px\", \"onMobXl\": \"1536px\"}), desktop_first: Some({\"onDeskSmall\": \"1024px\", \"onDeskMedium\": \"1280px\", \"onDeskTablet\": \"1440px\", \"onDeskDesktop\": \"1600px\", \"onDeskXl\": \"1920px\", \"onDeskUltraWide\": \"2560px\"}) }), aliases: Some(NenyrAliases { values: {\"bgd\": \"background\", \"bgdColor\": \"background-color\", \"bgdImg\": \"background-image\", \"bgdSize\": \"background-size\", \"bd\": \"border\", \"bdT\": \"border-top\", \"bdB\": \"border-bottom\", \"bdL\": \"border-left\", \"bdR\": \"border-right\", \"bdColor\": \"border-color\", \"bdRadius\": \"border-radius\", \"boxShdw\": \"box-shadow\", \"dp\": \"display\", \"pos\": \"position\", \"flt\": \"float\", \"ovf\": \"overflow\", \"ovfX\": \"overflow-x\", \"ovfY\": \"overflow-y\", \"zIdx\": \"z-index\", \"flexDir\": \"flex-direction\", \"flexWrp\": \"flex-wrap\", \"algnItems\": \"align-items\", \"justifyCnt\": \"justify-content\", \"gridTpl\": \"grid-template\", \"wd\": \"width\", \"hgt\": \"height\", \"maxWd\": \"max-width\", \"minWd\": \"min-width\", \"maxHgt\": \"max-height\", \"minHgt\": \"min-height\", \"mg\": \"margin\", \"mgT\": \"margin-top\", \"mgB\": \"margin-bottom\", \"mgL\": \"margin-left\", \"mgR\": \"margin-right\", \"pdg\": \"padding\", \"pdgT\": \"padding-top\", \"pdgB\": \"padding-bottom\", \"pdgL\": \"padding-left\", \"pdgR\": \"padding-right\", \"gp\": \"gap\", \"fntSize\": \"font-size\", \"fntWeight\": \"font-weight\", \"fntFam\": \"font-family\", \"txtAlign\": \"text-align\", \"txtDec\": \"text-decoration\", \"txtTrnsf\": \"text-transform\", \"lineHgt\": \"line-height\", \"letterSpc\": \"letter-spacing\", \"wordSpc\": \"word-spacing\", \"clr\": \"color\", \"opcty\": \"opacity\", \"trnsfrm\": \"transform\", \"trnsfrmOrgn\": \"transform-origin\", \"trnstn\": \"transition\", \"trnstnDur\": \"transition-duration\", \"crsr\": \"cursor\", \"vis\": \"visibility\", \"fltShdw\": \"filter\"} }), variables: Some(NenyrVariables { values: {\"myColor\": \"#FF6677\", \"grayColor\": \"gray\", \"blueColor\": \"blue\", \"redColor\": \"red\", \"primaryColor\": \"yellow\", \"secondaryColor\": \"white\", \"accColor\": \"#FF5733\", \"darkGrayColor\": \"#333333\", \"lightGrayColor\": \"#D3D3D3\", \"bgdColor\": \"#FAFAFA\", \"borColor\": \"#CCCCCC\", \"highlightColor\": \"#FFD700\", \"shadowColor\": \"rgba(0, 0, 0, 0.2)\", \"linkColor\": \"#1E90FF\", \"successColor\": \"#4CAF50\", \"warningColor\": \"#FFA500\", \"dangerColor\": \"#DC143C\"} }), themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#FFFFFF\", \"secondaryColor\": \"#F0F0F0\", \"accentColorVar\": \"#3498DB\", \"bgColor\": \"#FAFAFA\", \"bdrColor\": \"#DDDDDD\", \"textColor\": \"#333333\", \"textSecondaryColor\": \"#666666\", \"highlightColor\": \"#FFDD57\", \"shadowColor\": \"rgba(0, 0, 0, 0.1)\"} }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": \"#1E1E1E\", \"secondaryColor\": \"#333333\", \"accentColorVar\": \"#FF4500\", \"bgColor\": \"#121212\", \"bdrColor\": \"#444444\", \"textColor\": \"#F0F0F0\", \"textSecondaryColor\": \"#AAAAAA\", \"highlightColor\": \"#FF8C00\", \"shadowColor\": \"rgba(0, 0, 0, 0.4)\"} }) }), animations: Some({\"slideScale\": NenyrAnimation { animation_name: \"slideScale\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [20.0], properties: {\"transform\": \"translateX(10%) scale(1.1)\"} }, Fraction { stops: [40.0, 60.0], properties: {\"transform\": \"translateX(30%) scale(1.2)\"} }, Fraction { stops: [80.0], properties: {\"transform\": \"translateX(50%) scale(0.9)\"} }, Fraction { stops: [100.0], properties: {\"transform\": \"translateX(0) scale(1)\"} }] }, \"fadeColorChange\": NenyrAnimation { animation_name: \"fadeColorChange\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [10.0], properties: {\"opacity\": \"0.1\", \"background-color\": \"${primaryColorVar}\"} }, Fraction { stops: [30.0, 60.0], properties: {\"opacity\": \"0.5\", \"background-color\": \"green\"} }, Fraction { stops: [90.0], properties: {\"opacity\": \"1\", \"background-color\": \"${secondaryColorVar}\"} }, Fraction { stops: [100.0], properties: {\"opacity\": \"0.8\", \"background-color\": \"purple\"} }] }, \"rotateScale\": NenyrAnimation { animation_name: \"rotateScale\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [25.0], properties: {\"transform\": \"rotate(15deg) scale(1.05)\"} }, Fraction { stops: [50.0, 75.0], properties: {\"transform\": \"rotate(30deg) scale(0.95)\"} }, Fraction { stops: [90.0], properties: {\"transform\": \"rotate(45deg) scale(1.15)\"} }, Fraction { stops: [100.0], properties: {\"transform\": \"rotate(0deg) scale(1)\"} }] }, \"borderFlash\": NenyrAnimation { animation_name: \"borderFlash\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [10.0], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }, Fraction { stops: [30.0, 50.0, 70.0], properties: {\"border-color\": \"red\", \"border-width\": \"3px\"} }, Fraction { stops: [90.0], properties: {\"border-color\": \"green\", \"border-width\": \"2px\"} }, Fraction { stops: [100.0], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }] }, \"bounceOpacity\": NenyrAnimation { animation_name: \"bounceOpacity\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [15.0], properties: {\"transform\": \"translateY(-20%)\", \"opacity\": \"0.3\"} }, Fraction { stops: [45.0, 65.0], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }, Fraction { stops: [85.0], properties: {\"transform\": \"translateY(20%)\", \"opacity\": \"0.7\"} }, Fraction { stops: [100.0], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }] }, \"floatScaleOpacity\": NenyrAnimation { animation_name: \"floatScaleOpacity\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [10.5], properties: {\"transform\": \"scale(0.8)\", \"opacity\": \"0.5\"} }, Fraction { stops: [25.5, 50.75], properties: {\"transform\": \"scale(1.2)\", \"opacity\": \"0.8\"} }, Fraction { stops: [75.25], properties: {\"transform\": \"scale(1.05)\", \"opacity\": \"1\"} }, Fraction { stops: [100.0], properties: {\"transform\": \"scale(1)\", \"opacity\": \"0.9\"} }] }, \"smoothColorFade\": NenyrAnimation { animation_name: \"smoothColorFade\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [5.5], properties: {\"background-color\": \"${highlightColorVar}\", \"opacity\": \"0.2\"} }, Fraction { stops: [30.25, 60.5], properties: {\"background-color\": \"lightblue\", \"opacity\": \"0.6\"} }, Fraction { stops: [85.75], properties: {\"background-color\": \"lightcoral\", \"opacity\": \"0.9\"} }, Fraction { stops: [100.0], properties: {\"background-color\": \"${backgroundColorVar}\", \"opacity\": \"1\"} }] }, \"complexRotateScale\": NenyrAnimation { animation_name: \"complexRotateScale\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [15.5], properties: {\"transform\": \"rotate(12.5deg) scale(0.95)\"} }, Fraction { stops: [40.25, 65.75], properties: {\"transform\": \"rotate(25.5deg) scale(1.1)\"} }, Fraction { stops: [85.5], properties: {\"transform\": \"rotate(37.5deg) scale(0.8)\"} }, Fraction { stops: [100.0], properties: {\"transform\": \"rotate(0deg) scale(1)\"} }] }, \"floatMoveOpacity\": NenyrAnimation { animation_name: \"floatMoveOpacity\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [8.5], properties: {\"transform\": \"translateY(-10.5%)\", \"opacity\": \"0.3\"} }, Fraction { stops: [35.5, 55.25], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }, Fraction { stops: [78.75], properties: {\"transform\": \"translateY(15.75%)\", \"opacity\": \"0.7\"} }, Fraction { stops: [100.0], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }] }, \"floatBorderFlash\": NenyrAnimation { animation_name: \"floatBorderFlash\", kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [12.5], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }, Fraction { stops: [35.75, 58.5, 78.25], properties: {\"border-color\": \"orange\", \"border-width\": \"3px\"} }, Fraction { stops: [90.5], properties: {\"border-color\": \"teal\", \"border-width\": \"2px\"} }, Fraction { stops: [100.0], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }] }, \"horizontalMove\": NenyrAnimation { animation_name: \"horizontalMove\", kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"transform\": \"translateX(0)\", \"background-color\": \"lightgray\"}), Progressive({\"transform\": \"translateX(50px)\", \"background-color\": \"lightblue\"}), Progressive({\"transform\": \"translateX(100px)\", \"background-color\": \"lightgreen\"}), Progressive({\"transform\": \"translateX(150px)\", \"background-color\": \"lightcoral\"}), Progressive({\"transform\": \"translateX(200px)\", \"background-color\": \"lightgoldenrodyellow\"})] }, \"fadeScale\": NenyrAnimation { animation_name: \"fadeScale\", kind: Some(Progressive), progressive_count: Some(4), keyframe: [Progressive({\"opacity\": \"0.2\", \"transform\": \"scale(0.8)\"}), Progressive({\"opacity\": \"0.5\", \"transform\": \"scale(1)\"}), Progressive({\"opacity\": \"0.8\", \"transform\": \"scale(1.2)\"}), Progressive({\"opacity\": \"1\", \"transform\": \"scale(1.1)\"})] }, \"colorBorderSize\": NenyrAnimation { animation_name: \"colorBorderSize\", kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"background-color\": \"lavender\", \"border\": \"2px solid ${primaryColorVar}\", \"height\": \"50px\", \"width\": \"50px\"}), Progressive({\"background-color\": \"lightpink\", \"border\": \"4px solid ${secondaryColorVar}\", \"height\": \"75px\", \"width\": \"75px\"}), Progressive({\"background-color\": \"lightyellow\", \"border\": \"6px solid ${accentColorVar}\", \"height\": \"100px\", \"width\": \"100px\"}), Progressive({\"background-color\": \"lightgreen\", \"border\": \"8px solid teal\", \"height\": \"125px\", \"width\": \"125px\"}), Progressive({\"background-color\": \"lightblue\", \"border\": \"10px solid navy\", \"height\": \"150px\", \"width\": \"150px\"})] }, \"rotateColorChange\": NenyrAnimation { animation_name: \"rotateColorChange\", kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"transform\": \"rotate(0deg)\", \"background-color\": \"white\"}), Progressive({\"transform\": \"rotate(45deg)\", \"background-color\": \"lightgray\"}), Progressive({\"transform\": \"rotate(90deg)\", \"background-color\": \"lightblue\"}), Progressive({\"transform\": \"rotate(135deg)\", \"background-color\": \"lightgreen\"}), Progressive({\"transform\": \"rotate(180deg)\", \"background-color\": \"lavender\"})] }, \"verticalBounce\": NenyrAnimation { animation_name: \"verticalBounce\", kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"transform\": \"translateY(0)\", \"border\": \"2px dashed ${highlightColorVar}\"}), Progressive({\"transform\": \"translateY(-20px)\", \"border\": \"2px solid orange\"}), Progressive({\"transform\": \"translateY(0)\", \"border\": \"3px solid ${highlightColorVar}\"}), Progressive({\"transform\": \"translateY(20px)\", \"border\": \"4px dotted teal\"}), Progressive({\"transform\": \"translateY(0)\", \"border\": \"2px dashed ${highlightColorVar}\"})] }, \"fadeAndScale\": NenyrAnimation { animation_name: \"fadeAndScale\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"opacity\": \"0\", \"transform\": \"scale(0.5)\"}), Halfway({\"opacity\": \"0.5\", \"transform\": \"scale(1)\"}), To({\"opacity\": \"1\", \"transform\": \"scale(1.2)\"})] }, \"colorAndBorderChange\": NenyrAnimation { animation_name: \"colorAndBorderChange\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"background-color\": \"lightgray\", \"border\": \"2px solid ${accentColorVar}\"}), Halfway({\"background-color\": \"lightblue\", \"border\": \"4px solid ${highlightColorVar}\"}), To({\"background-color\": \"lightgreen\", \"border\": \"6px solid teal\"})] }, \"verticalMoveAndRotate\": NenyrAnimation { animation_name: \"verticalMoveAndRotate\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"transform\": \"translateY(0) rotate(0deg)\"}), Halfway({\"transform\": \"translateY(-20px) rotate(45deg)\"}), To({\"transform\": \"translateY(0) rotate(90deg)\"})] }, \"textFadeAndColorChange\": NenyrAnimation { animation_name: \"textFadeAndColorChange\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"color\": \"${primaryTextColorVar}\", \"opacity\": \"0.2\"}), Halfway({\"color\": \"${secondaryTextColorVar}\", \"opacity\": \"0.6\"}), To({\"color\": \"darkblue\", \"opacity\": \"1\"})] }, \"expandWidthHeight\": NenyrAnimation { animation_name: \"expandWidthHeight\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"50px\", \"height\": \"50px\"}), Halfway({\"width\": \"100px\", \"height\": \"100px\"}), To({\"width\": \"150px\", \"height\": \"150px\"})] }, \"borderColorChange\": NenyrAnimation { animation_name: \"borderColorChange\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"border\": \"2px dashed ${myColorVar}\", \"background-color\": \"lightyellow\"}), Halfway({\"border\": \"4px dotted ${secondaryColorVar}\", \"background-color\": \"lightpink\"}), To({\"border\": \"6px solid ${highlightColorVar}\", \"background-color\": \"lavender\"})] }, \"translateAndScale\": NenyrAnimation { animation_name: \"translateAndScale\", kind: Some(Transitive), progressive_count: None, keyframe: [From({\"transform\": \"translateX(0) scale(1)\"}), Halfway({\"transform\": \"translateX(50px) scale(1.5)\"}), To({\"transform\": \"translateX(100px) scale(1)\"})] }}), classes: Some({\"celestialHeron\": NenyrStyleClass { class_name: \"celestialHeron\", deriving_from: Some(\"stardustFeather\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"nickname;bgdColor\": \"${primaryColor}\", \"nickname;clr\": \"${accColor}\", \"nickname;pdg\": \"${m20px30}\", \"nickname;dp\": \"flex\", \"align-items\": \"center\"}, \":hover\": {\"nickname;clr\": \"${secondaryColor}\", \"nickname;bd\": \"2px solid ${primaryColor}\"}, \"::after\": {\"content\": \"' '\", \"nickname;dp\": \"block\", \"nickname;wd\": \"100%\", \"nickname;hgt\": \"2px\", \"nickname;bgd\": \"${secondaryColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;dp\": \"block\", \"nickname;flexDir\": \"column\", \"nickname;pdg\": \"${m8px12}\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}) }, \"ancientPhoenix\": NenyrStyleClass { class_name: \"ancientPhoenix\", deriving_from: Some(\"fieryAura\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"nickname;bgdColor\": \"${accColor}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;fntSize\": \"1.2em\", \"nickname;pdg\": \"${m12px18}\", \"nickname;txtAlign\": \"center\", \"nickname;bdRadius\": \"8px\"}, \":hover\": {\"nickname;bgd\": \"${primaryColor}\", \"nickname;clr\": \"${secondaryColor}\", \"nickname;boxShdw\": \"0 4px 8px ${shadowColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;wd\": \"100%\", \"nickname;pdg\": \"${m8px12}\", \"nickname;fntSize\": \"1em\"}}, \"onDeskDesktop\": {\"::after\": {\"content\": \"'🔥'\", \"nickname;pos\": \"absolute\", \"right\": \"5px\", \"top\": \"5px\"}}}) }, \"emeraldRaven\": NenyrStyleClass { class_name: \"emeraldRaven\", deriving_from: Some(\"mysticShroud\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;bd\": \"3px solid ${primaryColor}\", \"nickname;bdRadius\": \"10px\", \"nickname;pdg\": \"${m20px30}\", \"text-shadow\": \"1px 1px 2px ${accColor}\"}, \":hover\": {\"nickname;bgdColor\": \"${primaryColor}\", \"nickname;clr\": \"${accColor}\", \"nickname;boxShdw\": \"0 6px 12px ${shadowColor}\"}, \"::before\": {\"content\": \"' '\", \"nickname;dp\": \"block\", \"nickname;wd\": \"100%\", \"nickname;hgt\": \"4px\", \"nickname;bgd\": \"${accColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;pdg\": \"${m15px20}\", \"nickname;fntSize\": \"0.9em\", \"nickname;bdRadius\": \"5px\"}}, \"onDeskDesktop\": {\"_stylesheet\": {\"nickname;pdg\": \"${m15px20}\", \"nickname;fntSize\": \"0.9em\", \"nickname;bdRadius\": \"5px\"}, \":hover\": {\"nickname;clr\": \"${secondaryColor}\", \"nickname;bgd\": \"${accColor}\"}, \"::after\": {\"content\": \"' '\", \"nickname;dp\": \"block\", \"nickname;wd\": \"50%\", \"nickname;hgt\": \"2px\", \"nickname;bgd\": \"${primaryColor}\", \"nickname;mgT\": \"10px\", \"nickname;mgB\": \"0\"}}}) }, \"nebulousLion\": NenyrStyleClass { class_name: \"nebulousLion\", deriving_from: Some(\"stellarMane\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m12px20}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;fntWeight\": \"bold\", \"nickname;letterSpc\": \"0.1em\", \"nickname;bd\": \"1px solid ${accColor}\"}, \":hover\": {\"nickname;bgd\": \"${accColor}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;boxShdw\": \"0 6px 12px ${shadowColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;pdg\": \"${m10px16}\", \"nickname;fntSize\": \"1em\"}}, \"onDeskDesktop\": {\"_stylesheet\": {\"nickname;pdg\": \"${m15px25}\", \"nickname;fntSize\": \"1.1em\"}, \"::after\": {\"content\": \"'✨'\", \"nickname;pos\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\", \"nickname;fntSize\": \"1.5em\"}, \":hover\": {\"nickname;bgd\": \"${accColor}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;boxShdw\": \"0 6px 12px ${shadowColor}\"}}}) }, \"luminousDragon\": NenyrStyleClass { class_name: \"luminousDragon\", deriving_from: Some(\"radiantWings\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"${primaryColor}\", \"color\": \"${accColor}\", \"padding\": \"${m20px30}\", \"display\": \"flex\", \"align-items\": \"center\"}, \":hover\": {\"color\": \"${secondaryColor}\", \"border\": \"2px solid ${primaryColor}\"}, \"::after\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"100%\", \"height\": \"2px\", \"background\": \"${secondaryColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\", \"flex-direction\": \"column\", \"padding\": \"${m8px12}\"}}, \"onDeskDesktop\": {\":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px}\"}}}) }, \"ancientGuardian\": NenyrStyleClass { class_name: \"ancientGuardian\", deriving_from: Some(\"fieryEmber\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"${accColor}\", \"color\": \"${primaryColor}\", \"font-size\": \"1.2em\", \"padding\": \"${m12px18}\", \"text-align\": \"center\", \"border-radius\": \"8px\"}, \":hover\": {\"background\": \"${primaryColor}\", \"color\": \"${secondaryColor}\", \"box-shadow\": \"0 4px 8px ${shadowColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"width\": \"100%\", \"padding\": \"${m8px12}\", \"font-size\": \"1em\"}}, \"onDeskDesktop\": {\"::after\": {\"content\": \"'🔥'\", \"position\": \"absolute\", \"right\": \"5px\", \"top\": \"5px\"}}}) }, \"mysticalPhoenix\": NenyrStyleClass { class_name: \"mysticalPhoenix\", deriving_from: Some(\"fieryWings\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background\": \"${secondaryColor}\", \"border\": \"3px solid ${primaryColor}\", \"border-radius\": \"10px\", \"padding\": \"${m20px30}\", \"text-shadow\": \"1px 1px 2px ${accColor}\"}, \":hover\": {\"background-color\": \"${primaryColor}\", \"color\": \"${accColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}, \"::before\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"100%\", \"height\": \"4px\", \"background\": \"${accColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"padding\": \"${m15px20}\", \"font-size\": \"0.9em\", \"border-radius\": \"5px\"}, \":hover\": {\"color\": \"${secondaryColor}\", \"background\": \"${accColor}\"}, \"::after\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"50%\", \"height\": \"2px\", \"background\": \"${primaryColor}\", \"margin-top\": \"10px\", \"margin-bottom\": \"0\"}}, \"onDeskDesktop\": {\":hover\": {\"color\": \"${secondaryColor}\", \"background\": \"${accColor}\"}, \"::after\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"50%\", \"height\": \"2px\", \"background\": \"${primaryColor}\", \"margin-top\": \"10px\", \"margin-bottom\": \"0\"}}}) }, \"celestialLion\": NenyrStyleClass { class_name: \"celestialLion\", deriving_from: Some(\"stellarPride\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m12px20}\", \"color\": \"${primaryColor}\", \"font-weight\": \"bold\", \"letter-spacing\": \"0.1em\", \"border\": \"1px solid ${accColor}\"}, \":hover\": {\"background\": \"${accColor}\", \"color\": \"${primaryColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}}), responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"padding\": \"${m10px16}\", \"font-size\": \"1em\"}, \":hover\": {\"background\": \"${accColor}\", \"color\": \"${primaryColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}, \"::after\": {\"content\": \"'✨'\", \"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\", \"font-size\": \"1.5em\"}, \"::before\": {\"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\"}}, \"onDeskDesktop\": {\"_stylesheet\": {\"padding\": \"${m15px25}\", \"font-size\": \"1.1em\"}, \":hover\": {\"background\": \"${accColor}\", \"color\": \"${primaryColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}, \"::after\": {\"content\": \"'✨'\", \"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\", \"font-size\": \"1.5em\"}, \"::before\": {\"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\"}}}) }}) }))".to_string()
+                "Ok(CentralContext(CentralContext { imports: Some(NenyrImports { values: {\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": None, \"https://fonts.googleapis.com/css2?family=Roboto:ital,wght@0,100;0,300;0,400;0,500;0,700;0,900;1,100;1,300;1,400;1,500;1,700;1,900&display=swap\": None, \"https://fonts.googleapis.com/css2?family=Bungee+Tint&display=swap\": None, \"../../mocks/imports/another_external.css\": None, \"../../mocks/imports/external_styles.css\": None, \"../../mocks/imports/styles.css\": None} }), typefaces: Some(NenyrTypefaces { values: {\"roseMartin\": \"../../mocks/typefaces/rosemartin.regular.otf\", \"regularEot\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.eot\", \"regularSvg\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.svg\", \"regularTtf\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.ttf\", \"regularWoff\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.woff\", \"regularWoff2\": \"../../mocks/typefaces/showa-source-curry.regular-webfont.woff2\"} }), breakpoints: Some(NenyrBreakpoints { mobile_first: Some({\"onMobXs\": \"360px\", \"onMobSmall\": \"480px\", \"onMobMedium\": \"640px\", \"onMobTablet\": \"768px\", \"onMobLarge\": \"1024px\", \"onMobDesktop\": \"1280px\", \"onMobXl\": \"1536px\"}), desktop_first: Some({\"onDeskSmall\": \"1024px\", \"onDeskMedium\": \"1280px\", \"onDeskTablet\": \"1440px\", \"onDeskDesktop\": \"1600px\", \"onDeskXl\": \"1920px\", \"onDeskUltraWide\": \"2560px\"}) }), aliases: Some(NenyrAliases { values: {\"bgd\": \"background\", \"bgdColor\": \"background-color\", \"bgdImg\": \"background-image\", \"bgdSize\": \"background-size\", \"bd\": \"border\", \"bdT\": \"border-top\", \"bdB\": \"border-bottom\", \"bdL\": \"border-left\", \"bdR\": \"border-right\", \"bdColor\": \"border-color\", \"bdRadius\": \"border-radius\", \"boxShdw\": \"box-shadow\", \"dp\": \"display\", \"pos\": \"position\", \"flt\": \"float\", \"ovf\": \"overflow\", \"ovfX\": \"overflow-x\", \"ovfY\": \"overflow-y\", \"zIdx\": \"z-index\", \"flexDir\": \"flex-direction\", \"flexWrp\": \"flex-wrap\", \"algnItems\": \"align-items\", \"justifyCnt\": \"justify-content\", \"gridTpl\": \"grid-template\", \"wd\": \"width\", \"hgt\": \"height\", \"maxWd\": \"max-width\", \"minWd\": \"min-width\", \"maxHgt\": \"max-height\", \"minHgt\": \"min-height\", \"mg\": \"margin\", \"mgT\": \"margin-top\", \"mgB\": \"margin-bottom\", \"mgL\": \"margin-left\", \"mgR\": \"margin-right\", \"pdg\": \"padding\", \"pdgT\": \"padding-top\", \"pdgB\": \"padding-bottom\", \"pdgL\": \"padding-left\", \"pdgR\": \"padding-right\", \"gp\": \"gap\", \"fntSize\": \"font-size\", \"fntWeight\": \"font-weight\", \"fntFam\": \"font-family\", \"txtAlign\": \"text-align\", \"txtDec\": \"text-decoration\", \"txtTrnsf\": \"text-transform\", \"lineHgt\": \"line-height\", \"letterSpc\": \"letter-spacing\", \"wordSpc\": \"word-spacing\", \"clr\": \"color\", \"opcty\": \"opacity\", \"trnsfrm\": \"transform\", \"trnsfrmOrgn\": \"transform-origin\", \"trnstn\": \"transition\", \"trnstnDur\": \"transition-duration\", \"crsr\": \"cursor\", \"vis\": \"visibility\", \"fltShdw\": \"filter\"} }), variables: Some(NenyrVariables { values: {\"myColor\": Text(\"#FF6677\"), \"grayColor\": Text(\"gray\"), \"blueColor\": Text(\"blue\"), \"redColor\": Text(\"red\"), \"primaryColor\": Text(\"yellow\"), \"secondaryColor\": Text(\"white\"), \"accColor\": Text(\"#FF5733\"), \"darkGrayColor\": Text(\"#333333\"), \"lightGrayColor\": Text(\"#D3D3D3\"), \"bgdColor\": Text(\"#FAFAFA\"), \"borColor\": Text(\"#CCCCCC\"), \"highlightColor\": Text(\"#FFD700\"), \"shadowColor\": Text(\"rgba(0, 0, 0, 0.2)\"), \"linkColor\": Text(\"#1E90FF\"), \"successColor\": Text(\"#4CAF50\"), \"warningColor\": Text(\"#FFA500\"), \"dangerColor\": Text(\"#DC143C\")}, dimensions: None, expressions: None }), consts: None, themes: Some(NenyrThemes { light_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#FFFFFF\"), \"secondaryColor\": Text(\"#F0F0F0\"), \"accentColorVar\": Text(\"#3498DB\"), \"bgColor\": Text(\"#FAFAFA\"), \"bdrColor\": Text(\"#DDDDDD\"), \"textColor\": Text(\"#333333\"), \"textSecondaryColor\": Text(\"#666666\"), \"highlightColor\": Text(\"#FFDD57\"), \"shadowColor\": Text(\"rgba(0, 0, 0, 0.1)\")}, dimensions: None, expressions: None }), dark_schema: Some(NenyrVariables { values: {\"primaryColor\": Text(\"#1E1E1E\"), \"secondaryColor\": Text(\"#333333\"), \"accentColorVar\": Text(\"#FF4500\"), \"bgColor\": Text(\"#121212\"), \"bdrColor\": Text(\"#444444\"), \"textColor\": Text(\"#F0F0F0\"), \"textSecondaryColor\": Text(\"#AAAAAA\"), \"highlightColor\": Text(\"#FF8C00\"), \"shadowColor\": Text(\"rgba(0, 0, 0, 0.4)\")}, dimensions: None, expressions: None }), light_aliases: None, dark_aliases: None, light_typefaces: None, dark_typefaces: None }), mixins: None, animations: Some({\"slideScale\": NenyrAnimation { animation_name: \"slideScale\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [20.0], is_percentage: [false], properties: {\"transform\": \"translateX(10%) scale(1.1)\"} }, Fraction { stops: [40.0, 60.0], is_percentage: [false, false], properties: {\"transform\": \"translateX(30%) scale(1.2)\"} }, Fraction { stops: [80.0], is_percentage: [false], properties: {\"transform\": \"translateX(50%) scale(0.9)\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"transform\": \"translateX(0) scale(1)\"} }] }, \"fadeColorChange\": NenyrAnimation { animation_name: \"fadeColorChange\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [10.0], is_percentage: [false], properties: {\"opacity\": \"0.1\", \"background-color\": \"${primaryColorVar}\"} }, Fraction { stops: [30.0, 60.0], is_percentage: [false, false], properties: {\"opacity\": \"0.5\", \"background-color\": \"green\"} }, Fraction { stops: [90.0], is_percentage: [false], properties: {\"opacity\": \"1\", \"background-color\": \"${secondaryColorVar}\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"opacity\": \"0.8\", \"background-color\": \"purple\"} }] }, \"rotateScale\": NenyrAnimation { animation_name: \"rotateScale\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [25.0], is_percentage: [false], properties: {\"transform\": \"rotate(15deg) scale(1.05)\"} }, Fraction { stops: [50.0, 75.0], is_percentage: [false, false], properties: {\"transform\": \"rotate(30deg) scale(0.95)\"} }, Fraction { stops: [90.0], is_percentage: [false], properties: {\"transform\": \"rotate(45deg) scale(1.15)\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"transform\": \"rotate(0deg) scale(1)\"} }] }, \"borderFlash\": NenyrAnimation { animation_name: \"borderFlash\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [10.0], is_percentage: [false], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }, Fraction { stops: [30.0, 50.0, 70.0], is_percentage: [false, false, false], properties: {\"border-color\": \"red\", \"border-width\": \"3px\"} }, Fraction { stops: [90.0], is_percentage: [false], properties: {\"border-color\": \"green\", \"border-width\": \"2px\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }] }, \"bounceOpacity\": NenyrAnimation { animation_name: \"bounceOpacity\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [15.0], is_percentage: [false], properties: {\"transform\": \"translateY(-20%)\", \"opacity\": \"0.3\"} }, Fraction { stops: [45.0, 65.0], is_percentage: [false, false], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }, Fraction { stops: [85.0], is_percentage: [false], properties: {\"transform\": \"translateY(20%)\", \"opacity\": \"0.7\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }] }, \"floatScaleOpacity\": NenyrAnimation { animation_name: \"floatScaleOpacity\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [10.5], is_percentage: [false], properties: {\"transform\": \"scale(0.8)\", \"opacity\": \"0.5\"} }, Fraction { stops: [25.5, 50.75], is_percentage: [false, false], properties: {\"transform\": \"scale(1.2)\", \"opacity\": \"0.8\"} }, Fraction { stops: [75.25], is_percentage: [false], properties: {\"transform\": \"scale(1.05)\", \"opacity\": \"1\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"transform\": \"scale(1)\", \"opacity\": \"0.9\"} }] }, \"smoothColorFade\": NenyrAnimation { animation_name: \"smoothColorFade\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [5.5], is_percentage: [false], properties: {\"background-color\": \"${highlightColorVar}\", \"opacity\": \"0.2\"} }, Fraction { stops: [30.25, 60.5], is_percentage: [false, false], properties: {\"background-color\": \"lightblue\", \"opacity\": \"0.6\"} }, Fraction { stops: [85.75], is_percentage: [false], properties: {\"background-color\": \"lightcoral\", \"opacity\": \"0.9\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"background-color\": \"${backgroundColorVar}\", \"opacity\": \"1\"} }] }, \"complexRotateScale\": NenyrAnimation { animation_name: \"complexRotateScale\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [15.5], is_percentage: [false], properties: {\"transform\": \"rotate(12.5deg) scale(0.95)\"} }, Fraction { stops: [40.25, 65.75], is_percentage: [false, false], properties: {\"transform\": \"rotate(25.5deg) scale(1.1)\"} }, Fraction { stops: [85.5], is_percentage: [false], properties: {\"transform\": \"rotate(37.5deg) scale(0.8)\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"transform\": \"rotate(0deg) scale(1)\"} }] }, \"floatMoveOpacity\": NenyrAnimation { animation_name: \"floatMoveOpacity\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [8.5], is_percentage: [false], properties: {\"transform\": \"translateY(-10.5%)\", \"opacity\": \"0.3\"} }, Fraction { stops: [35.5, 55.25], is_percentage: [false, false], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }, Fraction { stops: [78.75], is_percentage: [false], properties: {\"transform\": \"translateY(15.75%)\", \"opacity\": \"0.7\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"transform\": \"translateY(0)\", \"opacity\": \"1\"} }] }, \"floatBorderFlash\": NenyrAnimation { animation_name: \"floatBorderFlash\", is_keyframes_declaration: false, kind: Some(Fraction), progressive_count: None, keyframe: [Fraction { stops: [12.5], is_percentage: [false], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }, Fraction { stops: [35.75, 58.5, 78.25], is_percentage: [false, false, false], properties: {\"border-color\": \"orange\", \"border-width\": \"3px\"} }, Fraction { stops: [90.5], is_percentage: [false], properties: {\"border-color\": \"teal\", \"border-width\": \"2px\"} }, Fraction { stops: [100.0], is_percentage: [false], properties: {\"border-color\": \"${accentColorVar}\", \"border-width\": \"1px\"} }] }, \"horizontalMove\": NenyrAnimation { animation_name: \"horizontalMove\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"transform\": \"translateX(0)\", \"background-color\": \"lightgray\"}), Progressive({\"transform\": \"translateX(50px)\", \"background-color\": \"lightblue\"}), Progressive({\"transform\": \"translateX(100px)\", \"background-color\": \"lightgreen\"}), Progressive({\"transform\": \"translateX(150px)\", \"background-color\": \"lightcoral\"}), Progressive({\"transform\": \"translateX(200px)\", \"background-color\": \"lightgoldenrodyellow\"})] }, \"fadeScale\": NenyrAnimation { animation_name: \"fadeScale\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(4), keyframe: [Progressive({\"opacity\": \"0.2\", \"transform\": \"scale(0.8)\"}), Progressive({\"opacity\": \"0.5\", \"transform\": \"scale(1)\"}), Progressive({\"opacity\": \"0.8\", \"transform\": \"scale(1.2)\"}), Progressive({\"opacity\": \"1\", \"transform\": \"scale(1.1)\"})] }, \"colorBorderSize\": NenyrAnimation { animation_name: \"colorBorderSize\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"background-color\": \"lavender\", \"border\": \"2px solid ${primaryColorVar}\", \"height\": \"50px\", \"width\": \"50px\"}), Progressive({\"background-color\": \"lightpink\", \"border\": \"4px solid ${secondaryColorVar}\", \"height\": \"75px\", \"width\": \"75px\"}), Progressive({\"background-color\": \"lightyellow\", \"border\": \"6px solid ${accentColorVar}\", \"height\": \"100px\", \"width\": \"100px\"}), Progressive({\"background-color\": \"lightgreen\", \"border\": \"8px solid teal\", \"height\": \"125px\", \"width\": \"125px\"}), Progressive({\"background-color\": \"lightblue\", \"border\": \"10px solid navy\", \"height\": \"150px\", \"width\": \"150px\"})] }, \"rotateColorChange\": NenyrAnimation { animation_name: \"rotateColorChange\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"transform\": \"rotate(0deg)\", \"background-color\": \"white\"}), Progressive({\"transform\": \"rotate(45deg)\", \"background-color\": \"lightgray\"}), Progressive({\"transform\": \"rotate(90deg)\", \"background-color\": \"lightblue\"}), Progressive({\"transform\": \"rotate(135deg)\", \"background-color\": \"lightgreen\"}), Progressive({\"transform\": \"rotate(180deg)\", \"background-color\": \"lavender\"})] }, \"verticalBounce\": NenyrAnimation { animation_name: \"verticalBounce\", is_keyframes_declaration: false, kind: Some(Progressive), progressive_count: Some(5), keyframe: [Progressive({\"transform\": \"translateY(0)\", \"border\": \"2px dashed ${highlightColorVar}\"}), Progressive({\"transform\": \"translateY(-20px)\", \"border\": \"2px solid orange\"}), Progressive({\"transform\": \"translateY(0)\", \"border\": \"3px solid ${highlightColorVar}\"}), Progressive({\"transform\": \"translateY(20px)\", \"border\": \"4px dotted teal\"}), Progressive({\"transform\": \"translateY(0)\", \"border\": \"2px dashed ${highlightColorVar}\"})] }, \"fadeAndScale\": NenyrAnimation { animation_name: \"fadeAndScale\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"opacity\": \"0\", \"transform\": \"scale(0.5)\"}), Halfway({\"opacity\": \"0.5\", \"transform\": \"scale(1)\"}), To({\"opacity\": \"1\", \"transform\": \"scale(1.2)\"})] }, \"colorAndBorderChange\": NenyrAnimation { animation_name: \"colorAndBorderChange\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"background-color\": \"lightgray\", \"border\": \"2px solid ${accentColorVar}\"}), Halfway({\"background-color\": \"lightblue\", \"border\": \"4px solid ${highlightColorVar}\"}), To({\"background-color\": \"lightgreen\", \"border\": \"6px solid teal\"})] }, \"verticalMoveAndRotate\": NenyrAnimation { animation_name: \"verticalMoveAndRotate\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"transform\": \"translateY(0) rotate(0deg)\"}), Halfway({\"transform\": \"translateY(-20px) rotate(45deg)\"}), To({\"transform\": \"translateY(0) rotate(90deg)\"})] }, \"textFadeAndColorChange\": NenyrAnimation { animation_name: \"textFadeAndColorChange\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"color\": \"${primaryTextColorVar}\", \"opacity\": \"0.2\"}), Halfway({\"color\": \"${secondaryTextColorVar}\", \"opacity\": \"0.6\"}), To({\"color\": \"darkblue\", \"opacity\": \"1\"})] }, \"expandWidthHeight\": NenyrAnimation { animation_name: \"expandWidthHeight\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"width\": \"50px\", \"height\": \"50px\"}), Halfway({\"width\": \"100px\", \"height\": \"100px\"}), To({\"width\": \"150px\", \"height\": \"150px\"})] }, \"borderColorChange\": NenyrAnimation { animation_name: \"borderColorChange\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"border\": \"2px dashed ${myColorVar}\", \"background-color\": \"lightyellow\"}), Halfway({\"border\": \"4px dotted ${secondaryColorVar}\", \"background-color\": \"lightpink\"}), To({\"border\": \"6px solid ${highlightColorVar}\", \"background-color\": \"lavender\"})] }, \"translateAndScale\": NenyrAnimation { animation_name: \"translateAndScale\", is_keyframes_declaration: false, kind: Some(Transitive), progressive_count: None, keyframe: [From({\"transform\": \"translateX(0) scale(1)\"}), Halfway({\"transform\": \"translateX(50px) scale(1.5)\"}), To({\"transform\": \"translateX(100px) scale(1)\"})] }}), classes: Some({\"celestialHeron\": NenyrStyleClass { class_name: \"celestialHeron\", deriving_from: Some(\"stardustFeather\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"nickname;bgdColor\": \"${primaryColor}\", \"nickname;clr\": \"${accColor}\", \"nickname;pdg\": \"${m20px30}\", \"nickname;dp\": \"flex\", \"align-items\": \"center\"}, \":hover\": {\"nickname;clr\": \"${secondaryColor}\", \"nickname;bd\": \"2px solid ${primaryColor}\"}, \"::after\": {\"content\": \"' '\", \"nickname;dp\": \"block\", \"nickname;wd\": \"100%\", \"nickname;hgt\": \"2px\", \"nickname;bgd\": \"${secondaryColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;dp\": \"block\", \"nickname;flexDir\": \"column\", \"nickname;pdg\": \"${m8px12}\"}}, \"onDeskDesktop\": {\":hover\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m15px}\"}}}), media_patterns: None }, \"ancientPhoenix\": NenyrStyleClass { class_name: \"ancientPhoenix\", deriving_from: Some(\"fieryAura\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"nickname;bgdColor\": \"${accColor}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;fntSize\": \"1.2em\", \"nickname;pdg\": \"${m12px18}\", \"nickname;txtAlign\": \"center\", \"nickname;bdRadius\": \"8px\"}, \":hover\": {\"nickname;bgd\": \"${primaryColor}\", \"nickname;clr\": \"${secondaryColor}\", \"nickname;boxShdw\": \"0 4px 8px ${shadowColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;wd\": \"100%\", \"nickname;pdg\": \"${m8px12}\", \"nickname;fntSize\": \"1em\"}}, \"onDeskDesktop\": {\"::after\": {\"content\": \"'🔥'\", \"nickname;pos\": \"absolute\", \"right\": \"5px\", \"top\": \"5px\"}}}), media_patterns: None }, \"emeraldRaven\": NenyrStyleClass { class_name: \"emeraldRaven\", deriving_from: Some(\"mysticShroud\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;bd\": \"3px solid ${primaryColor}\", \"nickname;bdRadius\": \"10px\", \"nickname;pdg\": \"${m20px30}\", \"text-shadow\": \"1px 1px 2px ${accColor}\"}, \":hover\": {\"nickname;bgdColor\": \"${primaryColor}\", \"nickname;clr\": \"${accColor}\", \"nickname;boxShdw\": \"0 6px 12px ${shadowColor}\"}, \"::before\": {\"content\": \"' '\", \"nickname;dp\": \"block\", \"nickname;wd\": \"100%\", \"nickname;hgt\": \"4px\", \"nickname;bgd\": \"${accColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;pdg\": \"${m15px20}\", \"nickname;fntSize\": \"0.9em\", \"nickname;bdRadius\": \"5px\"}}, \"onDeskDesktop\": {\"_stylesheet\": {\"nickname;pdg\": \"${m15px20}\", \"nickname;fntSize\": \"0.9em\", \"nickname;bdRadius\": \"5px\"}, \":hover\": {\"nickname;clr\": \"${secondaryColor}\", \"nickname;bgd\": \"${accColor}\"}, \"::after\": {\"content\": \"' '\", \"nickname;dp\": \"block\", \"nickname;wd\": \"50%\", \"nickname;hgt\": \"2px\", \"nickname;bgd\": \"${primaryColor}\", \"nickname;mgT\": \"10px\", \"nickname;mgB\": \"0\"}}}), media_patterns: None }, \"nebulousLion\": NenyrStyleClass { class_name: \"nebulousLion\", deriving_from: Some(\"stellarMane\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"nickname;bgd\": \"${secondaryColor}\", \"nickname;pdg\": \"${m12px20}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;fntWeight\": \"bold\", \"nickname;letterSpc\": \"0.1em\", \"nickname;bd\": \"1px solid ${accColor}\"}, \":hover\": {\"nickname;bgd\": \"${accColor}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;boxShdw\": \"0 6px 12px ${shadowColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"nickname;pdg\": \"${m10px16}\", \"nickname;fntSize\": \"1em\"}}, \"onDeskDesktop\": {\"_stylesheet\": {\"nickname;pdg\": \"${m15px25}\", \"nickname;fntSize\": \"1.1em\"}, \"::after\": {\"content\": \"'✨'\", \"nickname;pos\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\", \"nickname;fntSize\": \"1.5em\"}, \":hover\": {\"nickname;bgd\": \"${accColor}\", \"nickname;clr\": \"${primaryColor}\", \"nickname;boxShdw\": \"0 6px 12px ${shadowColor}\"}}}), media_patterns: None }, \"luminousDragon\": NenyrStyleClass { class_name: \"luminousDragon\", deriving_from: Some(\"radiantWings\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background-color\": \"${primaryColor}\", \"color\": \"${accColor}\", \"padding\": \"${m20px30}\", \"display\": \"flex\", \"align-items\": \"center\"}, \":hover\": {\"color\": \"${secondaryColor}\", \"border\": \"2px solid ${primaryColor}\"}, \"::after\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"100%\", \"height\": \"2px\", \"background\": \"${secondaryColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"display\": \"block\", \"flex-direction\": \"column\", \"padding\": \"${m8px12}\"}}, \"onDeskDesktop\": {\":hover\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m15px}\"}}}), media_patterns: None }, \"ancientGuardian\": NenyrStyleClass { class_name: \"ancientGuardian\", deriving_from: Some(\"fieryEmber\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"background-color\": \"${accColor}\", \"color\": \"${primaryColor}\", \"font-size\": \"1.2em\", \"padding\": \"${m12px18}\", \"text-align\": \"center\", \"border-radius\": \"8px\"}, \":hover\": {\"background\": \"${primaryColor}\", \"color\": \"${secondaryColor}\", \"box-shadow\": \"0 4px 8px ${shadowColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"width\": \"100%\", \"padding\": \"${m8px12}\", \"font-size\": \"1em\"}}, \"onDeskDesktop\": {\"::after\": {\"content\": \"'🔥'\", \"position\": \"absolute\", \"right\": \"5px\", \"top\": \"5px\"}}}), media_patterns: None }, \"mysticalPhoenix\": NenyrStyleClass { class_name: \"mysticalPhoenix\", deriving_from: Some(\"fieryWings\"), is_important: Some(true), style_patterns: Some({\"_stylesheet\": {\"background\": \"${secondaryColor}\", \"border\": \"3px solid ${primaryColor}\", \"border-radius\": \"10px\", \"padding\": \"${m20px30}\", \"text-shadow\": \"1px 1px 2px ${accColor}\"}, \":hover\": {\"background-color\": \"${primaryColor}\", \"color\": \"${accColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}, \"::before\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"100%\", \"height\": \"4px\", \"background\": \"${accColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"padding\": \"${m15px20}\", \"font-size\": \"0.9em\", \"border-radius\": \"5px\"}, \":hover\": {\"color\": \"${secondaryColor}\", \"background\": \"${accColor}\"}, \"::after\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"50%\", \"height\": \"2px\", \"background\": \"${primaryColor}\", \"margin-top\": \"10px\", \"margin-bottom\": \"0\"}}, \"onDeskDesktop\": {\":hover\": {\"color\": \"${secondaryColor}\", \"background\": \"${accColor}\"}, \"::after\": {\"content\": \"''\", \"display\": \"block\", \"width\": \"50%\", \"height\": \"2px\", \"background\": \"${primaryColor}\", \"margin-top\": \"10px\", \"margin-bottom\": \"0\"}}}), media_patterns: None }, \"celestialLion\": NenyrStyleClass { class_name: \"celestialLion\", deriving_from: Some(\"stellarPride\"), is_important: None, style_patterns: Some({\"_stylesheet\": {\"background\": \"${secondaryColor}\", \"padding\": \"${m12px20}\", \"color\": \"${primaryColor}\", \"font-weight\": \"bold\", \"letter-spacing\": \"0.1em\", \"border\": \"1px solid ${accColor}\"}, \":hover\": {\"background\": \"${accColor}\", \"color\": \"${primaryColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}}), supports_patterns: None, container_patterns: None, responsive_patterns: Some({\"onMobTablet\": {\"_stylesheet\": {\"padding\": \"${m10px16}\", \"font-size\": \"1em\"}, \":hover\": {\"background\": \"${accColor}\", \"color\": \"${primaryColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}, \"::after\": {\"content\": \"'✨'\", \"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\", \"font-size\": \"1.5em\"}, \"::before\": {\"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\"}}, \"onDeskDesktop\": {\"_stylesheet\": {\"padding\": \"${m15px25}\", \"font-size\": \"1.1em\"}, \":hover\": {\"background\": \"${accColor}\", \"color\": \"${primaryColor}\", \"box-shadow\": \"0 6px 12px ${shadowColor}\"}, \"::after\": {\"content\": \"'✨'\", \"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\", \"font-size\": \"1.5em\"}, \"::before\": {\"position\": \"absolute\", \"top\": \"10px\", \"left\": \"10px\"}}}), media_patterns: None }}) }))".to_string()
             );
         }
         Err(err) => {
@@ -19,3 +24,298 @@ fn central_context_is_valid() {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn central_context_must_round_trip_through_json() {
+    let mut parser = NenyrParser::new();
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            let central_ast = parser
+                .parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string())
+                .unwrap();
+
+            let serialized_ast = serde_json::to_string(&central_ast).unwrap();
+            let deserialized_ast: nenyr::types::ast::NenyrAst =
+                serde_json::from_str(&serialized_ast).unwrap();
+
+            assert_eq!(deserialized_ast, central_ast);
+            assert_eq!(
+                format!("{:?}", deserialized_ast),
+                format!("{:?}", central_ast)
+            );
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_declarations_must_reach_the_sink() {
+    let declarations_count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let sink_count = declarations_count.clone();
+    let mut parser = NenyrParser::new().with_declaration_sink(move |_| {
+        sink_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    });
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            let central_ast =
+                parser.parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string());
+
+            assert_eq!(
+                format!("{:?}", central_ast),
+                "Ok(CentralContext(CentralContext { imports: None, typefaces: None, breakpoints: None, aliases: None, variables: None, consts: None, themes: None, mixins: None, animations: None, classes: None }))".to_string()
+            );
+            assert_eq!(
+                declarations_count.load(std::sync::atomic::Ordering::SeqCst),
+                36
+            );
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_sunk_declarations_must_be_kept_when_enabled() {
+    let declarations_count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let sink_count = declarations_count.clone();
+    let mut parser = NenyrParser::new().with_declaration_sink(move |_| {
+        sink_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    });
+
+    parser.set_sunk_declarations_kept(true);
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            let central_ast =
+                parser.parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string());
+
+            assert_eq!(
+                central_ast,
+                NenyrParser::new()
+                    .parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string())
+            );
+            assert_eq!(
+                declarations_count.load(std::sync::atomic::Ordering::SeqCst),
+                36
+            );
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_declarations_must_be_iterable() {
+    let mut parser = NenyrParser::new();
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            let central_ast =
+                parser.parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string());
+
+            if let Ok(nenyr::types::ast::NenyrAst::CentralContext(central_context)) = central_ast {
+                assert_eq!(central_context.all_declarations().count(), 346);
+                assert!(central_context
+                    .all_declarations()
+                    .any(|declaration| declaration == ("celestialHeron", "nickname;hgt", "2px")));
+            } else {
+                panic!("{:?}", central_ast);
+            }
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_declarations_must_be_estimated() {
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            assert_eq!(
+                NenyrParser::estimate_declarations(&raw_nenyr),
+                raw_nenyr.matches("Declare").count()
+            );
+            assert_eq!(NenyrParser::estimate_declarations(&raw_nenyr), 36);
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_parsing_must_be_measured() {
+    let mut parser = NenyrParser::new();
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            match parser.parse_timed(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string()) {
+                Ok((nenyr::types::ast::NenyrAst::CentralContext(_), stats)) => {
+                    assert!(stats.token_count > 0);
+                    assert_eq!(
+                        stats.declaration_count,
+                        NenyrParser::estimate_declarations(&raw_nenyr)
+                    );
+                }
+                other => panic!("{:?}", other),
+            }
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_animations_must_be_iterated_in_declaration_order() {
+    let mut parser = NenyrParser::new();
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            let central_ast =
+                parser.parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string());
+
+            if let Ok(nenyr::types::ast::NenyrAst::CentralContext(central_context)) = central_ast {
+                let mut animation_names = vec![];
+
+                for (animation_name, _) in central_context.animations() {
+                    animation_names.push(animation_name);
+                }
+
+                assert_eq!(animation_names.len(), 22);
+                assert_eq!(animation_names[0], "slideScale");
+                assert_eq!(animation_names[1], "fadeColorChange");
+                assert_eq!(animation_names[21], "translateAndScale");
+                assert_eq!(
+                    central_context.classes().names().collect::<Vec<_>>(),
+                    central_context
+                        .classes
+                        .as_ref()
+                        .unwrap()
+                        .keys()
+                        .map(|class_name| class_name.as_str())
+                        .collect::<Vec<_>>()
+                );
+            } else {
+                panic!("{:?}", central_ast);
+            }
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_classes_must_be_found_by_pattern() {
+    let mut parser = NenyrParser::new();
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            let central_ast =
+                parser.parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string());
+
+            if let Ok(nenyr::types::ast::NenyrAst::CentralContext(central_context)) = central_ast {
+                assert_eq!(
+                    central_context.classes_with_pattern(&NenyrTokens::Hover),
+                    vec![
+                        "celestialHeron",
+                        "ancientPhoenix",
+                        "emeraldRaven",
+                        "nebulousLion",
+                        "luminousDragon",
+                        "ancientGuardian",
+                        "mysticalPhoenix",
+                        "celestialLion"
+                    ]
+                );
+                assert_eq!(
+                    central_context.classes_with_pattern(&NenyrTokens::Before),
+                    vec!["emeraldRaven", "mysticalPhoenix", "celestialLion"]
+                );
+                assert!(central_context
+                    .classes_with_pattern(&NenyrTokens::Focus)
+                    .is_empty());
+                assert!(central_context
+                    .classes_with_pattern(&NenyrTokens::Class)
+                    .is_empty());
+            } else {
+                panic!("{:?}", central_ast);
+            }
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_variables_must_be_emitted_as_custom_properties_or_inline() {
+    let mut parser = NenyrParser::new();
+
+    match std::fs::read_to_string("mocks/nenyr/central.nyr") {
+        Ok(raw_nenyr) => {
+            let central_ast = parser
+                .parse(raw_nenyr.to_string(), "mocks/nenyr/central.nyr".to_string())
+                .unwrap();
+            let custom_properties_css = emit_css_with(
+                &central_ast,
+                EmitOptions {
+                    custom_properties: true,
+                    ..EmitOptions::default()
+                },
+            );
+            let inline_css = emit_css(&central_ast);
+
+            assert!(custom_properties_css.starts_with(":root {\n    --myColor: #FF6677;\n"));
+            assert!(custom_properties_css.contains("    --primaryColor: yellow;\n"));
+            assert!(custom_properties_css.contains("    --accColor: #FF5733;\n"));
+            assert!(custom_properties_css.contains(
+                ".celestialHeron {\n    background-color: var(--primaryColor) !important;\n    color: var(--accColor) !important;\n"
+            ));
+            assert!(custom_properties_css
+                .contains("    border: 2px solid var(--primaryColor) !important;\n"));
+
+            assert!(!inline_css.contains(":root"));
+            assert!(!inline_css.contains("var(--"));
+            assert!(inline_css.contains(
+                ".celestialHeron {\n    background-color: yellow !important;\n    color: #FF5733 !important;\n"
+            ));
+            assert!(inline_css.contains("    border: 2px solid yellow !important;\n"));
+        }
+        Err(err) => {
+            panic!("{:?}", err);
+        }
+    }
+}
+
+#[test]
+fn central_context_declarations_are_counted() {
+    let mut parser = NenyrParser::new();
+    let raw_nenyr = std::fs::read_to_string("mocks/nenyr/central.nyr").unwrap();
+    let central_ast = parser
+        .parse(raw_nenyr, "mocks/nenyr/central.nyr".to_string())
+        .unwrap();
+
+    assert_eq!(
+        central_ast.declaration_counts(),
+        DeclarationCounts {
+            classes: 8,
+            animations: 22,
+            variables: 17,
+            aliases: 59,
+            themes: 2,
+            imports: 6,
+            typefaces: 6,
+            breakpoints: 13,
+        }
+    );
+}